@@ -12,6 +12,7 @@ pub struct OrdersClient {
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct AdminUpdateOrderPayoutRequest {
+    pub customer_id: String,
     pub order_id: String,
     pub payout: Money,
 }
@@ -1,6 +1,6 @@
 use crate::clients::common::{send, Result};
 use crate::models::money::Money;
-use crate::models::part::Part;
+use crate::models::part::{Part, QuantityTier};
 use gloo_net::http::Request;
 use serde_derive::{Deserialize, Serialize};
 use web_sys::RequestCredentials;
@@ -19,6 +19,7 @@ pub struct CreatePartQuotesRequestData {
     pub unit_price: Money,
     pub sub_total: Money,
     pub workdays_to_complete: u64,
+    pub quantity_tiers: Option<Vec<QuantityTier>>,
     pub quantity: u64,
 }
 
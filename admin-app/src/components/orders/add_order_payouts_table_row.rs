@@ -21,6 +21,7 @@ pub fn AddOrderPayoutsTableRow(
     // -- actions -- //
     let update_order_payout = create_action(move |_| {
         let request = AdminUpdateOrderPayoutRequest {
+            customer_id: order.customer_id.clone(),
             order_id: order.id.clone(),
             payout: payout.get_untracked().unwrap(),
         };
@@ -1,4 +1,5 @@
 use crate::models::money::Money;
+use crate::models::part::PartAttributes;
 use leptos::*;
 use thaw::{Input, InputNumber, InputPrefix};
 
@@ -6,7 +7,12 @@ use thaw::{Input, InputNumber, InputPrefix};
 pub fn PartQuoteCard(
     #[prop(into)] price_option: RwSignal<Option<Money>>,
     #[prop(into)] workdays_to_complete_option: RwSignal<u64>,
+    #[prop(into)] attributes: PartAttributes,
 ) -> impl IntoView {
+    // -- variables -- //
+
+    let options = attributes.options();
+
     // -- signals -- //
 
     let price = create_rw_signal({
@@ -66,24 +72,38 @@ pub fn PartQuoteCard(
     });
 
     view! {
-        <div class="grow flex justify-between items-center rounded-md border p-3">
-            <InputNumber
-                class="w-36"
-                value=workdays_to_complete_option
-                step=1
-                formatter=workdays_to_complete_formatter
-            />
-            <Input
-                class="w-36"
-                value=price
-                placeholder="$100,000.00"
-                allow_value=allow_price_value
-                on_blur=set_price_value
-            >
-                <InputPrefix slot>
-                    {move || if price.get().is_empty() { "" } else { "$" }}
-                </InputPrefix>
-            </Input>
+        <div class="grow flex flex-col justify-center rounded-md border p-3 space-y-2">
+            <div class="flex flex-wrap gap-1">
+                {options
+                    .into_iter()
+                    .map(|option| {
+                        view! {
+                            <span class="rounded-xl bg-gray-100 px-2 py-0.5 text-xs text-gray-600">
+                                {option}
+                            </span>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+            <div class="flex justify-between items-center">
+                <InputNumber
+                    class="w-36"
+                    value=workdays_to_complete_option
+                    step=1
+                    formatter=workdays_to_complete_formatter
+                />
+                <Input
+                    class="w-36"
+                    value=price
+                    placeholder="$100,000.00"
+                    allow_value=allow_price_value
+                    on_blur=set_price_value
+                >
+                    <InputPrefix slot>
+                        {move || if price.get().is_empty() { "" } else { "$" }}
+                    </InputPrefix>
+                </Input>
+            </div>
         </div>
     }
 }
@@ -88,6 +88,7 @@ pub fn PartQuotesTable(
                         unit_price,
                         sub_total,
                         workdays_to_complete: workdays_to_complete.get_untracked(),
+                        quantity_tiers: None,
                         quantity: part.quantity,
                     });
                 });
@@ -2,7 +2,7 @@ use crate::clients::parts::{GeneratePresignedUrlRequest, PartsClient};
 use crate::components::parts::part_quote_card::PartQuoteCard;
 use crate::models::file::File;
 use crate::models::money::Money;
-use crate::models::part::{Part, PartAttributes};
+use crate::models::part::Part;
 use leptos::*;
 use thaw::Button;
 use thaw::ButtonColor::Error;
@@ -100,6 +100,16 @@ pub fn PartQuotesTableRow(
                     }}
 
                 </div>
+                {part
+                    .conversion_error
+                    .clone()
+                    .map(|conversion_error| {
+                        view! {
+                            <p class="text-xs text-red-600">
+                                {format!("Model conversion failed: {conversion_error}")}
+                            </p>
+                        }
+                    })}
                 <div class="flex items-baseline">
                     <p class="font-bold text-base pr-2">"Process:"</p>
                     <p class="text-md text-gray-900">{part.process.to_string()}</p>
@@ -107,18 +117,14 @@ pub fn PartQuotesTableRow(
                 <div class="flex items-baseline">
                     <p class="font-bold text-base pr-2">"Material:"</p>
                     <p class="text-md text-gray-900">
-                        {match part.attributes.clone() {
-                            PartAttributes::CNC(attributes) => attributes.material,
-                        }}
+                        {part.attributes.material()}
 
                     </p>
                 </div>
                 <div class="flex items-baseline">
                     <p class="font-bold text-base pr-2">"Tolerance:"</p>
                     <p class="text-md text-gray-900">
-                        {match part.attributes.clone() {
-                            PartAttributes::CNC(attributes) => attributes.tolerance,
-                        }}
+                        {part.attributes.tolerance().unwrap_or(String::from("-"))}
 
                     </p>
                 </div>
@@ -126,6 +132,17 @@ pub fn PartQuotesTableRow(
                     <p class="font-bold text-base pr-2">"Quantity:"</p>
                     <p class="text-md text-gray-900">{part.quantity}</p>
                 </div>
+                <div class="flex items-baseline">
+                    <p class="font-bold text-base pr-2">"Geometry:"</p>
+                    <p class="text-md text-gray-900">
+                        {part
+                            .geometry
+                            .as_ref()
+                            .map(|geometry| geometry.to_string())
+                            .unwrap_or(String::from("-"))}
+
+                    </p>
+                </div>
                 <p class="font-bold text-base pr-2">"Additional Notes:"</p>
                 <div class="flex w-96">{part.additional_notes}</div>
             </div>
@@ -133,14 +150,17 @@ pub fn PartQuotesTableRow(
                 <PartQuoteCard
                     price_option=price_options[0]
                     workdays_to_complete_option=workdays_to_complete_options[0]
+                    attributes=part.attributes.clone()
                 />
                 <PartQuoteCard
                     price_option=price_options[1]
                     workdays_to_complete_option=workdays_to_complete_options[1]
+                    attributes=part.attributes.clone()
                 />
                 <PartQuoteCard
                     price_option=price_options[2]
                     workdays_to_complete_option=workdays_to_complete_options[2]
+                    attributes=part.attributes.clone()
                 />
             </div>
         </div>
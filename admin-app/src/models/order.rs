@@ -1,3 +1,4 @@
+use crate::models::money::Money;
 use chrono::{DateTime, NaiveDate, Utc};
 use serde_derive::{Deserialize, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
@@ -15,6 +16,8 @@ pub struct Order {
     pub shipping_recipient_name: String,
     pub shipping_address: Address,
     pub is_open: Option<String>,
+    #[serde(default)]
+    pub refund: Option<Money>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -28,6 +31,8 @@ pub enum OrderStatus {
     Ready,
     Shipped,
     Delivered,
+    Refunded,
+    Cancelled,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
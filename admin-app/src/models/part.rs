@@ -1,7 +1,7 @@
 use crate::models::file::File;
 use crate::models::money::Money;
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use std::fmt::{Display, Formatter};
 
@@ -12,10 +12,14 @@ pub struct Part {
     pub project_id: String,
     pub quotation_id: String,
     pub model_file: File,
-    pub render_file: File,
+    pub render_file: Option<File>,
+    #[serde(default)]
+    pub conversion_error: Option<String>,
     pub drawing_file: Option<File>,
     pub process: PartProcess,
     pub attributes: PartAttributes,
+    #[serde(default)]
+    pub geometry: Option<PartGeometry>,
     pub quantity: u64,
     pub additional_notes: String,
     pub selected_part_quote_id: Option<String>,
@@ -24,14 +28,84 @@ pub struct Part {
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PartGeometry {
+    pub bounding_box: BoundingBox,
+    pub volume_mm3: f64,
+    pub surface_area_mm2: f64,
+    pub triangle_count: u64,
+}
+
+impl Display for PartGeometry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:.1} x {:.1} x {:.1} mm, {:.1} cm³, {:.1} cm²",
+            self.bounding_box.x_mm,
+            self.bounding_box.y_mm,
+            self.bounding_box.z_mm,
+            self.volume_mm3 / 1_000.0,
+            self.surface_area_mm2 / 100.0
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct BoundingBox {
+    pub x_mm: f64,
+    pub y_mm: f64,
+    pub z_mm: f64,
+}
+
 #[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
 pub enum PartProcess {
     CNC,
+    #[serde(rename = "3D_PRINTING")]
+    ThreeDPrinting,
+    #[serde(rename = "SHEET_METAL")]
+    SheetMetal,
+    #[serde(rename = "INJECTION_MOLDING")]
+    InjectionMolding,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "process")]
 pub enum PartAttributes {
+    #[serde(rename = "CNC")]
     CNC(CNCAttributes),
+    #[serde(rename = "3D_PRINTING")]
+    ThreeDPrinting(ThreeDPrintingAttributes),
+    #[serde(rename = "SHEET_METAL")]
+    SheetMetal(SheetMetalAttributes),
+    #[serde(rename = "INJECTION_MOLDING")]
+    InjectionMolding(InjectionMoldingAttributes),
+}
+
+impl PartAttributes {
+    pub fn material(&self) -> String {
+        match self {
+            PartAttributes::CNC(attributes) => attributes.material.clone(),
+            PartAttributes::ThreeDPrinting(attributes) => attributes.material.clone(),
+            PartAttributes::SheetMetal(attributes) => attributes.material.clone(),
+            PartAttributes::InjectionMolding(attributes) => attributes.material.clone(),
+        }
+    }
+
+    pub fn options(&self) -> Vec<String> {
+        match self {
+            PartAttributes::CNC(attributes) => attributes.options(),
+            _ => Vec::new(),
+        }
+    }
+
+    pub fn tolerance(&self) -> Option<String> {
+        match self {
+            PartAttributes::CNC(attributes) => Some(attributes.tolerance.clone()),
+            PartAttributes::ThreeDPrinting(_) => None,
+            PartAttributes::SheetMetal(attributes) => Some(attributes.tolerance.clone()),
+            PartAttributes::InjectionMolding(attributes) => Some(attributes.tolerance.clone()),
+        }
+    }
 }
 
 impl Default for PartAttributes {
@@ -40,15 +114,25 @@ impl Default for PartAttributes {
     }
 }
 
-impl Serialize for PartAttributes {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match self {
-            PartAttributes::CNC(attrs) => attrs.serialize(serializer),
-        }
-    }
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PartAttributesRepresentation {
+    Tagged(TaggedPartAttributes),
+    Legacy(CNCAttributes),
+}
+
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Deserialize)]
+#[serde(tag = "process")]
+enum TaggedPartAttributes {
+    #[serde(rename = "CNC")]
+    CNC(CNCAttributes),
+    #[serde(rename = "3D_PRINTING")]
+    ThreeDPrinting(ThreeDPrintingAttributes),
+    #[serde(rename = "SHEET_METAL")]
+    SheetMetal(SheetMetalAttributes),
+    #[serde(rename = "INJECTION_MOLDING")]
+    InjectionMolding(InjectionMoldingAttributes),
 }
 
 impl<'de> Deserialize<'de> for PartAttributes {
@@ -56,8 +140,21 @@ impl<'de> Deserialize<'de> for PartAttributes {
     where
         D: Deserializer<'de>,
     {
-        let attrs = CNCAttributes::deserialize(deserializer)?;
-        Ok(PartAttributes::CNC(attrs))
+        let attributes = match PartAttributesRepresentation::deserialize(deserializer)? {
+            PartAttributesRepresentation::Tagged(tagged) => match tagged {
+                TaggedPartAttributes::CNC(attrs) => PartAttributes::CNC(attrs),
+                TaggedPartAttributes::ThreeDPrinting(attrs) => {
+                    PartAttributes::ThreeDPrinting(attrs)
+                }
+                TaggedPartAttributes::SheetMetal(attrs) => PartAttributes::SheetMetal(attrs),
+                TaggedPartAttributes::InjectionMolding(attrs) => {
+                    PartAttributes::InjectionMolding(attrs)
+                }
+            },
+            PartAttributesRepresentation::Legacy(attrs) => PartAttributes::CNC(attrs),
+        };
+
+        Ok(attributes)
     }
 }
 
@@ -65,6 +162,9 @@ impl Display for PartAttributes {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             PartAttributes::CNC(attr) => write!(f, "{}", attr),
+            PartAttributes::ThreeDPrinting(attr) => write!(f, "{}", attr),
+            PartAttributes::SheetMetal(attr) => write!(f, "{}", attr),
+            PartAttributes::InjectionMolding(attr) => write!(f, "{}", attr),
         }
     }
 }
@@ -73,6 +173,44 @@ impl Display for PartAttributes {
 pub struct CNCAttributes {
     pub material: String,
     pub tolerance: String,
+    #[serde(default)]
+    pub surface_finish: Option<String>,
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub threads: Vec<ThreadCallout>,
+    #[serde(default)]
+    pub heat_treatment: Option<String>,
+    #[serde(default)]
+    pub inspection_level: Option<String>,
+}
+
+impl CNCAttributes {
+    /// Labels for the options that affect pricing, empty when the part is as machined.
+    pub fn options(&self) -> Vec<String> {
+        let mut options = Vec::new();
+        if let Some(surface_finish) = &self.surface_finish {
+            match &self.color {
+                Some(color) => options.push(format!("Finish: {surface_finish} ({color})")),
+                None => options.push(format!("Finish: {surface_finish}")),
+            }
+        }
+        for thread in &self.threads {
+            options.push(format!("Threads: {thread}"));
+        }
+        if let Some(heat_treatment) = &self.heat_treatment {
+            options.push(format!("Heat Treatment: {heat_treatment}"));
+        }
+        if let Some(inspection_level) = self
+            .inspection_level
+            .as_ref()
+            .filter(|inspection_level| *inspection_level != "STANDARD")
+        {
+            options.push(format!("Inspection: {inspection_level}"));
+        }
+
+        options
+    }
 }
 
 impl Default for CNCAttributes {
@@ -80,6 +218,11 @@ impl Default for CNCAttributes {
         Self {
             material: String::from("Aluminum 6061-T6"),
             tolerance: String::from("+/- .005\" (+/- 0.13mm)"),
+            surface_finish: None,
+            color: None,
+            threads: Vec::new(),
+            heat_treatment: None,
+            inspection_level: None,
         }
     }
 }
@@ -88,8 +231,89 @@ impl Display for CNCAttributes {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Process: CNC, Material: {}, Tolerance: {})",
+            "Process: CNC, Material: {}, Tolerance: {}",
             self.material, self.tolerance
+        )?;
+        for option in self.options() {
+            write!(f, ", {option}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ThreadCallout {
+    pub specification: String,
+    pub count: u64,
+    pub depth_mm: Option<f64>,
+}
+
+impl Display for ThreadCallout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x {}", self.count, self.specification)?;
+        if let Some(depth_mm) = self.depth_mm {
+            write!(f, " {depth_mm}mm deep")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ThreeDPrintingAttributes {
+    pub technology: String,
+    pub material: String,
+    pub layer_height_mm: f64,
+    pub infill_percentage: Option<u8>,
+}
+
+impl Display for ThreeDPrintingAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Process: 3D Printing ({}), Material: {}, Layer Height: {}mm",
+            self.technology, self.material, self.layer_height_mm
+        )?;
+        if let Some(infill_percentage) = self.infill_percentage {
+            write!(f, ", Infill: {infill_percentage}%")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SheetMetalAttributes {
+    pub material: String,
+    pub thickness_mm: f64,
+    pub bend_count: u64,
+    pub tolerance: String,
+}
+
+impl Display for SheetMetalAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Process: Sheet Metal, Material: {}, Thickness: {}mm, Bends: {}, Tolerance: {}",
+            self.material, self.thickness_mm, self.bend_count, self.tolerance
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InjectionMoldingAttributes {
+    pub material: String,
+    pub mold_cavities: u64,
+    pub tolerance: String,
+}
+
+impl Display for InjectionMoldingAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Process: Injection Molding, Material: {}, Mold Cavities: {}, Tolerance: {}",
+            self.material, self.mold_cavities, self.tolerance
         )
     }
 }
@@ -100,7 +324,15 @@ pub struct PartQuote {
     pub unit_price: Money,
     pub sub_total: Money,
     pub workdays_to_complete: u64,
+    pub quantity_tiers: Option<Vec<QuantityTier>>,
     pub valid_until: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QuantityTier {
+    pub min_quantity: u64,
+    pub max_quantity: Option<u64>,
+    pub unit_price: Money,
+}
@@ -21,4 +21,9 @@ pub enum QuotationStatus {
     PendingReview,
     PendingPayment,
     Payed,
+    Expired,
+    PaymentProcessing,
+    PaymentFailed,
+    Refunded,
+    Cancelled,
 }
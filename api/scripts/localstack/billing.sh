@@ -0,0 +1,20 @@
+#!/bin/bash
+
+# DynamoDB Tables
+awslocal dynamodb create-table \
+    --table-name BillingProfiles \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+    --billing-mod PAY_PER_REQUEST
+
+awslocal dynamodb create-table \
+    --table-name Invoices \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+        AttributeName=sk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+        AttributeName=sk,KeyType=RANGE \
+    --billing-mod PAY_PER_REQUEST
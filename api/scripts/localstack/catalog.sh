@@ -0,0 +1,47 @@
+#!/bin/bash
+
+# DynamoDB Tables
+awslocal dynamodb create-table \
+    --table-name Catalog \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+        AttributeName=sk,AttributeType=S \
+        AttributeName=lsi1_sk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+        AttributeName=sk,KeyType=RANGE \
+    --billing-mod PAY_PER_REQUEST \
+    --local-secondary-indexes \
+      '[
+        {
+          "IndexName": "LSI1_KindAndName",
+          "KeySchema": [
+            {"AttributeName":"pk", "KeyType":"HASH"},
+            {"AttributeName":"lsi1_sk", "KeyType":"RANGE"}
+          ],
+          "Projection": {
+            "ProjectionType": "ALL"
+          }
+        }
+      ]'
+
+# Default catalog items
+NOW=$(date -u +"%Y-%m-%dT%H:%M:%S+00:00")
+awslocal dynamodb put-item \
+    --table-name Catalog \
+    --item '{
+      "pk": {"S": "CNC"},
+      "sk": {"S": "cat_default_material"},
+      "lsi1_sk": {"S": "MATERIAL&Aluminum 6061-T6"},
+      "created_at": {"S": "'"$NOW"'"},
+      "updated_at": {"S": "'"$NOW"'"}
+    }'
+awslocal dynamodb put-item \
+    --table-name Catalog \
+    --item '{
+      "pk": {"S": "CNC"},
+      "sk": {"S": "cat_default_tolerance"},
+      "lsi1_sk": {"S": "TOLERANCE&+/- .005\" (+/- 0.13mm)"},
+      "created_at": {"S": "'"$NOW"'"},
+      "updated_at": {"S": "'"$NOW"'"}
+    }'
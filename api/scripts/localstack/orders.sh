@@ -11,6 +11,7 @@ awslocal dynamodb create-table \
         AttributeName=gsi1_sk,AttributeType=S \
         AttributeName=gsi2_pk,AttributeType=S \
         AttributeName=gsi2_sk,AttributeType=S \
+        AttributeName=supplier_id,AttributeType=S \
     --key-schema \
         AttributeName=pk,KeyType=HASH \
         AttributeName=sk,KeyType=RANGE \
@@ -59,5 +60,40 @@ awslocal dynamodb create-table \
           "Projection": {
             "ProjectionType": "ALL"
           }
+        },
+        {
+          "IndexName": "GSI3_SupplierOrders",
+          "KeySchema": [
+            {"AttributeName":"supplier_id", "KeyType":"HASH"},
+            {"AttributeName":"sk", "KeyType":"RANGE"}
+          ],
+          "Projection": {
+            "ProjectionType": "ALL"
+          }
+        }
+      ]'
+
+awslocal dynamodb create-table \
+    --table-name WorkOrders \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+        AttributeName=sk,AttributeType=S \
+        AttributeName=gsi1_pk,AttributeType=S \
+        AttributeName=gsi1_sk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+        AttributeName=sk,KeyType=RANGE \
+    --billing-mod PAY_PER_REQUEST \
+    --global-secondary-indexes \
+      '[
+        {
+          "IndexName": "GSI1_WorkOrderIsOpen",
+          "KeySchema": [
+            {"AttributeName":"gsi1_pk", "KeyType":"HASH"},
+            {"AttributeName":"gsi1_sk", "KeyType":"RANGE"}
+          ],
+          "Projection": {
+            "ProjectionType": "ALL"
+          }
         }
       ]'
@@ -0,0 +1,25 @@
+#!/bin/bash
+
+# DynamoDB Tables
+awslocal dynamodb create-table \
+    --table-name WebhookEvents \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+        AttributeName=gsi1_pk,AttributeType=S \
+        AttributeName=gsi1_sk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+    --billing-mod PAY_PER_REQUEST \
+    --global-secondary-indexes \
+      '[
+        {
+          "IndexName": "GSI1_WebhookEventIsFailed",
+          "KeySchema": [
+            {"AttributeName":"gsi1_pk", "KeyType":"HASH"},
+            {"AttributeName":"gsi1_sk", "KeyType":"RANGE"}
+          ],
+          "Projection": {
+            "ProjectionType": "ALL"
+          }
+        }
+      ]'
@@ -0,0 +1,18 @@
+#!/bin/bash
+
+# DynamoDB Tables
+awslocal dynamodb create-table \
+    --table-name PromotionCodes \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+    --billing-mod PAY_PER_REQUEST
+
+awslocal dynamodb create-table \
+    --table-name PricingAgreements \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+    --billing-mod PAY_PER_REQUEST
@@ -10,6 +10,7 @@ awslocal dynamodb create-table \
         AttributeName=gsi1_sk,AttributeType=S \
         AttributeName=gsi2_pk,AttributeType=S \
         AttributeName=gsi2_sk,AttributeType=S \
+        AttributeName=gsi3_pk,AttributeType=S \
     --key-schema \
         AttributeName=pk,KeyType=HASH \
         AttributeName=sk,KeyType=RANGE \
@@ -48,6 +49,25 @@ awslocal dynamodb create-table \
           "Projection":{
             "ProjectionType":"ALL"
           }
+        },
+        {
+          "IndexName": "GSI3_QuoteIsPendingPayment",
+          "KeySchema": [
+            {"AttributeName":"gsi3_pk", "KeyType":"HASH"},
+            {"AttributeName":"gsi2_sk", "KeyType":"RANGE"}
+          ],
+          "Projection":{
+            "ProjectionType":"ALL"
+          }
         }
       ]'
 
+awslocal dynamodb create-table \
+    --table-name QuotationRevisions \
+    --attribute-definitions \
+        AttributeName=pk,AttributeType=S \
+        AttributeName=sk,AttributeType=S \
+    --key-schema \
+        AttributeName=pk,KeyType=HASH \
+        AttributeName=sk,KeyType=RANGE \
+    --billing-mod PAY_PER_REQUEST
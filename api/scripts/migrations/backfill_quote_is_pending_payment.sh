@@ -0,0 +1,33 @@
+#!/bin/bash
+
+# Sets `gsi3_pk` on quotations that were already pending payment before the
+# GSI3_QuoteIsPendingPayment index existed. The index is sparse, so the quotation expirer
+# doesn't see them until they are backfilled. Safe to run more than once.
+#
+# Usage: ./scripts/migrations/backfill_quote_is_pending_payment.sh [aws cli options]
+#   e.g. ./scripts/migrations/backfill_quote_is_pending_payment.sh --profile torvek-staging
+
+set -euo pipefail
+
+TABLE_NAME=${TABLE_NAME:-Quotes}
+
+aws "$@" dynamodb scan \
+    --table-name "$TABLE_NAME" \
+    --filter-expression "begins_with(gsi1_sk, :status) AND attribute_not_exists(gsi3_pk)" \
+    --expression-attribute-values '{":status": {"S": "PENDING_PAYMENT&"}}' \
+    --projection-expression "pk, sk" \
+    --output json \
+    | jq -c '.Items[] | {pk: .pk, sk: .sk}' \
+    | while read -r key; do
+        # The quotation could have moved on since the scan.
+        if aws "$@" dynamodb update-item \
+            --table-name "$TABLE_NAME" \
+            --key "$key" \
+            --condition-expression "begins_with(gsi1_sk, :status)" \
+            --update-expression "SET gsi3_pk = :is_pending_payment" \
+            --expression-attribute-values '{":status": {"S": "PENDING_PAYMENT&"}, ":is_pending_payment": {"S": "true"}}'; then
+            echo "Backfilled $key"
+        else
+            echo "Skipped $key, it's no longer pending payment"
+        fi
+    done
@@ -1,7 +1,10 @@
 use crate::app_state::AppState;
 use crate::config::Config;
 use crate::middleware::SessionLayer;
-use crate::{auth, landing, orders, parts, payments, projects, quotations};
+use crate::{
+    auth, billing, catalog, landing, orders, parts, payments, projects, promotions, quotations,
+    suppliers,
+};
 use aws_config::BehaviorVersion;
 use axum::Router;
 use http::header::{CONTENT_TYPE, ORIGIN};
@@ -46,6 +49,13 @@ pub async fn create_local_app() -> (Router, Config) {
 }
 
 pub async fn create_lambda_app() -> Router {
+    let app_config = load_lambda_config().await;
+
+    create_app_from_config(&app_config).await
+}
+
+/// Loads the config of the environment from S3, shared by every lambda in the workspace.
+pub async fn load_lambda_config() -> Config {
     let (config_bucket, config_key) = match env::var("ENV").unwrap_or(String::from("prod")).as_str()
     {
         "prod" => (CONFIG_BUCKET_PROD, CONFIG_KEY_PROD),
@@ -70,9 +80,7 @@ pub async fn create_lambda_app() -> Router {
         .into_bytes();
 
     let config_string = std::str::from_utf8(&bytes).expect("error parsing body");
-    let app_config = Config::from(config_string);
-
-    create_app_from_config(&app_config).await
+    Config::from(config_string)
 }
 
 pub async fn create_app_from_config(config: &Config) -> Router {
@@ -83,13 +91,19 @@ pub async fn create_app_from_config(config: &Config) -> Router {
 fn create_base_router(state: AppState) -> Router<AppState> {
     let public_router = Router::new()
         .nest("/v1", auth::routes::create_public_router())
-        .nest("/v1", landing::routes::create_router());
+        .nest("/v1", landing::routes::create_router())
+        .nest("/v1", catalog::routes::create_public_router())
+        .nest("/v1", suppliers::routes::create_public_router());
 
     let private_router = Router::new()
         .nest("/v1", auth::routes::create_private_router())
+        .nest("/v1", billing::routes::create_router())
+        .nest("/v1", catalog::routes::create_private_router())
         .nest("/v1", orders::routes::create_router())
         .nest("/v1", projects::routes::create_router())
+        .nest("/v1", promotions::routes::create_router())
         .nest("/v1", quotations::routes::create_router())
+        .nest("/v1", suppliers::routes::create_private_router())
         .nest("/v1", parts::routes::create_router())
         .nest("/v1", payments::routes::create_router())
         .layer(SessionLayer::new(state));
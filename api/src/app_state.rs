@@ -2,31 +2,52 @@ use std::env;
 use std::sync::Arc;
 
 use aws_config::{BehaviorVersion, SdkConfig};
-use http::header::AUTHORIZATION;
 use reqwest::header::ACCEPT;
 use reqwest::header::{HeaderMap, HeaderValue};
 use stripe::Client;
 
 use crate::config::{Config, Environment};
+use crate::repositories::billing_profiles_dynamodb::DynamodbBillingProfiles;
+use crate::repositories::catalog_dynamodb::DynamodbCatalog;
+use crate::repositories::invoices_dynamodb::DynamodbInvoices;
 use crate::repositories::orders_dynamodb::DynamodbOrders;
 use crate::repositories::parts_dynamodb::DynamodbParts;
+use crate::repositories::pricing_agreements_dynamodb::DynamodbPricingAgreements;
 use crate::repositories::projects_dynamodb::DynamodbProjects;
+use crate::repositories::promotion_codes_dynamodb::DynamodbPromotionCodes;
+use crate::repositories::quotation_revisions_dynamodb::DynamodbQuotationRevisions;
 use crate::repositories::quotes_dynamodb::DynamodbQuotes;
+use crate::repositories::rfqs_dynamodb::DynamodbRfqs;
+use crate::repositories::suppliers_dynamodb::DynamodbSuppliers;
 use crate::repositories::transaction_dynamodb::DynamodbTransaction;
+use crate::repositories::webhook_events_dynamodb::DynamodbWebhookEvents;
+use crate::repositories::work_orders_dynamodb::DynamodbWorkOrders;
+use crate::services::certificate_renderer_pdf::PdfCertificateRenderer;
 use crate::services::emailer_ses::EmailerSES;
+use crate::services::exchange_rates_fixed::FixedExchangeRates;
 use crate::services::identity_manager_ory::OryIdentityManager;
+use crate::services::invoice_renderer_pdf::PdfInvoiceRenderer;
+use crate::services::model_converter_freecad::FreeCadModelConverter;
 use crate::services::object_storage_s3::S3ObjectStorage;
+use crate::services::pricing_engine_rate_table::RateTablePricingEngine;
+use crate::services::quote_renderer_pdf::PdfQuoteRenderer;
+use crate::services::shipping_calculator_rate_table::RateTableShippingCalculator;
 use crate::services::stripe::Stripe;
 use crate::services::stripe_client::StripeClient;
+use crate::services::tax_calculator_rate_table::RateTableTaxCalculator;
 
 #[derive(Clone)]
 pub struct AppState {
     pub env: Environment,
     pub domain: String,
     pub auth: AppStateAuth,
+    pub billing: AppStateBilling,
+    pub catalog: AppStateCatalog,
     pub orders: AppStateOrders,
     pub projects: AppStateProjects,
+    pub promotions: AppStatePromotions,
     pub quotes: AppStateQuotes,
+    pub suppliers: AppStateSuppliers,
     pub parts: AppStateParts,
     pub services: AppStateServices,
     pub payments: AppStatePayments,
@@ -37,9 +58,23 @@ pub struct AppStateAuth {
     pub ory_kratos: Arc<OryIdentityManager>,
 }
 
+#[derive(Clone)]
+pub struct AppStateBilling {
+    pub dynamodb_billing_profiles: Arc<DynamodbBillingProfiles>,
+    pub dynamodb_invoices: Arc<DynamodbInvoices>,
+    pub pdf_renderer: Arc<PdfInvoiceRenderer>,
+}
+
+#[derive(Clone)]
+pub struct AppStateCatalog {
+    pub dynamodb_catalog: Arc<DynamodbCatalog>,
+}
+
 #[derive(Clone)]
 pub struct AppStateOrders {
     pub dynamodb_orders: Arc<DynamodbOrders>,
+    pub dynamodb_work_orders: Arc<DynamodbWorkOrders>,
+    pub pdf_renderer: Arc<PdfCertificateRenderer>,
 }
 
 #[derive(Clone)]
@@ -47,20 +82,42 @@ pub struct AppStateProjects {
     pub dynamodb_projects: Arc<DynamodbProjects>,
 }
 
+#[derive(Clone)]
+pub struct AppStatePromotions {
+    pub dynamodb_promotion_codes: Arc<DynamodbPromotionCodes>,
+    pub dynamodb_pricing_agreements: Arc<DynamodbPricingAgreements>,
+}
+
 #[derive(Clone)]
 pub struct AppStateQuotes {
     pub dynamodb_quotes: Arc<DynamodbQuotes>,
+    pub dynamodb_quotation_revisions: Arc<DynamodbQuotationRevisions>,
+    pub pdf_renderer: Arc<PdfQuoteRenderer>,
+}
+
+#[derive(Clone)]
+pub struct AppStateSuppliers {
+    pub dynamodb_suppliers: Arc<DynamodbSuppliers>,
+    pub dynamodb_rfqs: Arc<DynamodbRfqs>,
+    pub rfq_margin_rate: f64,
+    pub rfq_url: String,
+    pub invitation_url: String,
 }
 
 #[derive(Clone)]
 pub struct AppStateParts {
     pub dynamodb_parts: Arc<DynamodbParts>,
     pub s3: Arc<S3ObjectStorage>,
+    pub pricing_engine: Arc<RateTablePricingEngine>,
+    pub model_converter: Arc<FreeCadModelConverter>,
 }
 
 #[derive(Clone)]
 pub struct AppStateServices {
     pub emailer: AppStateEmailer,
+    pub exchange_rates: Arc<FixedExchangeRates>,
+    pub tax_calculator: Arc<RateTableTaxCalculator>,
+    pub shipping_calculator: Arc<RateTableShippingCalculator>,
 }
 
 #[derive(Clone)]
@@ -73,6 +130,7 @@ pub struct AppStatePayments {
     pub webhook_secret: String,
     pub stripe_client: Arc<dyn StripeClient>,
     pub transaction: DynamodbTransaction,
+    pub dynamodb_webhook_events: Arc<DynamodbWebhookEvents>,
 }
 
 impl AppState {
@@ -81,9 +139,13 @@ impl AppState {
             env: config.app.env.clone(),
             domain: config.app.domain.clone(),
             auth: AppStateAuth::from(config).await,
+            billing: AppStateBilling::from(config).await,
+            catalog: AppStateCatalog::from(config).await,
             orders: AppStateOrders::from(config).await,
             projects: AppStateProjects::from(config).await,
+            promotions: AppStatePromotions::from(config).await,
             quotes: AppStateQuotes::from(config).await,
+            suppliers: AppStateSuppliers::from(config).await,
             parts: AppStateParts::from(config).await,
             services: AppStateServices::from(config).await,
             payments: AppStatePayments::from(config).await,
@@ -119,6 +181,58 @@ impl AppStateAuth {
     }
 }
 
+impl AppStateBilling {
+    async fn from(config: &Config) -> Self {
+        // Configs
+        let shared_config = get_shared_config(config).await;
+        let dynamodb_config = aws_sdk_dynamodb::config::Builder::from(&shared_config).build();
+
+        // Clients
+        let dynamodb_client = aws_sdk_dynamodb::Client::from_conf(dynamodb_config);
+
+        // Services & Repositories
+        let billing_profiles_repository = Arc::new(DynamodbBillingProfiles::new(
+            dynamodb_client.clone(),
+            config.billing.billing_profiles_table.clone(),
+        ));
+        let invoices_repository = Arc::new(DynamodbInvoices::new(
+            dynamodb_client,
+            config.billing.invoices_table.clone(),
+        ));
+        let pdf_renderer = Arc::new(PdfInvoiceRenderer::new(
+            config.quotes.document.clone(),
+            config.billing.clone(),
+        ));
+
+        Self {
+            dynamodb_billing_profiles: billing_profiles_repository,
+            dynamodb_invoices: invoices_repository,
+            pdf_renderer,
+        }
+    }
+}
+
+impl AppStateCatalog {
+    async fn from(config: &Config) -> Self {
+        // Configs
+        let shared_config = get_shared_config(config).await;
+        let dynamodb_config = aws_sdk_dynamodb::config::Builder::from(&shared_config).build();
+
+        // Clients
+        let dynamodb_client = aws_sdk_dynamodb::Client::from_conf(dynamodb_config);
+
+        // Services & Repositories
+        let catalog_repository = Arc::new(DynamodbCatalog::new(
+            dynamodb_client,
+            config.catalog.catalog_table.clone(),
+        ));
+
+        Self {
+            dynamodb_catalog: catalog_repository,
+        }
+    }
+}
+
 impl AppStateOrders {
     async fn from(config: &Config) -> Self {
         // Configs
@@ -133,9 +247,16 @@ impl AppStateOrders {
             dynamodb_client.clone(),
             config.orders.orders_table.clone(),
         ));
+        let work_orders_repository = Arc::new(DynamodbWorkOrders::new(
+            dynamodb_client,
+            config.orders.work_orders_table.clone(),
+        ));
+        let pdf_renderer = Arc::new(PdfCertificateRenderer::new(config.quotes.document.clone()));
 
         Self {
             dynamodb_orders: orders_repository,
+            dynamodb_work_orders: work_orders_repository,
+            pdf_renderer,
         }
     }
 }
@@ -160,6 +281,32 @@ impl AppStateProjects {
     }
 }
 
+impl AppStatePromotions {
+    async fn from(config: &Config) -> Self {
+        // Configs
+        let shared_config = get_shared_config(config).await;
+        let dynamodb_config = aws_sdk_dynamodb::config::Builder::from(&shared_config).build();
+
+        // Clients
+        let dynamodb_client = aws_sdk_dynamodb::Client::from_conf(dynamodb_config);
+
+        // Services & Repositories
+        let promotion_codes_repository = Arc::new(DynamodbPromotionCodes::new(
+            dynamodb_client.clone(),
+            config.promotions.promotion_codes_table.clone(),
+        ));
+        let pricing_agreements_repository = Arc::new(DynamodbPricingAgreements::new(
+            dynamodb_client,
+            config.promotions.pricing_agreements_table.clone(),
+        ));
+
+        Self {
+            dynamodb_promotion_codes: promotion_codes_repository,
+            dynamodb_pricing_agreements: pricing_agreements_repository,
+        }
+    }
+}
+
 impl AppStateQuotes {
     async fn from(config: &Config) -> Self {
         // Configs
@@ -171,18 +318,58 @@ impl AppStateQuotes {
 
         // Services & Repositories
         let quotes_repository = Arc::new(DynamodbQuotes::new(
-            dynamodb_client,
+            dynamodb_client.clone(),
             config.quotes.quotes_table.clone(),
         ));
+        let quotation_revisions_repository = Arc::new(DynamodbQuotationRevisions::new(
+            dynamodb_client,
+            config.quotes.quotation_revisions_table.clone(),
+        ));
+        let tax_calculator = Arc::new(RateTableTaxCalculator::new(config.services.taxes.clone()));
+        let pdf_renderer = Arc::new(PdfQuoteRenderer::new(
+            config.quotes.document.clone(),
+            tax_calculator,
+        ));
 
         Self {
             dynamodb_quotes: quotes_repository,
+            dynamodb_quotation_revisions: quotation_revisions_repository,
+            pdf_renderer,
         }
     }
 }
 
-impl AppStateParts {
+impl AppStateSuppliers {
     async fn from(config: &Config) -> Self {
+        // Configs
+        let shared_config = get_shared_config(config).await;
+        let dynamodb_config = aws_sdk_dynamodb::config::Builder::from(&shared_config).build();
+
+        // Clients
+        let dynamodb_client = aws_sdk_dynamodb::Client::from_conf(dynamodb_config);
+
+        // Services & Repositories
+        let suppliers_repository = Arc::new(DynamodbSuppliers::new(
+            dynamodb_client.clone(),
+            config.suppliers.suppliers_table.clone(),
+        ));
+        let rfqs_repository = Arc::new(DynamodbRfqs::new(
+            dynamodb_client,
+            config.suppliers.rfqs_table.clone(),
+        ));
+
+        Self {
+            dynamodb_suppliers: suppliers_repository,
+            dynamodb_rfqs: rfqs_repository,
+            rfq_margin_rate: config.suppliers.rfq_margin_rate,
+            rfq_url: config.suppliers.rfq_url.clone(),
+            invitation_url: config.suppliers.invitation_url.clone(),
+        }
+    }
+}
+
+impl AppStateParts {
+    pub async fn from(config: &Config) -> Self {
         // Configs
         let shared_s3_config = get_s3_shared_config(config).await;
         let shared_config = get_shared_config(config).await;
@@ -202,10 +389,20 @@ impl AppStateParts {
             s3_client,
             config.parts.s3_bucket.clone(),
         ));
+        let exchange_rates = Arc::new(FixedExchangeRates::new(
+            config.services.exchange_rates.clone(),
+        ));
+        let pricing_engine = Arc::new(RateTablePricingEngine::new(
+            config.pricing.clone(),
+            exchange_rates,
+        ));
+        let model_converter = Arc::new(FreeCadModelConverter::new());
 
         Self {
             dynamodb_parts: parts_repository,
             s3: object_storage,
+            pricing_engine,
+            model_converter,
         }
     }
 }
@@ -225,9 +422,21 @@ impl AppStateServices {
             config.services.emailer.no_reply_email.clone(),
             config.services.emailer.admin_emails.clone(),
         ));
+        let exchange_rates = Arc::new(FixedExchangeRates::new(
+            config.services.exchange_rates.clone(),
+        ));
+
+        let tax_calculator = Arc::new(RateTableTaxCalculator::new(config.services.taxes.clone()));
+        let shipping_calculator = Arc::new(RateTableShippingCalculator::new(
+            config.services.shipping.clone(),
+            exchange_rates.clone(),
+        ));
 
         Self {
             emailer: AppStateEmailer { ses },
+            exchange_rates,
+            tax_calculator,
+            shipping_calculator,
         }
     }
 }
@@ -240,31 +449,28 @@ impl AppStatePayments {
 
         // Clients
         let client = Client::new(&config.payments.secret_key);
-        let mut headers_map = HeaderMap::new();
-        headers_map.insert(
-            AUTHORIZATION,
-            HeaderValue::from_str(&format!("Bearer {}", config.payments.secret_key)).unwrap(),
-        );
-        let files_client = reqwest::Client::builder()
-            .default_headers(headers_map)
-            .build()
-            .expect("error while creating files client");
         let dynamodb_client = aws_sdk_dynamodb::Client::from_conf(dynamodb_config);
 
         // Services
+        let tax_calculator = Arc::new(RateTableTaxCalculator::new(config.services.taxes.clone()));
         let stripe_client = Arc::new(Stripe::new(
             client,
-            files_client,
             config.payments.success_url.clone(),
-            config.payments.tax_ids.clone(),
+            config.payments.connect_return_url.clone(),
+            tax_calculator,
         ));
 
-        let transaction = DynamodbTransaction::new(dynamodb_client);
+        let transaction = DynamodbTransaction::new(dynamodb_client.clone());
+        let webhook_events_repository = Arc::new(DynamodbWebhookEvents::new(
+            dynamodb_client,
+            config.payments.webhook_events_table.clone(),
+        ));
 
         Self {
             webhook_secret: config.payments.webhook_secret.clone(),
             stripe_client,
             transaction,
+            dynamodb_webhook_events: webhook_events_repository,
         }
     }
 }
@@ -1,14 +1,15 @@
 use crate::app_state::AppState;
-use crate::auth::models::inputs::{LoginUserInput, RegisterUserInput};
+use crate::auth::models::inputs::{LoginUserInput, RegisterSupplierInput, RegisterUserInput};
 use crate::auth::models::mappers::GetSessionResponseMapper;
 use crate::auth::models::session::Role;
 use crate::auth::usecases::get_session::GetSession;
 use crate::auth::usecases::login::Login;
 use crate::auth::usecases::logout::Logout;
 use crate::auth::usecases::register::Register;
+use crate::auth::usecases::register_supplier::RegisterSupplier;
 use crate::shared::error::Error;
 use crate::shared::into_error_response::IntoError;
-use crate::shared::UseCase;
+use crate::shared::{SupplierId, UseCase};
 use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
@@ -20,6 +21,7 @@ use time::OffsetDateTime;
 
 pub static CUSTOMER_SESSION_TOKEN: &'static str = "x-customer-session";
 pub static ADMIN_SESSION_TOKEN: &'static str = "x-admin-session";
+pub static SUPPLIER_SESSION_TOKEN: &str = "x-supplier-session";
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct RegisterClientRequest {
@@ -28,6 +30,13 @@ pub struct RegisterClientRequest {
     pub password: String,
 }
 
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RegisterSupplierRequest {
+    pub supplier_id: SupplierId,
+    pub invitation_token: String,
+    pub password: String,
+}
+
 #[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct LoginUserRequest {
     pub email: String,
@@ -223,3 +232,115 @@ pub async fn admin_logout(
         Err(err) => Err(err.into_error_response()),
     }
 }
+
+pub async fn register_supplier(
+    cookies: CookieJar,
+    State(app_state): State<AppState>,
+    Json(request): Json<RegisterSupplierRequest>,
+) -> impl IntoResponse {
+    let input = RegisterSupplierInput {
+        supplier_id: request.supplier_id,
+        invitation_token: request.invitation_token,
+        password: request.password,
+    };
+    let usecase = RegisterSupplier::new(
+        app_state.auth.ory_kratos,
+        app_state.suppliers.dynamodb_suppliers,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(auth_session) => Ok((
+            StatusCode::NO_CONTENT,
+            cookies.add(
+                auth_session
+                    .session_cookie(
+                        SUPPLIER_SESSION_TOKEN,
+                        app_state.env.secure_session_cookie(),
+                        app_state.domain,
+                    )
+                    .into_owned(),
+            ),
+        )),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn supplier_login(
+    cookies: CookieJar,
+    State(app_state): State<AppState>,
+    Json(request): Json<LoginUserRequest>,
+) -> impl IntoResponse {
+    let input = LoginUserInput {
+        email: request.email,
+        password: request.password,
+        role: Role::Supplier,
+    };
+    let usecases = Login::new(app_state.auth.ory_kratos);
+    let result = usecases.execute(input).await;
+
+    match result {
+        Ok(auth_session) => Ok((
+            StatusCode::OK,
+            cookies.add(
+                auth_session
+                    .session_cookie(
+                        SUPPLIER_SESSION_TOKEN,
+                        app_state.env.secure_session_cookie(),
+                        app_state.domain,
+                    )
+                    .into_owned(),
+            ),
+        )),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn get_supplier_session(
+    cookies: CookieJar,
+    State(app_state): State<AppState>,
+) -> impl IntoResponse {
+    let usecase = GetSession::new(app_state.auth.ory_kratos);
+    let session_cookie = cookies.get(SUPPLIER_SESSION_TOKEN);
+
+    let result = match session_cookie {
+        Some(session_cookie) => usecase.execute(session_cookie.value().to_string()).await,
+        None => Err(Error::Unauthorized),
+    };
+
+    match result {
+        Ok(session_information) => Ok(http::Response::builder()
+            .status(StatusCode::OK)
+            .body(
+                serde_json::to_string(&GetSessionResponseMapper::to_api(session_information))
+                    .unwrap(),
+            )
+            .unwrap()),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn supplier_logout(
+    cookies: CookieJar,
+    State(app_state): State<AppState>,
+) -> impl IntoResponse {
+    let usecase = Logout::new(app_state.auth.ory_kratos);
+    let session_cookie = cookies.get(SUPPLIER_SESSION_TOKEN);
+
+    let result = match session_cookie {
+        Some(session_cookie) => usecase.execute(session_cookie.value().to_string()).await,
+        None => Err(Error::Unauthorized),
+    };
+
+    match result {
+        Ok(_) => {
+            let cookie = Cookie::build((SUPPLIER_SESSION_TOKEN, ""))
+                .path("/")
+                .expires(OffsetDateTime::now_utc())
+                .build();
+
+            Ok((StatusCode::NO_CONTENT, cookies.add(cookie)))
+        }
+        Err(err) => Err(err.into_error_response()),
+    }
+}
@@ -1,4 +1,5 @@
 use crate::auth::models::session::Role;
+use crate::shared::SupplierId;
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Clone, Debug)]
@@ -15,3 +16,10 @@ pub struct LoginUserInput {
     pub password: String,
     pub role: Role,
 }
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct RegisterSupplierInput {
+    pub supplier_id: SupplierId,
+    pub invitation_token: String,
+    pub password: String,
+}
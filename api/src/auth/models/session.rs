@@ -1,3 +1,4 @@
+use crate::shared::SupplierId;
 use axum_extra::extract::cookie::{Cookie, SameSite};
 use chrono::{DateTime, Utc};
 use serde_derive::{Deserialize, Serialize};
@@ -37,12 +38,16 @@ pub struct Traits {
 pub struct MetadataPublic {
     pub stripe_customer_id: Option<String>,
     pub role: Role,
+    /// Supplier the identity signs in for, only set for the `Supplier` role.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supplier_id: Option<SupplierId>,
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone, Debug)]
 pub enum Role {
     Admin,
     Customer,
+    Supplier,
 }
 
 impl SessionWithToken {
@@ -4,7 +4,8 @@ use axum::Router;
 
 use crate::app_state::AppState;
 use crate::auth::controllers::{
-    admin_login, admin_logout, get_admin_session, get_session, login, logout, register_customer,
+    admin_login, admin_logout, get_admin_session, get_session, get_supplier_session, login, logout,
+    register_customer, register_supplier, supplier_login, supplier_logout,
 };
 
 pub fn create_public_router() -> Router<AppState> {
@@ -12,6 +13,8 @@ pub fn create_public_router() -> Router<AppState> {
         .route("/accounts/customers/register", post(register_customer))
         .route("/accounts/customers/login", post(login))
         .route("/accounts/admins/login", post(admin_login))
+        .route("/accounts/suppliers/register", post(register_supplier))
+        .route("/accounts/suppliers/login", post(supplier_login))
 }
 
 pub fn create_private_router() -> Router<AppState> {
@@ -20,4 +23,6 @@ pub fn create_private_router() -> Router<AppState> {
         .route("/accounts/customers/session", get(get_session))
         .route("/accounts/admins/session", get(get_admin_session))
         .route("/accounts/admins/logout", post(admin_logout))
+        .route("/accounts/suppliers/session", get(get_supplier_session))
+        .route("/accounts/suppliers/logout", post(supplier_logout))
 }
@@ -2,3 +2,4 @@ pub mod get_session;
 pub mod login;
 pub mod logout;
 pub mod register;
+pub mod register_supplier;
@@ -36,6 +36,7 @@ impl UseCase<RegisterUserInput, SessionWithToken> for Register {
         let metadata = MetadataPublic {
             stripe_customer_id: Some(stripe_customer.id.to_string()),
             role: input.role.clone(),
+            supplier_id: None,
         };
 
         self.identity_manager
@@ -0,0 +1,70 @@
+use crate::auth::models::inputs::RegisterSupplierInput;
+use crate::auth::models::session::{MetadataPublic, Role, SessionWithToken};
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::services::identity_manager::IdentityManager;
+use crate::shared;
+use crate::suppliers::models::dynamodb_requests::UpdatableSupplier;
+use async_trait::async_trait;
+use shared::Result;
+use shared::UseCase;
+use std::sync::Arc;
+
+/// Creates the supplier portal account of an invited supplier, signed up with the supplier's
+/// email.
+pub struct RegisterSupplier<S>
+where
+    S: SuppliersRepository,
+{
+    identity_manager: Arc<dyn IdentityManager>,
+    suppliers_repository: Arc<S>,
+}
+
+impl<S> RegisterSupplier<S>
+where
+    S: SuppliersRepository,
+{
+    pub fn new(identity_manager: Arc<dyn IdentityManager>, suppliers_repository: Arc<S>) -> Self {
+        Self {
+            identity_manager,
+            suppliers_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> UseCase<RegisterSupplierInput, SessionWithToken> for RegisterSupplier<S>
+where
+    S: SuppliersRepository,
+{
+    async fn execute(&self, input: RegisterSupplierInput) -> Result<SessionWithToken> {
+        let supplier = self.suppliers_repository.get(input.supplier_id).await?;
+        supplier.verify_invitation_token(&input.invitation_token)?;
+
+        let metadata = MetadataPublic {
+            stripe_customer_id: None,
+            role: Role::Supplier,
+            supplier_id: Some(supplier.id.clone()),
+        };
+        let session_with_token = self
+            .identity_manager
+            .register(supplier.email, input.password, metadata)
+            .await?;
+
+        let linked_supplier = self
+            .suppliers_repository
+            .update(UpdatableSupplier {
+                id: supplier.id,
+                invitation_token: None,
+                identity_id: Some(session_with_token.session.identity.id.clone()),
+            })
+            .await;
+        if let Err(err) = linked_supplier {
+            self.identity_manager
+                .logout(session_with_token.session_token)
+                .await?;
+            return Err(err);
+        }
+
+        Ok(session_with_token)
+    }
+}
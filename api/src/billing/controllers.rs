@@ -0,0 +1,91 @@
+use crate::app_state::AppState;
+use crate::billing::models::inputs::{
+    DownloadInvoicePdfInput, GetBillingProfileInput, UpdateBillingProfileInput,
+};
+use crate::billing::usecases::download_invoice_pdf::DownloadInvoicePdf;
+use crate::billing::usecases::get_billing_profile::GetBillingProfile;
+use crate::billing::usecases::update_billing_profile::UpdateBillingProfile;
+use crate::shared::extractors::session::CustomerSession;
+use crate::shared::into_error_response::IntoError;
+use crate::shared::{QuoteId, UseCase};
+use axum::extract::{Path, State};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use http::{header, StatusCode};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UpdateBillingProfileRequest {
+    pub rfc: String,
+    pub legal_name: String,
+    pub tax_regime: String,
+    pub cfdi_use: String,
+    pub postal_code: String,
+}
+
+pub async fn get_billing_profile(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+) -> impl IntoResponse {
+    let input = GetBillingProfileInput {
+        identity: session.identity,
+    };
+    let usecase = GetBillingProfile::new(app_state.billing.dynamodb_billing_profiles);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn update_billing_profile(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+    Json(request): Json<UpdateBillingProfileRequest>,
+) -> impl IntoResponse {
+    let input = UpdateBillingProfileInput {
+        identity: session.identity,
+        rfc: request.rfc,
+        legal_name: request.legal_name,
+        tax_regime: request.tax_regime,
+        cfdi_use: request.cfdi_use,
+        postal_code: request.postal_code,
+    };
+    let usecase = UpdateBillingProfile::new(app_state.billing.dynamodb_billing_profiles);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn download_invoice_pdf(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+    Path(quotation_id): Path<QuoteId>,
+) -> impl IntoResponse {
+    let input = DownloadInvoicePdfInput {
+        identity: session.identity,
+        quotation_id,
+    };
+    let usecase = DownloadInvoicePdf::new(
+        app_state.billing.dynamodb_invoices,
+        app_state.billing.pdf_renderer,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok(Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "application/pdf")
+            .header(
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"invoice.pdf\"",
+            )
+            .body(axum::body::Body::from(response))
+            .unwrap()),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
@@ -0,0 +1,94 @@
+use crate::shared::error::Error;
+use crate::shared::CustomerId;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+/// Tax data of a customer printed on its invoices, as registered with the SAT.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BillingProfile {
+    pub customer_id: CustomerId,
+    /// Registro Federal de Contribuyentes, 12 characters for companies and 13 for individuals.
+    pub rfc: String,
+    /// Razón social, exactly as it appears in the customer's tax certificate.
+    pub legal_name: String,
+    /// SAT catalog code of the tax regime (e.g., "601" for general de ley personas morales).
+    pub tax_regime: String,
+    /// SAT catalog code of the CFDI use (e.g., "G03" for gastos en general).
+    pub cfdi_use: String,
+    /// Postal code of the tax address.
+    pub postal_code: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BillingProfile {
+    pub fn new(
+        customer_id: CustomerId,
+        rfc: String,
+        legal_name: String,
+        tax_regime: String,
+        cfdi_use: String,
+        postal_code: String,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            customer_id,
+            rfc: rfc.trim().to_uppercase(),
+            legal_name: legal_name.trim().to_string(),
+            tax_regime: tax_regime.trim().to_string(),
+            cfdi_use: cfdi_use.trim().to_uppercase(),
+            postal_code: postal_code.trim().to_string(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), Error> {
+        if !is_valid_rfc(&self.rfc) {
+            return Err(Error::InvalidBillingProfile(String::from(
+                "The RFC must have 3 or 4 letters, a date of 6 digits and 3 characters",
+            )));
+        }
+        if self.legal_name.is_empty() {
+            return Err(Error::InvalidBillingProfile(String::from(
+                "A legal name is required",
+            )));
+        }
+        if self.tax_regime.len() != 3 || !self.tax_regime.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::InvalidBillingProfile(String::from(
+                "The tax regime must be a code of 3 digits",
+            )));
+        }
+        if self.cfdi_use.len() != 3 || !self.cfdi_use.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(Error::InvalidBillingProfile(String::from(
+                "The CFDI use must be a code of 3 characters",
+            )));
+        }
+        if self.postal_code.len() != 5 || !self.postal_code.chars().all(|c| c.is_ascii_digit()) {
+            return Err(Error::InvalidBillingProfile(String::from(
+                "The postal code must have 5 digits",
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Companies have 3 letters before the date and individuals 4, the last 3 are the homoclave.
+fn is_valid_rfc(rfc: &str) -> bool {
+    let chars = rfc.chars().collect::<Vec<char>>();
+    let letters = match chars.len() {
+        12 => 3,
+        13 => 4,
+        _ => return false,
+    };
+
+    chars[..letters]
+        .iter()
+        .all(|c| c.is_ascii_uppercase() || *c == 'Ñ' || *c == '&')
+        && chars[letters..letters + 6].iter().all(char::is_ascii_digit)
+        && chars[letters + 6..]
+            .iter()
+            .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+}
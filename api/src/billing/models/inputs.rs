@@ -0,0 +1,24 @@
+use crate::auth::models::session::Identity;
+use crate::shared::QuoteId;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetBillingProfileInput {
+    pub identity: Identity,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UpdateBillingProfileInput {
+    pub identity: Identity,
+    pub rfc: String,
+    pub legal_name: String,
+    pub tax_regime: String,
+    pub cfdi_use: String,
+    pub postal_code: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct DownloadInvoicePdfInput {
+    pub identity: Identity,
+    pub quotation_id: QuoteId,
+}
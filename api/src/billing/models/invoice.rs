@@ -0,0 +1,153 @@
+use crate::billing::models::billing_profile::BillingProfile;
+use crate::parts::models::part::Part;
+use crate::promotions::models::discount::{discounted_line_amounts, DiscountLine};
+use crate::quotations::models::quotation::Quotation;
+use crate::services::tax_calculator::TaxCalculator;
+use crate::shared::error::Error;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingCharge;
+use crate::shared::tax::{total_with_taxes, TaxJurisdiction, TaxLine};
+use crate::shared::{CustomerId, InvoiceId, PartId, ProjectId, QuoteId};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use uuid::{ContextV7, Timestamp, Uuid};
+
+/// Invoice of a paid quotation. The billing profile is copied when the invoice is issued, so
+/// later changes to it don't alter invoices already issued.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Invoice {
+    pub id: InvoiceId,
+    pub customer_id: CustomerId,
+    pub project_id: ProjectId,
+    pub quotation_id: QuoteId,
+    /// Customers without a billing profile get a receipt to the general public.
+    pub billing_profile: Option<BillingProfile>,
+    pub payment_intent_id: Option<String>,
+    pub lines: Vec<InvoiceLine>,
+    pub subtotal: Money,
+    /// Promotion code discounted at checkout, taken off before taxes.
+    pub discount: Option<DiscountLine>,
+    pub tax_jurisdiction: TaxJurisdiction,
+    pub tax_lines: Vec<TaxLine>,
+    /// Shipping charged at checkout, added after taxes as Stripe doesn't tax it.
+    pub shipping: Option<ShippingCharge>,
+    pub total: Money,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct InvoiceLine {
+    pub part_id: PartId,
+    pub name: String,
+    pub description: String,
+    pub quantity: u64,
+    pub unit_price: Money,
+    pub amount: Money,
+}
+
+impl Invoice {
+    /// Fails when a part has no selected part quote or was priced in another currency than the
+    /// quotation.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        quotation: &Quotation,
+        parts: &[Part],
+        billing_profile: Option<BillingProfile>,
+        payment_intent_id: Option<String>,
+        tax_calculator: &dyn TaxCalculator,
+        tax_jurisdiction: TaxJurisdiction,
+        discount: Option<DiscountLine>,
+        shipping: Option<ShippingCharge>,
+    ) -> Result<Self, Error> {
+        let lines = parts
+            .iter()
+            .map(|part| {
+                let part_quote = part
+                    .selected_part_quote()
+                    .ok_or(Error::NoSelectedQuoteAvailableForPart(part.id.clone()))?;
+
+                Ok(InvoiceLine {
+                    part_id: part.id.clone(),
+                    name: part.model_file.name.clone(),
+                    description: part.attributes.to_string(),
+                    quantity: part.quantity,
+                    unit_price: part_quote.unit_price_for(part.quantity),
+                    amount: part_quote.sub_total_for(part.quantity)?,
+                })
+            })
+            .collect::<Result<Vec<InvoiceLine>, Error>>()?;
+
+        let currency = quotation.currency;
+        let line_amounts = lines
+            .iter()
+            .map(|line| line.amount.clone())
+            .collect::<Vec<Money>>();
+        let subtotal = Money::checked_sum(currency, &line_amounts)?;
+        let discount_amount = discount
+            .as_ref()
+            .map_or_else(|| Money::zero(currency), |discount| discount.amount.clone());
+        let tax_lines = tax_calculator.tax_lines(
+            &tax_jurisdiction,
+            currency,
+            &discounted_line_amounts(&line_amounts, &discount_amount)?,
+        )?;
+        let mut total = total_with_taxes(&subtotal.checked_sub(&discount_amount)?, &tax_lines)?;
+        if let Some(shipping) = &shipping {
+            total = total.checked_add(&shipping.cost)?;
+        }
+
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let encoded_id = format!("inv_{}", bs58::encode(id).into_string());
+
+        Ok(Self {
+            id: encoded_id,
+            customer_id: quotation.customer_id.clone(),
+            project_id: quotation.project_id.clone(),
+            quotation_id: quotation.id.clone(),
+            billing_profile,
+            payment_intent_id,
+            lines,
+            subtotal,
+            discount,
+            tax_jurisdiction,
+            tax_lines,
+            shipping,
+            total,
+            created_at: Utc::now(),
+        })
+    }
+
+    /// What was charged for the line of `part_id`: its share of the discounted subtotal plus
+    /// the taxes on it, rounded per line like at checkout. Shipping isn't part of any line.
+    pub fn charged_line_amount(&self, part_id: &PartId) -> Result<Money, Error> {
+        let currency = self.subtotal.currency;
+        let index = self
+            .lines
+            .iter()
+            .position(|line| &line.part_id == part_id)
+            .ok_or(Error::ItemNotFoundError)?;
+        let line_amounts = self
+            .lines
+            .iter()
+            .map(|line| line.amount.clone())
+            .collect::<Vec<Money>>();
+        let discount_amount = self
+            .discount
+            .as_ref()
+            .map_or_else(|| Money::zero(currency), |discount| discount.amount.clone());
+        let line_amount = discounted_line_amounts(&line_amounts, &discount_amount)?.remove(index);
+        let tax_lines = self
+            .tax_lines
+            .iter()
+            .map(|tax_line| TaxLine {
+                amount: Money::new(
+                    (line_amount.amount as f64 * tax_line.rate).round() as i64,
+                    currency,
+                ),
+                ..tax_line.clone()
+            })
+            .collect::<Vec<TaxLine>>();
+
+        total_with_taxes(&line_amount, &tax_lines)
+    }
+}
@@ -0,0 +1,3 @@
+pub mod billing_profile;
+pub mod inputs;
+pub mod invoice;
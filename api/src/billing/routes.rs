@@ -0,0 +1,17 @@
+use axum::routing::{get, put};
+use axum::Router;
+
+use crate::app_state::AppState;
+use crate::billing::controllers::{
+    download_invoice_pdf, get_billing_profile, update_billing_profile,
+};
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route("/billing_profile", get(get_billing_profile))
+        .route("/billing_profile", put(update_billing_profile))
+        .route(
+            "/quotations/:quotation_id/invoice/download_pdf",
+            get(download_invoice_pdf),
+        )
+}
@@ -0,0 +1,44 @@
+use crate::billing::models::inputs::DownloadInvoicePdfInput;
+use crate::repositories::invoices::InvoicesRepository;
+use crate::services::invoice_renderer::InvoiceRenderer;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use axum::body::Bytes;
+use std::sync::Arc;
+
+pub struct DownloadInvoicePdf<I>
+where
+    I: InvoicesRepository,
+{
+    invoices_repository: Arc<I>,
+    invoice_renderer: Arc<dyn InvoiceRenderer>,
+}
+
+impl<I> DownloadInvoicePdf<I>
+where
+    I: InvoicesRepository,
+{
+    pub fn new(invoices_repository: Arc<I>, invoice_renderer: Arc<dyn InvoiceRenderer>) -> Self {
+        Self {
+            invoices_repository,
+            invoice_renderer,
+        }
+    }
+}
+
+#[async_trait]
+impl<I> UseCase<DownloadInvoicePdfInput, Bytes> for DownloadInvoicePdf<I>
+where
+    I: InvoicesRepository,
+{
+    async fn execute(&self, input: DownloadInvoicePdfInput) -> Result<Bytes> {
+        let invoice = self
+            .invoices_repository
+            .get(input.identity.id, input.quotation_id)
+            .await?;
+
+        let pdf = self.invoice_renderer.render_invoice(&invoice).await?;
+
+        Ok(Bytes::from(pdf))
+    }
+}
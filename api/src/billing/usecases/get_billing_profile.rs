@@ -0,0 +1,36 @@
+use crate::billing::models::billing_profile::BillingProfile;
+use crate::billing::models::inputs::GetBillingProfileInput;
+use crate::repositories::billing_profiles::BillingProfilesRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct GetBillingProfile<B>
+where
+    B: BillingProfilesRepository,
+{
+    billing_profiles_repository: Arc<B>,
+}
+
+impl<B> GetBillingProfile<B>
+where
+    B: BillingProfilesRepository,
+{
+    pub fn new(billing_profiles_repository: Arc<B>) -> Self {
+        Self {
+            billing_profiles_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<B> UseCase<GetBillingProfileInput, BillingProfile> for GetBillingProfile<B>
+where
+    B: BillingProfilesRepository,
+{
+    async fn execute(&self, input: GetBillingProfileInput) -> Result<BillingProfile> {
+        self.billing_profiles_repository
+            .get(input.identity.id)
+            .await
+    }
+}
@@ -0,0 +1,3 @@
+pub mod download_invoice_pdf;
+pub mod get_billing_profile;
+pub mod update_billing_profile;
@@ -0,0 +1,63 @@
+use crate::billing::models::billing_profile::BillingProfile;
+use crate::billing::models::inputs::UpdateBillingProfileInput;
+use crate::repositories::billing_profiles::BillingProfilesRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Creates the billing profile of the customer or replaces the existing one. Invoices already
+/// issued keep the billing profile they were issued with.
+pub struct UpdateBillingProfile<B>
+where
+    B: BillingProfilesRepository,
+{
+    billing_profiles_repository: Arc<B>,
+}
+
+impl<B> UpdateBillingProfile<B>
+where
+    B: BillingProfilesRepository,
+{
+    pub fn new(billing_profiles_repository: Arc<B>) -> Self {
+        Self {
+            billing_profiles_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<B> UseCase<UpdateBillingProfileInput, BillingProfile> for UpdateBillingProfile<B>
+where
+    B: BillingProfilesRepository,
+{
+    async fn execute(&self, input: UpdateBillingProfileInput) -> Result<BillingProfile> {
+        let mut billing_profile = BillingProfile::new(
+            input.identity.id.clone(),
+            input.rfc,
+            input.legal_name,
+            input.tax_regime,
+            input.cfdi_use,
+            input.postal_code,
+        );
+        billing_profile.validate()?;
+
+        match self
+            .billing_profiles_repository
+            .get(input.identity.id)
+            .await
+        {
+            Ok(existing_billing_profile) => {
+                billing_profile.created_at = existing_billing_profile.created_at;
+            }
+            Err(Error::ItemNotFoundError) => (),
+            Err(err) => return Err(err),
+        }
+
+        self.billing_profiles_repository
+            .put(billing_profile.clone())
+            .await?;
+
+        Ok(billing_profile)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::app_state::AppState;
+use crate::catalog::models::catalog_item::CatalogItemKind;
+use crate::catalog::models::inputs::{
+    AdminCreateCatalogItemInput, AdminDeleteCatalogItemInput, AdminUpdateCatalogItemInput,
+    QueryCatalogInput,
+};
+use crate::catalog::usecases::admin_create_catalog_item::AdminCreateCatalogItem;
+use crate::catalog::usecases::admin_delete_catalog_item::AdminDeleteCatalogItem;
+use crate::catalog::usecases::admin_update_catalog_item::AdminUpdateCatalogItem;
+use crate::catalog::usecases::query_catalog::QueryCatalog;
+use crate::parts::models::part::PartProcess;
+use crate::shared::extractors::session::AdminSession;
+use crate::shared::into_error_response::IntoError;
+use crate::shared::{CatalogItemId, UseCase};
+use axum::extract::{Path, Query, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use http::StatusCode;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryCatalogQueryParameters {
+    pub process: PartProcess,
+    pub kind: Option<CatalogItemKind>,
+    pub cursor: Option<String>,
+    pub limit: Option<i32>,
+}
+
+pub async fn query_catalog(
+    State(app_state): State<AppState>,
+    Query(params): Query<QueryCatalogQueryParameters>,
+) -> impl IntoResponse {
+    let input = QueryCatalogInput {
+        process: params.process,
+        kind: params.kind,
+        cursor: params.cursor,
+        limit: params.limit.unwrap_or(100),
+    };
+    let usecase = QueryCatalog::new(app_state.catalog.dynamodb_catalog);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_create_catalog_item(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminCreateCatalogItemInput>,
+) -> impl IntoResponse {
+    let usecase = AdminCreateCatalogItem::new(app_state.catalog.dynamodb_catalog);
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::CREATED, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_update_catalog_item(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminUpdateCatalogItemInput>,
+) -> impl IntoResponse {
+    let usecase = AdminUpdateCatalogItem::new(app_state.catalog.dynamodb_catalog);
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_delete_catalog_item(
+    State(app_state): State<AppState>,
+    Path((process, item_id)): Path<(PartProcess, CatalogItemId)>,
+    AdminSession(_): AdminSession,
+) -> impl IntoResponse {
+    let input = AdminDeleteCatalogItemInput { process, item_id };
+    let usecase = AdminDeleteCatalogItem::new(app_state.catalog.dynamodb_catalog);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
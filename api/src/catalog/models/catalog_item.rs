@@ -0,0 +1,49 @@
+use crate::parts::models::part::PartProcess;
+use crate::shared::CatalogItemId;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use uuid::{ContextV7, Timestamp, Uuid};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CatalogItem {
+    pub id: CatalogItemId,
+    pub process: PartProcess,
+    pub kind: CatalogItemKind,
+    /// Value customers select in the part attributes (e.g., "Aluminum 6061-T6").
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl CatalogItem {
+    pub fn new(
+        process: PartProcess,
+        kind: CatalogItemKind,
+        name: String,
+        description: Option<String>,
+    ) -> Self {
+        let now = Utc::now();
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let encoded_id = format!("cat_{}", bs58::encode(id).into_string());
+
+        Self {
+            id: encoded_id,
+            process,
+            kind,
+            name,
+            description,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CatalogItemKind {
+    Material,
+    Finish,
+    Tolerance,
+}
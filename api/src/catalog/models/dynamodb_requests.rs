@@ -0,0 +1,14 @@
+use crate::catalog::models::catalog_item::CatalogItemKind;
+use crate::parts::models::part::PartProcess;
+use crate::shared::CatalogItemId;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdatableCatalogItem {
+    pub id: CatalogItemId,
+    pub process: PartProcess,
+    /// Needed to rebuild the kind and name sort key when renaming.
+    pub kind: CatalogItemKind,
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
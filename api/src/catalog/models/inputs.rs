@@ -0,0 +1,34 @@
+use crate::catalog::models::catalog_item::CatalogItemKind;
+use crate::parts::models::part::PartProcess;
+use crate::shared::CatalogItemId;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryCatalogInput {
+    pub process: PartProcess,
+    pub kind: Option<CatalogItemKind>,
+    pub cursor: Option<String>,
+    pub limit: i32,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCreateCatalogItemInput {
+    pub process: PartProcess,
+    pub kind: CatalogItemKind,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdateCatalogItemInput {
+    pub process: PartProcess,
+    pub item_id: CatalogItemId,
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminDeleteCatalogItemInput {
+    pub process: PartProcess,
+    pub item_id: CatalogItemId,
+}
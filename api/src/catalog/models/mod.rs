@@ -0,0 +1,4 @@
+pub mod catalog_item;
+pub mod dynamodb_requests;
+pub mod inputs;
+pub mod responses;
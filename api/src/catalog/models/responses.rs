@@ -0,0 +1,8 @@
+use crate::catalog::models::catalog_item::CatalogItem;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryCatalogResponse {
+    pub items: Vec<CatalogItem>,
+    pub cursor: Option<String>,
+}
@@ -0,0 +1,20 @@
+use crate::app_state::AppState;
+use crate::catalog::controllers::{
+    admin_create_catalog_item, admin_delete_catalog_item, admin_update_catalog_item, query_catalog,
+};
+use axum::routing::{delete, get, patch, post};
+use axum::Router;
+
+pub fn create_public_router() -> Router<AppState> {
+    Router::new().route("/catalog", get(query_catalog))
+}
+
+pub fn create_private_router() -> Router<AppState> {
+    Router::new()
+        .route("/admin/catalog", post(admin_create_catalog_item))
+        .route("/admin/catalog", patch(admin_update_catalog_item))
+        .route(
+            "/admin/catalog/:process/:item_id",
+            delete(admin_delete_catalog_item),
+        )
+}
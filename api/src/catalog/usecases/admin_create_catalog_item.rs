@@ -0,0 +1,50 @@
+use crate::catalog::models::catalog_item::CatalogItem;
+use crate::catalog::models::inputs::AdminCreateCatalogItemInput;
+use crate::repositories::catalog::CatalogRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminCreateCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    catalog_repository: Arc<C>,
+}
+
+impl<C> AdminCreateCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    pub fn new(catalog_repository: Arc<C>) -> Self {
+        Self { catalog_repository }
+    }
+}
+
+#[async_trait]
+impl<C> UseCase<AdminCreateCatalogItemInput, CatalogItem> for AdminCreateCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    async fn execute(&self, input: AdminCreateCatalogItemInput) -> Result<CatalogItem> {
+        let existing_items = self
+            .catalog_repository
+            .query(
+                input.process.clone(),
+                Some(input.kind.clone()),
+                Some(input.name.clone()),
+                None,
+                1,
+            )
+            .await?;
+        if !existing_items.data.is_empty() {
+            return Err(Error::CatalogItemAlreadyExists(input.name));
+        }
+
+        let item = CatalogItem::new(input.process, input.kind, input.name, input.description);
+        self.catalog_repository.create(item.clone()).await?;
+
+        Ok(item)
+    }
+}
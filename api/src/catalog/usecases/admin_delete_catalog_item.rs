@@ -0,0 +1,33 @@
+use crate::catalog::models::inputs::AdminDeleteCatalogItemInput;
+use crate::repositories::catalog::CatalogRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminDeleteCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    catalog_repository: Arc<C>,
+}
+
+impl<C> AdminDeleteCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    pub fn new(catalog_repository: Arc<C>) -> Self {
+        Self { catalog_repository }
+    }
+}
+
+#[async_trait]
+impl<C> UseCase<AdminDeleteCatalogItemInput, ()> for AdminDeleteCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    async fn execute(&self, input: AdminDeleteCatalogItemInput) -> Result<()> {
+        self.catalog_repository
+            .delete(input.process, input.item_id)
+            .await
+    }
+}
@@ -0,0 +1,67 @@
+use crate::catalog::models::catalog_item::CatalogItem;
+use crate::catalog::models::dynamodb_requests::UpdatableCatalogItem;
+use crate::catalog::models::inputs::AdminUpdateCatalogItemInput;
+use crate::repositories::catalog::CatalogRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminUpdateCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    catalog_repository: Arc<C>,
+}
+
+impl<C> AdminUpdateCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    pub fn new(catalog_repository: Arc<C>) -> Self {
+        Self { catalog_repository }
+    }
+}
+
+#[async_trait]
+impl<C> UseCase<AdminUpdateCatalogItemInput, CatalogItem> for AdminUpdateCatalogItem<C>
+where
+    C: CatalogRepository,
+{
+    async fn execute(&self, input: AdminUpdateCatalogItemInput) -> Result<CatalogItem> {
+        let item = self
+            .catalog_repository
+            .get(input.process.clone(), input.item_id.clone())
+            .await?;
+
+        if let Some(name) = &input.name {
+            let existing_items = self
+                .catalog_repository
+                .query(
+                    item.process.clone(),
+                    Some(item.kind.clone()),
+                    Some(name.clone()),
+                    None,
+                    1,
+                )
+                .await?;
+            if existing_items
+                .data
+                .iter()
+                .any(|existing| existing.id != item.id)
+            {
+                return Err(Error::CatalogItemAlreadyExists(name.clone()));
+            }
+        }
+
+        let updatable_item = UpdatableCatalogItem {
+            id: item.id,
+            process: item.process,
+            kind: item.kind,
+            name: input.name,
+            description: input.description,
+        };
+
+        self.catalog_repository.update(updatable_item).await
+    }
+}
@@ -0,0 +1,4 @@
+pub mod admin_create_catalog_item;
+pub mod admin_delete_catalog_item;
+pub mod admin_update_catalog_item;
+pub mod query_catalog;
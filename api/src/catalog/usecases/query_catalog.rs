@@ -0,0 +1,40 @@
+use crate::catalog::models::inputs::QueryCatalogInput;
+use crate::catalog::models::responses::QueryCatalogResponse;
+use crate::repositories::catalog::CatalogRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct QueryCatalog<C>
+where
+    C: CatalogRepository,
+{
+    catalog_repository: Arc<C>,
+}
+
+impl<C> QueryCatalog<C>
+where
+    C: CatalogRepository,
+{
+    pub fn new(catalog_repository: Arc<C>) -> Self {
+        Self { catalog_repository }
+    }
+}
+
+#[async_trait]
+impl<C> UseCase<QueryCatalogInput, QueryCatalogResponse> for QueryCatalog<C>
+where
+    C: CatalogRepository,
+{
+    async fn execute(&self, input: QueryCatalogInput) -> Result<QueryCatalogResponse> {
+        let response = self
+            .catalog_repository
+            .query(input.process, input.kind, None, input.cursor, input.limit)
+            .await?;
+
+        Ok(QueryCatalogResponse {
+            items: response.data,
+            cursor: response.cursor,
+        })
+    }
+}
@@ -1,14 +1,22 @@
+use crate::shared::shipping::ShippingMethod;
+use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use std::collections::HashMap;
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Config {
     pub app: ConfigApp,
     pub auth: ConfigAuth,
+    pub billing: ConfigBilling,
+    pub catalog: ConfigCatalog,
     pub orders: ConfigOrders,
     pub projects: ConfigProjects,
+    pub promotions: ConfigPromotions,
     pub quotes: ConfigQuotes,
+    pub suppliers: ConfigSuppliers,
     pub parts: ConfigParts,
+    pub pricing: ConfigPricing,
     pub services: ConfigServices,
     pub payments: ConfigPayments,
 }
@@ -26,10 +34,26 @@ pub struct ConfigAuth {
     pub ory_clients_api_key: String,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigBilling {
+    pub billing_profiles_table: String,
+    pub invoices_table: String,
+    /// RFC of the company issuing the invoices.
+    pub issuer_rfc: String,
+    /// SAT catalog code of the tax regime of the issuing company.
+    pub issuer_tax_regime: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConfigCatalog {
+    pub catalog_table: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ConfigOrders {
     pub s3_bucket: String,
     pub orders_table: String,
+    pub work_orders_table: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -37,9 +61,38 @@ pub struct ConfigProjects {
     pub projects_table: String,
 }
 
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConfigSuppliers {
+    pub suppliers_table: String,
+    pub rfqs_table: String,
+    /// Markup over the awarded bid charged to the customer, e.g. `0.35` for 35%.
+    pub rfq_margin_rate: f64,
+    /// Page suppliers open to bid, the quotation id, RFQ id and access token are appended to it.
+    pub rfq_url: String,
+    /// Page suppliers open to create their portal account, the supplier id and invitation token
+    /// are appended to it.
+    pub invitation_url: String,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConfigPromotions {
+    pub promotion_codes_table: String,
+    pub pricing_agreements_table: String,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ConfigQuotes {
     pub quotes_table: String,
+    pub quotation_revisions_table: String,
+    pub document: ConfigQuotesDocument,
+}
+
+/// Issuer details printed on quotation documents.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigQuotesDocument {
+    pub company_name: String,
+    /// One entry per printed line.
+    pub company_address: Vec<String>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -48,17 +101,50 @@ pub struct ConfigParts {
     pub parts_table: String,
 }
 
+/// Rate tables used to instantly quote low-risk CNC parts. Amounts are in cents (MXN).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigPricing {
+    pub setup_cost: i64,
+    pub removal_cost_per_cm3: i64,
+    pub surface_cost_per_cm2: i64,
+    /// Parts with a bigger dimension always go through manual review.
+    pub max_dimension_mm: f64,
+    /// Quotations above this subtotal always go through manual review.
+    pub max_instant_subtotal: i64,
+    /// Stock cost by material name, as it appears in the catalog.
+    pub materials: HashMap<String, ConfigPricingMaterial>,
+    /// One part quote is offered per lead time.
+    pub lead_times: Vec<ConfigPricingLeadTime>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigPricingMaterial {
+    pub stock_cost_per_cm3: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigPricingLeadTime {
+    pub workdays: u64,
+    pub multiplier: f64,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ConfigPayments {
     pub secret_key: String,
     pub webhook_secret: String,
     pub success_url: String,
-    pub tax_ids: ConfigPaymentsTaxIds,
+    /// Where suppliers land after setting up their Stripe account, or when the setup link
+    /// expired.
+    pub connect_return_url: String,
+    pub webhook_events_table: String,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct ConfigServices {
     pub emailer: ConfigEmailer,
+    pub exchange_rates: ConfigExchangeRates,
+    pub taxes: ConfigTaxes,
+    pub shipping: ConfigShipping,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -67,9 +153,56 @@ pub struct ConfigEmailer {
     pub admin_emails: Vec<String>,
 }
 
+/// How much one unit of `base` is worth in each of the other currencies.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigExchangeRates {
+    pub base: Currency,
+    pub rates: HashMap<Currency, f64>,
+}
+
+/// Taxes added on top of quotation subtotals, by shipping country and state.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigTaxes {
+    /// Two-letter country code used until the customer enters a shipping address.
+    pub default_country: String,
+    /// Shipping to countries without rates isn't supported.
+    pub rates: Vec<ConfigTaxRate>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigTaxRate {
+    pub country: String,
+    /// Rates for a state replace the country rate with the same name.
+    pub state: Option<String>,
+    /// Name printed on documents (e.g., "IVA").
+    pub name: String,
+    /// 0.16 for 16%.
+    pub rate: f64,
+    /// Stripe tax rate with the same percentage, country and state.
+    pub stripe_tax_rate_id: String,
+}
+
+/// Shipping rates by destination country and method, in MXN like the pricing rates.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConfigShipping {
+    /// g/cm³ by material name, as it appears in the catalog.
+    pub material_densities: HashMap<String, f64>,
+    /// g/cm³ of materials without a density of their own.
+    pub default_density: f64,
+    /// Weight of parts whose model couldn't be analyzed.
+    pub default_part_weight_kg: f64,
+    /// Countries without rates can't be shipped to, methods without a rate aren't offered.
+    pub rates: Vec<ConfigShippingRate>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct ConfigPaymentsTaxIds {
-    pub mexico: Vec<String>,
+pub struct ConfigShippingRate {
+    pub country: String,
+    pub method: ShippingMethod,
+    pub base_cost: i64,
+    pub cost_per_kg: i64,
+    pub min_workdays: u64,
+    pub max_workdays: u64,
 }
 
 impl From<&str> for Config {
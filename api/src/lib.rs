@@ -1,6 +1,8 @@
 pub mod app;
 pub mod app_state;
 pub mod auth;
+pub mod billing;
+pub mod catalog;
 pub mod config;
 mod landing;
 mod middleware;
@@ -8,8 +10,14 @@ pub mod orders;
 pub mod parts;
 pub mod payments;
 pub mod projects;
+pub mod promotions;
 pub mod quotations;
+// Repositories are only public for the in-memory fakes of the integration tests.
+#[cfg(feature = "test-utils")]
+pub mod repositories;
+#[cfg(not(feature = "test-utils"))]
 mod repositories;
-mod services;
+pub mod services;
 pub mod shared;
+pub mod suppliers;
 mod utils;
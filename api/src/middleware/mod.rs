@@ -1,8 +1,10 @@
 use crate::app_state::AppState;
 use crate::auth;
-use crate::auth::controllers::{ADMIN_SESSION_TOKEN, CUSTOMER_SESSION_TOKEN};
+use crate::auth::controllers::{
+    ADMIN_SESSION_TOKEN, CUSTOMER_SESSION_TOKEN, SUPPLIER_SESSION_TOKEN,
+};
 use crate::services::identity_manager::IdentityManager;
-use crate::shared::extractors::session::{AdminSession, CustomerSession};
+use crate::shared::extractors::session::{AdminSession, CustomerSession, SupplierSession};
 use axum::response::{IntoResponse, Response};
 use http::{header, HeaderMap, HeaderValue, Request};
 use lambda_http::tower::Layer;
@@ -51,7 +53,10 @@ impl<S> Session<S> {
 
         for cookie in self.get_cookie_str(header_map).split(';') {
             let (token_name, token_value) = cookie.trim().split_once('=').unwrap();
-            if token_name == CUSTOMER_SESSION_TOKEN || token_name == ADMIN_SESSION_TOKEN {
+            if token_name == CUSTOMER_SESSION_TOKEN
+                || token_name == ADMIN_SESSION_TOKEN
+                || token_name == SUPPLIER_SESSION_TOKEN
+            {
                 tokens.push(token_value.to_owned());
             }
         }
@@ -99,6 +104,15 @@ where
                         auth::models::session::Role::Customer => {
                             req.extensions_mut().insert(CustomerSession(session));
                         }
+                        // Supplier identities without a supplier don't get a session.
+                        auth::models::session::Role::Supplier => {
+                            if let Some(supplier_id) =
+                                session.identity.metadata_public.supplier_id.clone()
+                            {
+                                req.extensions_mut()
+                                    .insert(SupplierSession(session, supplier_id));
+                            }
+                        }
                     };
                 }
             }
@@ -1,14 +1,34 @@
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
 use axum::response::IntoResponse;
 use axum::Json;
-use serde_derive::Deserialize;
+use http::StatusCode;
+use serde_derive::{Deserialize, Serialize};
 
 use crate::app_state::AppState;
-use crate::orders::models::inputs::QueryOrdersForCustomerInput;
+use crate::orders::models::inputs::{
+    AdminCancelOrdersInput, AdminCreateWorkOrderInput, AdminGetWorkOrderInput,
+    AdminQueryOpenWorkOrdersInput, AdminRecordInspectionReportInput, AdminUpdateOrderStatusInput,
+    AdminUpdateWorkOrderOperationInput, AdminUploadMaterialCertificateInput, CancelOrderData,
+    GetOrderQualityDocumentsInput, OperationUpdate, QueryOrdersForCustomerInput,
+};
+use crate::orders::models::inspection_report::Measurement;
+use crate::orders::models::order::{OrderStatus, Shipment};
+use crate::orders::usecases::admin_cancel_orders::AdminCancelOrders;
+use crate::orders::usecases::admin_create_work_order::AdminCreateWorkOrder;
+use crate::orders::usecases::admin_get_work_order::AdminGetWorkOrder;
+use crate::orders::usecases::admin_query_open_work_orders::AdminQueryOpenWorkOrders;
+use crate::orders::usecases::admin_record_inspection_report::AdminRecordInspectionReport;
+use crate::orders::usecases::admin_update_order_status::AdminUpdateOrderStatus;
+use crate::orders::usecases::admin_update_work_order_operation::AdminUpdateWorkOrderOperation;
+use crate::orders::usecases::admin_upload_material_certificate::AdminUploadMaterialCertificate;
+use crate::orders::usecases::get_order_quality_documents::GetOrderQualityDocuments;
+use crate::orders::usecases::query_open_orders::QueryOpenOrders;
 use crate::orders::usecases::query_orders_by_customer::QueryOrdersByCustomer;
-use crate::shared::extractors::session::CustomerSession;
+use crate::shared::extractors::session::{AdminSession, CustomerSession};
 use crate::shared::into_error_response::IntoError;
-use crate::shared::UseCase;
+use crate::shared::{CustomerId, OrderId, QuoteId, UseCase};
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 #[derive(Deserialize)]
 pub struct QueryOrdersForCustomerQueryParameters {
@@ -39,3 +59,250 @@ pub async fn query_orders_for_customer(
         Err(err) => Err(err.into_error_response()),
     }
 }
+
+pub async fn admin_query_open_orders(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+) -> impl IntoResponse {
+    let usecase = QueryOpenOrders::new(app_state.orders.dynamodb_orders);
+    let result = usecase.execute(()).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdateOrderStatusRequest {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub status: OrderStatus,
+    pub shipment: Option<Shipment>,
+}
+
+pub async fn admin_update_order_status(
+    State(app_state): State<AppState>,
+    AdminSession(session): AdminSession,
+    Json(request): Json<AdminUpdateOrderStatusRequest>,
+) -> impl IntoResponse {
+    let input = AdminUpdateOrderStatusInput {
+        identity: session.identity,
+        customer_id: request.customer_id,
+        order_id: request.order_id,
+        status: request.status,
+        shipment: request.shipment,
+    };
+    let usecase = AdminUpdateOrderStatus::new(
+        app_state.orders.dynamodb_orders,
+        app_state.auth.ory_kratos,
+        app_state.services.emailer.ses,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCancelOrdersRequest {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+    pub orders: Vec<CancelOrderData>,
+}
+
+pub async fn admin_cancel_orders(
+    State(app_state): State<AppState>,
+    AdminSession(session): AdminSession,
+    Json(request): Json<AdminCancelOrdersRequest>,
+) -> impl IntoResponse {
+    let input = AdminCancelOrdersInput {
+        identity: session.identity,
+        customer_id: request.customer_id,
+        quotation_id: request.quotation_id,
+        orders: request.orders,
+    };
+    let usecase = AdminCancelOrders::new(
+        app_state.orders.dynamodb_orders,
+        app_state.quotes.dynamodb_quotes,
+        app_state.projects.dynamodb_projects,
+        app_state.parts.dynamodb_parts,
+        app_state.billing.dynamodb_invoices,
+        Arc::new(Mutex::new(app_state.payments.transaction)),
+        app_state.payments.stripe_client,
+        app_state.auth.ory_kratos,
+        app_state.services.emailer.ses,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct AdminQueryOpenWorkOrdersQueryParameters {
+    pub cursor: Option<String>,
+    pub limit: Option<i32>,
+}
+
+pub async fn admin_query_open_work_orders(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Query(params): Query<AdminQueryOpenWorkOrdersQueryParameters>,
+) -> impl IntoResponse {
+    let input = AdminQueryOpenWorkOrdersInput {
+        cursor: params.cursor,
+        limit: params.limit.unwrap_or(20),
+    };
+    let usecase = AdminQueryOpenWorkOrders::new(app_state.orders.dynamodb_work_orders);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_create_work_order(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminCreateWorkOrderInput>,
+) -> impl IntoResponse {
+    let usecase = AdminCreateWorkOrder::new(
+        app_state.orders.dynamodb_orders,
+        app_state.parts.dynamodb_parts,
+        app_state.orders.dynamodb_work_orders,
+    );
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::CREATED, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_get_work_order(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Path((customer_id, order_id)): Path<(CustomerId, OrderId)>,
+) -> impl IntoResponse {
+    let input = AdminGetWorkOrderInput {
+        customer_id,
+        order_id,
+    };
+    let usecase = AdminGetWorkOrder::new(app_state.orders.dynamodb_work_orders);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdateWorkOrderOperationRequest {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub sequence: u32,
+    pub update: OperationUpdate,
+}
+
+pub async fn admin_update_work_order_operation(
+    State(app_state): State<AppState>,
+    AdminSession(session): AdminSession,
+    Json(request): Json<AdminUpdateWorkOrderOperationRequest>,
+) -> impl IntoResponse {
+    let input = AdminUpdateWorkOrderOperationInput {
+        identity: session.identity,
+        customer_id: request.customer_id,
+        order_id: request.order_id,
+        sequence: request.sequence,
+        update: request.update,
+    };
+    let update_order_status = AdminUpdateOrderStatus::new(
+        app_state.orders.dynamodb_orders.clone(),
+        app_state.auth.ory_kratos,
+        app_state.services.emailer.ses,
+    );
+    let usecase = AdminUpdateWorkOrderOperation::new(
+        app_state.orders.dynamodb_orders,
+        app_state.orders.dynamodb_work_orders,
+        Arc::new(update_order_status),
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminRecordInspectionReportRequest {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub measurements: Vec<Measurement>,
+}
+
+pub async fn admin_record_inspection_report(
+    State(app_state): State<AppState>,
+    AdminSession(session): AdminSession,
+    Json(request): Json<AdminRecordInspectionReportRequest>,
+) -> impl IntoResponse {
+    let input = AdminRecordInspectionReportInput {
+        identity: session.identity,
+        customer_id: request.customer_id,
+        order_id: request.order_id,
+        measurements: request.measurements,
+    };
+    let usecase = AdminRecordInspectionReport::new(
+        app_state.orders.dynamodb_orders,
+        app_state.parts.dynamodb_parts,
+        app_state.parts.s3,
+        app_state.orders.pdf_renderer,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_upload_material_certificate(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminUploadMaterialCertificateInput>,
+) -> impl IntoResponse {
+    let usecase =
+        AdminUploadMaterialCertificate::new(app_state.orders.dynamodb_orders, app_state.parts.s3);
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::CREATED, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn get_order_quality_documents(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+    Path(order_id): Path<OrderId>,
+) -> impl IntoResponse {
+    let input = GetOrderQualityDocumentsInput {
+        identity: session.identity,
+        order_id,
+    };
+    let usecase =
+        GetOrderQualityDocuments::new(app_state.orders.dynamodb_orders, app_state.parts.s3);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
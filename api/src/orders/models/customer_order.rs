@@ -0,0 +1,70 @@
+use crate::orders::models::order::{Address, Order, OrderStatus, Shipment};
+use crate::shared::file::File;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingCharge;
+use crate::shared::{OrderId, PartId, PartQuoteId, ProjectId, QuoteId};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+/// Order as the customer that paid for it sees it. The supplier making it, its payout and the
+/// prices used to calculate it are left out. Quality documents are fetched with their download
+/// links.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CustomerOrder {
+    pub id: OrderId,
+    pub project_id: ProjectId,
+    pub quotation_id: QuoteId,
+    pub part_id: PartId,
+    pub part_quote_id: PartQuoteId,
+    pub deadline: NaiveDate,
+    pub status: OrderStatus,
+    pub shipping_recipient_name: String,
+    pub shipping_address: Address,
+    pub shipping: Option<ShippingCharge>,
+    pub status_history: Vec<CustomerOrderStatusTransition>,
+    pub shipment: Option<Shipment>,
+    pub shipping_documents: Vec<File>,
+    /// Amount given back when the order got cancelled.
+    pub refund: Option<Money>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Status change without who made it, which can be the supplier.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CustomerOrderStatusTransition {
+    pub from: OrderStatus,
+    pub to: OrderStatus,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<Order> for CustomerOrder {
+    fn from(order: Order) -> Self {
+        Self {
+            id: order.id,
+            project_id: order.project_id,
+            quotation_id: order.quotation_id,
+            part_id: order.part_id,
+            part_quote_id: order.part_quote_id,
+            deadline: order.deadline,
+            status: order.status,
+            shipping_recipient_name: order.shipping_recipient_name,
+            shipping_address: order.shipping_address,
+            shipping: order.shipping,
+            status_history: order
+                .status_history
+                .into_iter()
+                .map(|transition| CustomerOrderStatusTransition {
+                    from: transition.from,
+                    to: transition.to,
+                    updated_at: transition.updated_at,
+                })
+                .collect(),
+            shipment: order.shipment,
+            shipping_documents: order.shipping_documents,
+            refund: order.refund,
+            created_at: order.created_at,
+            updated_at: order.updated_at,
+        }
+    }
+}
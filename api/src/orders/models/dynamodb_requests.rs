@@ -0,0 +1,69 @@
+use crate::orders::models::inspection_report::InspectionReport;
+use crate::orders::models::order::{OrderStatusTransition, Shipment};
+use crate::orders::models::work_order::Operation;
+use crate::shared::file::File;
+use crate::shared::money::Money;
+use crate::shared::{CustomerId, OrderId, SupplierId, WorkOrderId};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdatableOrder {
+    pub id: OrderId,
+    pub customer_id: CustomerId,
+    /// Needed to rebuild the status sort key, which embeds the creation date.
+    pub created_at: DateTime<Utc>,
+    pub status_transition: Option<OrderStatusTransition>,
+    pub shipment: Option<Shipment>,
+    /// Appended to the shipping documents of the order.
+    pub shipping_document: Option<File>,
+    /// Replaces the inspection report along with its certificate of conformance, which is
+    /// removed when none is given.
+    pub inspection_report: Option<InspectionReport>,
+    pub certificate_of_conformance: Option<File>,
+    /// Appended to the material certificates of the order.
+    pub material_certificate: Option<File>,
+    pub refund: Option<Money>,
+    pub pending_refund_key: Option<String>,
+    /// Removes the pending refund key once Stripe accepted the refund.
+    pub is_refund_issued: bool,
+    pub supplier_id: Option<SupplierId>,
+    pub payout: Option<Money>,
+    pub payout_transfer_id: Option<String>,
+}
+
+impl UpdatableOrder {
+    pub fn partial_new(
+        customer_id: CustomerId,
+        order_id: OrderId,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: order_id,
+            customer_id,
+            created_at,
+            status_transition: None,
+            shipment: None,
+            shipping_document: None,
+            inspection_report: None,
+            certificate_of_conformance: None,
+            material_certificate: None,
+            refund: None,
+            pending_refund_key: None,
+            is_refund_issued: false,
+            supplier_id: None,
+            payout: None,
+            payout_transfer_id: None,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdatableWorkOrder {
+    pub id: WorkOrderId,
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub operations: Vec<Operation>,
+    /// When the work order was read, the update is rejected if it changed since.
+    pub read_updated_at: DateTime<Utc>,
+}
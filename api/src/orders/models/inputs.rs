@@ -1,6 +1,9 @@
 use crate::auth::models::session::Identity;
+use crate::orders::models::inspection_report::Measurement;
+use crate::orders::models::order::{OrderStatus, Shipment};
 use crate::shared::file::File;
 use crate::shared::money::Money;
+use crate::shared::{CustomerId, OrderId, QuoteId};
 use chrono::NaiveDate;
 use serde_derive::{Deserialize, Serialize};
 
@@ -19,3 +22,84 @@ pub struct QueryOrdersForCustomerInput {
     pub cursor: Option<String>,
     pub limit: i32,
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdateOrderStatusInput {
+    pub identity: Identity,
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub status: OrderStatus,
+    pub shipment: Option<Shipment>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCancelOrdersInput {
+    pub identity: Identity,
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+    pub orders: Vec<CancelOrderData>,
+}
+
+#[derive(Deserialize, Serialize, Clone, Debug)]
+pub struct CancelOrderData {
+    pub order_id: OrderId,
+    /// Amount to give back, the full price of the order when missing.
+    pub refund: Option<Money>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCreateWorkOrderInput {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminGetWorkOrderInput {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminQueryOpenWorkOrdersInput {
+    pub cursor: Option<String>,
+    pub limit: i32,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdateWorkOrderOperationInput {
+    pub identity: Identity,
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub sequence: u32,
+    pub update: OperationUpdate,
+}
+
+/// What an operator did on an operation.
+#[derive(Deserialize, Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "action", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OperationUpdate {
+    Start,
+    Complete,
+    LogTime { minutes: u64, note: Option<String> },
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminRecordInspectionReportInput {
+    pub identity: Identity,
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub measurements: Vec<Measurement>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUploadMaterialCertificateInput {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub file_name: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetOrderQualityDocumentsInput {
+    pub identity: Identity,
+    pub order_id: OrderId,
+}
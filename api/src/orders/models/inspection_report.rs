@@ -0,0 +1,90 @@
+use crate::auth::models::session::IdentityId;
+use crate::shared::error::Error;
+use crate::shared::Result;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+/// Dimensional inspection of the parts of an order, a new inspection replaces the previous one.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InspectionReport {
+    pub measurements: Vec<Measurement>,
+    /// Every measurement is within its tolerance.
+    pub passed: bool,
+    pub inspector_id: IdentityId,
+    pub inspector_email: String,
+    pub inspected_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Measurement {
+    /// Feature as called out on the drawing (e.g., "Bore Ø12").
+    pub feature: String,
+    pub nominal_mm: f64,
+    /// Allowed deviation above the nominal.
+    pub upper_tolerance_mm: f64,
+    /// Allowed deviation below the nominal.
+    pub lower_tolerance_mm: f64,
+    pub measured_mm: f64,
+}
+
+impl InspectionReport {
+    pub fn new(
+        measurements: Vec<Measurement>,
+        inspector_id: IdentityId,
+        inspector_email: String,
+    ) -> Result<Self> {
+        if measurements.is_empty() {
+            return Err(Error::InvalidInspectionReport(String::from(
+                "At least one measurement is required",
+            )));
+        }
+        for measurement in &measurements {
+            measurement.validate()?;
+        }
+
+        Ok(Self {
+            passed: measurements.iter().all(Measurement::passed),
+            measurements,
+            inspector_id,
+            inspector_email,
+            inspected_at: Utc::now(),
+        })
+    }
+}
+
+impl Measurement {
+    pub fn passed(&self) -> bool {
+        let lower_limit = self.nominal_mm - self.lower_tolerance_mm;
+        let upper_limit = self.nominal_mm + self.upper_tolerance_mm;
+
+        (lower_limit..=upper_limit).contains(&self.measured_mm)
+    }
+
+    fn validate(&self) -> Result<()> {
+        if self.feature.trim().is_empty() {
+            return Err(Error::InvalidInspectionReport(String::from(
+                "Measurements require the feature they were taken on",
+            )));
+        }
+        let values = [
+            self.nominal_mm,
+            self.upper_tolerance_mm,
+            self.lower_tolerance_mm,
+            self.measured_mm,
+        ];
+        if values.iter().any(|value| !value.is_finite()) {
+            return Err(Error::InvalidInspectionReport(format!(
+                "Measurement of `{}` has an invalid value",
+                self.feature
+            )));
+        }
+        if self.upper_tolerance_mm < 0.0 || self.lower_tolerance_mm < 0.0 {
+            return Err(Error::InvalidInspectionReport(format!(
+                "Tolerances of `{}` can't be negative",
+                self.feature
+            )));
+        }
+
+        Ok(())
+    }
+}
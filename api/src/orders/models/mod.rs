@@ -1,3 +1,7 @@
+pub mod customer_order;
+pub mod dynamodb_requests;
 pub mod inputs;
+pub mod inspection_report;
 pub mod order;
 pub mod responses;
+pub mod work_order;
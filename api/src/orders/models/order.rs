@@ -1,4 +1,11 @@
-use crate::shared::{CustomerId, OrderId, PartId, PartQuoteId, ProjectId, QuoteId};
+use crate::auth::models::session::IdentityId;
+use crate::orders::models::inspection_report::InspectionReport;
+use crate::shared::file::File;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingCharge;
+use crate::shared::{
+    CustomerId, OrderId, PartId, PartQuoteId, ProjectId, QuoteId, Result, SupplierId,
+};
 use chrono::{DateTime, NaiveDate, Utc};
 use serde_derive::{Deserialize, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
@@ -16,11 +23,41 @@ pub struct Order {
     pub status: OrderStatus,
     pub shipping_recipient_name: String,
     pub shipping_address: Address,
+    /// Method chosen at checkout and its cost. Shipping is charged once per quotation, so every
+    /// order of the quotation has the same one. Orders paid before shipping was charged don't
+    /// have it.
+    pub shipping: Option<ShippingCharge>,
+    pub status_history: Vec<OrderStatusTransition>,
+    pub shipment: Option<Shipment>,
+    /// Packing lists, customs invoices and the like uploaded by the supplier.
+    pub shipping_documents: Vec<File>,
+    pub inspection_report: Option<InspectionReport>,
+    /// Mill certs of the stock the part was made from, uploaded by admins.
+    pub material_certificates: Vec<File>,
+    /// Generated once the parts pass inspection.
+    pub certificate_of_conformance: Option<File>,
+    /// Stripe payment intent of the quotation, orders paid before refunds existed don't have it.
+    pub payment_intent_id: Option<String>,
+    /// Amount given back to the customer when the order got cancelled.
+    pub refund: Option<Money>,
+    /// Idempotency key of the refund, stored along with the cancellation and removed once Stripe
+    /// accepts the refund. Cancelling the order again retries a refund that is still pending.
+    pub pending_refund_key: Option<String>,
+    /// Subtotal of the part quote paid for the order, before discounts, taxes and shipping.
+    /// Orders paid before suppliers existed don't have it.
+    pub price: Option<Money>,
+    /// Supplier making the part.
+    pub supplier_id: Option<SupplierId>,
+    /// Amount paid to the supplier for making the part.
+    pub payout: Option<Money>,
+    /// Stripe transfer of the payout to the supplier, the payout can't change once it exists.
+    pub payout_transfer_id: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl Order {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         customer_id: CustomerId,
         project_id: ProjectId,
@@ -31,6 +68,9 @@ impl Order {
         status: OrderStatus,
         shipping_recipient_name: String,
         shipping_address: Address,
+        shipping: Option<ShippingCharge>,
+        payment_intent_id: Option<String>,
+        price: Money,
     ) -> Self {
         let now = Utc::now();
         let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
@@ -47,10 +87,32 @@ impl Order {
             status,
             shipping_recipient_name,
             shipping_address,
+            shipping,
+            status_history: Vec::new(),
+            shipment: None,
+            shipping_documents: Vec::new(),
+            inspection_report: None,
+            material_certificates: Vec::new(),
+            certificate_of_conformance: None,
+            payment_intent_id,
+            refund: None,
+            pending_refund_key: None,
+            price: Some(price),
+            supplier_id: None,
+            payout: None,
+            payout_transfer_id: None,
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// What's left of the price after paying the supplier, known once both are set.
+    pub fn margin(&self) -> Result<Option<Money>> {
+        match (&self.price, &self.payout) {
+            (Some(price), Some(payout)) => Ok(Some(price.checked_sub(payout)?)),
+            _ => Ok(None),
+        }
+    }
 }
 
 #[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
@@ -61,6 +123,67 @@ pub enum OrderStatus {
     Ready,
     Shipped,
     Delivered,
+    /// The payment of the quotation was fully refunded.
+    Refunded,
+    /// Cancelled by an admin after payment, see `Order::refund`.
+    Cancelled,
+}
+
+impl OrderStatus {
+    /// Orders can only move forward through the production lifecycle, one step at a time.
+    pub fn can_transition_to(&self, next: &OrderStatus) -> bool {
+        matches!(
+            (self, next),
+            (OrderStatus::Open, OrderStatus::InProgress)
+                | (OrderStatus::InProgress, OrderStatus::Ready)
+                | (OrderStatus::Ready, OrderStatus::Shipped)
+                | (OrderStatus::Shipped, OrderStatus::Delivered)
+        )
+    }
+
+    /// Orders can be cancelled until they leave the shop.
+    pub fn can_be_cancelled(&self) -> bool {
+        self.is_in_shop()
+    }
+
+    /// Orders waiting for or going through production.
+    pub fn is_in_shop(&self) -> bool {
+        matches!(
+            self,
+            OrderStatus::Open | OrderStatus::InProgress | OrderStatus::Ready
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OrderStatusTransition {
+    pub from: OrderStatus,
+    pub to: OrderStatus,
+    /// Identity of the admin or supplier that made the change.
+    pub updated_by: IdentityId,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OrderStatusTransition {
+    pub fn new(from: OrderStatus, to: OrderStatus, updated_by: IdentityId) -> Self {
+        Self {
+            from,
+            to,
+            updated_by,
+            updated_at: Utc::now(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Shipment {
+    /// Name of the shipping company (e.g., DHL, FedEx, Estafeta).
+    pub carrier: String,
+    pub tracking_number: String,
+    /// Link where the customer can follow the package.
+    pub tracking_url: Option<String>,
+    /// Date the package left the shop.
+    pub shipped_at: NaiveDate,
 }
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
@@ -1,7 +1,11 @@
 use serde_derive::{Deserialize, Serialize};
 
+use crate::orders::models::customer_order::CustomerOrder;
+use crate::orders::models::inspection_report::InspectionReport;
 use crate::orders::models::order::Order;
+use crate::orders::models::work_order::WorkOrder;
 use crate::parts::models::part::Part;
+use crate::shared::file::File;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct CreateDrawingUploadUrlResponse {
@@ -28,6 +32,31 @@ pub struct QueryOrdersForCustomerResponse {
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct QueryOrdersForCustomerResponseData {
-    pub order: Order,
+    pub order: CustomerOrder,
     pub part: Option<Part>,
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryOpenWorkOrdersResponse {
+    pub work_orders: Vec<WorkOrder>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UploadMaterialCertificateResponse {
+    pub upload_url: String,
+    pub file: File,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QualityDocument {
+    pub file_name: String,
+    pub url: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct OrderQualityDocumentsResponse {
+    pub inspection_report: Option<InspectionReport>,
+    pub material_certificates: Vec<QualityDocument>,
+    pub certificate_of_conformance: Option<QualityDocument>,
+}
@@ -0,0 +1,257 @@
+use crate::auth::models::session::IdentityId;
+use crate::orders::models::order::{Order, OrderStatus};
+use crate::parts::models::part::{PartAttributes, PartProcess};
+use crate::parts::models::part_attributes::{InspectionLevel, PrintingTechnology};
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, OrderId, PartId, Result, WorkOrderId};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use uuid::{ContextV7, Timestamp, Uuid};
+
+/// Gap between the sequence numbers of the routing, leaves room to add operations in between.
+static OPERATION_SEQUENCE_STEP: u32 = 10;
+
+/// Shop-floor traveler of an order, with the routing of operations the part goes through.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct WorkOrder {
+    pub id: WorkOrderId,
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub part_id: PartId,
+    pub process: PartProcess,
+    pub quantity: u64,
+    /// Done one after the other, by ascending sequence.
+    pub operations: Vec<Operation>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Operation {
+    pub sequence: u32,
+    pub name: String,
+    pub status: OperationStatus,
+    pub started_at: Option<DateTime<Utc>>,
+    /// Identity of the operator that started the operation.
+    pub started_by: Option<IdentityId>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub completed_by: Option<IdentityId>,
+    pub time_logs: Vec<TimeLog>,
+}
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OperationStatus {
+    Pending,
+    InProgress,
+    Completed,
+}
+
+/// Time an operator spent on an operation, logged when it's done rather than clocked.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TimeLog {
+    pub operator_id: IdentityId,
+    pub minutes: u64,
+    pub note: Option<String>,
+    pub logged_at: DateTime<Utc>,
+}
+
+impl WorkOrder {
+    pub fn new(order: &Order, quantity: u64, attributes: &PartAttributes) -> Self {
+        let now = Utc::now();
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let encoded_id = format!("wo_{}", bs58::encode(id).into_string());
+
+        Self {
+            id: encoded_id,
+            customer_id: order.customer_id.clone(),
+            order_id: order.id.clone(),
+            part_id: order.part_id.clone(),
+            process: attributes.process(),
+            quantity,
+            operations: Operation::routing(attributes),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.operations
+            .iter()
+            .all(|operation| operation.status == OperationStatus::Completed)
+    }
+
+    /// Statuses the order went through given the progress of the operations, production
+    /// starts with the first operation and the part is ready once every operation is done.
+    pub fn reached_order_statuses(&self) -> Vec<OrderStatus> {
+        let started = self
+            .operations
+            .iter()
+            .any(|operation| operation.status != OperationStatus::Pending);
+
+        match (started, self.is_completed()) {
+            (_, true) => vec![OrderStatus::InProgress, OrderStatus::Ready],
+            (true, false) => vec![OrderStatus::InProgress],
+            (false, false) => Vec::new(),
+        }
+    }
+
+    pub fn start_operation(
+        &mut self,
+        sequence: u32,
+        operator_id: IdentityId,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let index = self.operation_index(sequence)?;
+        if self.operations[index].status != OperationStatus::Pending {
+            return Err(Error::InvalidWorkOrderOperation(format!(
+                "Operation `{sequence}` was already started"
+            )));
+        }
+        if let Some(previous) = self.operations[..index]
+            .iter()
+            .find(|operation| operation.status != OperationStatus::Completed)
+        {
+            return Err(Error::InvalidWorkOrderOperation(format!(
+                "Operation `{}` has to be completed first",
+                previous.sequence
+            )));
+        }
+
+        let operation = &mut self.operations[index];
+        operation.status = OperationStatus::InProgress;
+        operation.started_at = Some(now);
+        operation.started_by = Some(operator_id);
+
+        Ok(())
+    }
+
+    pub fn complete_operation(
+        &mut self,
+        sequence: u32,
+        operator_id: IdentityId,
+        now: DateTime<Utc>,
+    ) -> Result<()> {
+        let index = self.operation_index(sequence)?;
+        let operation = &mut self.operations[index];
+        if operation.status != OperationStatus::InProgress {
+            return Err(Error::InvalidWorkOrderOperation(format!(
+                "Operation `{sequence}` isn't in progress"
+            )));
+        }
+
+        operation.status = OperationStatus::Completed;
+        operation.completed_at = Some(now);
+        operation.completed_by = Some(operator_id);
+
+        Ok(())
+    }
+
+    /// Time can be logged while the operation is in progress and after it's completed.
+    pub fn log_time(&mut self, sequence: u32, time_log: TimeLog) -> Result<()> {
+        if time_log.minutes == 0 {
+            return Err(Error::InvalidTimeLog(String::from(
+                "At least one minute has to be logged",
+            )));
+        }
+
+        let index = self.operation_index(sequence)?;
+        let operation = &mut self.operations[index];
+        if operation.status == OperationStatus::Pending {
+            return Err(Error::InvalidWorkOrderOperation(format!(
+                "Operation `{sequence}` hasn't been started"
+            )));
+        }
+
+        operation.time_logs.push(time_log);
+
+        Ok(())
+    }
+
+    fn operation_index(&self, sequence: u32) -> Result<usize> {
+        self.operations
+            .iter()
+            .position(|operation| operation.sequence == sequence)
+            .ok_or_else(|| {
+                Error::InvalidWorkOrderOperation(format!(
+                    "Operation `{sequence}` isn't part of the routing"
+                ))
+            })
+    }
+}
+
+impl Operation {
+    fn new(sequence: u32, name: String) -> Self {
+        Self {
+            sequence,
+            name,
+            status: OperationStatus::Pending,
+            started_at: None,
+            started_by: None,
+            completed_at: None,
+            completed_by: None,
+            time_logs: Vec::new(),
+        }
+    }
+
+    /// Operations a part goes through given its process, CNC parts also get their heat
+    /// treatment, surface finish and the inspection level asked for.
+    pub fn routing(attributes: &PartAttributes) -> Vec<Operation> {
+        let names: Vec<String> = match attributes {
+            PartAttributes::CNC(attrs) => {
+                let mut names = vec![
+                    String::from("Saw"),
+                    String::from("Mill Op 1"),
+                    String::from("Mill Op 2"),
+                    String::from("Deburr"),
+                ];
+                if let Some(heat_treatment) = &attrs.heat_treatment {
+                    names.push(format!("Heat Treat: {heat_treatment}"));
+                }
+                if let Some(surface_finish) = &attrs.surface_finish {
+                    match &attrs.color {
+                        Some(color) => names.push(format!("{surface_finish} ({color})")),
+                        None => names.push(surface_finish.clone()),
+                    }
+                }
+                names.push(String::from(match attrs.inspection_level {
+                    InspectionLevel::Standard => "Inspect",
+                    InspectionLevel::FirstArticle => "First Article Inspection",
+                    InspectionLevel::Cmm => "CMM Inspection",
+                }));
+                names
+            }
+            PartAttributes::ThreeDPrinting(attrs) => {
+                let post_processing: &[&str] = match attrs.technology {
+                    PrintingTechnology::FDM => &["Remove Supports"],
+                    PrintingTechnology::SLA => &["Wash & Cure", "Remove Supports"],
+                    PrintingTechnology::SLS => &["Depowder", "Bead Blast"],
+                };
+                std::iter::once(format!("Print ({})", attrs.technology))
+                    .chain(post_processing.iter().map(|name| name.to_string()))
+                    .chain(std::iter::once(String::from("Inspect")))
+                    .collect()
+            }
+            PartAttributes::SheetMetal(attrs) => {
+                let mut names = vec![String::from("Laser Cut"), String::from("Deburr")];
+                if attrs.bend_count > 0 {
+                    names.push(String::from("Bend"));
+                }
+                names.push(String::from("Inspect"));
+                names
+            }
+            PartAttributes::InjectionMolding(_) => vec![
+                String::from("Mold"),
+                String::from("Trim"),
+                String::from("Inspect"),
+            ],
+        };
+
+        names
+            .into_iter()
+            .zip(1..)
+            .map(|(name, position)| Operation::new(position * OPERATION_SEQUENCE_STEP, name))
+            .collect()
+    }
+}
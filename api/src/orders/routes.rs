@@ -1,9 +1,40 @@
-use axum::routing::get;
+use axum::routing::{get, patch, post};
 use axum::Router;
 
 use crate::app_state::AppState;
-use crate::orders::controllers::query_orders_for_customer;
+use crate::orders::controllers::{
+    admin_cancel_orders, admin_create_work_order, admin_get_work_order, admin_query_open_orders,
+    admin_query_open_work_orders, admin_record_inspection_report, admin_update_order_status,
+    admin_update_work_order_operation, admin_upload_material_certificate,
+    get_order_quality_documents, query_orders_for_customer,
+};
 
 pub fn create_router() -> Router<AppState> {
-    Router::new().route("/orders", get(query_orders_for_customer))
+    Router::new()
+        .route("/orders", get(query_orders_for_customer))
+        .route(
+            "/orders/:order_id/quality_documents",
+            get(get_order_quality_documents),
+        )
+        .route("/admin/orders", get(admin_query_open_orders))
+        .route("/admin/orders/status", patch(admin_update_order_status))
+        .route("/admin/orders/cancel", post(admin_cancel_orders))
+        .route(
+            "/admin/orders/inspection_report",
+            post(admin_record_inspection_report),
+        )
+        .route(
+            "/admin/orders/material_certificates",
+            post(admin_upload_material_certificate),
+        )
+        .route("/admin/work_orders", get(admin_query_open_work_orders))
+        .route("/admin/work_orders", post(admin_create_work_order))
+        .route(
+            "/admin/work_orders/operations",
+            patch(admin_update_work_order_operation),
+        )
+        .route(
+            "/admin/customers/:customer_id/orders/:order_id/work_order",
+            get(admin_get_work_order),
+        )
 }
@@ -0,0 +1,392 @@
+use crate::auth::models::session::IdentityId;
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::inputs::AdminCancelOrdersInput;
+use crate::orders::models::order::{Order, OrderStatus, OrderStatusTransition};
+use crate::quotations::models::quotation::{Quotation, QuoteStatus};
+use crate::quotations::usecases::unlock_project::unlock_project_transaction;
+use crate::repositories::invoices::InvoicesRepository;
+use crate::repositories::orders::{self, OrdersRepository};
+use crate::repositories::parts::PartsRepository;
+use crate::repositories::projects::ProjectsRepository;
+use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::transaction::Transaction;
+use crate::services::emailer::Emailer;
+use crate::services::identity_manager::IdentityManager;
+use crate::services::stripe_client::StripeClient;
+use crate::shared::error::Error;
+use crate::shared::money::Money;
+use crate::shared::{OrderId, Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub struct AdminCancelOrders<O, Q, Pro, P, Inv, Tx, TxItem>
+where
+    O: OrdersRepository<TransactionItem = TxItem>,
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Inv: InvoicesRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    orders_repository: Arc<O>,
+    quotes_repository: Arc<Q>,
+    projects_repository: Arc<Pro>,
+    parts_repository: Arc<P>,
+    invoices_repository: Arc<Inv>,
+    transaction: Arc<Mutex<Tx>>,
+    stripe_client: Arc<dyn StripeClient>,
+    identity_manager: Arc<dyn IdentityManager>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<O, Q, Pro, P, Inv, Tx, TxItem> AdminCancelOrders<O, Q, Pro, P, Inv, Tx, TxItem>
+where
+    O: OrdersRepository<TransactionItem = TxItem>,
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Inv: InvoicesRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        orders_repository: Arc<O>,
+        quotes_repository: Arc<Q>,
+        projects_repository: Arc<Pro>,
+        parts_repository: Arc<P>,
+        invoices_repository: Arc<Inv>,
+        transaction: Arc<Mutex<Tx>>,
+        stripe_client: Arc<dyn StripeClient>,
+        identity_manager: Arc<dyn IdentityManager>,
+        emailer_service: Arc<dyn Emailer>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            quotes_repository,
+            projects_repository,
+            parts_repository,
+            invoices_repository,
+            transaction,
+            stripe_client,
+            identity_manager,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, Q, Pro, P, Inv, Tx, TxItem> UseCase<AdminCancelOrdersInput, Vec<Order>>
+    for AdminCancelOrders<O, Q, Pro, P, Inv, Tx, TxItem>
+where
+    O: OrdersRepository<TransactionItem = TxItem>,
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Inv: InvoicesRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn execute(&self, input: AdminCancelOrdersInput) -> Result<Vec<Order>> {
+        if input.orders.is_empty() {
+            return Err(Error::MissingRequiredParameter(String::from("orders")));
+        }
+
+        let quotation = self
+            .quotes_repository
+            .get(input.customer_id.clone(), input.quotation_id.clone())
+            .await?;
+        let quotation_orders = self.quotation_orders(&quotation).await?;
+        let parts = self
+            .parts_repository
+            .query(input.customer_id.clone(), quotation.id.clone(), None, 100)
+            .await?
+            .data;
+        // The invoice records the discount and taxes charged for each order.
+        let invoice = match self
+            .invoices_repository
+            .get(input.customer_id.clone(), quotation.id.clone())
+            .await
+        {
+            Ok(invoice) => Some(invoice),
+            Err(Error::ItemNotFoundError) => None,
+            Err(err) => return Err(err),
+        };
+
+        let order_ids = input
+            .orders
+            .iter()
+            .map(|cancel_order| cancel_order.order_id.clone())
+            .collect::<Vec<OrderId>>();
+        let mut cancellations: Vec<(Order, Money)> = Vec::with_capacity(input.orders.len());
+        let mut pending_refund_keys = Vec::<String>::new();
+        for cancel_order in input.orders {
+            if cancellations
+                .iter()
+                .any(|(order, _)| order.id == cancel_order.order_id)
+            {
+                continue;
+            }
+
+            let order = quotation_orders
+                .iter()
+                .find(|order| order.id == cancel_order.order_id)
+                .ok_or(Error::OrderCannotBeCancelled(cancel_order.order_id.clone()))?;
+
+            // The order was cancelled before but its refund didn't go through.
+            if let (OrderStatus::Cancelled, Some(pending_refund_key)) =
+                (&order.status, &order.pending_refund_key)
+            {
+                if !pending_refund_keys.contains(pending_refund_key) {
+                    pending_refund_keys.push(pending_refund_key.clone());
+                }
+                continue;
+            }
+            if !order.status.can_be_cancelled() {
+                return Err(Error::OrderCannotBeCancelled(order.id.clone()));
+            }
+
+            // Quotations paid before invoices were issued are refunded up to the quoted price.
+            let charged = match &invoice {
+                Some(invoice) => invoice.charged_line_amount(&order.part_id)?,
+                None => parts
+                    .iter()
+                    .find(|part| part.id == order.part_id)
+                    .and_then(|part| {
+                        part.part_quotes
+                            .as_ref()?
+                            .iter()
+                            .find(|part_quote| part_quote.id == order.part_quote_id)
+                            .map(|part_quote| part_quote.sub_total_for(part.quantity))
+                    })
+                    .ok_or(Error::OrderCannotBeCancelled(order.id.clone()))??,
+            };
+
+            let refund = cancel_order.refund.unwrap_or_else(|| charged.clone());
+            let currency = cancellations
+                .first()
+                .map_or(&charged.currency, |(_, refund)| &refund.currency);
+            if refund.currency != *currency || refund.amount < 0 || refund.amount > charged.amount {
+                return Err(Error::InvalidRefundAmount(order.id.clone()));
+            }
+            if refund.amount > 0 && order.payment_intent_id.is_none() {
+                return Err(Error::OrderCannotBeCancelled(order.id.clone()));
+            }
+
+            cancellations.push((order.clone(), refund));
+        }
+
+        if let Some(refund_key) = self
+            .persist_cancellations(cancellations, input.identity.id)
+            .await?
+        {
+            pending_refund_keys.push(refund_key);
+        }
+
+        let mut quotation_orders = self.quotation_orders(&quotation).await?;
+        if !pending_refund_keys.is_empty() {
+            for pending_refund_key in pending_refund_keys {
+                self.refund(&quotation_orders, pending_refund_key).await?;
+            }
+            quotation_orders = self.quotation_orders(&quotation).await?;
+        }
+
+        let has_active_orders = quotation_orders
+            .iter()
+            .any(|order| !matches!(order.status, OrderStatus::Cancelled | OrderStatus::Refunded));
+        if !has_active_orders {
+            self.cancel_quotation(&quotation).await?;
+        }
+
+        let cancelled_orders = quotation_orders
+            .into_iter()
+            .filter(|order| order_ids.contains(&order.id))
+            .collect::<Vec<Order>>();
+        self.notify_customer_of_cancellation(&quotation, &cancelled_orders)
+            .await;
+
+        Ok(cancelled_orders)
+    }
+}
+
+impl<O, Q, Pro, P, Inv, Tx, TxItem> AdminCancelOrders<O, Q, Pro, P, Inv, Tx, TxItem>
+where
+    O: OrdersRepository<TransactionItem = TxItem>,
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Inv: InvoicesRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn quotation_orders(&self, quotation: &Quotation) -> Result<Vec<Order>> {
+        Ok(self
+            .orders_repository
+            .query(
+                Some(quotation.customer_id.clone()),
+                Some(quotation.project_id.clone()),
+                Some(quotation.id.clone()),
+                None,
+                None,
+                None,
+                None,
+                orders::QueryBy::Customer,
+                None,
+                100,
+            )
+            .await?
+            .data)
+    }
+
+    /// Cancels the orders in a single transaction, recording their refunds as pending under a key
+    /// of the orders, which is returned when there's something to refund. The refund is only
+    /// issued afterwards, so a failed refund can be retried with the same key.
+    async fn persist_cancellations(
+        &self,
+        cancellations: Vec<(Order, Money)>,
+        identity_id: IdentityId,
+    ) -> Result<Option<String>> {
+        if cancellations.is_empty() {
+            return Ok(None);
+        }
+
+        let refund_key = cancellations
+            .iter()
+            .any(|(_, refund)| refund.amount > 0)
+            .then(|| {
+                let mut order_ids = cancellations
+                    .iter()
+                    .map(|(order, _)| order.id.as_str())
+                    .collect::<Vec<&str>>();
+                order_ids.sort();
+                format!("cancel_{}", order_ids.join("_"))
+            });
+
+        let mut transaction = self.transaction.lock().await;
+        for (order, refund) in cancellations {
+            let mut updatable_order =
+                UpdatableOrder::partial_new(order.customer_id, order.id, order.created_at);
+            updatable_order.status_transition = Some(OrderStatusTransition::new(
+                order.status,
+                OrderStatus::Cancelled,
+                identity_id.clone(),
+            ));
+            updatable_order.refund = Some(refund);
+            updatable_order.pending_refund_key = refund_key.clone();
+            transaction.add_item(self.orders_repository.transaction_update(updatable_order));
+        }
+        transaction.execute().await?;
+
+        Ok(refund_key)
+    }
+
+    /// Issues a single refund for all the orders cancelled under the refund key, all of them were
+    /// paid with the same payment intent. Once Stripe accepts it the refund stops being pending.
+    async fn refund(&self, quotation_orders: &[Order], refund_key: String) -> Result<()> {
+        let orders = quotation_orders
+            .iter()
+            .filter(|order| order.pending_refund_key.as_ref() == Some(&refund_key))
+            .collect::<Vec<&Order>>();
+        let Some(first_order) = orders.first() else {
+            return Ok(());
+        };
+        let Some(currency) = first_order.refund.as_ref().map(|refund| refund.currency) else {
+            return Err(Error::OrderCannotBeCancelled(first_order.id.clone()));
+        };
+        let amount = orders
+            .iter()
+            .filter_map(|order| order.refund.as_ref())
+            .map(|refund| refund.amount)
+            .sum::<i64>();
+        let payment_intent_id = first_order
+            .payment_intent_id
+            .clone()
+            .ok_or(Error::OrderCannotBeCancelled(first_order.id.clone()))?;
+
+        self.stripe_client
+            .create_refund(payment_intent_id, Money::new(amount, currency), refund_key)
+            .await?;
+
+        let mut transaction = self.transaction.lock().await;
+        for order in orders {
+            let mut updatable_order = UpdatableOrder::partial_new(
+                order.customer_id.clone(),
+                order.id.clone(),
+                order.created_at,
+            );
+            updatable_order.is_refund_issued = true;
+            transaction.add_item(self.orders_repository.transaction_update(updatable_order));
+        }
+        transaction.execute().await
+    }
+
+    /// Cancels a quotation without active orders, unlocking its project when it has no other
+    /// paid quotations. The quotation is read again, the `charge.refunded` webhook of a full
+    /// refund can mark it as refunded while its orders are being cancelled.
+    async fn cancel_quotation(&self, quotation: &Quotation) -> Result<()> {
+        let quotation = self
+            .quotes_repository
+            .get(quotation.customer_id.clone(), quotation.id.clone())
+            .await?;
+        if !matches!(quotation.status, QuoteStatus::Payed | QuoteStatus::Refunded) {
+            return Ok(());
+        }
+
+        let project_transaction = unlock_project_transaction(
+            self.quotes_repository.as_ref(),
+            self.projects_repository.as_ref(),
+            &quotation,
+        )
+        .await?;
+        let quote_transaction = self.quotes_repository.transaction_update(
+            quotation.customer_id.clone(),
+            quotation.project_id.clone(),
+            quotation.id.clone(),
+            quotation.status,
+            QuoteStatus::Cancelled,
+        );
+
+        let mut transaction = self.transaction.lock().await;
+        transaction.add_item(quote_transaction);
+        if let Some(project_transaction) = project_transaction {
+            transaction.add_item(project_transaction);
+        }
+        transaction.execute().await
+    }
+
+    async fn notify_customer_of_cancellation(&self, quotation: &Quotation, orders: &[Order]) {
+        let identity = match self
+            .identity_manager
+            .get_identity(quotation.customer_id.clone())
+            .await
+        {
+            Ok(identity) => identity,
+            Err(_) => return,
+        };
+
+        let refunds = orders
+            .iter()
+            .filter_map(|order| order.refund.as_ref())
+            .filter(|refund| refund.amount > 0)
+            .map(|refund| refund.to_string())
+            .collect::<Vec<String>>();
+        let refund_message = if refunds.is_empty() {
+            String::new()
+        } else {
+            format!("\nRefunds: {}.", refunds.join(", "))
+        };
+
+        let _ = self
+            .emailer_service
+            .send_email(
+                &identity.traits.email,
+                "Your order was cancelled",
+                &format!(
+                    "{} order(s) of your quote {} were cancelled.{refund_message}",
+                    orders.len(),
+                    quotation.name
+                ),
+            )
+            .await;
+    }
+}
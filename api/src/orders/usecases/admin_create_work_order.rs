@@ -0,0 +1,73 @@
+use crate::orders::models::inputs::AdminCreateWorkOrderInput;
+use crate::orders::models::work_order::WorkOrder;
+use crate::repositories::orders::OrdersRepository;
+use crate::repositories::parts::PartsRepository;
+use crate::repositories::work_orders::WorkOrdersRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Creates the work order of an order paid before work orders existed, or whose work order
+/// couldn't be created along with it.
+pub struct AdminCreateWorkOrder<O, P, W>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+    W: WorkOrdersRepository,
+{
+    orders_repository: Arc<O>,
+    parts_repository: Arc<P>,
+    work_orders_repository: Arc<W>,
+}
+
+impl<O, P, W> AdminCreateWorkOrder<O, P, W>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+    W: WorkOrdersRepository,
+{
+    pub fn new(
+        orders_repository: Arc<O>,
+        parts_repository: Arc<P>,
+        work_orders_repository: Arc<W>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            parts_repository,
+            work_orders_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, P, W> UseCase<AdminCreateWorkOrderInput, WorkOrder> for AdminCreateWorkOrder<O, P, W>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+    W: WorkOrdersRepository,
+{
+    async fn execute(&self, input: AdminCreateWorkOrderInput) -> Result<WorkOrder> {
+        let order = self
+            .orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+        if !order.status.is_in_shop() {
+            return Err(Error::InvalidWorkOrderOperation(format!(
+                "Order `{}` already left the shop",
+                order.id
+            )));
+        }
+
+        let part = self
+            .parts_repository
+            .get(order.customer_id.clone(), order.part_id.clone())
+            .await?;
+        let work_order = WorkOrder::new(&order, part.quantity, &part.attributes);
+        self.work_orders_repository
+            .create(work_order.clone())
+            .await?;
+
+        Ok(work_order)
+    }
+}
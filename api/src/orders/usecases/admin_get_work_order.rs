@@ -0,0 +1,36 @@
+use crate::orders::models::inputs::AdminGetWorkOrderInput;
+use crate::orders::models::work_order::WorkOrder;
+use crate::repositories::work_orders::WorkOrdersRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminGetWorkOrder<W>
+where
+    W: WorkOrdersRepository,
+{
+    work_orders_repository: Arc<W>,
+}
+
+impl<W> AdminGetWorkOrder<W>
+where
+    W: WorkOrdersRepository,
+{
+    pub fn new(work_orders_repository: Arc<W>) -> Self {
+        Self {
+            work_orders_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<W> UseCase<AdminGetWorkOrderInput, WorkOrder> for AdminGetWorkOrder<W>
+where
+    W: WorkOrdersRepository,
+{
+    async fn execute(&self, input: AdminGetWorkOrderInput) -> Result<WorkOrder> {
+        self.work_orders_repository
+            .get(input.customer_id, input.order_id)
+            .await
+    }
+}
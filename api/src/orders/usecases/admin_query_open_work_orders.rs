@@ -0,0 +1,47 @@
+use crate::orders::models::inputs::AdminQueryOpenWorkOrdersInput;
+use crate::orders::models::responses::QueryOpenWorkOrdersResponse;
+use crate::repositories::work_orders::WorkOrdersRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Shop-floor queue, the work orders with operations left to do.
+pub struct AdminQueryOpenWorkOrders<W>
+where
+    W: WorkOrdersRepository,
+{
+    work_orders_repository: Arc<W>,
+}
+
+impl<W> AdminQueryOpenWorkOrders<W>
+where
+    W: WorkOrdersRepository,
+{
+    pub fn new(work_orders_repository: Arc<W>) -> Self {
+        Self {
+            work_orders_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<W> UseCase<AdminQueryOpenWorkOrdersInput, QueryOpenWorkOrdersResponse>
+    for AdminQueryOpenWorkOrders<W>
+where
+    W: WorkOrdersRepository,
+{
+    async fn execute(
+        &self,
+        input: AdminQueryOpenWorkOrdersInput,
+    ) -> Result<QueryOpenWorkOrdersResponse> {
+        let response = self
+            .work_orders_repository
+            .query_open(input.cursor, input.limit)
+            .await?;
+
+        Ok(QueryOpenWorkOrdersResponse {
+            work_orders: response.data,
+            cursor: response.cursor,
+        })
+    }
+}
@@ -0,0 +1,106 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::inputs::AdminRecordInspectionReportInput;
+use crate::orders::models::inspection_report::InspectionReport;
+use crate::orders::models::order::Order;
+use crate::repositories::orders::OrdersRepository;
+use crate::repositories::parts::PartsRepository;
+use crate::services::certificate_renderer::CertificateRenderer;
+use crate::services::object_storage::ObjectStorage;
+use crate::shared::error::Error;
+use crate::shared::file::File;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::{ContextV7, Timestamp, Uuid};
+
+/// Records the inspection of the parts of an order, generating its certificate of conformance
+/// when every measurement passed.
+pub struct AdminRecordInspectionReport<O, P>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+{
+    orders_repository: Arc<O>,
+    parts_repository: Arc<P>,
+    object_storage: Arc<dyn ObjectStorage>,
+    certificate_renderer: Arc<dyn CertificateRenderer>,
+}
+
+impl<O, P> AdminRecordInspectionReport<O, P>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+{
+    pub fn new(
+        orders_repository: Arc<O>,
+        parts_repository: Arc<P>,
+        object_storage: Arc<dyn ObjectStorage>,
+        certificate_renderer: Arc<dyn CertificateRenderer>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            parts_repository,
+            object_storage,
+            certificate_renderer,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, P> UseCase<AdminRecordInspectionReportInput, Order> for AdminRecordInspectionReport<O, P>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+{
+    async fn execute(&self, input: AdminRecordInspectionReportInput) -> Result<Order> {
+        let order = self
+            .orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+        if !order.status.is_in_shop() {
+            return Err(Error::InvalidInspectionReport(format!(
+                "Order `{}` already left the shop",
+                order.id
+            )));
+        }
+
+        let inspection_report = InspectionReport::new(
+            input.measurements,
+            input.identity.id,
+            input.identity.traits.email,
+        )?;
+
+        let certificate_of_conformance = if inspection_report.passed {
+            let part = self
+                .parts_repository
+                .get(order.customer_id.clone(), order.part_id.clone())
+                .await?;
+            let pdf = self
+                .certificate_renderer
+                .render_certificate_of_conformance(&order, &part, &inspection_report)
+                .await?;
+
+            let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+            let file_id = format!("file_{}", bs58::encode(id).into_string());
+            let file_key = format!(
+                "orders/certificates_of_conformance/{}/{file_id}.pdf",
+                order.id
+            );
+            self.object_storage.put_object(&file_key, pdf).await?;
+
+            Some(File::new(
+                format!("certificate_of_conformance_{}.pdf", order.id),
+                file_key,
+            ))
+        } else {
+            None
+        };
+
+        let mut updatable_order =
+            UpdatableOrder::partial_new(order.customer_id, order.id, order.created_at);
+        updatable_order.inspection_report = Some(inspection_report);
+        updatable_order.certificate_of_conformance = certificate_of_conformance;
+
+        self.orders_repository.update(updatable_order).await
+    }
+}
@@ -0,0 +1,122 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::inputs::AdminUpdateOrderStatusInput;
+use crate::orders::models::order::{Order, OrderStatus, OrderStatusTransition, Shipment};
+use crate::repositories::orders::OrdersRepository;
+use crate::services::emailer::Emailer;
+use crate::services::identity_manager::IdentityManager;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use url::Url;
+
+pub struct AdminUpdateOrderStatus<O>
+where
+    O: OrdersRepository,
+{
+    orders_repository: Arc<O>,
+    identity_manager: Arc<dyn IdentityManager>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<O> AdminUpdateOrderStatus<O>
+where
+    O: OrdersRepository,
+{
+    pub fn new(
+        orders_repository: Arc<O>,
+        identity_manager: Arc<dyn IdentityManager>,
+        emailer_service: Arc<dyn Emailer>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            identity_manager,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<O> UseCase<AdminUpdateOrderStatusInput, Order> for AdminUpdateOrderStatus<O>
+where
+    O: OrdersRepository,
+{
+    async fn execute(&self, input: AdminUpdateOrderStatusInput) -> Result<Order> {
+        let order = self
+            .orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+
+        if !order.status.can_transition_to(&input.status) {
+            return Err(Error::InvalidOrderStatusTransition(
+                order.status,
+                input.status,
+            ));
+        }
+
+        let shipment = match (&input.status, input.shipment) {
+            (OrderStatus::Shipped, Some(shipment)) => {
+                if let Some(tracking_url) = &shipment.tracking_url {
+                    Url::parse(tracking_url).map_err(|_| Error::InvalidUrl)?;
+                }
+                Some(shipment)
+            }
+            (OrderStatus::Shipped, None) => {
+                return Err(Error::MissingRequiredParameter(String::from("shipment")))
+            }
+            (_, _) => None,
+        };
+
+        let mut updatable_order =
+            UpdatableOrder::partial_new(order.customer_id, order.id, order.created_at);
+        updatable_order.status_transition = Some(OrderStatusTransition::new(
+            order.status,
+            input.status,
+            input.identity.id,
+        ));
+        updatable_order.shipment = shipment;
+
+        let order = self.orders_repository.update(updatable_order).await?;
+
+        if let Some(shipment) = &order.shipment {
+            if order.status == OrderStatus::Shipped {
+                self.notify_customer_of_shipment(&order, shipment).await;
+            }
+        }
+
+        Ok(order)
+    }
+}
+
+impl<O> AdminUpdateOrderStatus<O>
+where
+    O: OrdersRepository,
+{
+    async fn notify_customer_of_shipment(&self, order: &Order, shipment: &Shipment) {
+        let identity = match self
+            .identity_manager
+            .get_identity(order.customer_id.clone())
+            .await
+        {
+            Ok(identity) => identity,
+            Err(_) => return,
+        };
+
+        let mut message = format!(
+            "Your order with id {} has been shipped on {} with {}.\nTracking number: {}",
+            order.id, shipment.shipped_at, shipment.carrier, shipment.tracking_number,
+        );
+        if let Some(tracking_url) = &shipment.tracking_url {
+            message.push_str(&format!("\nTrack your package at: {tracking_url}"));
+        }
+
+        let _ = self
+            .emailer_service
+            .send_email(
+                &identity.traits.email,
+                "Your order has been shipped",
+                &message,
+            )
+            .await;
+    }
+}
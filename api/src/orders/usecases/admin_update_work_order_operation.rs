@@ -0,0 +1,117 @@
+use crate::orders::models::dynamodb_requests::UpdatableWorkOrder;
+use crate::orders::models::inputs::{
+    AdminUpdateOrderStatusInput, AdminUpdateWorkOrderOperationInput, OperationUpdate,
+};
+use crate::orders::models::order::Order;
+use crate::orders::models::work_order::{TimeLog, WorkOrder};
+use crate::repositories::orders::OrdersRepository;
+use crate::repositories::work_orders::WorkOrdersRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// Records the progress of an operator on an operation and moves the order along with it, to
+/// `InProgress` once the first operation starts and to `Ready` once the last one is completed.
+pub struct AdminUpdateWorkOrderOperation<O, W>
+where
+    O: OrdersRepository,
+    W: WorkOrdersRepository,
+{
+    orders_repository: Arc<O>,
+    work_orders_repository: Arc<W>,
+    update_order_status: Arc<dyn UseCase<AdminUpdateOrderStatusInput, Order> + Send + Sync>,
+}
+
+impl<O, W> AdminUpdateWorkOrderOperation<O, W>
+where
+    O: OrdersRepository,
+    W: WorkOrdersRepository,
+{
+    pub fn new(
+        orders_repository: Arc<O>,
+        work_orders_repository: Arc<W>,
+        update_order_status: Arc<dyn UseCase<AdminUpdateOrderStatusInput, Order> + Send + Sync>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            work_orders_repository,
+            update_order_status,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, W> UseCase<AdminUpdateWorkOrderOperationInput, WorkOrder>
+    for AdminUpdateWorkOrderOperation<O, W>
+where
+    O: OrdersRepository,
+    W: WorkOrdersRepository,
+{
+    async fn execute(&self, input: AdminUpdateWorkOrderOperationInput) -> Result<WorkOrder> {
+        let mut order = self
+            .orders_repository
+            .get(input.customer_id.clone(), input.order_id.clone())
+            .await?;
+        if !order.status.is_in_shop() {
+            return Err(Error::InvalidWorkOrderOperation(format!(
+                "Order `{}` already left the shop",
+                order.id
+            )));
+        }
+
+        let mut work_order = self
+            .work_orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+        let now = Utc::now();
+        let operator_id = input.identity.id.clone();
+        match input.update {
+            OperationUpdate::Start => {
+                work_order.start_operation(input.sequence, operator_id, now)?
+            }
+            OperationUpdate::Complete => {
+                work_order.complete_operation(input.sequence, operator_id, now)?
+            }
+            OperationUpdate::LogTime { minutes, note } => work_order.log_time(
+                input.sequence,
+                TimeLog {
+                    operator_id,
+                    minutes,
+                    note,
+                    logged_at: now,
+                },
+            )?,
+        }
+
+        let work_order = self
+            .work_orders_repository
+            .update(UpdatableWorkOrder {
+                id: work_order.id,
+                customer_id: work_order.customer_id,
+                order_id: work_order.order_id,
+                operations: work_order.operations,
+                read_updated_at: work_order.updated_at,
+            })
+            .await?;
+
+        for status in work_order.reached_order_statuses() {
+            if !order.status.can_transition_to(&status) {
+                continue;
+            }
+            order = self
+                .update_order_status
+                .execute(AdminUpdateOrderStatusInput {
+                    identity: input.identity.clone(),
+                    customer_id: order.customer_id,
+                    order_id: order.id,
+                    status,
+                    shipment: None,
+                })
+                .await?;
+        }
+
+        Ok(work_order)
+    }
+}
@@ -0,0 +1,82 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::inputs::AdminUploadMaterialCertificateInput;
+use crate::orders::models::responses::UploadMaterialCertificateResponse;
+use crate::repositories::orders::OrdersRepository;
+use crate::services::object_storage::ObjectStorage;
+use crate::shared::error::Error;
+use crate::shared::file::File;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+use uuid::{ContextV7, Timestamp, Uuid};
+
+static PRESIGNED_URLS_PUT_DURATION_SECONDS: u64 = 300;
+
+pub struct AdminUploadMaterialCertificate<O>
+where
+    O: OrdersRepository,
+{
+    orders_repository: Arc<O>,
+    object_storage: Arc<dyn ObjectStorage>,
+}
+
+impl<O> AdminUploadMaterialCertificate<O>
+where
+    O: OrdersRepository,
+{
+    pub fn new(orders_repository: Arc<O>, object_storage: Arc<dyn ObjectStorage>) -> Self {
+        Self {
+            orders_repository,
+            object_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<O> UseCase<AdminUploadMaterialCertificateInput, UploadMaterialCertificateResponse>
+    for AdminUploadMaterialCertificate<O>
+where
+    O: OrdersRepository,
+{
+    async fn execute(
+        &self,
+        input: AdminUploadMaterialCertificateInput,
+    ) -> Result<UploadMaterialCertificateResponse> {
+        let file_extension = match input.file_name.rsplit_once('.') {
+            Some((_, extension)) if !extension.is_empty() => extension.to_lowercase(),
+            _ => {
+                return Err(Error::MissingRequiredParameter(String::from(
+                    "file_name extension",
+                )))
+            }
+        };
+        let order = self
+            .orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let file_id = format!("file_{}", bs58::encode(id).into_string());
+        let file_key = format!(
+            "orders/material_certificates/{}/{file_id}.{file_extension}",
+            order.id
+        );
+        let file = File::new(input.file_name, file_key.clone());
+
+        let mut updatable_order =
+            UpdatableOrder::partial_new(order.customer_id, order.id, order.created_at);
+        updatable_order.material_certificate = Some(file.clone());
+        self.orders_repository.update(updatable_order).await?;
+
+        let upload_url = self
+            .object_storage
+            .put_object_presigned_url(
+                &file_key,
+                Duration::from_secs(PRESIGNED_URLS_PUT_DURATION_SECONDS),
+            )
+            .await?;
+
+        Ok(UploadMaterialCertificateResponse { upload_url, file })
+    }
+}
@@ -0,0 +1,80 @@
+use crate::orders::models::inputs::GetOrderQualityDocumentsInput;
+use crate::orders::models::responses::{OrderQualityDocumentsResponse, QualityDocument};
+use crate::repositories::orders::OrdersRepository;
+use crate::services::object_storage::ObjectStorage;
+use crate::shared::file::File;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+static PRESIGNED_URL_DURATION_SECONDS: u64 = 300;
+
+pub struct GetOrderQualityDocuments<O>
+where
+    O: OrdersRepository,
+{
+    orders_repository: Arc<O>,
+    object_storage: Arc<dyn ObjectStorage>,
+}
+
+impl<O> GetOrderQualityDocuments<O>
+where
+    O: OrdersRepository,
+{
+    pub fn new(orders_repository: Arc<O>, object_storage: Arc<dyn ObjectStorage>) -> Self {
+        Self {
+            orders_repository,
+            object_storage,
+        }
+    }
+
+    async fn presign(&self, file: File) -> Result<QualityDocument> {
+        let url = self
+            .object_storage
+            .get_object_presigned_url(
+                &file.key,
+                Duration::from_secs(PRESIGNED_URL_DURATION_SECONDS),
+            )
+            .await?;
+
+        Ok(QualityDocument {
+            file_name: file.name,
+            url,
+        })
+    }
+}
+
+#[async_trait]
+impl<O> UseCase<GetOrderQualityDocumentsInput, OrderQualityDocumentsResponse>
+    for GetOrderQualityDocuments<O>
+where
+    O: OrdersRepository,
+{
+    async fn execute(
+        &self,
+        input: GetOrderQualityDocumentsInput,
+    ) -> Result<OrderQualityDocumentsResponse> {
+        let order = self
+            .orders_repository
+            .get(input.identity.id, input.order_id)
+            .await?;
+
+        let mut material_certificates = Vec::with_capacity(order.material_certificates.len());
+        for material_certificate in order.material_certificates {
+            material_certificates.push(self.presign(material_certificate).await?);
+        }
+        let certificate_of_conformance = match order.certificate_of_conformance {
+            Some(certificate_of_conformance) => {
+                Some(self.presign(certificate_of_conformance).await?)
+            }
+            None => None,
+        };
+
+        Ok(OrderQualityDocumentsResponse {
+            inspection_report: order.inspection_report,
+            material_certificates,
+            certificate_of_conformance,
+        })
+    }
+}
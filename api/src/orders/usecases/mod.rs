@@ -1,2 +1,11 @@
+pub mod admin_cancel_orders;
+pub mod admin_create_work_order;
+pub mod admin_get_work_order;
+pub mod admin_query_open_work_orders;
+pub mod admin_record_inspection_report;
+pub mod admin_update_order_status;
+pub mod admin_update_work_order_operation;
+pub mod admin_upload_material_certificate;
+pub mod get_order_quality_documents;
 pub mod query_open_orders;
 pub mod query_orders_by_customer;
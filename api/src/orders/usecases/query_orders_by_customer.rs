@@ -1,3 +1,4 @@
+use crate::orders::models::customer_order::CustomerOrder;
 use crate::orders::models::inputs::QueryOrdersForCustomerInput;
 use crate::orders::models::responses::{
     QueryOrdersForCustomerResponse, QueryOrdersForCustomerResponseData,
@@ -81,7 +82,10 @@ where
             .map(|order| {
                 let part_id = order.part_id.clone();
                 let part = parts_map.remove(&part_id);
-                QueryOrdersForCustomerResponseData { order, part }
+                QueryOrdersForCustomerResponseData {
+                    order: CustomerOrder::from(order),
+                    part,
+                }
             })
             .collect();
 
@@ -1,13 +1,15 @@
 use crate::app_state::AppState;
 use crate::parts::models::inputs::{
-    AdminQueryPartsForQuotationInput, CreatePartQuotesInput, CreatePartsInput, DeletePartInput,
-    GetPartInput, QueryPartsForQuotationInput, UpdatePartInput, UpdateSelectedPartQuoteInput,
+    AdminQueryPartsForQuotationInput, AnalyzePartGeometryInput, CreatePartQuotesInput,
+    CreatePartsInput, DeletePartInput, GetPartInput, QueryPartsForQuotationInput, UpdatePartInput,
+    UpdateSelectedPartQuoteInput,
 };
 use crate::parts::models::part::{PartAttributes, PartProcess};
 use crate::parts::usecases::admin_generate_presigned_url::{
     AdminGeneratePresignedUrl, AdminGeneratePresignedUrlInput,
 };
 use crate::parts::usecases::admin_query_parts_for_quotation::AdminQueryPartsForQuotation;
+use crate::parts::usecases::analyze_part_geometry::AnalyzePartGeometry;
 use crate::parts::usecases::create_part_quotes::CreatePartQuotes;
 use crate::parts::usecases::create_parts::CreateParts;
 use crate::parts::usecases::delete_drawing_file::{DeleteDrawingFile, DeleteDrawingFileInput};
@@ -102,7 +104,10 @@ pub async fn admin_create_part_quotes(
     let usecase = CreatePartQuotes::new(
         app_state.parts.dynamodb_parts,
         app_state.quotes.dynamodb_quotes,
+        app_state.quotes.dynamodb_quotation_revisions,
+        app_state.promotions.dynamodb_pricing_agreements,
         Arc::new(Mutex::new(app_state.payments.transaction)),
+        app_state.services.exchange_rates,
     );
     let result = usecase.execute(request).await;
 
@@ -152,6 +157,24 @@ pub async fn admin_generate_presigned_url(
     }
 }
 
+pub async fn admin_analyze_part_geometry(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AnalyzePartGeometryInput>,
+) -> impl IntoResponse {
+    let usecase = AnalyzePartGeometry::new(
+        app_state.parts.dynamodb_parts,
+        app_state.quotes.dynamodb_quotes,
+        app_state.parts.s3,
+    );
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(part) => Ok((StatusCode::OK, Json(part))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
 pub async fn get_part(
     State(app_state): State<AppState>,
     Path(part_id): Path<PartId>,
@@ -263,6 +286,7 @@ pub async fn update_part(
     let usecase = UpdatePart::new(
         app_state.parts.dynamodb_parts,
         app_state.quotes.dynamodb_quotes,
+        app_state.catalog.dynamodb_catalog,
     );
     let result = usecase.execute(input).await;
 
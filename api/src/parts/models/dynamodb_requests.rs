@@ -1,5 +1,6 @@
 use crate::parts::models::inputs::UpdatePartInput;
 use crate::parts::models::part::{PartAttributes, PartProcess};
+use crate::parts::models::part_geometry::PartGeometry;
 use crate::shared::file::File;
 use crate::shared::{CustomerId, PartId, PartQuoteId};
 use serde_derive::{Deserialize, Serialize};
@@ -8,9 +9,13 @@ use serde_derive::{Deserialize, Serialize};
 pub struct UpdatablePart {
     pub id: PartId,
     pub customer_id: CustomerId,
+    /// Setting the render file clears any previous conversion error.
+    pub render_file: Option<File>,
+    pub conversion_error: Option<String>,
     pub drawing_file: Option<File>,
     pub process: Option<PartProcess>,
     pub attributes: Option<PartAttributes>,
+    pub geometry: Option<PartGeometry>,
     pub quantity: Option<u64>,
     pub additional_notes: Option<String>,
     pub selected_part_quote_id: Option<PartQuoteId>,
@@ -32,9 +37,12 @@ impl From<&UpdatePartInput> for UpdatablePart {
         Self {
             id: input.part_id.clone(),
             customer_id: input.identity.id.clone(),
+            render_file: None,
+            conversion_error: None,
             drawing_file: input.drawing_file.clone(),
             process: input.process.clone(),
             attributes: input.attributes.clone(),
+            geometry: None,
             quantity: input.quantity,
             additional_notes: input.additional_notes.clone(),
             selected_part_quote_id: None,
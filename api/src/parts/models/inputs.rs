@@ -1,5 +1,5 @@
 use crate::auth::models::session::Identity;
-use crate::parts::models::part::{PartAttributes, PartProcess};
+use crate::parts::models::part::{PartAttributes, PartProcess, QuantityTier};
 use crate::shared::file::File;
 use crate::shared::money::Money;
 use crate::shared::{CustomerId, PartId, PartQuoteId, ProjectId, QuoteId};
@@ -63,8 +63,10 @@ pub struct CreatePartQuotesInput {
 pub struct CreatePartQuotesInputData {
     pub part_id: PartId,
     pub unit_price: Money,
+    /// Recalculated from `unit_price` and the quantity of the part once priced.
     pub sub_total: Money,
     pub workdays_to_complete: u64,
+    pub quantity_tiers: Option<Vec<QuantityTier>>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -82,3 +84,15 @@ pub struct DeletePartInput {
     pub quotation_id: QuoteId,
     pub part_id: PartId,
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AnalyzePartGeometryInput {
+    pub customer_id: CustomerId,
+    pub part_id: PartId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ConvertPartModelInput {
+    /// Object storage key of the original model.
+    pub key: String,
+}
@@ -2,4 +2,6 @@ pub mod dynamodb_requests;
 pub mod inputs;
 pub mod part;
 pub mod part_attributes;
+pub mod part_file_key;
+pub mod part_geometry;
 pub mod responses;
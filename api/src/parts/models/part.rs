@@ -1,15 +1,18 @@
 pub(crate) use crate::parts::models::part_attributes::PartAttributes;
-use crate::parts::models::part_attributes::Tolerance;
+use crate::parts::models::part_attributes::{PrintingTechnology, Tolerance};
+use crate::parts::models::part_geometry::PartGeometry;
 use crate::shared::error::Error;
 use crate::shared::file::File;
 use crate::shared::money::Money;
 use crate::shared::{CustomerId, PartId, PartQuoteId, ProjectId, QuoteId};
 use chrono::{DateTime, Days, Utc};
+use iso_currency::Currency;
 use serde::{Deserialize, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use uuid::{ContextV7, Timestamp, Uuid};
 
 static PART_QUOTE_VALID_DAYS: u64 = 30;
+static SHEET_METAL_THICKNESS_RANGE_MM: (f64, f64) = (0.5, 6.35);
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Part {
@@ -19,9 +22,12 @@ pub struct Part {
     pub quotation_id: QuoteId,
     pub model_file: File,
     pub render_file: Option<File>,
+    /// Why the model couldn't be converted into a render, if it failed.
+    pub conversion_error: Option<String>,
     pub drawing_file: Option<File>,
     pub process: PartProcess,
     pub attributes: PartAttributes,
+    pub geometry: Option<PartGeometry>,
     pub quantity: u64,
     pub additional_notes: String,
     pub selected_part_quote_id: Option<PartQuoteId>,
@@ -50,9 +56,11 @@ impl Part {
             quotation_id,
             model_file,
             render_file: None,
+            conversion_error: None,
             drawing_file: None,
             process,
             attributes,
+            geometry: None,
             quantity: 1,
             additional_notes: String::default(),
             selected_part_quote_id: None,
@@ -62,39 +70,144 @@ impl Part {
         }
     }
 
+    pub fn selected_part_quote(&self) -> Option<&PartQuote> {
+        self.part_quotes
+            .as_ref()?
+            .iter()
+            .find(|part_quote| Some(&part_quote.id) == self.selected_part_quote_id.as_ref())
+    }
+
+    /// Fails when the selected part quote is past its `valid_until`.
+    pub fn validate_part_quote_expiry(&self, now: DateTime<Utc>) -> Result<(), Error> {
+        match self.selected_part_quote() {
+            Some(part_quote) if part_quote.is_expired(now) => {
+                Err(Error::PartQuoteExpired(self.id.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn validate(&self) -> Result<(), Error> {
+        if self.attributes.process() != self.process {
+            return Err(Error::InvalidPartAttributes(format!(
+                "The attributes provided don't belong to the '{}' process",
+                self.process
+            )));
+        }
+
         match &self.attributes {
             PartAttributes::CNC(attributes) => {
-                if attributes.tolerance == Tolerance::Other && self.drawing_file.is_none() {
+                self.validate_tolerance(&attributes.tolerance)?;
+
+                if attributes.color.is_some() && attributes.surface_finish.is_none() {
+                    return Err(Error::InvalidPartAttributes(String::from(
+                        "A surface finish is required when selecting a color",
+                    )));
+                }
+                if attributes
+                    .threads
+                    .iter()
+                    .any(|thread| thread.specification.trim().is_empty() || thread.count == 0)
+                {
+                    return Err(Error::InvalidPartAttributes(String::from(
+                        "Thread callouts require a specification and at least one hole",
+                    )));
+                }
+            }
+            PartAttributes::ThreeDPrinting(attributes) => {
+                let (min, max) = attributes.technology.layer_height_range_mm();
+                if !(min..=max).contains(&attributes.layer_height_mm) {
+                    return Err(Error::InvalidPartAttributes(format!(
+                        "Layer height for {} must be between {min}mm and {max}mm",
+                        attributes.technology
+                    )));
+                }
+
+                match (&attributes.technology, attributes.infill_percentage) {
+                    (PrintingTechnology::FDM, None) => {
+                        return Err(Error::InvalidPartAttributes(String::from(
+                            "Infill percentage is required for FDM prints",
+                        )));
+                    }
+                    (PrintingTechnology::FDM, Some(infill_percentage))
+                        if infill_percentage == 0 || infill_percentage > 100 =>
+                    {
+                        return Err(Error::InvalidPartAttributes(String::from(
+                            "Infill percentage must be between 1 and 100",
+                        )));
+                    }
+                    (PrintingTechnology::SLA | PrintingTechnology::SLS, Some(_)) => {
+                        return Err(Error::InvalidPartAttributes(String::from(
+                            "Infill percentage only applies to FDM prints",
+                        )));
+                    }
+                    _ => (),
+                }
+            }
+            PartAttributes::SheetMetal(attributes) => {
+                let (min, max) = SHEET_METAL_THICKNESS_RANGE_MM;
+                if !(min..=max).contains(&attributes.thickness_mm) {
+                    return Err(Error::InvalidPartAttributes(format!(
+                        "Sheet thickness must be between {min}mm and {max}mm"
+                    )));
+                }
+                self.validate_tolerance(&attributes.tolerance)?;
+            }
+            PartAttributes::InjectionMolding(attributes) => {
+                if attributes.mold_cavities == 0 {
                     return Err(Error::InvalidPartAttributes(String::from(
-                        "A drawing file is required when selecting 'Other' tolerance",
+                        "At least one mold cavity is required",
                     )));
                 }
+                self.validate_tolerance(&attributes.tolerance)?;
             }
         }
 
         Ok(())
     }
+
+    fn validate_tolerance(&self, tolerance: &Tolerance) -> Result<(), Error> {
+        if *tolerance == Tolerance::Other && self.drawing_file.is_none() {
+            return Err(Error::InvalidPartAttributes(String::from(
+                "A drawing file is required when selecting 'Other' tolerance",
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
 pub enum PartProcess {
     CNC,
+    #[serde(rename = "3D_PRINTING")]
+    ThreeDPrinting,
+    #[serde(rename = "SHEET_METAL")]
+    SheetMetal,
+    #[serde(rename = "INJECTION_MOLDING")]
+    InjectionMolding,
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct PartQuote {
     pub id: PartQuoteId,
     pub unit_price: Money,
     pub sub_total: Money,
     pub workdays_to_complete: u64,
+    /// Unit prices by quantity, so the quantity can change without a new review.
+    pub quantity_tiers: Option<Vec<QuantityTier>>,
     pub valid_until: DateTime<Utc>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl PartQuote {
-    pub fn new(unit_price: Money, sub_total: Money, workdays_to_complete: u64) -> Self {
+    pub fn new(
+        unit_price: Money,
+        sub_total: Money,
+        workdays_to_complete: u64,
+        quantity_tiers: Option<Vec<QuantityTier>>,
+    ) -> Self {
         let now = Utc::now();
         let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
         let encoded_id = format!("pq_{}", bs58::encode(id).into_string());
@@ -107,9 +220,108 @@ impl PartQuote {
             unit_price,
             sub_total,
             workdays_to_complete,
+            quantity_tiers,
             valid_until,
             created_at: now,
             updated_at: now,
         }
     }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.valid_until < now
+    }
+
+    pub fn quantity_tier(&self, quantity: u64) -> Option<&QuantityTier> {
+        self.quantity_tiers
+            .as_ref()?
+            .iter()
+            .find(|quantity_tier| quantity_tier.contains(quantity))
+    }
+
+    /// Unit price of the tier matching the quantity, or the quoted one for quotes without tiers.
+    pub fn unit_price_for(&self, quantity: u64) -> Money {
+        self.quantity_tier(quantity)
+            .map_or(self.unit_price.clone(), |quantity_tier| {
+                quantity_tier.unit_price.clone()
+            })
+    }
+
+    /// Subtotal of the tier matching the quantity, or the quoted one for quotes without tiers.
+    pub fn sub_total_for(&self, quantity: u64) -> Result<Money, Error> {
+        match self.quantity_tier(quantity) {
+            Some(quantity_tier) => quantity_tier.unit_price.checked_mul(quantity),
+            None => Ok(self.sub_total.clone()),
+        }
+    }
+}
+
+/// Unit price for quantities between `min_quantity` and `max_quantity`, both inclusive. Tiers
+/// without `max_quantity` apply to any larger quantity.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct QuantityTier {
+    pub min_quantity: u64,
+    pub max_quantity: Option<u64>,
+    pub unit_price: Money,
+}
+
+impl QuantityTier {
+    /// Tiers of a part quote must price each quantity once, in the currency of the part quote.
+    pub fn validate_tiers(
+        quantity_tiers: &[QuantityTier],
+        currency: Currency,
+    ) -> Result<(), Error> {
+        if quantity_tiers.is_empty() {
+            return Err(Error::InvalidQuantityTiers(String::from(
+                "At least one tier is required, leave them out to price a single quantity",
+            )));
+        }
+        if let Some(quantity_tier) = quantity_tiers
+            .iter()
+            .find(|quantity_tier| quantity_tier.unit_price.currency != currency)
+        {
+            return Err(Error::CurrencyMismatch(
+                currency,
+                quantity_tier.unit_price.currency,
+            ));
+        }
+
+        let mut sorted_tiers = quantity_tiers.iter().collect::<Vec<&QuantityTier>>();
+        sorted_tiers.sort_by_key(|quantity_tier| quantity_tier.min_quantity);
+        for quantity_tier in sorted_tiers.iter() {
+            if quantity_tier.min_quantity == 0 {
+                return Err(Error::InvalidQuantityTiers(String::from(
+                    "The minimum quantity of a tier must be at least 1",
+                )));
+            }
+            if quantity_tier
+                .max_quantity
+                .is_some_and(|max_quantity| max_quantity < quantity_tier.min_quantity)
+            {
+                return Err(Error::InvalidQuantityTiers(format!(
+                    "The tier starting at {} ends before it starts",
+                    quantity_tier.min_quantity
+                )));
+            }
+        }
+        for tiers in sorted_tiers.windows(2) {
+            if tiers[0]
+                .max_quantity
+                .is_none_or(|max_quantity| max_quantity >= tiers[1].min_quantity)
+            {
+                return Err(Error::InvalidQuantityTiers(format!(
+                    "The tiers starting at {} and {} overlap",
+                    tiers[0].min_quantity, tiers[1].min_quantity
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn contains(&self, quantity: u64) -> bool {
+        quantity >= self.min_quantity
+            && self
+                .max_quantity
+                .is_none_or(|max_quantity| quantity <= max_quantity)
+    }
 }
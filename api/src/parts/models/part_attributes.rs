@@ -1,10 +1,65 @@
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::catalog::models::catalog_item::CatalogItemKind;
+use crate::parts::models::part::PartProcess;
+use serde::de::Error as DeserializeError;
+use serde::{Deserialize, Deserializer, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use std::fmt::{Display, Formatter};
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Serialize, Clone, Debug, PartialEq)]
+#[serde(tag = "process")]
 pub enum PartAttributes {
+    #[serde(rename = "CNC")]
     CNC(CNCAttributes),
+    #[serde(rename = "3D_PRINTING")]
+    ThreeDPrinting(ThreeDPrintingAttributes),
+    #[serde(rename = "SHEET_METAL")]
+    SheetMetal(SheetMetalAttributes),
+    #[serde(rename = "INJECTION_MOLDING")]
+    InjectionMolding(InjectionMoldingAttributes),
+}
+
+impl PartAttributes {
+    pub fn process(&self) -> PartProcess {
+        match self {
+            PartAttributes::CNC(_) => PartProcess::CNC,
+            PartAttributes::ThreeDPrinting(_) => PartProcess::ThreeDPrinting,
+            PartAttributes::SheetMetal(_) => PartProcess::SheetMetal,
+            PartAttributes::InjectionMolding(_) => PartProcess::InjectionMolding,
+        }
+    }
+
+    /// Name of the material, as it appears in the catalog.
+    pub fn material(&self) -> &str {
+        match self {
+            PartAttributes::CNC(attrs) => &attrs.material,
+            PartAttributes::ThreeDPrinting(attrs) => &attrs.material,
+            PartAttributes::SheetMetal(attrs) => &attrs.material,
+            PartAttributes::InjectionMolding(attrs) => &attrs.material,
+        }
+    }
+
+    /// Values that must exist in the catalog of the attributes' process.
+    pub fn catalog_references(&self) -> Vec<(CatalogItemKind, String)> {
+        let (material, tolerance) = match self {
+            PartAttributes::CNC(attrs) => (&attrs.material, Some(&attrs.tolerance)),
+            PartAttributes::ThreeDPrinting(attrs) => (&attrs.material, None),
+            PartAttributes::SheetMetal(attrs) => (&attrs.material, Some(&attrs.tolerance)),
+            PartAttributes::InjectionMolding(attrs) => (&attrs.material, Some(&attrs.tolerance)),
+        };
+
+        let mut references = vec![(CatalogItemKind::Material, material.clone())];
+        if let PartAttributes::CNC(attrs) = self {
+            if let Some(surface_finish) = &attrs.surface_finish {
+                references.push((CatalogItemKind::Finish, surface_finish.clone()));
+            }
+        }
+        // Custom tolerances are described in the drawing instead.
+        if let Some(tolerance) = tolerance.filter(|tolerance| **tolerance != Tolerance::Other) {
+            references.push((CatalogItemKind::Tolerance, tolerance.to_string()));
+        }
+
+        references
+    }
 }
 
 impl Default for PartAttributes {
@@ -13,24 +68,46 @@ impl Default for PartAttributes {
     }
 }
 
-impl Serialize for PartAttributes {
-    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        match self {
-            PartAttributes::CNC(attrs) => attrs.serialize(serializer),
-        }
-    }
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Deserialize)]
+#[serde(tag = "process")]
+enum TaggedPartAttributes {
+    #[serde(rename = "CNC")]
+    CNC(CNCAttributes),
+    #[serde(rename = "3D_PRINTING")]
+    ThreeDPrinting(ThreeDPrintingAttributes),
+    #[serde(rename = "SHEET_METAL")]
+    SheetMetal(SheetMetalAttributes),
+    #[serde(rename = "INJECTION_MOLDING")]
+    InjectionMolding(InjectionMoldingAttributes),
 }
 
+/// Attributes stored before the `process` tag was introduced only ever described CNC parts, so
+/// they are only read as such when the tag is missing.
 impl<'de> Deserialize<'de> for PartAttributes {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let attrs = CNCAttributes::deserialize(deserializer)?;
-        Ok(PartAttributes::CNC(attrs))
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if value.get("process").is_none() {
+            return CNCAttributes::deserialize(value)
+                .map(PartAttributes::CNC)
+                .map_err(DeserializeError::custom);
+        }
+
+        let attributes = match TaggedPartAttributes::deserialize(value)
+            .map_err(DeserializeError::custom)?
+        {
+            TaggedPartAttributes::CNC(attrs) => PartAttributes::CNC(attrs),
+            TaggedPartAttributes::ThreeDPrinting(attrs) => PartAttributes::ThreeDPrinting(attrs),
+            TaggedPartAttributes::SheetMetal(attrs) => PartAttributes::SheetMetal(attrs),
+            TaggedPartAttributes::InjectionMolding(attrs) => {
+                PartAttributes::InjectionMolding(attrs)
+            }
+        };
+
+        Ok(attributes)
     }
 }
 
@@ -38,6 +115,9 @@ impl Display for PartAttributes {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             PartAttributes::CNC(attr) => write!(f, "{}", attr),
+            PartAttributes::ThreeDPrinting(attr) => write!(f, "{}", attr),
+            PartAttributes::SheetMetal(attr) => write!(f, "{}", attr),
+            PartAttributes::InjectionMolding(attr) => write!(f, "{}", attr),
         }
     }
 }
@@ -46,6 +126,18 @@ impl Display for PartAttributes {
 pub struct CNCAttributes {
     pub material: String,
     pub tolerance: Tolerance,
+    /// Finish from the catalog (e.g., "Anodized Type II"). `None` means as machined.
+    #[serde(default)]
+    pub surface_finish: Option<String>,
+    /// Only applies to finishes that can be dyed or coated.
+    #[serde(default)]
+    pub color: Option<String>,
+    #[serde(default)]
+    pub threads: Vec<ThreadCallout>,
+    #[serde(default)]
+    pub heat_treatment: Option<String>,
+    #[serde(default)]
+    pub inspection_level: InspectionLevel,
 }
 
 impl Default for CNCAttributes {
@@ -53,6 +145,11 @@ impl Default for CNCAttributes {
         Self {
             material: String::from("Aluminum 6061-T6"),
             tolerance: Tolerance::PlusMinus005Inch013mm,
+            surface_finish: None,
+            color: None,
+            threads: Vec::new(),
+            heat_treatment: None,
+            inspection_level: InspectionLevel::default(),
         }
     }
 }
@@ -61,8 +158,168 @@ impl Display for CNCAttributes {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Process: CNC, Material: {}, Tolerance: {})",
+            "Process: CNC, Material: {}, Tolerance: {}",
             self.material, self.tolerance
+        )?;
+        if let Some(surface_finish) = &self.surface_finish {
+            write!(f, ", Finish: {surface_finish}")?;
+            if let Some(color) = &self.color {
+                write!(f, " ({color})")?;
+            }
+        }
+        if !self.threads.is_empty() {
+            let threads = self
+                .threads
+                .iter()
+                .map(ThreadCallout::to_string)
+                .collect::<Vec<String>>()
+                .join(", ");
+            write!(f, ", Threads: {threads}")?;
+        }
+        if let Some(heat_treatment) = &self.heat_treatment {
+            write!(f, ", Heat Treatment: {heat_treatment}")?;
+        }
+        if self.inspection_level != InspectionLevel::Standard {
+            write!(f, ", Inspection: {}", self.inspection_level)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ThreadCallout {
+    /// Thread designation (e.g., "M6x1.0", "1/4-20 UNC").
+    pub specification: String,
+    pub count: u64,
+    pub depth_mm: Option<f64>,
+}
+
+impl Display for ThreadCallout {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}x {}", self.count, self.specification)?;
+        if let Some(depth_mm) = self.depth_mm {
+            write!(f, " {depth_mm}mm deep")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InspectionLevel {
+    #[default]
+    Standard,
+    FirstArticle,
+    Cmm,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ThreeDPrintingAttributes {
+    pub technology: PrintingTechnology,
+    pub material: String,
+    pub layer_height_mm: f64,
+    /// Only meaningful for FDM, resin and powder based prints are always solid.
+    pub infill_percentage: Option<u8>,
+}
+
+impl Default for ThreeDPrintingAttributes {
+    fn default() -> Self {
+        Self {
+            technology: PrintingTechnology::FDM,
+            material: String::from("PLA"),
+            layer_height_mm: 0.2,
+            infill_percentage: Some(20),
+        }
+    }
+}
+
+impl Display for ThreeDPrintingAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Process: 3D Printing ({}), Material: {}, Layer Height: {}mm",
+            self.technology, self.material, self.layer_height_mm
+        )?;
+        if let Some(infill_percentage) = self.infill_percentage {
+            write!(f, ", Infill: {infill_percentage}%")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
+pub enum PrintingTechnology {
+    FDM,
+    SLA,
+    SLS,
+}
+
+impl PrintingTechnology {
+    /// Range of layer heights, in millimeters, the printers can reliably produce.
+    pub fn layer_height_range_mm(&self) -> (f64, f64) {
+        match self {
+            PrintingTechnology::FDM => (0.05, 0.4),
+            PrintingTechnology::SLA => (0.025, 0.1),
+            PrintingTechnology::SLS => (0.06, 0.15),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SheetMetalAttributes {
+    pub material: String,
+    pub thickness_mm: f64,
+    pub bend_count: u64,
+    pub tolerance: Tolerance,
+}
+
+impl Default for SheetMetalAttributes {
+    fn default() -> Self {
+        Self {
+            material: String::from("Aluminum 5052-H32"),
+            thickness_mm: 1.5,
+            bend_count: 0,
+            tolerance: Tolerance::PlusMinus005Inch013mm,
+        }
+    }
+}
+
+impl Display for SheetMetalAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Process: Sheet Metal, Material: {}, Thickness: {}mm, Bends: {}, Tolerance: {}",
+            self.material, self.thickness_mm, self.bend_count, self.tolerance
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct InjectionMoldingAttributes {
+    pub material: String,
+    pub mold_cavities: u64,
+    pub tolerance: Tolerance,
+}
+
+impl Default for InjectionMoldingAttributes {
+    fn default() -> Self {
+        Self {
+            material: String::from("ABS"),
+            mold_cavities: 1,
+            tolerance: Tolerance::PlusMinus005Inch013mm,
+        }
+    }
+}
+
+impl Display for InjectionMoldingAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Process: Injection Molding, Material: {}, Mold Cavities: {}, Tolerance: {}",
+            self.material, self.mold_cavities, self.tolerance
         )
     }
 }
@@ -72,4 +329,7 @@ pub enum Tolerance {
     #[serde(rename = "+/- .005\" (+/- 0.13mm)")]
     PlusMinus005Inch013mm,
     Other,
+    /// Any other tolerance grade managed through the catalog.
+    #[serde(other)]
+    Grade(String),
 }
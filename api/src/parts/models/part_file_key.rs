@@ -0,0 +1,93 @@
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, PartId};
+use percent_encoding::percent_decode_str;
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+const PART_FILES_BASE_PATH: &str = "parts";
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum PartFileFolder {
+    /// Models as uploaded by the customer.
+    Originals,
+    /// STL renders generated from the original models.
+    WebReady,
+    Drawings,
+}
+
+/// Object storage key of a part file: `parts/{folder}/{customer_id}/{part_id}/{file_name}`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PartFileKey {
+    pub folder: PartFileFolder,
+    pub customer_id: CustomerId,
+    pub part_id: PartId,
+    pub file_name: String,
+}
+
+impl PartFileKey {
+    pub fn new(
+        folder: PartFileFolder,
+        customer_id: CustomerId,
+        part_id: PartId,
+        file_name: String,
+    ) -> Self {
+        Self {
+            folder,
+            customer_id,
+            part_id,
+            file_name,
+        }
+    }
+
+    /// Keys in S3 event notifications are URL-encoded the way HTML forms are, with spaces as `+`.
+    pub fn from_event_key(key: &str) -> Result<Self, Error> {
+        let key_with_spaces = key.replace('+', " ");
+        let decoded_key = percent_decode_str(&key_with_spaces)
+            .decode_utf8()
+            .map_err(|_| Error::InvalidModelFile(format!("unexpected key `{key}`")))?;
+
+        Self::from_str(&decoded_key)
+    }
+
+    pub fn file_stem(&self) -> &str {
+        self.file_name
+            .rsplit_once('.')
+            .map_or(self.file_name.as_str(), |(stem, _)| stem)
+    }
+
+    pub fn extension(&self) -> String {
+        self.file_name
+            .rsplit_once('.')
+            .map(|(_, extension)| extension.to_lowercase())
+            .unwrap_or_default()
+    }
+}
+
+impl Display for PartFileKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{PART_FILES_BASE_PATH}/{}/{}/{}/{}",
+            self.folder, self.customer_id, self.part_id, self.file_name
+        )
+    }
+}
+
+impl FromStr for PartFileKey {
+    type Err = Error;
+
+    fn from_str(key: &str) -> Result<Self, Self::Err> {
+        match key.split('/').collect::<Vec<&str>>()[..] {
+            [PART_FILES_BASE_PATH, folder, customer_id, part_id, file_name] => Ok(Self {
+                folder: PartFileFolder::from_str(folder)
+                    .map_err(|_| Error::InvalidModelFile(format!("unexpected key `{key}`")))?,
+                customer_id: customer_id.to_string(),
+                part_id: part_id.to_string(),
+                file_name: file_name.to_string(),
+            }),
+            _ => Err(Error::InvalidModelFile(format!("unexpected key `{key}`"))),
+        }
+    }
+}
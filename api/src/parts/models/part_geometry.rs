@@ -0,0 +1,196 @@
+use crate::shared::error::Error;
+use crate::shared::Result;
+use serde_derive::{Deserialize, Serialize};
+
+const STL_BINARY_HEADER_BYTES: usize = 80;
+const STL_BINARY_TRIANGLE_BYTES: usize = 50;
+
+type Vertex = [f64; 3];
+type Triangle = [Vertex; 3];
+
+/// Metrics extracted from the part's 3D model, all in millimeters.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PartGeometry {
+    pub bounding_box: BoundingBox,
+    pub volume_mm3: f64,
+    pub surface_area_mm2: f64,
+    #[serde(default)]
+    pub triangle_count: u64,
+}
+
+impl PartGeometry {
+    /// Analyzes an STL mesh, either binary or ASCII. Units are assumed to be millimeters.
+    pub fn from_stl(bytes: &[u8]) -> Result<Self> {
+        let triangles = if Self::is_binary_stl(bytes) {
+            Self::parse_binary_stl(bytes)
+        } else {
+            Self::parse_ascii_stl(bytes)?
+        };
+
+        if triangles.is_empty() {
+            return Err(Error::InvalidModelFile(String::from(
+                "the mesh doesn't have any triangles",
+            )));
+        }
+        if triangles
+            .iter()
+            .flatten()
+            .flatten()
+            .any(|axis| !axis.is_finite())
+        {
+            return Err(Error::InvalidModelFile(String::from(
+                "the mesh has non-finite coordinates",
+            )));
+        }
+
+        Ok(Self::from_triangles(&triangles))
+    }
+
+    fn from_triangles(triangles: &[Triangle]) -> Self {
+        let mut min = [f64::MAX; 3];
+        let mut max = [f64::MIN; 3];
+        let mut signed_volume = 0.0;
+        let mut surface_area = 0.0;
+
+        for [a, b, c] in triangles {
+            for vertex in [a, b, c] {
+                for axis in 0..3 {
+                    min[axis] = min[axis].min(vertex[axis]);
+                    max[axis] = max[axis].max(vertex[axis]);
+                }
+            }
+
+            // Signed volume of the tetrahedron formed with the origin, it adds up to the
+            // enclosed volume for closed meshes regardless of where the origin is.
+            signed_volume += dot(a, &cross(b, c)) / 6.0;
+            surface_area += norm(&cross(&sub(b, a), &sub(c, a))) / 2.0;
+        }
+
+        Self {
+            bounding_box: BoundingBox {
+                x_mm: max[0] - min[0],
+                y_mm: max[1] - min[1],
+                z_mm: max[2] - min[2],
+            },
+            volume_mm3: signed_volume.abs(),
+            surface_area_mm2: surface_area,
+            triangle_count: triangles.len() as u64,
+        }
+    }
+
+    /// ASCII files may also start with the 80 byte header, so the size of the file is what
+    /// tells both formats apart.
+    fn is_binary_stl(bytes: &[u8]) -> bool {
+        if bytes.len() < STL_BINARY_HEADER_BYTES + 4 {
+            return false;
+        }
+
+        let triangle_count = u32::from_le_bytes(
+            bytes[STL_BINARY_HEADER_BYTES..STL_BINARY_HEADER_BYTES + 4]
+                .try_into()
+                .unwrap(),
+        ) as usize;
+
+        bytes.len() == STL_BINARY_HEADER_BYTES + 4 + triangle_count * STL_BINARY_TRIANGLE_BYTES
+    }
+
+    fn parse_binary_stl(bytes: &[u8]) -> Vec<Triangle> {
+        bytes[STL_BINARY_HEADER_BYTES + 4..]
+            .chunks_exact(STL_BINARY_TRIANGLE_BYTES)
+            .map(|chunk| {
+                // Skip the normal (12 bytes), each vertex is 3 little endian f32.
+                let vertex = |offset: usize| -> Vertex {
+                    let coordinate = |index: usize| {
+                        let start = offset + index * 4;
+                        f32::from_le_bytes(chunk[start..start + 4].try_into().unwrap()) as f64
+                    };
+                    [coordinate(0), coordinate(1), coordinate(2)]
+                };
+                [vertex(12), vertex(24), vertex(36)]
+            })
+            .collect()
+    }
+
+    fn parse_ascii_stl(bytes: &[u8]) -> Result<Vec<Triangle>> {
+        let content = std::str::from_utf8(bytes).map_err(|_| {
+            Error::InvalidModelFile(String::from("the file is neither binary nor ASCII STL"))
+        })?;
+        if !content.trim_start().starts_with("solid") {
+            return Err(Error::InvalidModelFile(String::from(
+                "the file is neither binary nor ASCII STL",
+            )));
+        }
+
+        let mut vertices = Vec::new();
+        for line in content.lines() {
+            let mut tokens = line.split_whitespace();
+            if tokens.next() != Some("vertex") {
+                continue;
+            }
+
+            let coordinates = tokens
+                .map(|token| token.parse::<f64>())
+                .collect::<std::result::Result<Vec<f64>, _>>()
+                .map_err(|_| {
+                    Error::InvalidModelFile(format!("invalid vertex `{}`", line.trim()))
+                })?;
+            match coordinates[..] {
+                [x, y, z] => vertices.push([x, y, z]),
+                _ => {
+                    return Err(Error::InvalidModelFile(format!(
+                        "invalid vertex `{}`",
+                        line.trim()
+                    )))
+                }
+            }
+        }
+
+        if vertices.len() % 3 != 0 {
+            return Err(Error::InvalidModelFile(String::from(
+                "facets must have exactly 3 vertices",
+            )));
+        }
+
+        Ok(vertices
+            .chunks_exact(3)
+            .map(|vertices| [vertices[0], vertices[1], vertices[2]])
+            .collect())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Default)]
+pub struct BoundingBox {
+    pub x_mm: f64,
+    pub y_mm: f64,
+    pub z_mm: f64,
+}
+
+impl BoundingBox {
+    pub fn volume_mm3(&self) -> f64 {
+        self.x_mm * self.y_mm * self.z_mm
+    }
+
+    pub fn max_dimension_mm(&self) -> f64 {
+        self.x_mm.max(self.y_mm).max(self.z_mm)
+    }
+}
+
+fn sub(a: &Vertex, b: &Vertex) -> Vertex {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn cross(a: &Vertex, b: &Vertex) -> Vertex {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: &Vertex, b: &Vertex) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn norm(a: &Vertex) -> f64 {
+    dot(a, a).sqrt()
+}
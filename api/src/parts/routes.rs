@@ -3,9 +3,10 @@ use axum::Router;
 
 use crate::app_state::AppState;
 use crate::parts::controllers::{
-    admin_create_part_quotes, admin_generate_presigned_url, admin_query_parts_for_quotation,
-    create_parts, delete_drawing_file, delete_part, generate_presigned_url, get_part,
-    query_parts_for_quotation, update_part, update_selected_part_quote, upload_part_drawing,
+    admin_analyze_part_geometry, admin_create_part_quotes, admin_generate_presigned_url,
+    admin_query_parts_for_quotation, create_parts, delete_drawing_file, delete_part,
+    generate_presigned_url, get_part, query_parts_for_quotation, update_part,
+    update_selected_part_quote, upload_part_drawing,
 };
 
 pub fn create_router() -> Router<AppState> {
@@ -16,6 +17,7 @@ pub fn create_router() -> Router<AppState> {
             get(admin_query_parts_for_quotation),
         )
         .route("/admin/presigned_url", post(admin_generate_presigned_url))
+        .route("/admin/parts/geometry", post(admin_analyze_part_geometry))
         .route("/parts", post(create_parts))
         .route(
             "/projects/:project_id/quotations/:quotation_id/parts",
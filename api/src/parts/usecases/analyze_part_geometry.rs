@@ -0,0 +1,84 @@
+use crate::parts::models::dynamodb_requests::UpdatablePart;
+use crate::parts::models::inputs::AnalyzePartGeometryInput;
+use crate::parts::models::part::Part;
+use crate::parts::models::part_geometry::PartGeometry;
+use crate::quotations::models::quotation::QuoteStatus;
+use crate::repositories::parts::PartsRepository;
+use crate::repositories::quotes::QuotesRepository;
+use crate::services::object_storage::ObjectStorage;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AnalyzePartGeometry<P, Q>
+where
+    P: PartsRepository,
+    Q: QuotesRepository,
+{
+    parts_repository: Arc<P>,
+    quotations_repository: Arc<Q>,
+    object_storage: Arc<dyn ObjectStorage>,
+}
+
+impl<P, Q> AnalyzePartGeometry<P, Q>
+where
+    P: PartsRepository,
+    Q: QuotesRepository,
+{
+    pub fn new(
+        parts_repository: Arc<P>,
+        quotations_repository: Arc<Q>,
+        object_storage: Arc<dyn ObjectStorage>,
+    ) -> Self {
+        Self {
+            parts_repository,
+            quotations_repository,
+            object_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, Q> UseCase<AnalyzePartGeometryInput, Part> for AnalyzePartGeometry<P, Q>
+where
+    P: PartsRepository,
+    Q: QuotesRepository,
+{
+    async fn execute(&self, input: AnalyzePartGeometryInput) -> Result<Part> {
+        let part = self
+            .parts_repository
+            .get(input.customer_id.clone(), input.part_id.clone())
+            .await?;
+
+        // STEP models are analyzed through the STL render generated by the file converter.
+        let stl_file = if part.model_file.name.to_lowercase().ends_with(".stl") {
+            part.model_file
+        } else {
+            part.render_file
+                .ok_or(Error::MissingRequiredParameter(String::from("render_file")))?
+        };
+
+        let bytes = self.object_storage.get_object(&stl_file.key).await?;
+        let geometry = PartGeometry::from_stl(&bytes)?;
+
+        // Geometry drives pricing, so existing part quotes are no longer valid and the quotation
+        // has to be reviewed again. Fails if the quotation is already locked.
+        self.quotations_repository
+            .update_status(
+                input.customer_id.clone(),
+                part.project_id,
+                part.quotation_id,
+                QuoteStatus::Created,
+            )
+            .await?;
+
+        let updatable_part = UpdatablePart {
+            geometry: Some(geometry),
+            clear_part_quotes: Some(true),
+            ..UpdatablePart::partial_new(input.customer_id, input.part_id)
+        };
+
+        self.parts_repository.update(updatable_part).await
+    }
+}
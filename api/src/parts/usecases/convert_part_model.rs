@@ -0,0 +1,117 @@
+use crate::parts::models::dynamodb_requests::UpdatablePart;
+use crate::parts::models::inputs::ConvertPartModelInput;
+use crate::parts::models::part::Part;
+use crate::parts::models::part_file_key::{PartFileFolder, PartFileKey};
+use crate::parts::models::part_geometry::PartGeometry;
+use crate::repositories::parts::PartsRepository;
+use crate::services::model_converter::ModelConverter;
+use crate::services::object_storage::ObjectStorage;
+use crate::shared::error::Error;
+use crate::shared::file::File;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::str::FromStr;
+use std::sync::Arc;
+
+const STL_EXTENSION: &str = "stl";
+
+pub struct ConvertPartModel<P>
+where
+    P: PartsRepository,
+{
+    parts_repository: Arc<P>,
+    object_storage: Arc<dyn ObjectStorage>,
+    model_converter: Arc<dyn ModelConverter>,
+}
+
+impl<P> ConvertPartModel<P>
+where
+    P: PartsRepository,
+{
+    pub fn new(
+        parts_repository: Arc<P>,
+        object_storage: Arc<dyn ObjectStorage>,
+        model_converter: Arc<dyn ModelConverter>,
+    ) -> Self {
+        Self {
+            parts_repository,
+            object_storage,
+            model_converter,
+        }
+    }
+}
+
+#[async_trait]
+impl<P> UseCase<ConvertPartModelInput, Part> for ConvertPartModel<P>
+where
+    P: PartsRepository,
+{
+    async fn execute(&self, input: ConvertPartModelInput) -> Result<Part> {
+        let model_key = PartFileKey::from_str(&input.key)?;
+        if model_key.folder != PartFileFolder::Originals {
+            return Err(Error::InvalidModelFile(format!(
+                "`{}` is not an original model",
+                input.key
+            )));
+        }
+
+        match self.render(&model_key).await {
+            Ok((render_file, geometry)) => {
+                let updatable_part = UpdatablePart {
+                    render_file: Some(render_file),
+                    geometry: Some(geometry),
+                    ..UpdatablePart::partial_new(model_key.customer_id, model_key.part_id)
+                };
+
+                self.parts_repository.update(updatable_part).await
+            }
+            Err(err) => {
+                let updatable_part = UpdatablePart {
+                    conversion_error: Some(err.to_string()),
+                    ..UpdatablePart::partial_new(model_key.customer_id, model_key.part_id)
+                };
+                self.parts_repository.update(updatable_part).await?;
+
+                Err(err)
+            }
+        }
+    }
+}
+
+impl<P> ConvertPartModel<P>
+where
+    P: PartsRepository,
+{
+    /// Generates the STL render of the model and analyzes it on the way.
+    async fn render(&self, model_key: &PartFileKey) -> Result<(File, PartGeometry)> {
+        let model = self
+            .object_storage
+            .get_object(&model_key.to_string())
+            .await?;
+
+        let extension = model_key.extension();
+        let stl = if extension == STL_EXTENSION {
+            model
+        } else {
+            self.model_converter
+                .convert_to_stl(&model, &extension)
+                .await?
+        };
+        let geometry = PartGeometry::from_stl(&stl)?;
+
+        let render_key = PartFileKey::new(
+            PartFileFolder::WebReady,
+            model_key.customer_id.clone(),
+            model_key.part_id.clone(),
+            format!("{}.{STL_EXTENSION}", model_key.file_stem()),
+        );
+        self.object_storage
+            .put_object(&render_key.to_string(), stl)
+            .await?;
+
+        Ok((
+            File::new(render_key.file_name.clone(), render_key.to_string()),
+            geometry,
+        ))
+    }
+}
@@ -1,50 +1,72 @@
 use crate::parts::models::inputs::CreatePartQuotesInput;
-use crate::parts::models::part::PartQuote;
+use crate::parts::models::part::{PartQuote, QuantityTier};
 use crate::quotations::models::quotation::QuoteStatus;
+use crate::quotations::models::quotation_revision::{PartRevision, QuotationRevision};
 use crate::repositories::parts::PartsRepository;
+use crate::repositories::pricing_agreements::PricingAgreementsRepository;
+use crate::repositories::quotation_revisions::QuotationRevisionsRepository;
 use crate::repositories::quotes::QuotesRepository;
 use crate::repositories::transaction::Transaction;
+use crate::services::exchange_rates::ExchangeRates;
+use crate::shared::error::Error;
+use crate::shared::money::Money;
 use crate::shared::{PartId, PartQuoteId, Result, UseCase};
 use async_trait::async_trait;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-pub struct CreatePartQuotes<P, Q, Tx, TxItem>
+pub struct CreatePartQuotes<P, Q, R, A, Tx, TxItem>
 where
     P: PartsRepository<TransactionItem = TxItem>,
     Q: QuotesRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    A: PricingAgreementsRepository,
     Tx: Transaction<TransactionItem = TxItem>,
 {
     parts_repository: Arc<P>,
     quotes_repository: Arc<Q>,
+    quotation_revisions_repository: Arc<R>,
+    pricing_agreements_repository: Arc<A>,
     transaction: Arc<Mutex<Tx>>,
+    exchange_rates: Arc<dyn ExchangeRates>,
 }
 
-impl<P, Q, Tx, TxItem> CreatePartQuotes<P, Q, Tx, TxItem>
+impl<P, Q, R, A, Tx, TxItem> CreatePartQuotes<P, Q, R, A, Tx, TxItem>
 where
     P: PartsRepository<TransactionItem = TxItem>,
     Q: QuotesRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    A: PricingAgreementsRepository,
     Tx: Transaction<TransactionItem = TxItem>,
 {
     pub fn new(
         parts_repository: Arc<P>,
         quotes_repository: Arc<Q>,
+        quotation_revisions_repository: Arc<R>,
+        pricing_agreements_repository: Arc<A>,
         transaction: Arc<Mutex<Tx>>,
+        exchange_rates: Arc<dyn ExchangeRates>,
     ) -> Self {
         Self {
             parts_repository,
             quotes_repository,
+            quotation_revisions_repository,
+            pricing_agreements_repository,
             transaction,
+            exchange_rates,
         }
     }
 }
 
 #[async_trait]
-impl<P, Q, Tx, TxItem> UseCase<CreatePartQuotesInput, ()> for CreatePartQuotes<P, Q, Tx, TxItem>
+impl<P, Q, R, A, Tx, TxItem> UseCase<CreatePartQuotesInput, ()>
+    for CreatePartQuotes<P, Q, R, A, Tx, TxItem>
 where
     P: PartsRepository<TransactionItem = TxItem>,
     Q: QuotesRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    A: PricingAgreementsRepository,
     Tx: Transaction<TransactionItem = TxItem>,
     TxItem: Send,
 {
@@ -53,16 +75,76 @@ where
         let mut selected_part_quote_by_part: HashMap<PartId, PartQuoteId> = HashMap::new();
         let mut part_ids_set = HashSet::new();
 
-        input.data.into_iter().for_each(|quote_data| {
+        // Prices are entered in any supported currency and stored in the quotation's one.
+        let quotation = self
+            .quotes_repository
+            .get(input.customer_id.clone(), input.quotation_id.clone())
+            .await?;
+        let currency = quotation.currency;
+        // Customers with a negotiated discount get it on every part quote.
+        let pricing_agreement = match self
+            .pricing_agreements_repository
+            .get(input.customer_id.clone())
+            .await
+        {
+            Ok(pricing_agreement) => Some(pricing_agreement),
+            Err(Error::ItemNotFoundError) => None,
+            Err(err) => return Err(err),
+        };
+        let parts = self
+            .parts_repository
+            .query(
+                input.customer_id.clone(),
+                input.quotation_id.clone(),
+                None,
+                100,
+            )
+            .await?;
+        // The subtotal is recalculated from the converted and discounted unit price, so both
+        // stay consistent after rounding.
+        let price = |price: &Money| -> Result<Money> {
+            let price = self.exchange_rates.convert(price, currency)?;
+            Ok(match &pricing_agreement {
+                Some(pricing_agreement) => pricing_agreement.apply(&price),
+                None => price,
+            })
+        };
+
+        for quote_data in input.data {
             // Default selected to the first part quote. We might want to revisit this decision
             // and select by price or by deadline.
             let selected = !part_ids_set.contains(&quote_data.part_id);
             part_ids_set.insert(quote_data.part_id.clone());
 
+            if let Some(quantity_tiers) = &quote_data.quantity_tiers {
+                QuantityTier::validate_tiers(quantity_tiers, quote_data.unit_price.currency)?;
+            }
+            let quantity_tiers = quote_data
+                .quantity_tiers
+                .map(|quantity_tiers| {
+                    quantity_tiers
+                        .into_iter()
+                        .map(|quantity_tier| {
+                            Ok(QuantityTier {
+                                unit_price: price(&quantity_tier.unit_price)?,
+                                ..quantity_tier
+                            })
+                        })
+                        .collect::<Result<Vec<QuantityTier>>>()
+                })
+                .transpose()?;
+            let quantity = parts
+                .data
+                .iter()
+                .find(|part| part.id == quote_data.part_id)
+                .map(|part| part.quantity)
+                .ok_or(Error::ItemNotFoundError)?;
+            let unit_price = price(&quote_data.unit_price)?;
             let part_quote = PartQuote::new(
-                quote_data.unit_price,
-                quote_data.sub_total,
+                unit_price.clone(),
+                unit_price.checked_mul(quantity)?,
                 quote_data.workdays_to_complete,
+                quantity_tiers,
             );
 
             if selected {
@@ -74,35 +156,58 @@ where
                 .entry(quote_data.part_id.clone())
                 .or_default()
                 .push(part_quote);
-        });
+        }
 
         let quote_transaction = self.quotes_repository.transaction_update(
             input.customer_id.clone(),
-            input.project_id,
-            input.quotation_id,
+            input.project_id.clone(),
+            input.quotation_id.clone(),
             QuoteStatus::PendingReview,
             QuoteStatus::PendingPayment,
         );
 
         let part_quotes_transactions = part_ids_set
-            .into_iter()
+            .iter()
             .map(|part_id| {
                 self.parts_repository.transaction_create_part_quotes(
                     input.customer_id.clone(),
                     part_id.clone(),
-                    selected_part_quote_by_part[&part_id].clone(),
-                    part_quotes_by_part[&part_id].clone(),
+                    selected_part_quote_by_part[part_id].clone(),
+                    part_quotes_by_part[part_id].clone(),
                 )
             })
             .collect();
 
-        {
-            let mut transaction = self.transaction.lock().await;
-            transaction.add_item(quote_transaction);
-            transaction.add_items(part_quotes_transactions);
-            transaction.execute().await?;
-        }
+        // Keep the pricing round so it can be compared or restored after the customer
+        // changes the parts. It's written with the pricing so every priced round has one.
+        let part_revisions = parts
+            .data
+            .iter()
+            .filter(|part| part_ids_set.contains(&part.id))
+            .map(|part| {
+                PartRevision::new(
+                    part,
+                    selected_part_quote_by_part.get(&part.id).cloned(),
+                    part_quotes_by_part[&part.id].clone(),
+                )
+            })
+            .collect();
+        let revision = QuotationRevision::new(
+            input.customer_id,
+            input.project_id,
+            input.quotation_id,
+            part_revisions,
+            None,
+        );
+
+        let revision_transaction = self
+            .quotation_revisions_repository
+            .transaction_create(revision);
 
-        Ok(())
+        let mut transaction = self.transaction.lock().await;
+        transaction.add_item(quote_transaction);
+        transaction.add_items(part_quotes_transactions);
+        transaction.add_item(revision_transaction);
+        transaction.execute().await
     }
 }
@@ -7,6 +7,7 @@ use uuid::{ContextV7, Timestamp, Uuid};
 use crate::parts::models::inputs::CreatePartsInput;
 use crate::parts::models::part::{Part, PartAttributes, PartProcess};
 use crate::parts::models::part_attributes::CNCAttributes;
+use crate::parts::models::part_file_key::{PartFileFolder, PartFileKey};
 use crate::parts::models::responses::CreatePartsResponse;
 use crate::quotations::models::quotation::QuoteStatus;
 use crate::repositories::parts::PartsRepository;
@@ -14,10 +15,9 @@ use crate::repositories::quotes::QuotesRepository;
 use crate::services::object_storage::ObjectStorage;
 use crate::services::stripe_client::StripeClient;
 use crate::shared::file::File;
-use crate::shared::{CustomerId, FileId, PartId, Result, UseCase};
+use crate::shared::{Result, UseCase};
 
 static PRESIGNED_URLS_PUT_DURATION_SECONDS: u64 = 300;
-static ORIGINAL_FILES_BASE_FILE_PATH: &str = "parts/originals";
 
 pub struct CreateParts<Q, P>
 where
@@ -72,13 +72,13 @@ where
 
             let file_id = Self::generate_file_id();
             let file_extension = file_name.split(".").last().unwrap().to_string();
-            let file_key = self.file_key(
-                ORIGINAL_FILES_BASE_FILE_PATH,
+            let file_key = PartFileKey::new(
+                PartFileFolder::Originals,
                 input.identity.id.clone(),
                 part.id.clone(),
-                file_id,
-                file_extension,
-            );
+                format!("{file_id}.{file_extension}"),
+            )
+            .to_string();
 
             part.model_file.name = file_name;
             part.model_file.key = file_key.clone();
@@ -122,17 +122,6 @@ where
     Q: QuotesRepository,
     P: PartsRepository,
 {
-    fn file_key(
-        &self,
-        file_path: &str,
-        customer_id: CustomerId,
-        part_id: PartId,
-        file_id: FileId,
-        file_extension: String,
-    ) -> String {
-        format!("{file_path}/{customer_id}/{part_id}/{file_id}.{file_extension}").to_string()
-    }
-
     fn generate_file_id() -> String {
         let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
         let encoded_id = format!("file_{}", bs58::encode(id).into_string());
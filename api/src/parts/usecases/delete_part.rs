@@ -1,5 +1,4 @@
 use crate::parts::models::inputs::DeletePartInput;
-use crate::quotations::models::quotation::QuoteStatus;
 use crate::repositories::parts::PartsRepository;
 use crate::repositories::quotes::QuotesRepository;
 use crate::services::object_storage::ObjectStorage;
@@ -50,7 +49,7 @@ where
             .await?;
 
         // Check that the quotation is in an updatable status.
-        if quotation.status == QuoteStatus::Payed {
+        if quotation.status.is_locked() {
             return Err(Error::QuoteIsInPayedStatus);
         }
 
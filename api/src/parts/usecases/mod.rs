@@ -1,5 +1,7 @@
 pub mod admin_generate_presigned_url;
 pub mod admin_query_parts_for_quotation;
+pub mod analyze_part_geometry;
+pub mod convert_part_model;
 pub mod create_part_quotes;
 pub mod create_parts;
 pub mod delete_drawing_file;
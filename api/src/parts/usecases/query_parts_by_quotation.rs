@@ -1,5 +1,5 @@
 use crate::parts::models::inputs::QueryPartsForQuotationInput;
-use crate::parts::models::part::{Part, PartQuote};
+use crate::parts::models::part::Part;
 use crate::parts::models::responses::QueryPartsForQuotationResponse;
 use crate::repositories::parts::PartsRepository;
 use crate::shared::error::Error;
@@ -79,7 +79,7 @@ where
             None => (),
         }
 
-        let selected_part_quotes: Vec<PartQuote> = parts
+        let part_subtotals = parts
             .iter()
             .filter_map(|part| {
                 part.part_quotes
@@ -93,17 +93,16 @@ where
                                 .clone()
                                 .expect("expecting a selected part quote")
                     })
+                    .map(|part_quote| part_quote.sub_total_for(part.quantity))
             })
-            .collect::<Vec<PartQuote>>();
+            .collect::<Result<Vec<Money>>>()?;
 
-        let subtotal =
-            selected_part_quotes
-                .iter()
-                .fold(Money::default(), |mut money: Money, part_quote| {
-                    money.amount += part_quote.sub_total.amount;
-                    money
-                });
+        let currency = part_subtotals
+            .first()
+            .map_or(Money::default().currency, |part_subtotal| {
+                part_subtotal.currency
+            });
 
-        Ok(subtotal)
+        Money::checked_sum(currency, &part_subtotals)
     }
 }
@@ -1,42 +1,130 @@
 use crate::parts::models::dynamodb_requests::UpdatablePart;
 use crate::parts::models::inputs::UpdatePartInput;
-use crate::parts::models::part::Part;
+use crate::parts::models::part::{Part, PartAttributes};
 use crate::quotations::models::quotation::QuoteStatus;
+use crate::repositories::catalog::CatalogRepository;
 use crate::repositories::parts::PartsRepository;
 use crate::repositories::quotes::QuotesRepository;
+use crate::shared::error::Error;
 use crate::shared::{Result, UseCase};
 use async_trait::async_trait;
 use std::sync::Arc;
 
-pub struct UpdatePart<Q, P>
+pub struct UpdatePart<Q, P, C>
 where
     Q: QuotesRepository,
     P: PartsRepository,
+    C: CatalogRepository,
 {
     parts_repository: Arc<P>,
     quotations_repository: Arc<Q>,
+    catalog_repository: Arc<C>,
 }
 
-impl<Q, P> UpdatePart<Q, P>
+impl<Q, P, C> UpdatePart<Q, P, C>
 where
     Q: QuotesRepository,
     P: PartsRepository,
+    C: CatalogRepository,
 {
-    pub const fn new(parts_repository: Arc<P>, quotations_repository: Arc<Q>) -> Self {
+    pub const fn new(
+        parts_repository: Arc<P>,
+        quotations_repository: Arc<Q>,
+        catalog_repository: Arc<C>,
+    ) -> Self {
         Self {
             parts_repository,
             quotations_repository,
+            catalog_repository,
         }
     }
+
+    async fn validate_attributes_with_catalog(&self, attributes: &PartAttributes) -> Result<()> {
+        let process = attributes.process();
+
+        for (kind, name) in attributes.catalog_references() {
+            let response = self
+                .catalog_repository
+                .query(
+                    process.clone(),
+                    Some(kind.clone()),
+                    Some(name.clone()),
+                    None,
+                    1,
+                )
+                .await?;
+
+            if response.data.is_empty() {
+                return Err(Error::InvalidPartAttributes(format!(
+                    "{kind} '{name}' is not available for the '{process}' process"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Quantity changes within the quantity tiers of every part quote keep the current pricing.
+    /// Returns the part when that is the case.
+    async fn priced_quantity_change(&self, input: &UpdatePartInput) -> Result<Option<Part>> {
+        let Some(quantity) = input.quantity else {
+            return Ok(None);
+        };
+        if input.drawing_file.is_some()
+            || input.process.is_some()
+            || input.attributes.is_some()
+            || input.additional_notes.is_some()
+        {
+            return Ok(None);
+        }
+
+        let part = self
+            .parts_repository
+            .get(input.identity.id.clone(), input.part_id.clone())
+            .await?;
+
+        let is_priced = part.part_quotes.as_ref().is_some_and(|part_quotes| {
+            !part_quotes.is_empty()
+                && part_quotes
+                    .iter()
+                    .all(|part_quote| part_quote.quantity_tier(quantity).is_some())
+        });
+        if !is_priced {
+            return Ok(None);
+        }
+
+        // The status update is skipped for these changes, so check the lock here.
+        let quotation = self
+            .quotations_repository
+            .get(input.identity.id.clone(), input.quotation_id.clone())
+            .await?;
+        if quotation.status.is_locked() {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+
+        Ok(Some(part))
+    }
 }
 
 #[async_trait]
-impl<Q, P> UseCase<UpdatePartInput, Part> for UpdatePart<Q, P>
+impl<Q, P, C> UseCase<UpdatePartInput, Part> for UpdatePart<Q, P, C>
 where
     Q: QuotesRepository,
     P: PartsRepository,
+    C: CatalogRepository,
 {
     async fn execute(&self, input: UpdatePartInput) -> Result<Part> {
+        if let Some(attributes) = &input.attributes {
+            self.validate_attributes_with_catalog(attributes).await?;
+        }
+
+        if let Some(part) = self.priced_quantity_change(&input).await? {
+            let mut updatable_part = UpdatablePart::from(&input);
+            updatable_part.selected_part_quote_id = part.selected_part_quote_id;
+
+            return self.parts_repository.update(updatable_part).await;
+        }
+
         self.quotations_repository
             .update_status(
                 input.identity.id.clone(),
@@ -1,19 +1,19 @@
 use crate::auth::models::session::Identity;
 use crate::parts::models::dynamodb_requests::UpdatablePart;
+use crate::parts::models::part_file_key::{PartFileFolder, PartFileKey};
 use crate::parts::models::responses::UploadDrawingResponse;
 use crate::quotations::models::quotation::QuoteStatus;
 use crate::repositories::parts::PartsRepository;
 use crate::repositories::quotes::QuotesRepository;
 use crate::services::object_storage::ObjectStorage;
 use crate::shared::file::File;
-use crate::shared::{CustomerId, FileId, PartId, ProjectId, QuoteId, UseCase};
+use crate::shared::{PartId, ProjectId, QuoteId, UseCase};
 use async_trait::async_trait;
 use std::sync::Arc;
 use std::time::Duration;
 use uuid::{ContextV7, Timestamp, Uuid};
 
 static PRESIGNED_URLS_PUT_DURATION_SECONDS: u64 = 300;
-static DRAWING_FILES_BASE_FILE_PATH: &str = "parts/drawings";
 
 pub struct UploadDrawingInput {
     pub customer: Identity,
@@ -82,13 +82,13 @@ where
                 let file_id = Self::generate_file_id();
                 let file_extension = input.file_name.split(".").last().unwrap().to_string();
 
-                self.file_key(
-                    DRAWING_FILES_BASE_FILE_PATH,
+                PartFileKey::new(
+                    PartFileFolder::Drawings,
                     input.customer.id.clone(),
                     input.part_id.clone(),
-                    file_id,
-                    file_extension,
+                    format!("{file_id}.{file_extension}"),
                 )
+                .to_string()
             }
         };
 
@@ -124,17 +124,6 @@ where
     P: PartsRepository,
     Q: QuotesRepository,
 {
-    fn file_key(
-        &self,
-        file_path: &str,
-        customer_id: CustomerId,
-        part_id: PartId,
-        file_id: FileId,
-        file_extension: String,
-    ) -> String {
-        format!("{file_path}/{customer_id}/{part_id}/{file_id}.{file_extension}").to_string()
-    }
-
     fn generate_file_id() -> String {
         let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
         let encoded_id = format!("file_{}", bs58::encode(id).into_string());
@@ -1,26 +1,49 @@
 use crate::app_state::AppState;
+use crate::orders::models::order::Address;
 use crate::payments::models::inputs::{
-    CompleteCheckoutSessionWebhookRequest, CreateCheckoutSessionInput,
+    AdminGetFailedWebhookEventInput, AdminQueryFailedWebhookEventsInput,
+    ChargeRefundedWebhookRequest, CheckoutSessionWebhookRequest,
+    CompleteCheckoutSessionWebhookRequest, CreateCheckoutSessionInput, WebhookEventInput,
 };
+use crate::payments::models::webhook_event::WebhookEvent;
+use crate::payments::usecases::admin_get_failed_webhook_event::AdminGetFailedWebhookEvent;
+use crate::payments::usecases::admin_query_failed_webhook_events::AdminQueryFailedWebhookEvents;
+use crate::payments::usecases::await_quotation_async_payment::AwaitQuotationAsyncPayment;
 use crate::payments::usecases::create_checkout_session::CreateCheckoutSession;
 use crate::payments::usecases::create_orders_and_confirm_quotation_payment::CreateOrdersAndConfirmQuotationPayment;
-use crate::shared::extractors::session::CustomerSession;
+use crate::payments::usecases::fail_quotation_payment::FailQuotationPayment;
+use crate::payments::usecases::notify_expired_checkout_session::NotifyExpiredCheckoutSession;
+use crate::payments::usecases::process_webhook_event::ProcessWebhookEvent;
+use crate::payments::usecases::refund_quotation_payment::RefundQuotationPayment;
+use crate::shared::extractors::session::{AdminSession, CustomerSession};
 use crate::shared::extractors::stripe_event::StripeEvent;
 use crate::shared::into_error_response::IntoError;
-use crate::shared::UseCase;
-use axum::extract::State;
+use crate::shared::{UseCase, WebhookEventId};
+use axum::extract::{Query, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use serde_derive::{Deserialize, Serialize};
 use std::sync::Arc;
-use stripe::{EventObject, EventType};
+use stripe::{Event, EventObject, EventType};
 use tokio::sync::Mutex;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct CreateCheckoutSessionRequest {
     pub project_id: String,
     pub quotation_id: String,
+    pub shipping_address: Option<Address>,
+}
+
+#[derive(Deserialize)]
+pub struct AdminQueryFailedWebhookEventsQueryParameters {
+    pub cursor: Option<String>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminReplayWebhookEventRequest {
+    pub event_id: WebhookEventId,
 }
 
 pub async fn create_checkout_session(
@@ -32,10 +55,15 @@ pub async fn create_checkout_session(
         identity: session.identity,
         project_id: request.project_id,
         quotation_id: request.quotation_id,
+        shipping_address: request.shipping_address,
     };
     let usecase = CreateCheckoutSession::new(
         app_state.payments.stripe_client,
         app_state.parts.dynamodb_parts,
+        app_state.quotes.dynamodb_quotes,
+        app_state.promotions.dynamodb_promotion_codes,
+        app_state.services.tax_calculator,
+        app_state.services.shipping_calculator,
     );
     let result = usecase.execute(input).await;
 
@@ -49,32 +77,155 @@ pub async fn complete_checkout_session_webhook(
     State(app_state): State<AppState>,
     StripeEvent(event): StripeEvent,
 ) -> impl IntoResponse {
-    match event.type_ {
-        EventType::CheckoutSessionCompleted => {
-            if let EventObject::CheckoutSession(session) = event.data.object {
-                if let Ok(request) = CompleteCheckoutSessionWebhookRequest::try_from(session) {
-                    let usecase = CreateOrdersAndConfirmQuotationPayment::new(
-                        app_state.projects.dynamodb_projects,
-                        app_state.quotes.dynamodb_quotes,
-                        app_state.orders.dynamodb_orders,
-                        app_state.parts.dynamodb_parts,
-                        Arc::new(Mutex::new(app_state.payments.transaction)),
-                        app_state.services.emailer.ses,
-                    );
+    handle_stripe_event(app_state, event).await
+}
+
+pub async fn admin_query_failed_webhook_events(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Query(params): Query<AdminQueryFailedWebhookEventsQueryParameters>,
+) -> impl IntoResponse {
+    let input = AdminQueryFailedWebhookEventsInput {
+        cursor: params.cursor,
+        limit: params.limit.unwrap_or(20),
+    };
+    let usecase = AdminQueryFailedWebhookEvents::new(app_state.payments.dynamodb_webhook_events);
+    let result = usecase.execute(input).await;
 
-                    let result = usecase.execute(request).await;
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
 
-                    match result {
-                        Ok(_) => Ok(StatusCode::OK),
-                        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
-                    }
-                } else {
-                    Err(StatusCode::BAD_REQUEST)
-                }
+pub async fn admin_replay_webhook_event(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminReplayWebhookEventRequest>,
+) -> impl IntoResponse {
+    let input = AdminGetFailedWebhookEventInput {
+        event_id: request.event_id,
+    };
+    let usecase =
+        AdminGetFailedWebhookEvent::new(app_state.payments.dynamodb_webhook_events.clone());
+    let event = match usecase.execute(input).await {
+        Ok(event) => event,
+        Err(err) => return Err(err.into_error_response().into_response()),
+    };
+
+    handle_stripe_event(app_state, event)
+        .await
+        .map_err(IntoResponse::into_response)
+}
+
+/// Processes a Stripe event, both from the webhook and from admin replays.
+async fn handle_stripe_event(app_state: AppState, event: Event) -> Result<StatusCode, StatusCode> {
+    let webhook_event = WebhookEvent::new(&event);
+    let webhook_events = app_state.payments.dynamodb_webhook_events;
+    let transaction = Arc::new(Mutex::new(app_state.payments.transaction));
+
+    let result = match (event.type_, event.data.object) {
+        (
+            EventType::CheckoutSessionCompleted | EventType::CheckoutSessionAsyncPaymentSucceeded,
+            EventObject::CheckoutSession(session),
+        ) => {
+            let request = CompleteCheckoutSessionWebhookRequest::try_from(session)
+                .map_err(|_| StatusCode::BAD_REQUEST)?;
+            let input = WebhookEventInput {
+                webhook_event,
+                data: request,
+            };
+
+            if input.data.is_paid {
+                let usecase = CreateOrdersAndConfirmQuotationPayment::new(
+                    app_state.projects.dynamodb_projects,
+                    app_state.quotes.dynamodb_quotes,
+                    app_state.orders.dynamodb_orders,
+                    app_state.parts.dynamodb_parts,
+                    webhook_events.clone(),
+                    app_state.billing.dynamodb_invoices,
+                    app_state.billing.dynamodb_billing_profiles,
+                    app_state.promotions.dynamodb_promotion_codes,
+                    app_state.orders.dynamodb_work_orders,
+                    transaction,
+                    app_state.services.emailer.ses,
+                    app_state.services.tax_calculator,
+                );
+                ProcessWebhookEvent::new(webhook_events, usecase)
+                    .execute(input)
+                    .await
             } else {
-                Err(StatusCode::UNPROCESSABLE_ENTITY)
+                let usecase = AwaitQuotationAsyncPayment::new(
+                    app_state.quotes.dynamodb_quotes,
+                    webhook_events.clone(),
+                    transaction,
+                    app_state.auth.ory_kratos,
+                    app_state.services.emailer.ses,
+                );
+                ProcessWebhookEvent::new(webhook_events, usecase)
+                    .execute(input)
+                    .await
             }
         }
-        _ => Err(StatusCode::UNPROCESSABLE_ENTITY),
+        (EventType::CheckoutSessionAsyncPaymentFailed, EventObject::CheckoutSession(session)) => {
+            let request = CheckoutSessionWebhookRequest::try_from(session)
+                .map_err(|_| StatusCode::BAD_REQUEST)?;
+            let usecase = FailQuotationPayment::new(
+                app_state.quotes.dynamodb_quotes,
+                webhook_events.clone(),
+                transaction,
+                app_state.auth.ory_kratos,
+                app_state.services.emailer.ses,
+            );
+            ProcessWebhookEvent::new(webhook_events, usecase)
+                .execute(WebhookEventInput {
+                    webhook_event,
+                    data: request,
+                })
+                .await
+        }
+        (EventType::CheckoutSessionExpired, EventObject::CheckoutSession(session)) => {
+            let request = CheckoutSessionWebhookRequest::try_from(session)
+                .map_err(|_| StatusCode::BAD_REQUEST)?;
+            let usecase = NotifyExpiredCheckoutSession::new(
+                app_state.quotes.dynamodb_quotes,
+                webhook_events.clone(),
+                transaction,
+                app_state.auth.ory_kratos,
+                app_state.services.emailer.ses,
+            );
+            ProcessWebhookEvent::new(webhook_events, usecase)
+                .execute(WebhookEventInput {
+                    webhook_event,
+                    data: request,
+                })
+                .await
+        }
+        (EventType::ChargeRefunded, EventObject::Charge(charge)) => {
+            let request = ChargeRefundedWebhookRequest::try_from(charge)
+                .map_err(|_| StatusCode::BAD_REQUEST)?;
+            let usecase = RefundQuotationPayment::new(
+                app_state.quotes.dynamodb_quotes,
+                app_state.orders.dynamodb_orders,
+                app_state.projects.dynamodb_projects,
+                webhook_events.clone(),
+                transaction,
+                app_state.payments.stripe_client,
+                app_state.auth.ory_kratos,
+                app_state.services.emailer.ses,
+            );
+            ProcessWebhookEvent::new(webhook_events, usecase)
+                .execute(WebhookEventInput {
+                    webhook_event,
+                    data: request,
+                })
+                .await
+        }
+        _ => return Err(StatusCode::UNPROCESSABLE_ENTITY),
+    };
+
+    match result {
+        Ok(_) => Ok(StatusCode::OK),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
 }
@@ -1,7 +1,19 @@
 use crate::auth::models::session::Identity;
 use crate::orders::models::order::Address;
+use crate::payments::models::webhook_event::WebhookEvent;
+use crate::promotions::models::discount::DiscountLine;
+use crate::services::stripe::{
+    PROMOTION_CODE, SHIPPING_METHOD, SHIPPING_METHODS, TAX_COUNTRY, TAX_STATE,
+};
+use crate::shared::money::Money;
+use crate::shared::shipping::{ShippingCharge, ShippingMethod};
+use crate::shared::tax::TaxJurisdiction;
+use crate::shared::{CustomerId, ProjectId, QuoteId, WebhookEventId};
+use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
-use stripe::CheckoutSession;
+use stripe::{
+    Charge, CheckoutSession, CheckoutSessionPaymentStatus, Expandable, Metadata, ShippingRate,
+};
 
 #[derive(Debug)]
 pub enum WebhookRequestError {
@@ -10,6 +22,8 @@ pub enum WebhookRequestError {
     MissingShippingAddress,
     MissingMetadata,
     MissingField,
+    MissingPaymentIntent,
+    UnsupportedCurrency,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -17,6 +31,26 @@ pub struct CreateCheckoutSessionInput {
     pub identity: Identity,
     pub project_id: String,
     pub quotation_id: String,
+    /// Only the country and state are used, to charge the same taxes shown in the subtotal.
+    pub shipping_address: Option<Address>,
+}
+
+/// Data of a Stripe webhook event along with its ledger entry.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct WebhookEventInput<T> {
+    pub webhook_event: WebhookEvent,
+    pub data: T,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminQueryFailedWebhookEventsInput {
+    pub cursor: Option<String>,
+    pub limit: i32,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminGetFailedWebhookEventInput {
+    pub event_id: WebhookEventId,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -26,15 +60,83 @@ pub struct CompleteCheckoutSessionWebhookRequest {
     pub quotation_id: String,
     pub shipping_recipient_name: String,
     pub shipping_address: Address,
+    /// Delayed payment methods (OXXO, SPEI) complete the checkout before the money arrives.
+    pub is_paid: bool,
+    pub payment_intent_id: Option<String>,
+    /// Jurisdiction of the taxes charged, recorded when the session was created.
+    pub tax_jurisdiction: Option<TaxJurisdiction>,
+    /// Promotion code recorded when the session was created, with the amount Stripe took off.
+    pub discount: Option<DiscountLine>,
+    /// Shipping method chosen by the customer and what Stripe charged for it. Sessions created
+    /// before shipping was charged don't have it.
+    pub shipping: Option<ShippingCharge>,
 }
 
 impl TryFrom<CheckoutSession> for CompleteCheckoutSessionWebhookRequest {
     type Error = WebhookRequestError;
 
     fn try_from(session: CheckoutSession) -> Result<Self, Self::Error> {
-        let metadata = session
+        let is_paid = session.payment_status != CheckoutSessionPaymentStatus::Unpaid;
+        let payment_intent_id =
+            session
+                .payment_intent
+                .as_ref()
+                .map(|payment_intent| match payment_intent {
+                    Expandable::Id(id) => id.to_string(),
+                    Expandable::Object(payment_intent) => payment_intent.id.to_string(),
+                });
+        let CheckoutSessionWebhookRequest {
+            customer_id,
+            project_id,
+            quotation_id,
+        } = metadata_ids(session.metadata.as_ref())?;
+        let tax_jurisdiction = session.metadata.as_ref().and_then(|metadata| {
+            Some(TaxJurisdiction {
+                country: metadata.get(TAX_COUNTRY)?.clone(),
+                state: metadata.get(TAX_STATE).cloned(),
+            })
+        });
+        let discount = match session
             .metadata
-            .ok_or(WebhookRequestError::MissingMetadata)?;
+            .as_ref()
+            .and_then(|metadata| metadata.get(PROMOTION_CODE))
+        {
+            Some(code) => {
+                let currency = session.currency.ok_or(WebhookRequestError::MissingField)?;
+                let currency = Currency::from_code(&currency.to_string().to_uppercase())
+                    .ok_or(WebhookRequestError::UnsupportedCurrency)?;
+                let amount_discount = session
+                    .total_details
+                    .as_ref()
+                    .ok_or(WebhookRequestError::MissingField)?
+                    .amount_discount;
+
+                Some(DiscountLine {
+                    code: code.clone(),
+                    amount: Money::new(amount_discount, currency),
+                })
+            }
+            None => None,
+        };
+        let shipping = match &session.shipping_cost {
+            Some(shipping_cost) => {
+                let shipping_rate = shipping_cost
+                    .shipping_rate
+                    .as_ref()
+                    .ok_or(WebhookRequestError::MissingField)?;
+                let method = shipping_method(&session, shipping_rate)
+                    .ok_or(WebhookRequestError::MissingMetadata)?;
+                let currency = session.currency.ok_or(WebhookRequestError::MissingField)?;
+                let currency = Currency::from_code(&currency.to_string().to_uppercase())
+                    .ok_or(WebhookRequestError::UnsupportedCurrency)?;
+
+                Some(ShippingCharge {
+                    method,
+                    cost: Money::new(shipping_cost.amount_total, currency),
+                })
+            }
+            None => None,
+        };
         let shipping_details = session
             .shipping_details
             .ok_or(WebhookRequestError::MissingShippingDetails)?;
@@ -43,19 +145,6 @@ impl TryFrom<CheckoutSession> for CompleteCheckoutSessionWebhookRequest {
             .address
             .ok_or(WebhookRequestError::MissingShippingAddress)?;
 
-        let customer_id = metadata
-            .get("customer_id")
-            .ok_or(WebhookRequestError::MissingField)?
-            .clone();
-        let project_id = metadata
-            .get("project_id")
-            .ok_or(WebhookRequestError::MissingField)?
-            .clone();
-        let quotation_id = metadata
-            .get("quotation_id")
-            .ok_or(WebhookRequestError::MissingField)?
-            .clone();
-
         let shipping_recipient_name = shipping_details
             .name
             .ok_or(WebhookRequestError::MissingShippingRecipientName)?;
@@ -75,6 +164,94 @@ impl TryFrom<CheckoutSession> for CompleteCheckoutSessionWebhookRequest {
             quotation_id,
             shipping_recipient_name,
             shipping_address,
+            is_paid,
+            payment_intent_id,
+            tax_jurisdiction,
+            discount,
+            shipping,
+        })
+    }
+}
+
+/// Shipping method of the shipping rate charged. Webhooks don't expand the shipping rate, so its
+/// method is looked up by its position among the session's shipping options.
+fn shipping_method(
+    session: &CheckoutSession,
+    shipping_rate: &Expandable<ShippingRate>,
+) -> Option<ShippingMethod> {
+    if let Expandable::Object(shipping_rate) = shipping_rate {
+        if let Some(method) = shipping_rate.metadata.get(SHIPPING_METHOD) {
+            return method.parse().ok();
+        }
+    }
+
+    let shipping_rate_id = shipping_rate.id();
+    let methods = session.metadata.as_ref()?.get(SHIPPING_METHODS)?;
+    let position = session
+        .shipping_options
+        .iter()
+        .position(|shipping_option| shipping_option.shipping_rate.id() == shipping_rate_id)?;
+    methods.split(',').nth(position)?.parse().ok()
+}
+
+/// Checkout session event that only needs to know which quotation is being paid.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CheckoutSessionWebhookRequest {
+    pub customer_id: CustomerId,
+    pub project_id: ProjectId,
+    pub quotation_id: QuoteId,
+}
+
+impl TryFrom<CheckoutSession> for CheckoutSessionWebhookRequest {
+    type Error = WebhookRequestError;
+
+    fn try_from(session: CheckoutSession) -> Result<Self, Self::Error> {
+        metadata_ids(session.metadata.as_ref())
+    }
+}
+
+fn metadata_ids(
+    metadata: Option<&Metadata>,
+) -> Result<CheckoutSessionWebhookRequest, WebhookRequestError> {
+    let metadata = metadata.ok_or(WebhookRequestError::MissingMetadata)?;
+    let metadata_value = |key: &str| {
+        metadata
+            .get(key)
+            .cloned()
+            .ok_or(WebhookRequestError::MissingField)
+    };
+
+    Ok(CheckoutSessionWebhookRequest {
+        customer_id: metadata_value("customer_id")?,
+        project_id: metadata_value("project_id")?,
+        quotation_id: metadata_value("quotation_id")?,
+    })
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ChargeRefundedWebhookRequest {
+    pub payment_intent_id: String,
+    /// Total refunded so far, charges can be refunded in several steps.
+    pub amount_refunded: Money,
+    pub is_fully_refunded: bool,
+}
+
+impl TryFrom<Charge> for ChargeRefundedWebhookRequest {
+    type Error = WebhookRequestError;
+
+    fn try_from(charge: Charge) -> Result<Self, Self::Error> {
+        let payment_intent_id = match charge.payment_intent {
+            Some(Expandable::Id(id)) => id.to_string(),
+            Some(Expandable::Object(payment_intent)) => payment_intent.id.to_string(),
+            None => return Err(WebhookRequestError::MissingPaymentIntent),
+        };
+        let currency = Currency::from_code(&charge.currency.to_string().to_uppercase())
+            .ok_or(WebhookRequestError::UnsupportedCurrency)?;
+
+        Ok(Self {
+            payment_intent_id,
+            amount_refunded: Money::new(charge.amount_refunded, currency),
+            is_fully_refunded: charge.refunded,
         })
     }
 }
@@ -1,2 +1,3 @@
 pub mod inputs;
 pub mod responses;
+pub mod webhook_event;
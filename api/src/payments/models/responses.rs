@@ -1,3 +1,4 @@
+use crate::payments::models::webhook_event::WebhookEvent;
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -9,3 +10,9 @@ impl CreateCheckoutSessionResponse {
         Self { url }
     }
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryWebhookEventsResponse {
+    pub events: Vec<WebhookEvent>,
+    pub cursor: Option<String>,
+}
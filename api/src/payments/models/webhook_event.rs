@@ -0,0 +1,59 @@
+use crate::shared::WebhookEventId;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use stripe::Event;
+
+/// Ledger entry of a Stripe webhook event, keyed by the Stripe event id.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct WebhookEvent {
+    pub id: WebhookEventId,
+    pub event_type: String,
+    pub status: WebhookEventStatus,
+    /// The Stripe event as delivered, kept to replay it.
+    pub payload: String,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WebhookEvent {
+    pub fn new(event: &Event) -> Self {
+        let now = Utc::now();
+
+        Self {
+            id: event.id.to_string(),
+            event_type: event.type_.to_string(),
+            status: WebhookEventStatus::Processed,
+            payload: serde_json::to_string(event).expect("error serializing stripe event"),
+            error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn failed(mut self, error: String) -> Self {
+        self.status = WebhookEventStatus::Failed;
+        self.error = Some(error);
+        self.updated_at = Utc::now();
+        self
+    }
+
+    pub fn processed(mut self) -> Self {
+        self.status = WebhookEventStatus::Processed;
+        self.error = None;
+        self.updated_at = Utc::now();
+        self
+    }
+
+    pub fn event(&self) -> serde_json::Result<Event> {
+        serde_json::from_str(&self.payload)
+    }
+}
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookEventStatus {
+    Processed,
+    Failed,
+}
@@ -1,8 +1,11 @@
-use axum::routing::post;
+use axum::routing::{get, post};
 use axum::Router;
 
 use crate::app_state::AppState;
-use crate::payments::controllers::{complete_checkout_session_webhook, create_checkout_session};
+use crate::payments::controllers::{
+    admin_query_failed_webhook_events, admin_replay_webhook_event,
+    complete_checkout_session_webhook, create_checkout_session,
+};
 
 pub fn create_router() -> Router<AppState> {
     Router::new()
@@ -14,4 +17,12 @@ pub fn create_router() -> Router<AppState> {
             "/payments/webhooks/complete_checkout_session",
             post(complete_checkout_session_webhook),
         )
+        .route(
+            "/admin/payments/webhook_events",
+            get(admin_query_failed_webhook_events),
+        )
+        .route(
+            "/admin/payments/webhook_events/replay",
+            post(admin_replay_webhook_event),
+        )
 }
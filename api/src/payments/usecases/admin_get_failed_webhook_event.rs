@@ -0,0 +1,46 @@
+use crate::payments::models::inputs::AdminGetFailedWebhookEventInput;
+use crate::payments::models::webhook_event::WebhookEventStatus;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use stripe::Event;
+
+/// Gets the Stripe event of a failed ledger entry so it can be replayed.
+pub struct AdminGetFailedWebhookEvent<W>
+where
+    W: WebhookEventsRepository,
+{
+    webhook_events_repository: Arc<W>,
+}
+
+impl<W> AdminGetFailedWebhookEvent<W>
+where
+    W: WebhookEventsRepository,
+{
+    pub fn new(webhook_events_repository: Arc<W>) -> Self {
+        Self {
+            webhook_events_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<W> UseCase<AdminGetFailedWebhookEventInput, Event> for AdminGetFailedWebhookEvent<W>
+where
+    W: WebhookEventsRepository,
+{
+    async fn execute(&self, input: AdminGetFailedWebhookEventInput) -> Result<Event> {
+        let webhook_event = self.webhook_events_repository.get(input.event_id).await?;
+
+        if webhook_event.status == WebhookEventStatus::Processed {
+            return Err(Error::WebhookEventAlreadyProcessed(webhook_event.id));
+        }
+
+        webhook_event.event().map_err(|err| {
+            tracing::error!("{err:?}");
+            Error::UnknownError
+        })
+    }
+}
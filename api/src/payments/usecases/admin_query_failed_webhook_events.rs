@@ -0,0 +1,46 @@
+use crate::payments::models::inputs::AdminQueryFailedWebhookEventsInput;
+use crate::payments::models::responses::QueryWebhookEventsResponse;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminQueryFailedWebhookEvents<W>
+where
+    W: WebhookEventsRepository,
+{
+    webhook_events_repository: Arc<W>,
+}
+
+impl<W> AdminQueryFailedWebhookEvents<W>
+where
+    W: WebhookEventsRepository,
+{
+    pub fn new(webhook_events_repository: Arc<W>) -> Self {
+        Self {
+            webhook_events_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<W> UseCase<AdminQueryFailedWebhookEventsInput, QueryWebhookEventsResponse>
+    for AdminQueryFailedWebhookEvents<W>
+where
+    W: WebhookEventsRepository,
+{
+    async fn execute(
+        &self,
+        input: AdminQueryFailedWebhookEventsInput,
+    ) -> Result<QueryWebhookEventsResponse> {
+        let response = self
+            .webhook_events_repository
+            .query_failed(input.cursor, input.limit)
+            .await?;
+
+        Ok(QueryWebhookEventsResponse {
+            events: response.data,
+            cursor: response.cursor,
+        })
+    }
+}
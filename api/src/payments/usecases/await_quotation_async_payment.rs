@@ -0,0 +1,122 @@
+use crate::payments::models::inputs::{CompleteCheckoutSessionWebhookRequest, WebhookEventInput};
+use crate::quotations::models::quotation::QuoteStatus;
+use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::transaction::Transaction;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::services::emailer::Emailer;
+use crate::services::identity_manager::IdentityManager;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Locks a quotation whose checkout was completed with a delayed payment method (OXXO, SPEI)
+/// until Stripe confirms or fails the payment.
+pub struct AwaitQuotationAsyncPayment<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    quotes_repository: Arc<Q>,
+    webhook_events_repository: Arc<W>,
+    transaction: Arc<Mutex<Tx>>,
+    identity_manager: Arc<dyn IdentityManager>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<Q, W, Tx, TxItem> AwaitQuotationAsyncPayment<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    pub fn new(
+        quotes_repository: Arc<Q>,
+        webhook_events_repository: Arc<W>,
+        transaction: Arc<Mutex<Tx>>,
+        identity_manager: Arc<dyn IdentityManager>,
+        emailer_service: Arc<dyn Emailer>,
+    ) -> Self {
+        Self {
+            quotes_repository,
+            webhook_events_repository,
+            transaction,
+            identity_manager,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, W, Tx, TxItem> UseCase<WebhookEventInput<CompleteCheckoutSessionWebhookRequest>, ()>
+    for AwaitQuotationAsyncPayment<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn execute(
+        &self,
+        input: WebhookEventInput<CompleteCheckoutSessionWebhookRequest>,
+    ) -> Result<()> {
+        let request = input.data;
+        let quotation = self
+            .quotes_repository
+            .get(request.customer_id.clone(), request.quotation_id.clone())
+            .await?;
+        if quotation.status.is_locked() {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+
+        let quote_transaction = self.quotes_repository.transaction_update(
+            request.customer_id.clone(),
+            request.project_id,
+            request.quotation_id,
+            quotation.status,
+            QuoteStatus::PaymentProcessing,
+        );
+        let webhook_event_transaction = self
+            .webhook_events_repository
+            .transaction_create_processed(input.webhook_event);
+        {
+            let mut transaction = self.transaction.lock().await;
+            transaction.add_item(quote_transaction);
+            transaction.add_item(webhook_event_transaction);
+            transaction.execute().await?;
+        }
+
+        if let Ok(identity) = self
+            .identity_manager
+            .get_identity(request.customer_id.clone())
+            .await
+        {
+            let _ = self
+                .emailer_service
+                .send_email(
+                    &identity.traits.email,
+                    "We are waiting for your payment",
+                    &format!(
+                        "We received the order for your quote {}.\nProduction starts as soon as your payment is confirmed.",
+                        quotation.name
+                    ),
+                )
+                .await;
+        }
+
+        let _ = self
+            .emailer_service
+            .send_email_to_admins(
+                "A quote is awaiting payment",
+                &format!(
+                    "Customer with id {} checked out the quote with id {} with a delayed payment method",
+                    request.customer_id, quotation.id
+                ),
+            )
+            .await;
+
+        Ok(())
+    }
+}
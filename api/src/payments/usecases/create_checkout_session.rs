@@ -1,41 +1,84 @@
 use crate::payments::models::inputs::CreateCheckoutSessionInput;
 use crate::payments::models::responses::CreateCheckoutSessionResponse;
+use crate::quotations::models::quotation::QuoteStatus;
 use crate::repositories::parts::PartsRepository;
+use crate::repositories::promotion_codes::PromotionCodesRepository;
+use crate::repositories::quotes::QuotesRepository;
+use crate::services::shipping_calculator::ShippingCalculator;
 use crate::services::stripe_client::StripeClient;
+use crate::services::tax_calculator::TaxCalculator;
+use crate::shared::error::Error;
 use crate::shared::{Result, UseCase};
 use async_trait::async_trait;
+use chrono::Utc;
 use std::sync::Arc;
 
-pub struct CreateCheckoutSession<P>
+pub struct CreateCheckoutSession<P, Q, C>
 where
     P: PartsRepository,
+    Q: QuotesRepository,
+    C: PromotionCodesRepository,
 {
     stripe_client: Arc<dyn StripeClient>,
     parts_repository: Arc<P>,
+    quotes_repository: Arc<Q>,
+    promotion_codes_repository: Arc<C>,
+    tax_calculator: Arc<dyn TaxCalculator>,
+    shipping_calculator: Arc<dyn ShippingCalculator>,
 }
 
-impl<P> CreateCheckoutSession<P>
+impl<P, Q, C> CreateCheckoutSession<P, Q, C>
 where
     P: PartsRepository,
+    Q: QuotesRepository,
+    C: PromotionCodesRepository,
 {
-    pub const fn new(stripe_client: Arc<dyn StripeClient>, parts_repository: Arc<P>) -> Self {
+    pub const fn new(
+        stripe_client: Arc<dyn StripeClient>,
+        parts_repository: Arc<P>,
+        quotes_repository: Arc<Q>,
+        promotion_codes_repository: Arc<C>,
+        tax_calculator: Arc<dyn TaxCalculator>,
+        shipping_calculator: Arc<dyn ShippingCalculator>,
+    ) -> Self {
         Self {
             stripe_client,
             parts_repository,
+            quotes_repository,
+            promotion_codes_repository,
+            tax_calculator,
+            shipping_calculator,
         }
     }
 }
 
 #[async_trait]
-impl<P> UseCase<CreateCheckoutSessionInput, CreateCheckoutSessionResponse>
-    for CreateCheckoutSession<P>
+impl<P, Q, C> UseCase<CreateCheckoutSessionInput, CreateCheckoutSessionResponse>
+    for CreateCheckoutSession<P, Q, C>
 where
     P: PartsRepository,
+    Q: QuotesRepository,
+    C: PromotionCodesRepository,
 {
     async fn execute(
         &self,
         input: CreateCheckoutSessionInput,
     ) -> Result<CreateCheckoutSessionResponse> {
+        let quotation = self
+            .quotes_repository
+            .get(input.identity.id.clone(), input.quotation_id.clone())
+            .await?;
+        // Paid quotations, or ones being paid, can't be checked out twice.
+        if quotation.status.is_locked() {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+        if quotation.status == QuoteStatus::Expired {
+            return Err(Error::QuotationExpired(quotation.id));
+        }
+        let jurisdiction = self
+            .tax_calculator
+            .jurisdiction(input.shipping_address.as_ref())?;
+
         let query_response = self
             .parts_repository
             .query(
@@ -46,18 +89,44 @@ where
             )
             .await?;
 
+        // Quotations sent for review before parts were capped could have too many to pay for.
+        quotation.validate_part_count(&query_response.data)?;
         query_response
             .data
             .iter()
             .try_for_each(|part| part.validate())?;
 
+        let now = Utc::now();
+        query_response
+            .data
+            .iter()
+            .try_for_each(|part| part.validate_part_quote_expiry(now))?;
+
+        // The code could have expired or run out since it was applied.
+        if let Some(promotion) = &quotation.promotion {
+            self.promotion_codes_repository
+                .get(promotion.code.clone())
+                .await?
+                .ensure_redeemable(&input.identity.id, now)?;
+        }
+        let discount = quotation.discount(&quotation.subtotal(&query_response.data)?)?;
+        let shipping_options = self.shipping_calculator.shipping_options(
+            &jurisdiction.country,
+            quotation.currency,
+            &query_response.data,
+        )?;
+
         let url = self
             .stripe_client
             .create_checkout_session(
                 input.identity.id,
                 input.project_id,
                 input.quotation_id,
+                quotation.currency,
                 query_response.data,
+                jurisdiction,
+                discount,
+                shipping_options,
             )
             .await?;
 
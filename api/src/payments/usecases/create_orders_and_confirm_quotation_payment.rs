@@ -1,13 +1,23 @@
+use crate::billing::models::invoice::Invoice;
 use crate::orders::models::order::{Order, OrderStatus};
+use crate::orders::models::work_order::WorkOrder;
 use crate::parts::models::part::PartQuote;
-use crate::payments::models::inputs::CompleteCheckoutSessionWebhookRequest;
+use crate::payments::models::inputs::{CompleteCheckoutSessionWebhookRequest, WebhookEventInput};
+use crate::payments::models::webhook_event::WebhookEvent;
 use crate::quotations::models::quotation::QuoteStatus;
-use crate::repositories::orders::OrdersRepository;
+use crate::repositories::billing_profiles::BillingProfilesRepository;
+use crate::repositories::invoices::InvoicesRepository;
+use crate::repositories::orders::{OrdersRepository, QueryBy};
 use crate::repositories::parts::PartsRepository;
 use crate::repositories::projects::ProjectsRepository;
+use crate::repositories::promotion_codes::PromotionCodesRepository;
 use crate::repositories::quotes::QuotesRepository;
 use crate::repositories::transaction::Transaction;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::repositories::work_orders::WorkOrdersRepository;
 use crate::services::emailer::Emailer;
+use crate::services::tax_calculator::TaxCalculator;
+use crate::shared::error::Error;
 use crate::shared::{Result, UseCase};
 use crate::utils::workdays::Workdays;
 use async_trait::async_trait;
@@ -16,62 +26,136 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-pub struct CreateOrdersAndConfirmQuotationPayment<Pro, Quo, Ord, Par, Tx, TxItem>
-where
+pub struct CreateOrdersAndConfirmQuotationPayment<
+    Pro,
+    Quo,
+    Ord,
+    Par,
+    Web,
+    Inv,
+    Bil,
+    Prm,
+    Wor,
+    Tx,
+    TxItem,
+> where
     Pro: ProjectsRepository<TransactionItem = TxItem>,
     Quo: QuotesRepository<TransactionItem = TxItem>,
     Ord: OrdersRepository<TransactionItem = TxItem>,
     Par: PartsRepository<TransactionItem = TxItem>,
+    Web: WebhookEventsRepository<TransactionItem = TxItem>,
+    Inv: InvoicesRepository<TransactionItem = TxItem>,
+    Bil: BillingProfilesRepository,
+    Prm: PromotionCodesRepository<TransactionItem = TxItem>,
+    Wor: WorkOrdersRepository,
     Tx: Transaction<TransactionItem = TxItem>,
 {
     projects_repository: Arc<Pro>,
     quotes_repository: Arc<Quo>,
     orders_repository: Arc<Ord>,
     parts_repository: Arc<Par>,
+    webhook_events_repository: Arc<Web>,
+    invoices_repository: Arc<Inv>,
+    billing_profiles_repository: Arc<Bil>,
+    promotion_codes_repository: Arc<Prm>,
+    work_orders_repository: Arc<Wor>,
     transaction: Arc<Mutex<Tx>>,
     emailer_service: Arc<dyn Emailer>,
+    tax_calculator: Arc<dyn TaxCalculator>,
 }
 
-impl<Pro, Quo, Ord, Par, Tx, TxItem>
-    CreateOrdersAndConfirmQuotationPayment<Pro, Quo, Ord, Par, Tx, TxItem>
+impl<Pro, Quo, Ord, Par, Web, Inv, Bil, Prm, Wor, Tx, TxItem>
+    CreateOrdersAndConfirmQuotationPayment<Pro, Quo, Ord, Par, Web, Inv, Bil, Prm, Wor, Tx, TxItem>
 where
     Pro: ProjectsRepository<TransactionItem = TxItem>,
     Quo: QuotesRepository<TransactionItem = TxItem>,
     Ord: OrdersRepository<TransactionItem = TxItem>,
     Par: PartsRepository<TransactionItem = TxItem>,
+    Web: WebhookEventsRepository<TransactionItem = TxItem>,
+    Inv: InvoicesRepository<TransactionItem = TxItem>,
+    Bil: BillingProfilesRepository,
+    Prm: PromotionCodesRepository<TransactionItem = TxItem>,
+    Wor: WorkOrdersRepository,
     Tx: Transaction<TransactionItem = TxItem>,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         projects_repository: Arc<Pro>,
         quotes_repository: Arc<Quo>,
         orders_repository: Arc<Ord>,
         parts_repository: Arc<Par>,
+        webhook_events_repository: Arc<Web>,
+        invoices_repository: Arc<Inv>,
+        billing_profiles_repository: Arc<Bil>,
+        promotion_codes_repository: Arc<Prm>,
+        work_orders_repository: Arc<Wor>,
         transaction: Arc<Mutex<Tx>>,
         emailer_service: Arc<dyn Emailer>,
+        tax_calculator: Arc<dyn TaxCalculator>,
     ) -> Self {
         Self {
             projects_repository,
             quotes_repository,
             orders_repository,
             parts_repository,
+            webhook_events_repository,
+            invoices_repository,
+            billing_profiles_repository,
+            promotion_codes_repository,
+            work_orders_repository,
             transaction,
             emailer_service,
+            tax_calculator,
         }
     }
 }
 
 #[async_trait]
-impl<Pro, Quo, Ord, Par, Tx, TxItem> UseCase<CompleteCheckoutSessionWebhookRequest, ()>
-    for CreateOrdersAndConfirmQuotationPayment<Pro, Quo, Ord, Par, Tx, TxItem>
+impl<Pro, Quo, Ord, Par, Web, Inv, Bil, Prm, Wor, Tx, TxItem>
+    UseCase<WebhookEventInput<CompleteCheckoutSessionWebhookRequest>, ()>
+    for CreateOrdersAndConfirmQuotationPayment<
+        Pro,
+        Quo,
+        Ord,
+        Par,
+        Web,
+        Inv,
+        Bil,
+        Prm,
+        Wor,
+        Tx,
+        TxItem,
+    >
 where
     Pro: ProjectsRepository<TransactionItem = TxItem>,
     Quo: QuotesRepository<TransactionItem = TxItem>,
     Ord: OrdersRepository<TransactionItem = TxItem>,
     Par: PartsRepository<TransactionItem = TxItem>,
+    Web: WebhookEventsRepository<TransactionItem = TxItem>,
+    Inv: InvoicesRepository<TransactionItem = TxItem>,
+    Bil: BillingProfilesRepository,
+    Prm: PromotionCodesRepository<TransactionItem = TxItem>,
+    Wor: WorkOrdersRepository,
     Tx: Transaction<TransactionItem = TxItem>,
     TxItem: Send,
 {
-    async fn execute(&self, request: CompleteCheckoutSessionWebhookRequest) -> Result<()> {
+    async fn execute(
+        &self,
+        input: WebhookEventInput<CompleteCheckoutSessionWebhookRequest>,
+    ) -> Result<()> {
+        let request = input.data;
+        let quotation = self
+            .quotes_repository
+            .get(request.customer_id.clone(), request.quotation_id.clone())
+            .await?;
+        // Delayed payments get here from `PaymentProcessing`, retries after a failed one from
+        // `PaymentFailed`. Replays of a recorded payment only finish what it left undone.
+        match quotation.status {
+            QuoteStatus::Payed => return self.complete_payment(request, input.webhook_event).await,
+            QuoteStatus::Refunded => return Err(Error::QuoteIsInPayedStatus),
+            _ => (),
+        }
+
         let query_parts_for_quotation_response = self
             .parts_repository
             .query(
@@ -82,6 +166,35 @@ where
             )
             .await?;
 
+        // Customers without a billing profile are invoiced as the general public.
+        let billing_profile = match self
+            .billing_profiles_repository
+            .get(request.customer_id.clone())
+            .await
+        {
+            Ok(billing_profile) => Some(billing_profile),
+            Err(Error::ItemNotFoundError) => None,
+            Err(err) => return Err(err),
+        };
+        // Invoice the taxes charged at checkout, sessions created before taxes were recorded
+        // in them are taxed by their shipping address.
+        let tax_jurisdiction = match request.tax_jurisdiction.clone() {
+            Some(tax_jurisdiction) => tax_jurisdiction,
+            None => self
+                .tax_calculator
+                .jurisdiction(Some(&request.shipping_address))?,
+        };
+        let invoice = Invoice::new(
+            &quotation,
+            &query_parts_for_quotation_response.data,
+            billing_profile,
+            request.payment_intent_id.clone(),
+            self.tax_calculator.as_ref(),
+            tax_jurisdiction,
+            request.discount.clone(),
+            request.shipping.clone(),
+        )?;
+
         let selected_part_quote_for_part = query_parts_for_quotation_response
             .data
             .iter()
@@ -101,49 +214,74 @@ where
             })
             .collect::<HashMap<String, PartQuote>>();
 
-        let orders: Vec<Order> = query_parts_for_quotation_response
-            .data
-            .into_iter()
-            .map(|part| {
-                let part_quote = selected_part_quote_for_part[&part.id].clone();
-                let now = Utc::now().naive_utc().date();
-                let deadline = Workdays::add_workdays(now, part_quote.workdays_to_complete);
-                Order::new(
-                    part.customer_id,
-                    part.project_id,
-                    part.quotation_id,
-                    part.id.clone(),
-                    part_quote.id,
-                    deadline,
-                    OrderStatus::Open,
-                    request.shipping_recipient_name.clone(),
-                    request.shipping_address.clone(),
-                )
-            })
-            .collect();
+        let (orders, work_orders): (Vec<Order>, Vec<WorkOrder>) =
+            query_parts_for_quotation_response
+                .data
+                .into_iter()
+                .map(|part| {
+                    let part_quote = selected_part_quote_for_part[&part.id].clone();
+                    let now = Utc::now().naive_utc().date();
+                    let deadline = Workdays::add_workdays(now, part_quote.workdays_to_complete);
+                    let price = part_quote.sub_total_for(part.quantity)?;
+                    let order = Order::new(
+                        part.customer_id,
+                        part.project_id,
+                        part.quotation_id,
+                        part.id.clone(),
+                        part_quote.id,
+                        deadline,
+                        OrderStatus::Open,
+                        request.shipping_recipient_name.clone(),
+                        request.shipping_address.clone(),
+                        request.shipping.clone(),
+                        request.payment_intent_id.clone(),
+                        price,
+                    );
+                    let work_order = WorkOrder::new(&order, part.quantity, &part.attributes);
+                    Ok((order, work_order))
+                })
+                .collect::<Result<Vec<(Order, WorkOrder)>>>()?
+                .into_iter()
+                .unzip();
 
-        let project_transaction = self
-            .projects_repository
-            .transaction_update(request.customer_id.clone(), request.project_id.clone());
+        let project_transaction = self.projects_repository.transaction_update(
+            request.customer_id.clone(),
+            request.project_id.clone(),
+            true,
+        );
         let quote_transaction = self.quotes_repository.transaction_update(
             request.customer_id.clone(),
             request.project_id.clone(),
             request.quotation_id.clone(),
-            QuoteStatus::PendingPayment,
+            quotation.status,
             QuoteStatus::Payed,
         );
         let orders_transactions: Vec<_> = orders
             .into_iter()
             .map(|order| self.orders_repository.transaction_create(order))
             .collect();
+        let invoice_transaction = self.invoices_repository.transaction_create(invoice);
+        let promotion_code_transaction = request.discount.as_ref().map(|discount| {
+            self.promotion_codes_repository
+                .transaction_redeem(discount.code.clone())
+        });
         {
             let mut transaction = self.transaction.lock().await;
             transaction.add_item(project_transaction);
             transaction.add_item(quote_transaction);
             transaction.add_items(orders_transactions);
+            transaction.add_item(invoice_transaction);
+            if let Some(promotion_code_transaction) = promotion_code_transaction {
+                transaction.add_item(promotion_code_transaction);
+            }
             transaction.execute().await?;
         }
 
+        // Work orders are kept out of the transaction to stay within its item limit. The event
+        // is only recorded as processed once all of them exist, so it gets retried until then.
+        self.create_work_orders(work_orders).await?;
+        self.record_processed(input.webhook_event).await?;
+
         let _ = self
             .emailer_service
             .send_email_to_admins(
@@ -158,3 +296,98 @@ where
         Ok(())
     }
 }
+
+impl<Pro, Quo, Ord, Par, Web, Inv, Bil, Prm, Wor, Tx, TxItem>
+    CreateOrdersAndConfirmQuotationPayment<Pro, Quo, Ord, Par, Web, Inv, Bil, Prm, Wor, Tx, TxItem>
+where
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    Quo: QuotesRepository<TransactionItem = TxItem>,
+    Ord: OrdersRepository<TransactionItem = TxItem>,
+    Par: PartsRepository<TransactionItem = TxItem>,
+    Web: WebhookEventsRepository<TransactionItem = TxItem>,
+    Inv: InvoicesRepository<TransactionItem = TxItem>,
+    Bil: BillingProfilesRepository,
+    Prm: PromotionCodesRepository<TransactionItem = TxItem>,
+    Wor: WorkOrdersRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    /// Creates the work orders missing from a payment that was already recorded, as long as the
+    /// event is about that same payment.
+    async fn complete_payment(
+        &self,
+        request: CompleteCheckoutSessionWebhookRequest,
+        webhook_event: WebhookEvent,
+    ) -> Result<()> {
+        let orders = self
+            .orders_repository
+            .query(
+                Some(request.customer_id.clone()),
+                Some(request.project_id.clone()),
+                Some(request.quotation_id.clone()),
+                None,
+                None,
+                None,
+                None,
+                QueryBy::Customer,
+                None,
+                100,
+            )
+            .await?
+            .data;
+        if orders.is_empty()
+            || orders
+                .iter()
+                .any(|order| order.payment_intent_id != request.payment_intent_id)
+        {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+
+        let parts = self
+            .parts_repository
+            .query(
+                request.customer_id.clone(),
+                request.quotation_id.clone(),
+                None,
+                100,
+            )
+            .await?
+            .data;
+        let work_orders = orders
+            .iter()
+            .filter(|order| order.status.is_in_shop())
+            .map(|order| {
+                let part = parts
+                    .iter()
+                    .find(|part| part.id == order.part_id)
+                    .ok_or(Error::ItemNotFoundError)?;
+                Ok(WorkOrder::new(order, part.quantity, &part.attributes))
+            })
+            .collect::<Result<Vec<WorkOrder>>>()?;
+
+        self.create_work_orders(work_orders).await?;
+        self.record_processed(webhook_event).await
+    }
+
+    /// Creating work orders that already exist is a no-op, so replays can create the rest.
+    async fn create_work_orders(&self, work_orders: Vec<WorkOrder>) -> Result<()> {
+        for work_order in work_orders {
+            match self.work_orders_repository.create(work_order).await {
+                Ok(()) | Err(Error::WorkOrderAlreadyExists(_)) => (),
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn record_processed(&self, webhook_event: WebhookEvent) -> Result<()> {
+        let webhook_event_transaction = self
+            .webhook_events_repository
+            .transaction_create_processed(webhook_event);
+
+        let mut transaction = self.transaction.lock().await;
+        transaction.add_item(webhook_event_transaction);
+        transaction.execute().await
+    }
+}
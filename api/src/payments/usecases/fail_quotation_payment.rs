@@ -0,0 +1,114 @@
+use crate::payments::models::inputs::{CheckoutSessionWebhookRequest, WebhookEventInput};
+use crate::quotations::models::quotation::QuoteStatus;
+use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::transaction::Transaction;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::services::emailer::Emailer;
+use crate::services::identity_manager::IdentityManager;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Unlocks a quotation whose delayed payment never arrived, so it can be checked out again.
+pub struct FailQuotationPayment<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    quotes_repository: Arc<Q>,
+    webhook_events_repository: Arc<W>,
+    transaction: Arc<Mutex<Tx>>,
+    identity_manager: Arc<dyn IdentityManager>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<Q, W, Tx, TxItem> FailQuotationPayment<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    pub fn new(
+        quotes_repository: Arc<Q>,
+        webhook_events_repository: Arc<W>,
+        transaction: Arc<Mutex<Tx>>,
+        identity_manager: Arc<dyn IdentityManager>,
+        emailer_service: Arc<dyn Emailer>,
+    ) -> Self {
+        Self {
+            quotes_repository,
+            webhook_events_repository,
+            transaction,
+            identity_manager,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, W, Tx, TxItem> UseCase<WebhookEventInput<CheckoutSessionWebhookRequest>, ()>
+    for FailQuotationPayment<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn execute(&self, input: WebhookEventInput<CheckoutSessionWebhookRequest>) -> Result<()> {
+        let request = input.data;
+        let quotation = self
+            .quotes_repository
+            .get(request.customer_id.clone(), request.quotation_id.clone())
+            .await?;
+
+        let quote_transaction = self.quotes_repository.transaction_update(
+            request.customer_id.clone(),
+            request.project_id,
+            request.quotation_id,
+            QuoteStatus::PaymentProcessing,
+            QuoteStatus::PaymentFailed,
+        );
+        let webhook_event_transaction = self
+            .webhook_events_repository
+            .transaction_create_processed(input.webhook_event);
+        {
+            let mut transaction = self.transaction.lock().await;
+            transaction.add_item(quote_transaction);
+            transaction.add_item(webhook_event_transaction);
+            transaction.execute().await?;
+        }
+
+        if let Ok(identity) = self
+            .identity_manager
+            .get_identity(request.customer_id.clone())
+            .await
+        {
+            let _ = self
+                .emailer_service
+                .send_email(
+                    &identity.traits.email,
+                    "Your payment could not be completed",
+                    &format!(
+                        "We didn't receive the payment for your quote {}.\nYou can check it out again to place the order.",
+                        quotation.name
+                    ),
+                )
+                .await;
+        }
+
+        let _ = self
+            .emailer_service
+            .send_email_to_admins(
+                "A quote payment failed",
+                &format!(
+                    "The delayed payment of customer with id {} for the quote with id {} failed",
+                    request.customer_id, quotation.id
+                ),
+            )
+            .await;
+
+        Ok(())
+    }
+}
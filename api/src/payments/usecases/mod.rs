@@ -1,2 +1,9 @@
+pub mod admin_get_failed_webhook_event;
+pub mod admin_query_failed_webhook_events;
+pub mod await_quotation_async_payment;
 pub mod create_checkout_session;
 pub mod create_orders_and_confirm_quotation_payment;
+pub mod fail_quotation_payment;
+pub mod notify_expired_checkout_session;
+pub mod process_webhook_event;
+pub mod refund_quotation_payment;
@@ -0,0 +1,101 @@
+use crate::payments::models::inputs::{CheckoutSessionWebhookRequest, WebhookEventInput};
+use crate::quotations::models::quotation::QuoteStatus;
+use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::transaction::Transaction;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::services::emailer::Emailer;
+use crate::services::identity_manager::IdentityManager;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Reminds the customer of an abandoned checkout. The quotation keeps its status since it can
+/// be checked out again.
+pub struct NotifyExpiredCheckoutSession<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    quotes_repository: Arc<Q>,
+    webhook_events_repository: Arc<W>,
+    transaction: Arc<Mutex<Tx>>,
+    identity_manager: Arc<dyn IdentityManager>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<Q, W, Tx, TxItem> NotifyExpiredCheckoutSession<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    pub fn new(
+        quotes_repository: Arc<Q>,
+        webhook_events_repository: Arc<W>,
+        transaction: Arc<Mutex<Tx>>,
+        identity_manager: Arc<dyn IdentityManager>,
+        emailer_service: Arc<dyn Emailer>,
+    ) -> Self {
+        Self {
+            quotes_repository,
+            webhook_events_repository,
+            transaction,
+            identity_manager,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, W, Tx, TxItem> UseCase<WebhookEventInput<CheckoutSessionWebhookRequest>, ()>
+    for NotifyExpiredCheckoutSession<Q, W, Tx, TxItem>
+where
+    Q: QuotesRepository,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn execute(&self, input: WebhookEventInput<CheckoutSessionWebhookRequest>) -> Result<()> {
+        let request = input.data;
+        let quotation = self
+            .quotes_repository
+            .get(request.customer_id.clone(), request.quotation_id)
+            .await?;
+
+        let webhook_event_transaction = self
+            .webhook_events_repository
+            .transaction_create_processed(input.webhook_event);
+        {
+            let mut transaction = self.transaction.lock().await;
+            transaction.add_item(webhook_event_transaction);
+            transaction.execute().await?;
+        }
+
+        // Another checkout of the same quotation may have gone through in the meantime.
+        if quotation.status != QuoteStatus::PendingPayment {
+            return Ok(());
+        }
+
+        if let Ok(identity) = self
+            .identity_manager
+            .get_identity(request.customer_id)
+            .await
+        {
+            let _ = self
+                .emailer_service
+                .send_email(
+                    &identity.traits.email,
+                    "Your checkout expired",
+                    &format!(
+                        "You didn't finish checking out your quote {}.\nYou can check it out again while its prices are valid.",
+                        quotation.name
+                    ),
+                )
+                .await;
+        }
+
+        Ok(())
+    }
+}
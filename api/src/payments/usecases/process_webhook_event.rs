@@ -0,0 +1,70 @@
+use crate::payments::models::inputs::WebhookEventInput;
+use crate::payments::models::webhook_event::WebhookEventStatus;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Runs the use case handling a Stripe event at most once per event, recording the failures so
+/// they can be replayed. The wrapped use case records the event as processed, in the same
+/// transaction as its own changes or once they are all done.
+pub struct ProcessWebhookEvent<W, U>
+where
+    W: WebhookEventsRepository,
+{
+    webhook_events_repository: Arc<W>,
+    usecase: U,
+}
+
+impl<W, U> ProcessWebhookEvent<W, U>
+where
+    W: WebhookEventsRepository,
+{
+    pub fn new(webhook_events_repository: Arc<W>, usecase: U) -> Self {
+        Self {
+            webhook_events_repository,
+            usecase,
+        }
+    }
+}
+
+#[async_trait]
+impl<W, U, T> UseCase<WebhookEventInput<T>, ()> for ProcessWebhookEvent<W, U>
+where
+    W: WebhookEventsRepository,
+    U: UseCase<WebhookEventInput<T>, ()> + Send + Sync,
+    T: Send + 'static,
+{
+    async fn execute(&self, input: WebhookEventInput<T>) -> Result<()> {
+        // Stripe delivers events at least once, replays of processed events are no-ops.
+        match self
+            .webhook_events_repository
+            .get(input.webhook_event.id.clone())
+            .await
+        {
+            Ok(webhook_event) if webhook_event.status == WebhookEventStatus::Processed => {
+                return Ok(())
+            }
+            Ok(_) | Err(Error::ItemNotFoundError) => (),
+            Err(err) => return Err(err),
+        }
+
+        let webhook_event = input.webhook_event.clone();
+        match self.usecase.execute(input).await {
+            Ok(()) => Ok(()),
+            Err(err) => {
+                let failed_event = webhook_event.failed(err.to_string());
+                match self
+                    .webhook_events_repository
+                    .save_failed(failed_event)
+                    .await
+                {
+                    // A concurrent delivery of the same event got processed first.
+                    Err(Error::WebhookEventAlreadyProcessed(_)) => Ok(()),
+                    _ => Err(err),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,227 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::order::{OrderStatus, OrderStatusTransition};
+use crate::payments::models::inputs::{ChargeRefundedWebhookRequest, WebhookEventInput};
+use crate::quotations::models::quotation::QuoteStatus;
+use crate::quotations::usecases::unlock_project::unlock_project_transaction;
+use crate::repositories::orders::{OrdersRepository, QueryBy};
+use crate::repositories::projects::ProjectsRepository;
+use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::transaction::Transaction;
+use crate::repositories::webhook_events::WebhookEventsRepository;
+use crate::services::emailer::Emailer;
+use crate::services::identity_manager::IdentityManager;
+use crate::services::stripe_client::StripeClient;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+/// Recorded as the author of the order transitions made by Stripe events.
+const STRIPE_IDENTITY: &str = "stripe";
+
+/// Marks a quotation and its orders as refunded once the payment is fully refunded. Partial
+/// refunds are only notified.
+pub struct RefundQuotationPayment<Q, O, Pro, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    O: OrdersRepository,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    quotes_repository: Arc<Q>,
+    orders_repository: Arc<O>,
+    projects_repository: Arc<Pro>,
+    webhook_events_repository: Arc<W>,
+    transaction: Arc<Mutex<Tx>>,
+    stripe_client: Arc<dyn StripeClient>,
+    identity_manager: Arc<dyn IdentityManager>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<Q, O, Pro, W, Tx, TxItem> RefundQuotationPayment<Q, O, Pro, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    O: OrdersRepository,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        quotes_repository: Arc<Q>,
+        orders_repository: Arc<O>,
+        projects_repository: Arc<Pro>,
+        webhook_events_repository: Arc<W>,
+        transaction: Arc<Mutex<Tx>>,
+        stripe_client: Arc<dyn StripeClient>,
+        identity_manager: Arc<dyn IdentityManager>,
+        emailer_service: Arc<dyn Emailer>,
+    ) -> Self {
+        Self {
+            quotes_repository,
+            orders_repository,
+            projects_repository,
+            webhook_events_repository,
+            transaction,
+            stripe_client,
+            identity_manager,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, O, Pro, W, Tx, TxItem> UseCase<WebhookEventInput<ChargeRefundedWebhookRequest>, ()>
+    for RefundQuotationPayment<Q, O, Pro, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    O: OrdersRepository,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn execute(&self, input: WebhookEventInput<ChargeRefundedWebhookRequest>) -> Result<()> {
+        let request = input.data;
+        let payment = self
+            .stripe_client
+            .get_quotation_payment(request.payment_intent_id)
+            .await?;
+        let quotation = self
+            .quotes_repository
+            .get(payment.customer_id.clone(), payment.quotation_id.clone())
+            .await?;
+
+        if request.is_fully_refunded {
+            // Orders are updated first so a failure leaves the event to be retried.
+            self.refund_orders(
+                payment.customer_id.clone(),
+                payment.project_id.clone(),
+                payment.quotation_id.clone(),
+            )
+            .await?;
+        }
+
+        // A full refund closes the quotation like cancelling all of its orders does.
+        let is_closed = request.is_fully_refunded && quotation.status == QuoteStatus::Payed;
+        let project_transaction = if is_closed {
+            unlock_project_transaction(
+                self.quotes_repository.as_ref(),
+                self.projects_repository.as_ref(),
+                &quotation,
+            )
+            .await?
+        } else {
+            None
+        };
+        let webhook_event_transaction = self
+            .webhook_events_repository
+            .transaction_create_processed(input.webhook_event);
+        {
+            let mut transaction = self.transaction.lock().await;
+            if is_closed {
+                transaction.add_item(self.quotes_repository.transaction_update(
+                    payment.customer_id.clone(),
+                    payment.project_id.clone(),
+                    payment.quotation_id.clone(),
+                    QuoteStatus::Payed,
+                    QuoteStatus::Refunded,
+                ));
+            }
+            if let Some(project_transaction) = project_transaction {
+                transaction.add_item(project_transaction);
+            }
+            transaction.add_item(webhook_event_transaction);
+            transaction.execute().await?;
+        }
+
+        if let Ok(identity) = self
+            .identity_manager
+            .get_identity(payment.customer_id.clone())
+            .await
+        {
+            let _ = self
+                .emailer_service
+                .send_email(
+                    &identity.traits.email,
+                    "Your payment was refunded",
+                    &format!(
+                        "We refunded {} of the payment for your quote {}.",
+                        request.amount_refunded, quotation.name
+                    ),
+                )
+                .await;
+        }
+
+        let _ = self
+            .emailer_service
+            .send_email_to_admins(
+                "A quote got refunded",
+                &format!(
+                    "{} of the payment of customer with id {} for the quote with id {} got refunded{}",
+                    request.amount_refunded,
+                    payment.customer_id,
+                    payment.quotation_id,
+                    if request.is_fully_refunded {
+                        ", its orders were marked as refunded"
+                    } else {
+                        ""
+                    }
+                ),
+            )
+            .await;
+
+        Ok(())
+    }
+}
+
+impl<Q, O, Pro, W, Tx, TxItem> RefundQuotationPayment<Q, O, Pro, W, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    O: OrdersRepository,
+    Pro: ProjectsRepository<TransactionItem = TxItem>,
+    W: WebhookEventsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    async fn refund_orders(
+        &self,
+        customer_id: String,
+        project_id: String,
+        quotation_id: String,
+    ) -> Result<()> {
+        let orders = self
+            .orders_repository
+            .query(
+                Some(customer_id.clone()),
+                Some(project_id),
+                Some(quotation_id),
+                None,
+                None,
+                None,
+                None,
+                QueryBy::Customer,
+                None,
+                100,
+            )
+            .await?;
+
+        for order in orders.data {
+            // Orders cancelled by an admin already recorded their own refund.
+            if matches!(order.status, OrderStatus::Refunded | OrderStatus::Cancelled) {
+                continue;
+            }
+
+            let mut updatable_order =
+                UpdatableOrder::partial_new(customer_id.clone(), order.id, order.created_at);
+            updatable_order.status_transition = Some(OrderStatusTransition::new(
+                order.status,
+                OrderStatus::Refunded,
+                String::from(STRIPE_IDENTITY),
+            ));
+            self.orders_repository.update(updatable_order).await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,102 @@
+use crate::app_state::AppState;
+use crate::promotions::models::inputs::{
+    AdminCreatePromotionCodeInput, AdminUpdatePricingAgreementInput, ApplyPromotionCodeInput,
+    RemovePromotionCodeInput,
+};
+use crate::promotions::usecases::admin_create_promotion_code::AdminCreatePromotionCode;
+use crate::promotions::usecases::admin_update_pricing_agreement::AdminUpdatePricingAgreement;
+use crate::promotions::usecases::apply_promotion_code::ApplyPromotionCode;
+use crate::promotions::usecases::remove_promotion_code::RemovePromotionCode;
+use crate::shared::extractors::session::{AdminSession, CustomerSession};
+use crate::shared::into_error_response::IntoError;
+use crate::shared::{CustomerId, QuoteId, UseCase};
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use http::StatusCode;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ApplyPromotionCodeRequest {
+    pub code: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdatePricingAgreementRequest {
+    pub discount_rate: f64,
+}
+
+pub async fn apply_promotion_code(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+    Path(quotation_id): Path<QuoteId>,
+    Json(request): Json<ApplyPromotionCodeRequest>,
+) -> impl IntoResponse {
+    let input = ApplyPromotionCodeInput {
+        identity: session.identity,
+        quotation_id,
+        code: request.code,
+    };
+    let usecase = ApplyPromotionCode::new(
+        app_state.quotes.dynamodb_quotes,
+        app_state.promotions.dynamodb_promotion_codes,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn remove_promotion_code(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+    Path(quotation_id): Path<QuoteId>,
+) -> impl IntoResponse {
+    let input = RemovePromotionCodeInput {
+        identity: session.identity,
+        quotation_id,
+    };
+    let usecase = RemovePromotionCode::new(app_state.quotes.dynamodb_quotes);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_create_promotion_code(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminCreatePromotionCodeInput>,
+) -> impl IntoResponse {
+    let usecase = AdminCreatePromotionCode::new(app_state.promotions.dynamodb_promotion_codes);
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::CREATED, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_update_pricing_agreement(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Path(customer_id): Path<CustomerId>,
+    Json(request): Json<AdminUpdatePricingAgreementRequest>,
+) -> impl IntoResponse {
+    let input = AdminUpdatePricingAgreementInput {
+        customer_id,
+        discount_rate: request.discount_rate,
+    };
+    let usecase =
+        AdminUpdatePricingAgreement::new(app_state.promotions.dynamodb_pricing_agreements);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
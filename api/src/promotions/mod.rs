@@ -0,0 +1,4 @@
+pub mod controllers;
+pub mod models;
+pub mod routes;
+pub mod usecases;
@@ -0,0 +1,99 @@
+use crate::shared::error::Error;
+use crate::shared::money::{validate_currency, Money};
+use crate::shared::Result;
+use serde_derive::{Deserialize, Serialize};
+
+/// How much a promotion takes off the subtotal of a quotation, before taxes.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Discount {
+    /// 0.1 for 10% off.
+    Percentage { rate: f64 },
+    /// Only applies to quotations priced in the currency of the amount.
+    Fixed { amount: Money },
+}
+
+impl Discount {
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Discount::Percentage { rate } => {
+                if !(*rate > 0.0 && *rate <= 1.0) {
+                    return Err(Error::InvalidPromotion(String::from(
+                        "The rate must be greater than 0 and at most 1",
+                    )));
+                }
+            }
+            Discount::Fixed { amount } => {
+                validate_currency(amount.currency)?;
+                if amount.amount <= 0 {
+                    return Err(Error::InvalidPromotion(String::from(
+                        "The amount must be greater than 0",
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Amount taken off `subtotal`, never more than the subtotal itself.
+    pub fn amount_off(&self, subtotal: &Money) -> Result<Money> {
+        let amount = match self {
+            Discount::Percentage { rate } => (subtotal.amount as f64 * rate).round() as i64,
+            Discount::Fixed { amount } => {
+                if amount.currency != subtotal.currency {
+                    return Err(Error::CurrencyMismatch(subtotal.currency, amount.currency));
+                }
+                amount.amount
+            }
+        };
+
+        Ok(Money::new(amount.min(subtotal.amount), subtotal.currency))
+    }
+}
+
+/// Promotion code applied to a quotation and the amount it takes off, as shown to the customer
+/// and charged at checkout.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct DiscountLine {
+    pub code: String,
+    pub amount: Money,
+}
+
+/// Spreads `discount` over the lines in proportion to their amounts, a cent at a time for the
+/// rounding remainder. Taxes are calculated on the discounted lines, as Stripe does with coupons.
+pub fn discounted_line_amounts(line_amounts: &[Money], discount: &Money) -> Result<Vec<Money>> {
+    let subtotal = Money::checked_sum(discount.currency, line_amounts)?;
+    if discount.amount <= 0 || subtotal.amount <= 0 {
+        return Ok(line_amounts.to_vec());
+    }
+    if discount.amount > subtotal.amount {
+        return Err(Error::InvalidPromotion(String::from(
+            "The discount can't be greater than the subtotal",
+        )));
+    }
+
+    let mut shares = line_amounts
+        .iter()
+        .map(|line_amount| {
+            (i128::from(line_amount.amount) * i128::from(discount.amount)
+                / i128::from(subtotal.amount)) as i64
+        })
+        .collect::<Vec<i64>>();
+    let mut remainder = discount.amount - shares.iter().sum::<i64>();
+    for (share, line_amount) in shares.iter_mut().zip(line_amounts) {
+        if remainder == 0 {
+            break;
+        }
+        if *share < line_amount.amount {
+            *share += 1;
+            remainder -= 1;
+        }
+    }
+
+    line_amounts
+        .iter()
+        .zip(shares)
+        .map(|(line_amount, share)| line_amount.checked_sub(&Money::new(share, discount.currency)))
+        .collect()
+}
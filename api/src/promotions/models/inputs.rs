@@ -0,0 +1,33 @@
+use crate::auth::models::session::Identity;
+use crate::promotions::models::discount::Discount;
+use crate::shared::{CustomerId, QuoteId};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ApplyPromotionCodeInput {
+    pub identity: Identity,
+    pub quotation_id: QuoteId,
+    pub code: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RemovePromotionCodeInput {
+    pub identity: Identity,
+    pub quotation_id: QuoteId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCreatePromotionCodeInput {
+    pub code: String,
+    pub discount: Discount,
+    pub customer_id: Option<CustomerId>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub max_uses: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdatePricingAgreementInput {
+    pub customer_id: CustomerId,
+    pub discount_rate: f64,
+}
@@ -0,0 +1,4 @@
+pub mod discount;
+pub mod inputs;
+pub mod pricing_agreement;
+pub mod promotion_code;
@@ -0,0 +1,46 @@
+use crate::shared::error::Error;
+use crate::shared::money::Money;
+use crate::shared::{CustomerId, Result};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+/// Discount negotiated with a repeat customer, taken off every part quote admins create for
+/// them.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PricingAgreement {
+    pub customer_id: CustomerId,
+    /// 0.1 for 10% off, 0 stops discounting new part quotes.
+    pub discount_rate: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PricingAgreement {
+    pub fn new(customer_id: CustomerId, discount_rate: f64) -> Self {
+        let now = Utc::now();
+
+        Self {
+            customer_id,
+            discount_rate,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !(0.0..1.0).contains(&self.discount_rate) {
+            return Err(Error::InvalidPromotion(String::from(
+                "The discount rate must be at least 0 and less than 1",
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Price after the discount, rounded to the minor unit.
+    pub fn apply(&self, price: &Money) -> Money {
+        let amount = (price.amount as f64 * (1.0 - self.discount_rate)).round() as i64;
+
+        Money::new(amount, price.currency)
+    }
+}
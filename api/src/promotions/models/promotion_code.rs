@@ -0,0 +1,107 @@
+use crate::promotions::models::discount::Discount;
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, Result};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+/// Code customers apply to a quotation before checkout to get a discount.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PromotionCode {
+    /// What customers enter, stored in uppercase.
+    pub code: String,
+    pub discount: Discount,
+    /// Only this customer can apply the code, anyone can when empty.
+    pub customer_id: Option<CustomerId>,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Paid quotations the code can be used in, unlimited when empty.
+    pub max_uses: Option<u64>,
+    /// Paid quotations the code was used in.
+    pub uses: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl PromotionCode {
+    pub fn new(
+        code: String,
+        discount: Discount,
+        customer_id: Option<CustomerId>,
+        expires_at: Option<DateTime<Utc>>,
+        max_uses: Option<u64>,
+    ) -> Self {
+        let now = Utc::now();
+
+        Self {
+            code: normalize_code(&code),
+            discount,
+            customer_id,
+            expires_at,
+            max_uses,
+            uses: 0,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if !(3..=32).contains(&self.code.len())
+            || !self
+                .code
+                .chars()
+                .all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || c == '-' || c == '_')
+        {
+            return Err(Error::InvalidPromotion(String::from(
+                "The code must have 3 to 32 letters, digits, dashes or underscores",
+            )));
+        }
+        if self.max_uses == Some(0) {
+            return Err(Error::InvalidPromotion(String::from(
+                "The usage limit must be greater than 0",
+            )));
+        }
+
+        self.discount.validate()
+    }
+
+    /// Fails when the customer can't use the code at `now`. Codes of other customers are
+    /// reported as not found, so they can't be guessed.
+    pub fn ensure_redeemable(&self, customer_id: &CustomerId, now: DateTime<Utc>) -> Result<()> {
+        if self
+            .customer_id
+            .as_ref()
+            .is_some_and(|code_customer_id| code_customer_id != customer_id)
+        {
+            return Err(Error::ItemNotFoundError);
+        }
+        if self.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(Error::PromotionCodeExpired(self.code.clone()));
+        }
+        if self.max_uses.is_some_and(|max_uses| self.uses >= max_uses) {
+            return Err(Error::PromotionCodeUsedUp(self.code.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+/// Promotion code applied to a quotation, copied so the discount doesn't depend on the code
+/// being looked up again.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct AppliedPromotion {
+    pub code: String,
+    pub discount: Discount,
+}
+
+impl From<PromotionCode> for AppliedPromotion {
+    fn from(value: PromotionCode) -> Self {
+        Self {
+            code: value.code,
+            discount: value.discount,
+        }
+    }
+}
+
+/// Codes are case insensitive.
+pub fn normalize_code(code: &str) -> String {
+    code.trim().to_uppercase()
+}
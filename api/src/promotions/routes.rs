@@ -0,0 +1,25 @@
+use axum::routing::{delete, post, put};
+use axum::Router;
+
+use crate::app_state::AppState;
+use crate::promotions::controllers::{
+    admin_create_promotion_code, admin_update_pricing_agreement, apply_promotion_code,
+    remove_promotion_code,
+};
+
+pub fn create_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/quotations/:quotation_id/promotion_code",
+            put(apply_promotion_code),
+        )
+        .route(
+            "/quotations/:quotation_id/promotion_code",
+            delete(remove_promotion_code),
+        )
+        .route("/admin/promotion_codes", post(admin_create_promotion_code))
+        .route(
+            "/admin/customers/:customer_id/pricing_agreement",
+            put(admin_update_pricing_agreement),
+        )
+}
@@ -0,0 +1,47 @@
+use crate::promotions::models::inputs::AdminCreatePromotionCodeInput;
+use crate::promotions::models::promotion_code::PromotionCode;
+use crate::repositories::promotion_codes::PromotionCodesRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminCreatePromotionCode<C>
+where
+    C: PromotionCodesRepository,
+{
+    promotion_codes_repository: Arc<C>,
+}
+
+impl<C> AdminCreatePromotionCode<C>
+where
+    C: PromotionCodesRepository,
+{
+    pub fn new(promotion_codes_repository: Arc<C>) -> Self {
+        Self {
+            promotion_codes_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<C> UseCase<AdminCreatePromotionCodeInput, PromotionCode> for AdminCreatePromotionCode<C>
+where
+    C: PromotionCodesRepository,
+{
+    async fn execute(&self, input: AdminCreatePromotionCodeInput) -> Result<PromotionCode> {
+        let promotion_code = PromotionCode::new(
+            input.code,
+            input.discount,
+            input.customer_id,
+            input.expires_at,
+            input.max_uses,
+        );
+        promotion_code.validate()?;
+
+        self.promotion_codes_repository
+            .create(promotion_code.clone())
+            .await?;
+
+        Ok(promotion_code)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::promotions::models::inputs::AdminUpdatePricingAgreementInput;
+use crate::promotions::models::pricing_agreement::PricingAgreement;
+use crate::repositories::pricing_agreements::PricingAgreementsRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Creates the pricing agreement of a customer or replaces the existing one. Part quotes already
+/// created keep their prices.
+pub struct AdminUpdatePricingAgreement<A>
+where
+    A: PricingAgreementsRepository,
+{
+    pricing_agreements_repository: Arc<A>,
+}
+
+impl<A> AdminUpdatePricingAgreement<A>
+where
+    A: PricingAgreementsRepository,
+{
+    pub fn new(pricing_agreements_repository: Arc<A>) -> Self {
+        Self {
+            pricing_agreements_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<A> UseCase<AdminUpdatePricingAgreementInput, PricingAgreement>
+    for AdminUpdatePricingAgreement<A>
+where
+    A: PricingAgreementsRepository,
+{
+    async fn execute(&self, input: AdminUpdatePricingAgreementInput) -> Result<PricingAgreement> {
+        let mut pricing_agreement =
+            PricingAgreement::new(input.customer_id.clone(), input.discount_rate);
+        pricing_agreement.validate()?;
+
+        match self
+            .pricing_agreements_repository
+            .get(input.customer_id)
+            .await
+        {
+            Ok(existing_pricing_agreement) => {
+                pricing_agreement.created_at = existing_pricing_agreement.created_at;
+            }
+            Err(Error::ItemNotFoundError) => (),
+            Err(err) => return Err(err),
+        }
+
+        self.pricing_agreements_repository
+            .put(pricing_agreement.clone())
+            .await?;
+
+        Ok(pricing_agreement)
+    }
+}
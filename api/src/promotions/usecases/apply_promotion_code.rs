@@ -0,0 +1,71 @@
+use crate::promotions::models::discount::Discount;
+use crate::promotions::models::inputs::ApplyPromotionCodeInput;
+use crate::promotions::models::promotion_code::{normalize_code, AppliedPromotion};
+use crate::quotations::models::quotation::Quotation;
+use crate::repositories::promotion_codes::PromotionCodesRepository;
+use crate::repositories::quotes::QuotesRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// Applies a promotion code to a quotation, replacing the one already applied. The code is
+/// checked again at checkout, in case it expires or runs out in the meantime.
+pub struct ApplyPromotionCode<Q, C>
+where
+    Q: QuotesRepository,
+    C: PromotionCodesRepository,
+{
+    quotes_repository: Arc<Q>,
+    promotion_codes_repository: Arc<C>,
+}
+
+impl<Q, C> ApplyPromotionCode<Q, C>
+where
+    Q: QuotesRepository,
+    C: PromotionCodesRepository,
+{
+    pub fn new(quotes_repository: Arc<Q>, promotion_codes_repository: Arc<C>) -> Self {
+        Self {
+            quotes_repository,
+            promotion_codes_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, C> UseCase<ApplyPromotionCodeInput, Quotation> for ApplyPromotionCode<Q, C>
+where
+    Q: QuotesRepository,
+    C: PromotionCodesRepository,
+{
+    async fn execute(&self, input: ApplyPromotionCodeInput) -> Result<Quotation> {
+        let quotation = self
+            .quotes_repository
+            .get(input.identity.id.clone(), input.quotation_id.clone())
+            .await?;
+        if quotation.status.is_locked() {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+
+        let promotion_code = self
+            .promotion_codes_repository
+            .get(normalize_code(&input.code))
+            .await?;
+        promotion_code.ensure_redeemable(&input.identity.id, Utc::now())?;
+        if let Discount::Fixed { amount } = &promotion_code.discount {
+            if amount.currency != quotation.currency {
+                return Err(Error::CurrencyMismatch(quotation.currency, amount.currency));
+            }
+        }
+
+        self.quotes_repository
+            .update_promotion(
+                input.identity.id,
+                input.quotation_id,
+                Some(AppliedPromotion::from(promotion_code)),
+            )
+            .await
+    }
+}
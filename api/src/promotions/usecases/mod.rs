@@ -0,0 +1,4 @@
+pub mod admin_create_promotion_code;
+pub mod admin_update_pricing_agreement;
+pub mod apply_promotion_code;
+pub mod remove_promotion_code;
@@ -0,0 +1,39 @@
+use crate::promotions::models::inputs::RemovePromotionCodeInput;
+use crate::quotations::models::quotation::Quotation;
+use crate::repositories::quotes::QuotesRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct RemovePromotionCode<Q>
+where
+    Q: QuotesRepository,
+{
+    quotes_repository: Arc<Q>,
+}
+
+impl<Q> RemovePromotionCode<Q>
+where
+    Q: QuotesRepository,
+{
+    pub fn new(quotes_repository: Arc<Q>) -> Self {
+        Self { quotes_repository }
+    }
+}
+
+#[async_trait]
+impl<Q> UseCase<RemovePromotionCodeInput, Quotation> for RemovePromotionCode<Q>
+where
+    Q: QuotesRepository,
+{
+    async fn execute(&self, input: RemovePromotionCodeInput) -> Result<Quotation> {
+        // Makes sure the quotation belongs to the customer before updating it.
+        self.quotes_repository
+            .get(input.identity.id.clone(), input.quotation_id.clone())
+            .await?;
+
+        self.quotes_repository
+            .update_promotion(input.identity.id, input.quotation_id, None)
+            .await
+    }
+}
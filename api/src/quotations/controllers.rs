@@ -1,30 +1,40 @@
 use crate::app_state::AppState;
+use crate::orders::models::order::Address;
 use crate::quotations::models::inputs::{
-    AdminQueryQuotationsByStatusInput, CreateQuotationInput, DeleteQuotationInput,
-    DownloadQuotePdfInput, GetQuotationByIdInput, GetQuotationSubtotalInput,
+    AdminQueryQuotationsByStatusInput, AdminRestoreQuotationRevisionInput, CreateQuotationInput,
+    DeleteQuotationInput, DiffQuotationRevisionsInput, DownloadQuotePdfInput,
+    GetQuotationByIdInput, GetQuotationSubtotalInput, QueryQuotationRevisionsInput,
     QueryQuotationsForProjectInput, SendForReviewInput,
 };
 use crate::quotations::models::quotation::QuoteStatus;
 use crate::quotations::usecases::admin_query_quotations_by_status::AdminQueryQuotationsByStatus;
+use crate::quotations::usecases::admin_restore_quotation_revision::AdminRestoreQuotationRevision;
 use crate::quotations::usecases::create_quotation::CreateQuotation;
 use crate::quotations::usecases::delete_quotation::DeleteQuotation;
+use crate::quotations::usecases::diff_quotation_revisions::DiffQuotationRevisions;
 use crate::quotations::usecases::download_quote_pdf::DownloadQuotePdf;
 use crate::quotations::usecases::get_quotation::GetQuotation;
 use crate::quotations::usecases::get_quotation_subtotal::GetQuotationSubtotal;
+use crate::quotations::usecases::query_quotation_revisions::QueryQuotationRevisions;
 use crate::quotations::usecases::query_quotations_by_project::QueryQuotationsByProject;
 use crate::quotations::usecases::send_for_review::SendForReview;
 use crate::shared::extractors::session::{AdminSession, CustomerSession};
 use crate::shared::into_error_response::IntoError;
-use crate::shared::{ProjectId, QuoteId, UseCase};
+use crate::shared::{CustomerId, ProjectId, QuotationRevisionId, QuoteId, UseCase};
 use axum::extract::{Path, Query, State};
 use axum::response::{IntoResponse, Response};
 use axum::Json;
 use http::{header, StatusCode};
+use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct CreateQuotationRequest {
     pub quotation_name: String,
+    /// Defaults to MXN.
+    pub currency: Option<Currency>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -38,6 +48,48 @@ pub struct AdminQueryQuotationsByStatusQueryParams {
     pub status: QuoteStatus,
 }
 
+/// Taxes depend on where the quotation is shipped, the default country is used until the
+/// customer enters it.
+#[derive(Deserialize)]
+pub struct ShippingAddressQueryParameters {
+    pub country: Option<String>,
+    pub state: Option<String>,
+}
+
+impl From<ShippingAddressQueryParameters> for Option<Address> {
+    fn from(value: ShippingAddressQueryParameters) -> Self {
+        if value.country.is_none() && value.state.is_none() {
+            return None;
+        }
+
+        Some(Address {
+            country: value.country,
+            state: value.state,
+            ..Default::default()
+        })
+    }
+}
+
+#[derive(Deserialize)]
+pub struct QueryQuotationRevisionsQueryParameters {
+    pub cursor: Option<String>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Deserialize)]
+pub struct DiffQuotationRevisionsQueryParameters {
+    pub from: QuotationRevisionId,
+    pub to: QuotationRevisionId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminRestoreQuotationRevisionRequest {
+    pub customer_id: CustomerId,
+    pub project_id: ProjectId,
+    pub quotation_id: QuoteId,
+    pub revision_id: QuotationRevisionId,
+}
+
 pub async fn create_quotation(
     State(app_state): State<AppState>,
     CustomerSession(session): CustomerSession,
@@ -48,6 +100,7 @@ pub async fn create_quotation(
         identity: session.identity,
         project_id,
         quotation_name: request.quotation_name,
+        currency: request.currency,
     };
     let usecase = CreateQuotation::new(app_state.quotes.dynamodb_quotes);
     let result = usecase.execute(input).await;
@@ -98,14 +151,17 @@ pub async fn get_quotation_subtotal(
     State(app_state): State<AppState>,
     CustomerSession(session): CustomerSession,
     Path(quotation_id): Path<QuoteId>,
+    Query(params): Query<ShippingAddressQueryParameters>,
 ) -> impl IntoResponse {
     let input = GetQuotationSubtotalInput {
         identity: session.identity,
         quotation_id,
+        shipping_address: params.into(),
     };
     let usecase = GetQuotationSubtotal::new(
         app_state.parts.dynamodb_parts,
         app_state.quotes.dynamodb_quotes,
+        app_state.services.tax_calculator,
     );
     let result = usecase.execute(input).await;
 
@@ -150,7 +206,11 @@ pub async fn send_quotation_for_review(
     let usecase = SendForReview::new(
         app_state.quotes.dynamodb_quotes,
         app_state.parts.dynamodb_parts,
+        app_state.quotes.dynamodb_quotation_revisions,
+        app_state.promotions.dynamodb_pricing_agreements,
+        Arc::new(Mutex::new(app_state.payments.transaction)),
         app_state.services.emailer.ses,
+        app_state.parts.pricing_engine,
     );
     let result = usecase.execute(input).await;
 
@@ -164,15 +224,20 @@ pub async fn download_pdf_quote(
     State(app_state): State<AppState>,
     CustomerSession(session): CustomerSession,
     Path(quotation_id): Path<QuoteId>,
+    Query(params): Query<ShippingAddressQueryParameters>,
 ) -> impl IntoResponse {
     let input = DownloadQuotePdfInput {
         identity: session.identity,
         quotation_id,
+        shipping_address: params.into(),
     };
     let usecase = DownloadQuotePdf::new(
-        app_state.parts.dynamodb_parts,
         app_state.quotes.dynamodb_quotes,
-        app_state.payments.stripe_client,
+        app_state.parts.dynamodb_parts,
+        app_state.quotes.dynamodb_quotation_revisions,
+        app_state.parts.s3,
+        app_state.quotes.pdf_renderer,
+        app_state.services.tax_calculator,
     );
     let result = usecase.execute(input).await;
 
@@ -215,3 +280,112 @@ pub async fn admin_query_quotations_by_status(
         Err(err) => Err(err.into_error_response()),
     }
 }
+
+pub async fn query_quotation_revisions(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+    Path(quotation_id): Path<QuoteId>,
+    Query(params): Query<QueryQuotationRevisionsQueryParameters>,
+) -> impl IntoResponse {
+    let input = QueryQuotationRevisionsInput {
+        customer_id: session.identity.id,
+        quotation_id,
+        cursor: params.cursor,
+        limit: params.limit.unwrap_or(20),
+    };
+    let usecase = QueryQuotationRevisions::new(app_state.quotes.dynamodb_quotation_revisions);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn diff_quotation_revisions(
+    State(app_state): State<AppState>,
+    CustomerSession(session): CustomerSession,
+    Path(quotation_id): Path<QuoteId>,
+    Query(params): Query<DiffQuotationRevisionsQueryParameters>,
+) -> impl IntoResponse {
+    let input = DiffQuotationRevisionsInput {
+        customer_id: session.identity.id,
+        quotation_id,
+        from_revision_id: params.from,
+        to_revision_id: params.to,
+    };
+    let usecase = DiffQuotationRevisions::new(app_state.quotes.dynamodb_quotation_revisions);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(diff) => Ok((StatusCode::OK, Json(diff))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_query_quotation_revisions(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Path((customer_id, quotation_id)): Path<(CustomerId, QuoteId)>,
+    Query(params): Query<QueryQuotationRevisionsQueryParameters>,
+) -> impl IntoResponse {
+    let input = QueryQuotationRevisionsInput {
+        customer_id,
+        quotation_id,
+        cursor: params.cursor,
+        limit: params.limit.unwrap_or(20),
+    };
+    let usecase = QueryQuotationRevisions::new(app_state.quotes.dynamodb_quotation_revisions);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_diff_quotation_revisions(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Path((customer_id, quotation_id)): Path<(CustomerId, QuoteId)>,
+    Query(params): Query<DiffQuotationRevisionsQueryParameters>,
+) -> impl IntoResponse {
+    let input = DiffQuotationRevisionsInput {
+        customer_id,
+        quotation_id,
+        from_revision_id: params.from,
+        to_revision_id: params.to,
+    };
+    let usecase = DiffQuotationRevisions::new(app_state.quotes.dynamodb_quotation_revisions);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(diff) => Ok((StatusCode::OK, Json(diff))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_restore_quotation_revision(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminRestoreQuotationRevisionRequest>,
+) -> impl IntoResponse {
+    let input = AdminRestoreQuotationRevisionInput {
+        customer_id: request.customer_id,
+        project_id: request.project_id,
+        quotation_id: request.quotation_id,
+        revision_id: request.revision_id,
+    };
+    let usecase = AdminRestoreQuotationRevision::new(
+        app_state.quotes.dynamodb_quotes,
+        app_state.parts.dynamodb_parts,
+        app_state.quotes.dynamodb_quotation_revisions,
+        Arc::new(Mutex::new(app_state.payments.transaction)),
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(quotation) => Ok((StatusCode::OK, Json(quotation))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
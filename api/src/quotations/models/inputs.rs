@@ -1,6 +1,8 @@
 use crate::auth::models::session::Identity;
+use crate::orders::models::order::Address;
 use crate::quotations::models::quotation::QuoteStatus;
-use crate::shared::{ProjectId, QuoteId};
+use crate::shared::{CustomerId, ProjectId, QuotationRevisionId, QuoteId};
+use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -8,6 +10,7 @@ pub struct CreateQuotationInput {
     pub identity: Identity,
     pub project_id: ProjectId,
     pub quotation_name: ProjectId,
+    pub currency: Option<Currency>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -26,6 +29,8 @@ pub struct GetQuotationByIdInput {
 pub struct GetQuotationSubtotalInput {
     pub identity: Identity,
     pub quotation_id: QuoteId,
+    /// Only the country and state are used, to calculate taxes.
+    pub shipping_address: Option<Address>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -45,9 +50,35 @@ pub struct SendForReviewInput {
 pub struct DownloadQuotePdfInput {
     pub identity: Identity,
     pub quotation_id: QuoteId,
+    /// Only the country and state are used, to calculate taxes.
+    pub shipping_address: Option<Address>,
 }
 
 #[derive(Deserialize, Serialize, Debug)]
 pub struct AdminQueryQuotationsByStatusInput {
     pub status: QuoteStatus,
 }
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryQuotationRevisionsInput {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+    pub cursor: Option<String>,
+    pub limit: i32,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct DiffQuotationRevisionsInput {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+    pub from_revision_id: QuotationRevisionId,
+    pub to_revision_id: QuotationRevisionId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminRestoreQuotationRevisionInput {
+    pub customer_id: CustomerId,
+    pub project_id: ProjectId,
+    pub quotation_id: QuoteId,
+    pub revision_id: QuotationRevisionId,
+}
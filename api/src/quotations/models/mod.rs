@@ -1,4 +1,6 @@
 pub mod dynamodb_requests;
 pub mod inputs;
 pub mod quotation;
+pub mod quotation_revision;
+pub mod quote_document;
 pub mod responses;
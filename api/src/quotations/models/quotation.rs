@@ -1,9 +1,20 @@
+use crate::parts::models::part::Part;
+use crate::promotions::models::discount::DiscountLine;
+use crate::promotions::models::promotion_code::AppliedPromotion;
+use crate::shared::error::Error;
+use crate::shared::money::Money;
 use crate::shared::{CustomerId, ProjectId};
 use chrono::{DateTime, Utc};
+use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 use uuid::{ContextV7, Timestamp, Uuid};
 
+/// Paying for a quotation writes an order per part in the same DynamoDB transaction as the
+/// project, the quotation, the invoice and the promotion code, and transactions can't have more
+/// than 100 items.
+pub const MAX_PARTS_PER_QUOTATION: usize = 90;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Quotation {
     pub id: String,
@@ -11,11 +22,15 @@ pub struct Quotation {
     pub project_id: ProjectId,
     pub name: String,
     pub status: QuoteStatus,
+    /// Every price of the quotation is in this currency, and it's the currency paid at checkout.
+    pub currency: Currency,
+    /// Promotion code the customer applied before checkout.
+    pub promotion: Option<AppliedPromotion>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 impl Quotation {
-    pub fn new(customer_id: String, project_id: String, name: String) -> Self {
+    pub fn new(customer_id: String, project_id: String, name: String, currency: Currency) -> Self {
         let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
         let encoded_id = format!("quo_{}", bs58::encode(id).into_string());
         let now = Utc::now();
@@ -26,10 +41,56 @@ impl Quotation {
             project_id,
             name,
             status: QuoteStatus::Created,
+            currency,
+            promotion: None,
             created_at: now,
             updated_at: now,
         }
     }
+
+    /// Quotations with more parts than can be paid for at once are rejected before being priced
+    /// or checked out.
+    pub fn validate_part_count(&self, parts: &[Part]) -> Result<(), Error> {
+        if parts.len() > MAX_PARTS_PER_QUOTATION {
+            return Err(Error::TooManyParts(
+                self.id.clone(),
+                MAX_PARTS_PER_QUOTATION,
+            ));
+        }
+        Ok(())
+    }
+
+    /// Subtotal of the selected part quote of each part. Fails when a part has no selected part
+    /// quote.
+    pub fn line_amounts(&self, parts: &[Part]) -> Result<Vec<Money>, Error> {
+        parts
+            .iter()
+            .map(|part| {
+                part.selected_part_quote()
+                    .ok_or(Error::NoSelectedQuoteAvailableForPart(part.id.clone()))?
+                    .sub_total_for(part.quantity)
+            })
+            .collect()
+    }
+
+    /// Sum of the selected part quotes. Fails when a part has no selected part quote or was
+    /// priced in another currency.
+    pub fn subtotal(&self, parts: &[Part]) -> Result<Money, Error> {
+        Money::checked_sum(self.currency, &self.line_amounts(parts)?)
+    }
+
+    /// Amount the applied promotion code takes off `subtotal`, if there is one.
+    pub fn discount(&self, subtotal: &Money) -> Result<Option<DiscountLine>, Error> {
+        self.promotion
+            .as_ref()
+            .map(|promotion| {
+                Ok(DiscountLine {
+                    code: promotion.code.clone(),
+                    amount: promotion.discount.amount_off(subtotal)?,
+                })
+            })
+            .transpose()
+    }
 }
 
 #[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
@@ -39,4 +100,27 @@ pub enum QuoteStatus {
     PendingReview,
     PendingPayment,
     Payed,
+    /// Part quotes went past their `valid_until` before the quotation was paid.
+    Expired,
+    /// Checkout completed with a delayed payment method (OXXO, SPEI) that hasn't been paid yet.
+    PaymentProcessing,
+    /// The delayed payment never arrived, the customer can check out again.
+    PaymentFailed,
+    /// The payment was fully refunded.
+    Refunded,
+    /// All its orders were cancelled after payment.
+    Cancelled,
+}
+
+impl QuoteStatus {
+    /// Quotations that were paid, or are being paid, can't be modified.
+    pub fn is_locked(&self) -> bool {
+        matches!(
+            self,
+            QuoteStatus::Payed
+                | QuoteStatus::PaymentProcessing
+                | QuoteStatus::Refunded
+                | QuoteStatus::Cancelled
+        )
+    }
 }
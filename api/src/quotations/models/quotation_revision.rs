@@ -0,0 +1,192 @@
+use crate::parts::models::part::{Part, PartAttributes, PartProcess, PartQuote};
+use crate::shared::file::File;
+use crate::shared::money::Money;
+use crate::shared::{CustomerId, PartId, PartQuoteId, ProjectId, QuotationRevisionId, QuoteId};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use uuid::{ContextV7, Timestamp, Uuid};
+
+/// Immutable snapshot of a quotation's parts and their part quotes after a pricing round.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QuotationRevision {
+    pub id: QuotationRevisionId,
+    pub customer_id: CustomerId,
+    pub project_id: ProjectId,
+    pub quotation_id: QuoteId,
+    pub parts: Vec<PartRevision>,
+    /// Revision whose pricing was restored to create this one.
+    pub restored_from: Option<QuotationRevisionId>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl QuotationRevision {
+    pub fn new(
+        customer_id: CustomerId,
+        project_id: ProjectId,
+        quotation_id: QuoteId,
+        parts: Vec<PartRevision>,
+        restored_from: Option<QuotationRevisionId>,
+    ) -> Self {
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let encoded_id = format!("rev_{}", bs58::encode(id).into_string());
+
+        Self {
+            id: encoded_id,
+            customer_id,
+            project_id,
+            quotation_id,
+            parts,
+            restored_from,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Compares this revision against a later one, part by part.
+    pub fn diff(&self, to: &QuotationRevision) -> QuotationRevisionDiff {
+        let mut parts = self
+            .parts
+            .iter()
+            .map(|from_part| {
+                let to_part = to
+                    .parts
+                    .iter()
+                    .find(|part| part.part_id == from_part.part_id);
+                PartRevisionDiff::new(from_part.part_id.clone(), Some(from_part), to_part)
+            })
+            .collect::<Vec<PartRevisionDiff>>();
+
+        parts.extend(
+            to.parts
+                .iter()
+                .filter(|to_part| {
+                    !self
+                        .parts
+                        .iter()
+                        .any(|part| part.part_id == to_part.part_id)
+                })
+                .map(|to_part| PartRevisionDiff::new(to_part.part_id.clone(), None, Some(to_part))),
+        );
+
+        QuotationRevisionDiff {
+            from_revision_id: self.id.clone(),
+            to_revision_id: to.id.clone(),
+            parts,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PartRevision {
+    pub part_id: PartId,
+    pub model_file: File,
+    pub drawing_file: Option<File>,
+    pub process: PartProcess,
+    pub attributes: PartAttributes,
+    pub quantity: u64,
+    pub selected_part_quote_id: Option<PartQuoteId>,
+    pub part_quotes: Vec<PartQuote>,
+}
+
+impl PartRevision {
+    pub fn new(
+        part: &Part,
+        selected_part_quote_id: Option<PartQuoteId>,
+        part_quotes: Vec<PartQuote>,
+    ) -> Self {
+        Self {
+            part_id: part.id.clone(),
+            model_file: part.model_file.clone(),
+            drawing_file: part.drawing_file.clone(),
+            process: part.process.clone(),
+            attributes: part.attributes.clone(),
+            quantity: part.quantity,
+            selected_part_quote_id,
+            part_quotes,
+        }
+    }
+
+    /// Whether the part is still specified exactly as it was when this revision was priced.
+    pub fn matches(&self, part: &Part) -> bool {
+        self.part_id == part.id
+            && self.model_file == part.model_file
+            && self.drawing_file == part.drawing_file
+            && self.process == part.process
+            && self.attributes == part.attributes
+            && self.quantity == part.quantity
+    }
+
+    pub fn selected_part_quote(&self) -> Option<&PartQuote> {
+        self.part_quotes
+            .iter()
+            .find(|part_quote| Some(&part_quote.id) == self.selected_part_quote_id.as_ref())
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct QuotationRevisionDiff {
+    pub from_revision_id: QuotationRevisionId,
+    pub to_revision_id: QuotationRevisionId,
+    pub parts: Vec<PartRevisionDiff>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PartRevisionDiff {
+    pub part_id: PartId,
+    pub change: PartRevisionChange,
+    pub from_quantity: Option<u64>,
+    pub to_quantity: Option<u64>,
+    pub from_attributes: Option<PartAttributes>,
+    pub to_attributes: Option<PartAttributes>,
+    /// Unit price of the selected part quote.
+    pub from_unit_price: Option<Money>,
+    pub to_unit_price: Option<Money>,
+}
+
+impl PartRevisionDiff {
+    fn new(part_id: PartId, from: Option<&PartRevision>, to: Option<&PartRevision>) -> Self {
+        let change = match (from, to) {
+            (Some(_), None) => PartRevisionChange::Removed,
+            (None, _) => PartRevisionChange::Added,
+            (Some(from), Some(to)) => {
+                let same_specification = from.model_file == to.model_file
+                    && from.drawing_file == to.drawing_file
+                    && from.attributes == to.attributes
+                    && from.quantity == to.quantity;
+                let same_price = from.selected_part_quote().map(|quote| &quote.unit_price)
+                    == to.selected_part_quote().map(|quote| &quote.unit_price);
+
+                if same_specification && same_price {
+                    PartRevisionChange::Unchanged
+                } else {
+                    PartRevisionChange::Modified
+                }
+            }
+        };
+
+        let unit_price = |part: Option<&PartRevision>| {
+            part.and_then(PartRevision::selected_part_quote)
+                .map(|part_quote| part_quote.unit_price.clone())
+        };
+
+        Self {
+            part_id,
+            change,
+            from_quantity: from.map(|part| part.quantity),
+            to_quantity: to.map(|part| part.quantity),
+            from_attributes: from.map(|part| part.attributes.clone()),
+            to_attributes: to.map(|part| part.attributes.clone()),
+            from_unit_price: unit_price(from),
+            to_unit_price: unit_price(to),
+        }
+    }
+}
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PartRevisionChange {
+    Added,
+    Removed,
+    Modified,
+    Unchanged,
+}
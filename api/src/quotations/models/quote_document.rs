@@ -0,0 +1,114 @@
+use crate::parts::models::part::Part;
+use crate::promotions::models::discount::{discounted_line_amounts, DiscountLine};
+use crate::quotations::models::quotation::Quotation;
+use crate::services::tax_calculator::TaxCalculator;
+use crate::shared::error::Error;
+use crate::shared::money::Money;
+use crate::shared::tax::{total_with_taxes, TaxJurisdiction, TaxLine};
+use crate::shared::{PartId, QuoteId};
+use chrono::{DateTime, Utc};
+
+/// Contents of the document of a quotation, priced with the part quote selected for each part.
+#[derive(Clone, Debug)]
+pub struct QuoteDocument {
+    pub quotation_id: QuoteId,
+    pub quotation_name: String,
+    pub customer_email: String,
+    pub issued_at: DateTime<Utc>,
+    /// Earliest expiration of the selected part quotes.
+    pub valid_until: DateTime<Utc>,
+    pub lines: Vec<QuoteDocumentLine>,
+    pub subtotal: Money,
+    /// Promotion code applied to the quotation, taken off before taxes.
+    pub discount: Option<DiscountLine>,
+    pub tax_jurisdiction: TaxJurisdiction,
+    pub tax_lines: Vec<TaxLine>,
+    pub total: Money,
+}
+
+#[derive(Clone, Debug)]
+pub struct QuoteDocumentLine {
+    pub part_id: PartId,
+    pub name: String,
+    pub description: String,
+    pub quantity: u64,
+    pub workdays_to_complete: u64,
+    pub unit_price: Money,
+    pub amount: Money,
+}
+
+impl QuoteDocument {
+    /// Fails when the quotation has no parts or a part has no selected part quote.
+    pub fn new(
+        quotation: &Quotation,
+        parts: &[Part],
+        customer_email: String,
+        tax_calculator: &dyn TaxCalculator,
+        tax_jurisdiction: TaxJurisdiction,
+        issued_at: DateTime<Utc>,
+    ) -> Result<Self, Error> {
+        let mut lines = Vec::with_capacity(parts.len());
+        let mut valid_until: Option<DateTime<Utc>> = None;
+        for part in parts {
+            let part_quote = part
+                .selected_part_quote()
+                .ok_or(Error::NoSelectedQuoteAvailableForPart(part.id.clone()))?;
+            valid_until = Some(valid_until.map_or(part_quote.valid_until, |valid_until| {
+                valid_until.min(part_quote.valid_until)
+            }));
+
+            lines.push(QuoteDocumentLine {
+                part_id: part.id.clone(),
+                name: part.model_file.name.clone(),
+                description: part.attributes.to_string(),
+                quantity: part.quantity,
+                workdays_to_complete: part_quote.workdays_to_complete,
+                unit_price: part_quote.unit_price_for(part.quantity),
+                amount: part_quote.sub_total_for(part.quantity)?,
+            });
+        }
+
+        let Some(valid_until) = valid_until else {
+            return Err(Error::NoPdfQuoteAvailable);
+        };
+        let currency = quotation.currency;
+        let line_amounts = lines
+            .iter()
+            .map(|line| line.amount.clone())
+            .collect::<Vec<Money>>();
+        let subtotal = Money::checked_sum(currency, &line_amounts)?;
+        let discount = quotation.discount(&subtotal)?;
+        let discount_amount = discount
+            .as_ref()
+            .map_or_else(|| Money::zero(currency), |discount| discount.amount.clone());
+        let tax_lines = tax_calculator.tax_lines(
+            &tax_jurisdiction,
+            currency,
+            &discounted_line_amounts(&line_amounts, &discount_amount)?,
+        )?;
+        let total = total_with_taxes(&subtotal.checked_sub(&discount_amount)?, &tax_lines)?;
+
+        Ok(Self {
+            quotation_id: quotation.id.clone(),
+            quotation_name: quotation.name.clone(),
+            customer_email,
+            issued_at,
+            valid_until,
+            lines,
+            subtotal,
+            discount,
+            tax_jurisdiction,
+            tax_lines,
+            total,
+        })
+    }
+
+    /// Longest lead time among the parts, the whole quotation ships once all parts are done.
+    pub fn workdays_to_complete(&self) -> u64 {
+        self.lines
+            .iter()
+            .map(|line| line.workdays_to_complete)
+            .max()
+            .unwrap_or_default()
+    }
+}
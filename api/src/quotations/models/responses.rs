@@ -1,5 +1,8 @@
+use crate::promotions::models::discount::DiscountLine;
 use crate::quotations::models::quotation::Quotation;
+use crate::quotations::models::quotation_revision::QuotationRevision;
 use crate::shared::money::Money;
+use crate::shared::tax::{TaxJurisdiction, TaxLine};
 use serde_derive::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug)]
@@ -25,4 +28,26 @@ impl AdminQueryQuotationsByStatusResponse {
 #[derive(Deserialize, Serialize, Debug)]
 pub struct GetQuotationSubtotalResponse {
     pub quotation_subtotal: Option<Money>,
+    /// Promotion code applied to the quotation, taken off the subtotal before taxes.
+    pub discount: Option<DiscountLine>,
+    pub tax_jurisdiction: Option<TaxJurisdiction>,
+    pub tax_lines: Option<Vec<TaxLine>>,
+    /// Subtotal minus the discount plus taxes, what the customer pays at checkout.
+    pub total: Option<Money>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QueryQuotationRevisionsResponse {
+    pub revisions: Vec<QuotationRevision>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ExpireQuotationsResponse {
+    pub quotations: Vec<Quotation>,
+}
+impl ExpireQuotationsResponse {
+    pub const fn new(quotations: Vec<Quotation>) -> Self {
+        Self { quotations }
+    }
 }
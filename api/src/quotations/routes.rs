@@ -3,8 +3,10 @@ use axum::Router;
 
 use crate::app_state::AppState;
 use crate::quotations::controllers::{
-    admin_query_quotations_by_status, create_quotation, delete_quotation, download_pdf_quote,
-    get_quotation_by_id, get_quotation_subtotal, query_quotations_for_project,
+    admin_diff_quotation_revisions, admin_query_quotation_revisions,
+    admin_query_quotations_by_status, admin_restore_quotation_revision, create_quotation,
+    delete_quotation, diff_quotation_revisions, download_pdf_quote, get_quotation_by_id,
+    get_quotation_subtotal, query_quotation_revisions, query_quotations_for_project,
     send_quotation_for_review,
 };
 
@@ -29,5 +31,25 @@ pub fn create_router() -> Router<AppState> {
             "/quotations/:quotation_id/download_pdf",
             get(download_pdf_quote),
         )
+        .route(
+            "/quotations/:quotation_id/revisions",
+            get(query_quotation_revisions),
+        )
+        .route(
+            "/quotations/:quotation_id/revisions/diff",
+            get(diff_quotation_revisions),
+        )
         .route("/admin/quotations", get(admin_query_quotations_by_status))
+        .route(
+            "/admin/customers/:customer_id/quotations/:quotation_id/revisions",
+            get(admin_query_quotation_revisions),
+        )
+        .route(
+            "/admin/customers/:customer_id/quotations/:quotation_id/revisions/diff",
+            get(admin_diff_quotation_revisions),
+        )
+        .route(
+            "/admin/quotations/revisions/restore",
+            post(admin_restore_quotation_revision),
+        )
 }
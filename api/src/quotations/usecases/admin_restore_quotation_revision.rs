@@ -0,0 +1,169 @@
+use crate::parts::models::part::PartQuote;
+use crate::quotations::models::inputs::AdminRestoreQuotationRevisionInput;
+use crate::quotations::models::quotation::{Quotation, QuoteStatus};
+use crate::quotations::models::quotation_revision::{PartRevision, QuotationRevision};
+use crate::repositories::parts::PartsRepository;
+use crate::repositories::quotation_revisions::QuotationRevisionsRepository;
+use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::transaction::Transaction;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub struct AdminRestoreQuotationRevision<Q, P, R, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    quotes_repository: Arc<Q>,
+    parts_repository: Arc<P>,
+    quotation_revisions_repository: Arc<R>,
+    transaction: Arc<Mutex<Tx>>,
+}
+
+impl<Q, P, R, Tx, TxItem> AdminRestoreQuotationRevision<Q, P, R, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    pub fn new(
+        quotes_repository: Arc<Q>,
+        parts_repository: Arc<P>,
+        quotation_revisions_repository: Arc<R>,
+        transaction: Arc<Mutex<Tx>>,
+    ) -> Self {
+        Self {
+            quotes_repository,
+            parts_repository,
+            quotation_revisions_repository,
+            transaction,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, P, R, Tx, TxItem> UseCase<AdminRestoreQuotationRevisionInput, Quotation>
+    for AdminRestoreQuotationRevision<Q, P, R, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn execute(&self, input: AdminRestoreQuotationRevisionInput) -> Result<Quotation> {
+        let revision = self
+            .quotation_revisions_repository
+            .get(
+                input.customer_id.clone(),
+                input.quotation_id.clone(),
+                input.revision_id,
+            )
+            .await?;
+
+        let quotation = self
+            .quotes_repository
+            .get(input.customer_id.clone(), input.quotation_id.clone())
+            .await?;
+        if quotation.status.is_locked() {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+
+        let parts = self
+            .parts_repository
+            .query(
+                input.customer_id.clone(),
+                input.quotation_id.clone(),
+                None,
+                100,
+            )
+            .await?;
+
+        // Every part must be priced by the revision exactly as it is specified today. Parts
+        // that were deleted since then are simply left out.
+        let mut part_revisions = Vec::with_capacity(parts.data.len());
+        for part in parts.data.iter() {
+            let restored = revision
+                .parts
+                .iter()
+                .find(|part_revision| {
+                    part_revision.matches(part) && !part_revision.part_quotes.is_empty()
+                })
+                .ok_or(Error::RevisionPartChanged(part.id.clone()))?;
+
+            // Restored part quotes start a new validity period.
+            let mut selected_part_quote_id = None;
+            let part_quotes = restored
+                .part_quotes
+                .iter()
+                .map(|part_quote| {
+                    let new_part_quote = PartQuote::new(
+                        part_quote.unit_price.clone(),
+                        part_quote.sub_total.clone(),
+                        part_quote.workdays_to_complete,
+                        part_quote.quantity_tiers.clone(),
+                    );
+                    if Some(&part_quote.id) == restored.selected_part_quote_id.as_ref() {
+                        selected_part_quote_id = Some(new_part_quote.id.clone());
+                    }
+                    new_part_quote
+                })
+                .collect::<Vec<PartQuote>>();
+            let selected_part_quote_id =
+                selected_part_quote_id.unwrap_or_else(|| part_quotes[0].id.clone());
+
+            part_revisions.push(PartRevision::new(
+                part,
+                Some(selected_part_quote_id),
+                part_quotes,
+            ));
+        }
+
+        let quote_transaction = self.quotes_repository.transaction_update(
+            input.customer_id.clone(),
+            input.project_id.clone(),
+            input.quotation_id.clone(),
+            quotation.status,
+            QuoteStatus::PendingPayment,
+        );
+        let part_quotes_transactions = part_revisions
+            .iter()
+            .map(|part_revision| {
+                self.parts_repository.transaction_create_part_quotes(
+                    input.customer_id.clone(),
+                    part_revision.part_id.clone(),
+                    part_revision.selected_part_quote_id.clone().unwrap(),
+                    part_revision.part_quotes.clone(),
+                )
+            })
+            .collect();
+
+        let revision_transaction =
+            self.quotation_revisions_repository
+                .transaction_create(QuotationRevision::new(
+                    input.customer_id.clone(),
+                    input.project_id,
+                    input.quotation_id.clone(),
+                    part_revisions,
+                    Some(revision.id),
+                ));
+
+        {
+            let mut transaction = self.transaction.lock().await;
+            transaction.add_item(quote_transaction);
+            transaction.add_items(part_quotes_transactions);
+            transaction.add_item(revision_transaction);
+            transaction.execute().await?;
+        }
+
+        self.quotes_repository
+            .get(input.customer_id, input.quotation_id)
+            .await
+    }
+}
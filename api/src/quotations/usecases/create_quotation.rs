@@ -1,6 +1,7 @@
 use crate::quotations::models::inputs::CreateQuotationInput;
 use crate::quotations::models::quotation::Quotation;
 use crate::repositories::quotes::QuotesRepository;
+use crate::shared::money::{default_currency, validate_currency};
 use crate::shared::{Result, UseCase};
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -29,7 +30,13 @@ where
     Q: QuotesRepository,
 {
     async fn execute(&self, input: CreateQuotationInput) -> Result<()> {
-        let quotation = Quotation::new(input.identity.id, input.project_id, input.quotation_name);
+        let currency = validate_currency(input.currency.unwrap_or_else(default_currency))?;
+        let quotation = Quotation::new(
+            input.identity.id,
+            input.project_id,
+            input.quotation_name,
+            currency,
+        );
         self.quotations_repository.create(quotation).await
     }
 }
@@ -0,0 +1,47 @@
+use crate::quotations::models::inputs::DiffQuotationRevisionsInput;
+use crate::quotations::models::quotation_revision::QuotationRevisionDiff;
+use crate::repositories::quotation_revisions::QuotationRevisionsRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct DiffQuotationRevisions<R>
+where
+    R: QuotationRevisionsRepository,
+{
+    quotation_revisions_repository: Arc<R>,
+}
+
+impl<R> DiffQuotationRevisions<R>
+where
+    R: QuotationRevisionsRepository,
+{
+    pub fn new(quotation_revisions_repository: Arc<R>) -> Self {
+        Self {
+            quotation_revisions_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase<DiffQuotationRevisionsInput, QuotationRevisionDiff> for DiffQuotationRevisions<R>
+where
+    R: QuotationRevisionsRepository,
+{
+    async fn execute(&self, input: DiffQuotationRevisionsInput) -> Result<QuotationRevisionDiff> {
+        let from = self
+            .quotation_revisions_repository
+            .get(
+                input.customer_id.clone(),
+                input.quotation_id.clone(),
+                input.from_revision_id,
+            )
+            .await?;
+        let to = self
+            .quotation_revisions_repository
+            .get(input.customer_id, input.quotation_id, input.to_revision_id)
+            .await?;
+
+        Ok(from.diff(&to))
+    }
+}
@@ -1,133 +1,165 @@
+use crate::parts::models::part::Part;
 use crate::quotations::models::inputs::DownloadQuotePdfInput;
 use crate::quotations::models::quotation::{Quotation, QuoteStatus};
+use crate::quotations::models::quotation_revision::QuotationRevision;
 use crate::repositories::parts::PartsRepository;
+use crate::repositories::quotation_revisions::QuotationRevisionsRepository;
 use crate::repositories::quotes::QuotesRepository;
-use crate::services::stripe_client::{PriceData, QuoteLineItem, StripeClient};
-use crate::shared;
+use crate::services::object_storage::ObjectStorage;
+use crate::services::quote_renderer::QuoteRenderer;
+use crate::services::tax_calculator::TaxCalculator;
 use crate::shared::error::Error;
-use crate::shared::{CustomerId, QuoteId, UseCase};
+use crate::shared::tax::TaxJurisdiction;
+use crate::shared::{Result, UseCase};
 use async_trait::async_trait;
 use axum::body::Bytes;
-use iso_currency::Currency;
-use shared::Result;
+use chrono::Utc;
 use std::sync::Arc;
 
-pub struct DownloadQuotePdf<Q, P>
+/// Renders the document of a quotation, caching it per quotation revision so repeated downloads
+/// return the same file.
+pub struct DownloadQuotePdf<Q, P, R>
 where
     Q: QuotesRepository,
     P: PartsRepository,
+    R: QuotationRevisionsRepository,
 {
-    parts_repository: Arc<P>,
     quotations_repository: Arc<Q>,
-    stripe_client: Arc<dyn StripeClient>,
+    parts_repository: Arc<P>,
+    quotation_revisions_repository: Arc<R>,
+    object_storage: Arc<dyn ObjectStorage>,
+    quote_renderer: Arc<dyn QuoteRenderer>,
+    tax_calculator: Arc<dyn TaxCalculator>,
 }
 
-impl<Q, P> DownloadQuotePdf<Q, P>
+impl<Q, P, R> DownloadQuotePdf<Q, P, R>
 where
     Q: QuotesRepository,
     P: PartsRepository,
+    R: QuotationRevisionsRepository,
 {
     pub fn new(
-        parts_repository: Arc<P>,
         quotations_repository: Arc<Q>,
-        stripe_client: Arc<dyn StripeClient>,
+        parts_repository: Arc<P>,
+        quotation_revisions_repository: Arc<R>,
+        object_storage: Arc<dyn ObjectStorage>,
+        quote_renderer: Arc<dyn QuoteRenderer>,
+        tax_calculator: Arc<dyn TaxCalculator>,
     ) -> Self {
         Self {
-            parts_repository,
             quotations_repository,
-            stripe_client,
+            parts_repository,
+            quotation_revisions_repository,
+            object_storage,
+            quote_renderer,
+            tax_calculator,
         }
     }
+
+    /// Revisions only cover pricing rounds, the selected part quotes and quantities can change
+    /// afterwards and also update the parts. Taxes change with the shipping destination, and the
+    /// total with the promotion code applied.
+    pub fn cache_key(
+        quotation: &Quotation,
+        revision: Option<&QuotationRevision>,
+        parts: &[Part],
+        tax_jurisdiction: &TaxJurisdiction,
+    ) -> String {
+        let revision_id = revision.map_or("unrevised", |revision| revision.id.as_str());
+        let parts_updated_at = parts
+            .iter()
+            .map(|part| part.updated_at.timestamp_millis())
+            .max()
+            .unwrap_or_default();
+        let destination = match &tax_jurisdiction.state {
+            Some(state) => format!("{}-{state}", tax_jurisdiction.country),
+            None => tax_jurisdiction.country.clone(),
+        };
+        let promotion = quotation
+            .promotion
+            .as_ref()
+            .map(|promotion| format!("_{}", promotion.code))
+            .unwrap_or_default();
+
+        format!(
+            "quotations/{}/{}/{revision_id}_{parts_updated_at}_{destination}{promotion}.pdf",
+            quotation.customer_id, quotation.id
+        )
+    }
 }
 
 #[async_trait]
-impl<Q, P> UseCase<DownloadQuotePdfInput, Bytes> for DownloadQuotePdf<Q, P>
+impl<Q, P, R> UseCase<DownloadQuotePdfInput, Bytes> for DownloadQuotePdf<Q, P, R>
 where
     Q: QuotesRepository,
     P: PartsRepository,
+    R: QuotationRevisionsRepository,
 {
-    async fn execute(&self, input: DownloadQuotePdfInput) -> crate::shared::Result<Bytes> {
-        let quote = self
+    async fn execute(&self, input: DownloadQuotePdfInput) -> Result<Bytes> {
+        let quotation = self
             .quotations_repository
             .get(input.identity.id.clone(), input.quotation_id.clone())
             .await?;
-
-        if !self.is_valid_quote_status(quote).await {
+        if !matches!(
+            quotation.status,
+            QuoteStatus::PendingPayment | QuoteStatus::Payed
+        ) {
             return Err(Error::NoPdfQuoteAvailable);
         }
+        let tax_jurisdiction = self
+            .tax_calculator
+            .jurisdiction(input.shipping_address.as_ref())?;
 
-        let quote_line_items = self
-            .generate_quote_line_items(input.identity.id, input.quotation_id)
-            .await?;
-
-        let stripe_customer_id = input
-            .identity
-            .metadata_public
-            .stripe_customer_id
-            .expect("No stripe-customer ID configured");
-
-        let stripe_quote = self
-            .stripe_client
-            .create_quote(stripe_customer_id, quote_line_items)
-            .await?;
+        let parts = self
+            .parts_repository
+            .query(
+                input.identity.id.clone(),
+                input.quotation_id.clone(),
+                None,
+                100,
+            )
+            .await?
+            .data;
 
-        self.stripe_client
-            .finalize_quote(stripe_quote.id.clone())
-            .await?;
+        // Paid quotations keep their pricing, only the ones pending payment can expire.
+        if quotation.status == QuoteStatus::PendingPayment {
+            let now = Utc::now();
+            parts
+                .iter()
+                .try_for_each(|part| part.validate_part_quote_expiry(now))?;
+        }
 
-        self.stripe_client.download_quote_pdf(stripe_quote.id).await
-    }
-}
+        let latest_revision = self
+            .quotation_revisions_repository
+            .query(input.identity.id, input.quotation_id, None, 1)
+            .await?
+            .data
+            .into_iter()
+            .next();
+        let key = Self::cache_key(
+            &quotation,
+            latest_revision.as_ref(),
+            &parts,
+            &tax_jurisdiction,
+        );
 
-impl<Q, P> DownloadQuotePdf<Q, P>
-where
-    Q: QuotesRepository,
-    P: PartsRepository,
-{
-    async fn is_valid_quote_status(&self, quote: Quotation) -> bool {
-        quote.status == QuoteStatus::PendingPayment || quote.status == QuoteStatus::Payed
-    }
+        if let Ok(pdf) = self.object_storage.get_object(&key).await {
+            return Ok(Bytes::from(pdf));
+        }
 
-    async fn generate_quote_line_items(
-        &self,
-        customer_id: CustomerId,
-        quotation_id: QuoteId,
-    ) -> Result<Vec<QuoteLineItem>> {
-        let page_limit = 100;
-        let query_part_response = self
-            .parts_repository
-            .query(customer_id, quotation_id, None, page_limit)
+        let pdf = self
+            .quote_renderer
+            .render_quote(
+                &quotation,
+                &parts,
+                &input.identity.traits.email,
+                tax_jurisdiction,
+            )
             .await?;
 
-        let quote_line_items = query_part_response
-            .data
-            .into_iter()
-            .map(|part| {
-                let selected_part_quote = part
-                    .part_quotes
-                    .unwrap()
-                    .iter()
-                    .find(|part_quote| {
-                        part.selected_part_quote_id.clone() == Some(part_quote.id.clone())
-                    })
-                    .unwrap()
-                    .clone();
-
-                QuoteLineItem {
-                    price_data: PriceData {
-                        currency: match selected_part_quote.sub_total.currency {
-                            Currency::MXN => stripe::Currency::MXN,
-                            Currency::USD => stripe::Currency::USD,
-                            _ => stripe::Currency::USD,
-                        },
-                        product: part.id.clone(),
-                        unit_amount: selected_part_quote.sub_total.amount as u64,
-                    },
-                    quantity: part.quantity,
-                }
-            })
-            .collect::<Vec<QuoteLineItem>>();
+        // The document can always be rendered again, a failed upload shouldn't fail the download.
+        let _ = self.object_storage.put_object(&key, pdf.clone()).await;
 
-        Ok(quote_line_items)
+        Ok(Bytes::from(pdf))
     }
 }
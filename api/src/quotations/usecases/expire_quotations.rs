@@ -0,0 +1,182 @@
+use crate::quotations::models::quotation::{Quotation, QuoteStatus};
+use crate::quotations::models::responses::ExpireQuotationsResponse;
+use crate::repositories::parts::PartsRepository;
+use crate::repositories::quotes::{QueryBy, QuotesRepository};
+use crate::repositories::transaction::Transaction;
+use crate::services::emailer::Emailer;
+use crate::services::identity_manager::IdentityManager;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub struct ExpireQuotations<Q, P, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    quotations_repository: Arc<Q>,
+    parts_repository: Arc<P>,
+    transaction: Arc<Mutex<Tx>>,
+    identity_manager: Arc<dyn IdentityManager>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<Q, P, Tx, TxItem> ExpireQuotations<Q, P, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    pub fn new(
+        quotations_repository: Arc<Q>,
+        parts_repository: Arc<P>,
+        transaction: Arc<Mutex<Tx>>,
+        identity_manager: Arc<dyn IdentityManager>,
+        emailer_service: Arc<dyn Emailer>,
+    ) -> Self {
+        Self {
+            quotations_repository,
+            parts_repository,
+            transaction,
+            identity_manager,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, P, Tx, TxItem> UseCase<(), ExpireQuotationsResponse> for ExpireQuotations<Q, P, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
+{
+    async fn execute(&self, _: ()) -> Result<ExpireQuotationsResponse> {
+        let now = Utc::now();
+        let mut expired_quotations = Vec::new();
+        let mut cursor = None;
+
+        loop {
+            let response = self
+                .quotations_repository
+                .query(
+                    None,
+                    None,
+                    None,
+                    None,
+                    None,
+                    QueryBy::IsPendingPayment,
+                    100,
+                    cursor,
+                )
+                .await?;
+
+            // A quotation that fails to expire is retried on the next run, it shouldn't keep
+            // the rest from expiring.
+            for quotation in response.data {
+                match self.expire_quotation(&quotation, now).await {
+                    Ok(Some(quotation)) => {
+                        self.notify_customer_of_expiry(&quotation).await;
+                        expired_quotations.push(quotation);
+                    }
+                    Ok(None) => (),
+                    Err(err) => {
+                        tracing::error!("failed to expire quotation `{}`: {err:?}", quotation.id);
+                    }
+                }
+            }
+
+            cursor = response.cursor;
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        Ok(ExpireQuotationsResponse::new(expired_quotations))
+    }
+}
+
+impl<Q, P, Tx, TxItem> ExpireQuotations<Q, P, Tx, TxItem>
+where
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+{
+    /// Moves the quotation to `Expired` only if it's still pending payment, so a checkout that
+    /// completed since it was queried isn't overwritten.
+    async fn expire_quotation(
+        &self,
+        quotation: &Quotation,
+        now: DateTime<Utc>,
+    ) -> Result<Option<Quotation>> {
+        if !self.has_expired_part_quotes(quotation, now).await? {
+            return Ok(None);
+        }
+
+        let quote_transaction = self.quotations_repository.transaction_update(
+            quotation.customer_id.clone(),
+            quotation.project_id.clone(),
+            quotation.id.clone(),
+            QuoteStatus::PendingPayment,
+            QuoteStatus::Expired,
+        );
+        {
+            let mut transaction = self.transaction.lock().await;
+            transaction.add_item(quote_transaction);
+            transaction.execute().await?;
+        }
+
+        self.quotations_repository
+            .get(quotation.customer_id.clone(), quotation.id.clone())
+            .await
+            .map(Some)
+    }
+
+    async fn has_expired_part_quotes(
+        &self,
+        quotation: &Quotation,
+        now: DateTime<Utc>,
+    ) -> Result<bool> {
+        let response = self
+            .parts_repository
+            .query(
+                quotation.customer_id.clone(),
+                quotation.id.clone(),
+                None,
+                100,
+            )
+            .await?;
+
+        Ok(response
+            .data
+            .iter()
+            .any(|part| part.validate_part_quote_expiry(now).is_err()))
+    }
+
+    async fn notify_customer_of_expiry(&self, quotation: &Quotation) {
+        let identity = match self
+            .identity_manager
+            .get_identity(quotation.customer_id.clone())
+            .await
+        {
+            Ok(identity) => identity,
+            Err(_) => return,
+        };
+
+        let _ = self
+            .emailer_service
+            .send_email(
+                &identity.traits.email,
+                "Your quote has expired",
+                &format!(
+                    "The prices of your quote {} are no longer valid.\nSend it for review again to get an updated quote.",
+                    quotation.name
+                ),
+            )
+            .await;
+    }
+}
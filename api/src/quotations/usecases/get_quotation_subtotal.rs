@@ -1,10 +1,12 @@
-use crate::parts::models::part::{Part, PartQuote};
+use crate::promotions::models::discount::discounted_line_amounts;
 use crate::quotations::models::inputs::GetQuotationSubtotalInput;
 use crate::quotations::models::quotation::QuoteStatus;
 use crate::quotations::models::responses::GetQuotationSubtotalResponse;
 use crate::repositories::parts::PartsRepository;
 use crate::repositories::quotes::QuotesRepository;
+use crate::services::tax_calculator::TaxCalculator;
 use crate::shared::money::Money;
+use crate::shared::tax::total_with_taxes;
 use crate::shared::{Result, UseCase};
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -16,6 +18,7 @@ where
 {
     parts_repository: Arc<P>,
     quotations_repository: Arc<Q>,
+    tax_calculator: Arc<dyn TaxCalculator>,
 }
 
 impl<Q, P> GetQuotationSubtotal<Q, P>
@@ -23,10 +26,15 @@ where
     Q: QuotesRepository,
     P: PartsRepository,
 {
-    pub fn new(parts_repository: Arc<P>, quotations_repository: Arc<Q>) -> Self {
+    pub fn new(
+        parts_repository: Arc<P>,
+        quotations_repository: Arc<Q>,
+        tax_calculator: Arc<dyn TaxCalculator>,
+    ) -> Self {
         Self {
             parts_repository,
             quotations_repository,
+            tax_calculator,
         }
     }
 }
@@ -50,6 +58,10 @@ where
         if quotation.status != QuoteStatus::PendingPayment {
             return Ok(GetQuotationSubtotalResponse {
                 quotation_subtotal: None,
+                discount: None,
+                tax_jurisdiction: None,
+                tax_lines: None,
+                total: None,
             });
         }
 
@@ -58,36 +70,34 @@ where
             .query(input.identity.id, input.quotation_id, None, 100)
             .await?;
 
-        let quotation_subtotal = Some(self.calculate_quotation_subtotal(response.data));
+        // Same calculation as the quote document and the checkout, so all of them show the
+        // same total.
+        let tax_jurisdiction = self
+            .tax_calculator
+            .jurisdiction(input.shipping_address.as_ref())?;
+        let line_amounts = quotation.line_amounts(&response.data)?;
+        let quotation_subtotal = quotation.subtotal(&response.data)?;
+        let discount = quotation.discount(&quotation_subtotal)?;
+        let discount_amount = discount.as_ref().map_or_else(
+            || Money::zero(quotation.currency),
+            |discount| discount.amount.clone(),
+        );
+        let tax_lines = self.tax_calculator.tax_lines(
+            &tax_jurisdiction,
+            quotation.currency,
+            &discounted_line_amounts(&line_amounts, &discount_amount)?,
+        )?;
+        let total = total_with_taxes(
+            &quotation_subtotal.checked_sub(&discount_amount)?,
+            &tax_lines,
+        )?;
 
-        Ok(GetQuotationSubtotalResponse { quotation_subtotal })
-    }
-}
-
-impl<Q, P> GetQuotationSubtotal<Q, P>
-where
-    Q: QuotesRepository,
-    P: PartsRepository,
-{
-    pub fn calculate_quotation_subtotal(&self, parts: Vec<Part>) -> Money {
-        let selected_part_quotes = parts
-            .into_iter()
-            .map(|part| {
-                part.part_quotes
-                    .unwrap_or(Vec::new())
-                    .into_iter()
-                    .find(|part_quote| {
-                        part_quote.id == part.selected_part_quote_id.clone().unwrap()
-                    })
-                    .expect("expecting to have selected part quotes")
-            })
-            .collect::<Vec<PartQuote>>();
-
-        selected_part_quotes
-            .into_iter()
-            .fold(Money::default(), |mut money, part_quote| {
-                money.amount += part_quote.sub_total.amount;
-                money
-            })
+        Ok(GetQuotationSubtotalResponse {
+            quotation_subtotal: Some(quotation_subtotal),
+            discount,
+            tax_jurisdiction: Some(tax_jurisdiction),
+            tax_lines: Some(tax_lines),
+            total: Some(total),
+        })
     }
 }
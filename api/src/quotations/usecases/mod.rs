@@ -1,8 +1,13 @@
 pub mod admin_query_quotations_by_status;
+pub mod admin_restore_quotation_revision;
 pub mod create_quotation;
 pub mod delete_quotation;
+pub mod diff_quotation_revisions;
 pub mod download_quote_pdf;
+pub mod expire_quotations;
 pub mod get_quotation;
 pub mod get_quotation_subtotal;
+pub mod query_quotation_revisions;
 pub mod query_quotations_by_project;
 pub mod send_for_review;
+pub mod unlock_project;
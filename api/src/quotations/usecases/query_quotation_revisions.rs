@@ -0,0 +1,51 @@
+use crate::quotations::models::inputs::QueryQuotationRevisionsInput;
+use crate::quotations::models::responses::QueryQuotationRevisionsResponse;
+use crate::repositories::quotation_revisions::QuotationRevisionsRepository;
+use crate::shared::{Result, UseCase};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct QueryQuotationRevisions<R>
+where
+    R: QuotationRevisionsRepository,
+{
+    quotation_revisions_repository: Arc<R>,
+}
+
+impl<R> QueryQuotationRevisions<R>
+where
+    R: QuotationRevisionsRepository,
+{
+    pub fn new(quotation_revisions_repository: Arc<R>) -> Self {
+        Self {
+            quotation_revisions_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase<QueryQuotationRevisionsInput, QueryQuotationRevisionsResponse>
+    for QueryQuotationRevisions<R>
+where
+    R: QuotationRevisionsRepository,
+{
+    async fn execute(
+        &self,
+        input: QueryQuotationRevisionsInput,
+    ) -> Result<QueryQuotationRevisionsResponse> {
+        let response = self
+            .quotation_revisions_repository
+            .query(
+                input.customer_id,
+                input.quotation_id,
+                input.cursor,
+                input.limit,
+            )
+            .await?;
+
+        Ok(QueryQuotationRevisionsResponse {
+            revisions: response.data,
+            cursor: response.cursor,
+        })
+    }
+}
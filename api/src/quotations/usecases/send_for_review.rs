@@ -1,45 +1,78 @@
+use crate::parts::models::part::{Part, PartQuote};
+use crate::promotions::models::pricing_agreement::PricingAgreement;
 use crate::quotations::models::inputs::SendForReviewInput;
 use crate::quotations::models::quotation::{Quotation, QuoteStatus};
+use crate::quotations::models::quotation_revision::{PartRevision, QuotationRevision};
 use crate::repositories::parts::PartsRepository;
+use crate::repositories::pricing_agreements::PricingAgreementsRepository;
+use crate::repositories::quotation_revisions::QuotationRevisionsRepository;
 use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::transaction::Transaction;
 use crate::services::emailer::Emailer;
-use crate::shared::{Result, UseCase};
+use crate::services::pricing_engine::PricingEngine;
+use crate::shared::error::Error;
+use crate::shared::{PartId, Result, UseCase};
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::Mutex;
 
-pub struct SendForReview<Q, P>
+pub struct SendForReview<Q, P, R, A, Tx, TxItem>
 where
-    Q: QuotesRepository,
-    P: PartsRepository,
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    A: PricingAgreementsRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
 {
     quotations_repository: Arc<Q>,
     parts_repository: Arc<P>,
+    quotation_revisions_repository: Arc<R>,
+    pricing_agreements_repository: Arc<A>,
+    transaction: Arc<Mutex<Tx>>,
     emailer_service: Arc<dyn Emailer>,
+    pricing_engine: Arc<dyn PricingEngine>,
 }
 
-impl<Q, P> SendForReview<Q, P>
+impl<Q, P, R, A, Tx, TxItem> SendForReview<Q, P, R, A, Tx, TxItem>
 where
-    Q: QuotesRepository,
-    P: PartsRepository,
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    A: PricingAgreementsRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
 {
     pub fn new(
         quotations_repository: Arc<Q>,
         parts_repository: Arc<P>,
+        quotation_revisions_repository: Arc<R>,
+        pricing_agreements_repository: Arc<A>,
+        transaction: Arc<Mutex<Tx>>,
         emailer_service: Arc<dyn Emailer>,
+        pricing_engine: Arc<dyn PricingEngine>,
     ) -> Self {
         Self {
             quotations_repository,
             parts_repository,
+            quotation_revisions_repository,
+            pricing_agreements_repository,
+            transaction,
             emailer_service,
+            pricing_engine,
         }
     }
 }
 
 #[async_trait]
-impl<Q, P> UseCase<SendForReviewInput, Quotation> for SendForReview<Q, P>
+impl<Q, P, R, A, Tx, TxItem> UseCase<SendForReviewInput, Quotation>
+    for SendForReview<Q, P, R, A, Tx, TxItem>
 where
-    Q: QuotesRepository,
-    P: PartsRepository,
+    Q: QuotesRepository<TransactionItem = TxItem>,
+    P: PartsRepository<TransactionItem = TxItem>,
+    R: QuotationRevisionsRepository<TransactionItem = TxItem>,
+    A: PricingAgreementsRepository,
+    Tx: Transaction<TransactionItem = TxItem>,
+    TxItem: Send,
 {
     async fn execute(&self, input: SendForReviewInput) -> Result<Quotation> {
         let query_response = self
@@ -57,6 +90,96 @@ where
             .iter()
             .try_for_each(|part| part.validate())?;
 
+        let quotation = self
+            .quotations_repository
+            .get(input.identity.id.clone(), input.quotation_id.clone())
+            .await?;
+        quotation.validate_part_count(&query_response.data)?;
+
+        // Low-risk quotations are priced right away and skip the review entirely.
+        if let Some(mut part_quotes_by_part) = self
+            .pricing_engine
+            .instant_quotes(&query_response.data, quotation.currency)
+        {
+            // Customers with a negotiated discount get it on instant quotes too.
+            match self
+                .pricing_agreements_repository
+                .get(input.identity.id.clone())
+                .await
+            {
+                Ok(pricing_agreement) => apply_pricing_agreement(
+                    &pricing_agreement,
+                    &query_response.data,
+                    &mut part_quotes_by_part,
+                )?,
+                Err(Error::ItemNotFoundError) => {}
+                Err(err) => return Err(err),
+            }
+
+            let quote_transaction = self.quotations_repository.transaction_update(
+                input.identity.id.clone(),
+                input.project_id.clone(),
+                input.quotation_id.clone(),
+                quotation.status,
+                QuoteStatus::PendingPayment,
+            );
+
+            let part_quotes_transactions = part_quotes_by_part
+                .iter()
+                .map(|(part_id, part_quotes)| {
+                    self.parts_repository.transaction_create_part_quotes(
+                        input.identity.id.clone(),
+                        part_id.clone(),
+                        part_quotes[0].id.clone(),
+                        part_quotes.clone(),
+                    )
+                })
+                .collect();
+
+            // Instant pricing rounds are kept like the reviewed ones.
+            let part_revisions = query_response
+                .data
+                .iter()
+                .filter_map(|part| {
+                    let part_quotes = part_quotes_by_part.get(&part.id)?.clone();
+                    Some(PartRevision::new(
+                        part,
+                        Some(part_quotes[0].id.clone()),
+                        part_quotes,
+                    ))
+                })
+                .collect();
+            let revision_transaction =
+                self.quotation_revisions_repository
+                    .transaction_create(QuotationRevision::new(
+                        input.identity.id.clone(),
+                        input.project_id,
+                        input.quotation_id.clone(),
+                        part_revisions,
+                        None,
+                    ));
+
+            {
+                let mut transaction = self.transaction.lock().await;
+                transaction.add_item(quote_transaction);
+                transaction.add_items(part_quotes_transactions);
+                transaction.add_item(revision_transaction);
+                transaction.execute().await?;
+            }
+
+            return self
+                .quotations_repository
+                .get(input.identity.id, input.quotation_id)
+                .await;
+        }
+
+        // Expired quotations go through the review again to be re-quoted.
+        let subject = if quotation.status == QuoteStatus::Expired {
+            "An expired quote needs to be re-quoted"
+        } else {
+            "A quote needs review"
+        };
+
         let quotation = self
             .quotations_repository
             .update_status(
@@ -70,7 +193,7 @@ where
         let _ = self
             .emailer_service
             .send_email_to_admins(
-                "A quote needs review",
+                subject,
                 &format!(
                     "Customer with id {} sent the quote with id {} for review.",
                     input.identity.id, input.quotation_id
@@ -81,3 +204,26 @@ where
         Ok(quotation)
     }
 }
+
+/// Discounts the unit prices of the part quotes, recalculating the subtotals from the discounted
+/// unit price so both stay consistent after rounding.
+fn apply_pricing_agreement(
+    pricing_agreement: &PricingAgreement,
+    parts: &[Part],
+    part_quotes_by_part: &mut HashMap<PartId, Vec<PartQuote>>,
+) -> Result<()> {
+    for part in parts {
+        let Some(part_quotes) = part_quotes_by_part.get_mut(&part.id) else {
+            continue;
+        };
+        for part_quote in part_quotes {
+            part_quote.unit_price = pricing_agreement.apply(&part_quote.unit_price);
+            part_quote.sub_total = part_quote.unit_price.checked_mul(part.quantity)?;
+            for quantity_tier in part_quote.quantity_tiers.iter_mut().flatten() {
+                quantity_tier.unit_price = pricing_agreement.apply(&quantity_tier.unit_price);
+            }
+        }
+    }
+
+    Ok(())
+}
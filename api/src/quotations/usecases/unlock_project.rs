@@ -0,0 +1,45 @@
+use crate::quotations::models::quotation::{Quotation, QuoteStatus};
+use crate::repositories::projects::ProjectsRepository;
+use crate::repositories::quotes::{QueryBy, QuotesRepository};
+use crate::shared::Result;
+
+/// Unlocks the project of a quotation that is no longer paid, unless another quotation of the
+/// project is still paid or being paid. The item goes in the same transaction that moves the
+/// quotation out of its paid status.
+pub(crate) async fn unlock_project_transaction<Q, Pro>(
+    quotes_repository: &Q,
+    projects_repository: &Pro,
+    quotation: &Quotation,
+) -> Result<Option<Pro::TransactionItem>>
+where
+    Q: QuotesRepository,
+    Pro: ProjectsRepository,
+{
+    for status in [QuoteStatus::Payed, QuoteStatus::PaymentProcessing] {
+        let response = quotes_repository
+            .query(
+                Some(quotation.customer_id.clone()),
+                Some(quotation.project_id.clone()),
+                None,
+                None,
+                Some(status),
+                QueryBy::Customer,
+                100,
+                None,
+            )
+            .await?;
+        if response
+            .data
+            .iter()
+            .any(|paid_quotation| paid_quotation.id != quotation.id)
+        {
+            return Ok(None);
+        }
+    }
+
+    Ok(Some(projects_repository.transaction_update(
+        quotation.customer_id.clone(),
+        quotation.project_id.clone(),
+        false,
+    )))
+}
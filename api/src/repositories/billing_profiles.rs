@@ -0,0 +1,54 @@
+use crate::billing::models::billing_profile::BillingProfile;
+use crate::shared::{CustomerId, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait BillingProfilesRepository: Send + Sync + 'static {
+    async fn get(&self, customer_id: CustomerId) -> Result<BillingProfile>;
+    /// Creates the billing profile of the customer or replaces the existing one.
+    async fn put(&self, billing_profile: BillingProfile) -> Result<()>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbBillingProfile {
+    pub pk: CustomerId,
+    pub rfc: String,
+    pub legal_name: String,
+    pub tax_regime: String,
+    pub cfdi_use: String,
+    pub postal_code: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DynamodbBillingProfile> for BillingProfile {
+    fn from(value: DynamodbBillingProfile) -> Self {
+        Self {
+            customer_id: value.pk,
+            rfc: value.rfc,
+            legal_name: value.legal_name,
+            tax_regime: value.tax_regime,
+            cfdi_use: value.cfdi_use,
+            postal_code: value.postal_code,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<BillingProfile> for DynamodbBillingProfile {
+    fn from(value: BillingProfile) -> Self {
+        Self {
+            pk: value.customer_id,
+            rfc: value.rfc,
+            legal_name: value.legal_name,
+            tax_regime: value.tax_regime,
+            cfdi_use: value.cfdi_use,
+            postal_code: value.postal_code,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use crate::billing::models::billing_profile::BillingProfile;
+use crate::repositories::billing_profiles::{BillingProfilesRepository, DynamodbBillingProfile};
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, Result};
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
+use serde_dynamo::to_item;
+
+#[derive(Clone)]
+pub struct DynamodbBillingProfiles {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbBillingProfiles {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl BillingProfilesRepository for DynamodbBillingProfiles {
+    async fn get(&self, customer_id: CustomerId) -> Result<BillingProfile> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(customer_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbBillingProfile>(item) {
+                    Ok(dynamodb_billing_profile) => Ok(dynamodb_billing_profile.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn put(&self, billing_profile: BillingProfile) -> Result<()> {
+        let item = to_item(DynamodbBillingProfile::from(billing_profile))
+            .expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(item))
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+use crate::catalog::models::catalog_item::{CatalogItem, CatalogItemKind};
+use crate::catalog::models::dynamodb_requests::UpdatableCatalogItem;
+use crate::parts::models::part::PartProcess;
+use crate::shared::error::Error;
+use crate::shared::error::Error::UnknownError;
+use crate::shared::{CatalogItemId, QueryResponse, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+pub const ATTRIBUTES_SEPARATOR: &str = "&";
+
+#[async_trait]
+pub trait CatalogRepository: Send + Sync + 'static {
+    async fn create(&self, item: CatalogItem) -> Result<()>;
+    async fn delete(&self, process: PartProcess, item_id: CatalogItemId) -> Result<()>;
+    async fn get(&self, process: PartProcess, item_id: CatalogItemId) -> Result<CatalogItem>;
+    /// Query items for a process. When `name` is given `kind` is required and only exact
+    /// matches are returned.
+    async fn query(
+        &self,
+        process: PartProcess,
+        kind: Option<CatalogItemKind>,
+        name: Option<String>,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<CatalogItem>, String>>;
+    async fn update(&self, updatable_item: UpdatableCatalogItem) -> Result<CatalogItem>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbCatalogItem {
+    pub pk: PartProcess,
+    pub sk: CatalogItemId,
+    /// kind&name
+    pub lsi1_sk: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TryInto<CatalogItem> for DynamodbCatalogItem {
+    type Error = Error;
+
+    fn try_into(self) -> std::result::Result<CatalogItem, Self::Error> {
+        let mut kind = None::<CatalogItemKind>;
+        let mut name = None::<String>;
+
+        if let Some((sk_kind, sk_name)) = self.lsi1_sk.split_once(ATTRIBUTES_SEPARATOR) {
+            if let Ok(parsed_kind) = sk_kind.parse() {
+                kind = Some(parsed_kind);
+            }
+            name = Some(sk_name.to_string());
+        }
+
+        let item = CatalogItem {
+            id: self.sk.clone(),
+            process: self.pk,
+            kind: kind.ok_or_else(|| {
+                tracing::error!(
+                    "kind is required but not found for catalog item with id {}",
+                    self.sk
+                );
+                UnknownError
+            })?,
+            name: name.ok_or_else(|| {
+                tracing::error!(
+                    "name is required but not found for catalog item with id {}",
+                    self.sk
+                );
+                UnknownError
+            })?,
+            description: self.description,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        };
+
+        Ok(item)
+    }
+}
+
+impl From<CatalogItem> for DynamodbCatalogItem {
+    fn from(value: CatalogItem) -> Self {
+        Self {
+            pk: value.process,
+            sk: value.id,
+            lsi1_sk: format!("{}{ATTRIBUTES_SEPARATOR}{}", value.kind, value.name),
+            description: value.description,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use crate::catalog::models::catalog_item::{CatalogItem, CatalogItemKind};
+use crate::catalog::models::dynamodb_requests::UpdatableCatalogItem;
+use crate::parts::models::part::PartProcess;
+use crate::repositories::catalog::{CatalogRepository, DynamodbCatalogItem, ATTRIBUTES_SEPARATOR};
+use crate::shared::error::Error;
+use crate::shared::{CatalogItemId, QueryResponse, Result};
+use crate::utils::dynamodb_key_codec::DynamodbKeyCodec;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::update_item::UpdateItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use chrono::Utc;
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
+use serde_dynamo::{from_items, to_item};
+use serde_enum_str::Serialize_enum_str;
+use std::collections::HashMap;
+
+#[derive(Serialize_enum_str)]
+enum TableIndex {
+    #[serde(rename = "LSI1_KindAndName")]
+    LSI1KindAndName,
+}
+
+#[derive(Clone)]
+pub struct DynamodbCatalog {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbCatalog {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl CatalogRepository for DynamodbCatalog {
+    async fn create(&self, item: CatalogItem) -> Result<()> {
+        let dynamodb_item = DynamodbCatalogItem::from(item);
+        let item = to_item(dynamodb_item).expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .set_item(Some(item))
+            .table_name(&self.table)
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn delete(&self, process: PartProcess, item_id: CatalogItemId) -> Result<()> {
+        let response = self
+            .client
+            .delete_item()
+            .table_name(&self.table)
+            .key("pk", AttributeValue::S(process.to_string()))
+            .key("sk", AttributeValue::S(item_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn get(&self, process: PartProcess, item_id: CatalogItemId) -> Result<CatalogItem> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(process.to_string()))
+            .key(String::from("sk"), AttributeValue::S(item_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbCatalogItem>(item) {
+                    Ok(dynamodb_item) => dynamodb_item.try_into(),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn query(
+        &self,
+        process: PartProcess,
+        kind: Option<CatalogItemKind>,
+        name: Option<String>,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<CatalogItem>, String>> {
+        let mut expression_attribute_values: HashMap<String, AttributeValue> = [(
+            String::from(":process"),
+            AttributeValue::S(process.to_string()),
+        )]
+        .into_iter()
+        .collect();
+
+        let mut query = self.client.query().table_name(&self.table);
+        query = match (kind, name) {
+            (Some(kind), Some(name)) => {
+                expression_attribute_values.insert(
+                    String::from(":lsi1_sk"),
+                    AttributeValue::S(format!("{kind}{ATTRIBUTES_SEPARATOR}{name}")),
+                );
+                query
+                    .index_name(TableIndex::LSI1KindAndName.to_string())
+                    .key_condition_expression("pk = :process AND lsi1_sk = :lsi1_sk")
+            }
+            (Some(kind), None) => {
+                expression_attribute_values.insert(
+                    String::from(":prefix"),
+                    AttributeValue::S(format!("{kind}{ATTRIBUTES_SEPARATOR}")),
+                );
+                query
+                    .index_name(TableIndex::LSI1KindAndName.to_string())
+                    .key_condition_expression("pk = :process AND begins_with(lsi1_sk, :prefix)")
+            }
+            (None, Some(_)) => {
+                return Err(Error::MissingRequiredParameter(String::from("kind")));
+            }
+            (None, None) => query
+                .index_name(TableIndex::LSI1KindAndName.to_string())
+                .key_condition_expression("pk = :process"),
+        };
+
+        let response = query
+            .set_expression_attribute_values(Some(expression_attribute_values))
+            .limit(limit)
+            .set_exclusive_start_key(DynamodbKeyCodec::decode_from_base64(cursor))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => {
+                let items = output.items().to_vec();
+                match from_items::<_, DynamodbCatalogItem>(items) {
+                    Ok(dynamodb_items) => {
+                        let mut catalog_items = Vec::with_capacity(dynamodb_items.len());
+                        for dynamodb_item in dynamodb_items {
+                            catalog_items.push(dynamodb_item.try_into()?);
+                        }
+                        Ok(QueryResponse {
+                            data: catalog_items,
+                            cursor: DynamodbKeyCodec::encode_to_base64(output.last_evaluated_key()),
+                        })
+                    }
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn update(&self, updatable_item: UpdatableCatalogItem) -> Result<CatalogItem> {
+        let mut update_expression = String::from("SET updated_at = :updated_at");
+        let mut expression_attribute_values: HashMap<String, AttributeValue> = [(
+            String::from(":updated_at"),
+            AttributeValue::S(Utc::now().to_rfc3339()),
+        )]
+        .into_iter()
+        .collect();
+
+        if let Some(name) = updatable_item.name {
+            update_expression.push_str(", lsi1_sk = :lsi1_sk");
+            expression_attribute_values.insert(
+                String::from(":lsi1_sk"),
+                AttributeValue::S(format!(
+                    "{}{ATTRIBUTES_SEPARATOR}{name}",
+                    updatable_item.kind
+                )),
+            );
+        }
+        if let Some(description) = updatable_item.description {
+            update_expression.push_str(", description = :description");
+            expression_attribute_values
+                .insert(String::from(":description"), AttributeValue::S(description));
+        }
+
+        let response = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key("pk", AttributeValue::S(updatable_item.process.to_string()))
+            .key("sk", AttributeValue::S(updatable_item.id))
+            .condition_expression("attribute_exists(pk)")
+            .update_expression(update_expression)
+            .set_expression_attribute_values(Some(expression_attribute_values))
+            .return_values(ReturnValue::AllNew)
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.attributes {
+                Some(item) => match from_item::<DynamodbCatalogItem>(item) {
+                    Ok(dynamodb_item) => dynamodb_item.try_into(),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    UpdateItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::ItemNotFoundError)
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+}
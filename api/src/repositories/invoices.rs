@@ -0,0 +1,82 @@
+use crate::billing::models::billing_profile::BillingProfile;
+use crate::billing::models::invoice::{Invoice, InvoiceLine};
+use crate::promotions::models::discount::DiscountLine;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingCharge;
+use crate::shared::tax::{TaxJurisdiction, TaxLine};
+use crate::shared::{CustomerId, InvoiceId, ProjectId, QuoteId, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait InvoicesRepository: Send + Sync + 'static {
+    type TransactionItem;
+    async fn get(&self, customer_id: CustomerId, quotation_id: QuoteId) -> Result<Invoice>;
+    /// Quotations are invoiced once, fails the whole transaction if the invoice already exists.
+    fn transaction_create(&self, invoice: Invoice) -> Self::TransactionItem;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbInvoice {
+    pub pk: CustomerId,
+    pub sk: QuoteId,
+    pub id: InvoiceId,
+    pub project_id: ProjectId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_profile: Option<BillingProfile>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_intent_id: Option<String>,
+    pub lines: Vec<InvoiceLine>,
+    pub subtotal: Money,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub discount: Option<DiscountLine>,
+    pub tax_jurisdiction: TaxJurisdiction,
+    pub tax_lines: Vec<TaxLine>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingCharge>,
+    pub total: Money,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<DynamodbInvoice> for Invoice {
+    fn from(value: DynamodbInvoice) -> Self {
+        Self {
+            id: value.id,
+            customer_id: value.pk,
+            project_id: value.project_id,
+            quotation_id: value.sk,
+            billing_profile: value.billing_profile,
+            payment_intent_id: value.payment_intent_id,
+            lines: value.lines,
+            subtotal: value.subtotal,
+            discount: value.discount,
+            tax_jurisdiction: value.tax_jurisdiction,
+            tax_lines: value.tax_lines,
+            shipping: value.shipping,
+            total: value.total,
+            created_at: value.created_at,
+        }
+    }
+}
+
+impl From<Invoice> for DynamodbInvoice {
+    fn from(value: Invoice) -> Self {
+        Self {
+            pk: value.customer_id,
+            sk: value.quotation_id,
+            id: value.id,
+            project_id: value.project_id,
+            billing_profile: value.billing_profile,
+            payment_intent_id: value.payment_intent_id,
+            lines: value.lines,
+            subtotal: value.subtotal,
+            discount: value.discount,
+            tax_jurisdiction: value.tax_jurisdiction,
+            tax_lines: value.tax_lines,
+            shipping: value.shipping,
+            total: value.total,
+            created_at: value.created_at,
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::billing::models::invoice::Invoice;
+use crate::repositories::invoices::{DynamodbInvoice, InvoicesRepository};
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, QuoteId, Result};
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem};
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
+use serde_dynamo::to_item;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct DynamodbInvoices {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbInvoices {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl InvoicesRepository for DynamodbInvoices {
+    type TransactionItem = TransactWriteItem;
+
+    async fn get(&self, customer_id: CustomerId, quotation_id: QuoteId) -> Result<Invoice> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .set_key(Some(HashMap::from([
+                (String::from("pk"), AttributeValue::S(customer_id)),
+                (String::from("sk"), AttributeValue::S(quotation_id)),
+            ])))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbInvoice>(item) {
+                    Ok(dynamodb_invoice) => Ok(dynamodb_invoice.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    fn transaction_create(&self, invoice: Invoice) -> TransactWriteItem {
+        let item =
+            to_item(DynamodbInvoice::from(invoice)).expect("error converting to dynamodb item");
+
+        TransactWriteItem::builder()
+            .put(
+                Put::builder()
+                    .table_name(&self.table)
+                    .set_item(Some(item))
+                    .condition_expression("attribute_not_exists(pk)")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    }
+}
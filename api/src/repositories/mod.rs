@@ -1,10 +1,30 @@
+pub mod billing_profiles;
+pub mod billing_profiles_dynamodb;
+pub mod catalog;
+pub mod catalog_dynamodb;
+pub mod invoices;
+pub mod invoices_dynamodb;
 pub mod orders;
 pub mod orders_dynamodb;
 pub mod parts;
 pub mod parts_dynamodb;
+pub mod pricing_agreements;
+pub mod pricing_agreements_dynamodb;
 pub mod projects;
 pub mod projects_dynamodb;
+pub mod promotion_codes;
+pub mod promotion_codes_dynamodb;
+pub mod quotation_revisions;
+pub mod quotation_revisions_dynamodb;
 pub mod quotes;
 pub mod quotes_dynamodb;
+pub mod rfqs;
+pub mod rfqs_dynamodb;
+pub mod suppliers;
+pub mod suppliers_dynamodb;
 pub mod transaction;
 pub mod transaction_dynamodb;
+pub mod webhook_events;
+pub mod webhook_events_dynamodb;
+pub mod work_orders;
+pub mod work_orders_dynamodb;
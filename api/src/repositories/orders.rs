@@ -1,8 +1,13 @@
-use crate::orders::models::order::{Address, Order, OrderStatus};
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::inspection_report::InspectionReport;
+use crate::orders::models::order::{Address, Order, OrderStatus, OrderStatusTransition, Shipment};
 use crate::shared::error::Error;
 use crate::shared::error::Error::UnknownError;
+use crate::shared::file::File;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingCharge;
 use crate::shared::{
-    CustomerId, OrderId, PartId, PartQuoteId, ProjectId, QueryResponse, QuoteId, Result,
+    CustomerId, OrderId, PartId, PartQuoteId, ProjectId, QueryResponse, QuoteId, Result, SupplierId,
 };
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
@@ -14,6 +19,7 @@ pub const ATTRIBUTES_SEPARATOR: &str = "&";
 pub enum QueryBy {
     Customer,
     IsOpen,
+    Supplier(SupplierId),
 }
 
 #[async_trait]
@@ -32,8 +38,13 @@ pub trait OrdersRepository: Send + Sync + 'static {
         cursor: Option<String>,
         limit: i32,
     ) -> Result<QueryResponse<Vec<Order>, String>>;
-    async fn update(&self, customer_id: CustomerId, order_id: OrderId) -> Result<()>;
+    async fn get(&self, customer_id: CustomerId, order_id: OrderId) -> Result<Order>;
+    /// Gets an order only if it's assigned to the supplier.
+    async fn get_by_supplier(&self, supplier_id: SupplierId, order_id: OrderId) -> Result<Order>;
+    async fn update(&self, updatable_order: UpdatableOrder) -> Result<Order>;
     fn transaction_create(&self, order: Order) -> Self::TransactionItem;
+    /// Same as `update`, with the same conditions, as part of a transaction.
+    fn transaction_update(&self, updatable_order: UpdatableOrder) -> Self::TransactionItem;
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -55,6 +66,34 @@ pub struct DynamodbOrder {
     pub deadline: NaiveDate,
     pub shipping_recipient_name: String,
     pub shipping_address: Address,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shipping: Option<ShippingCharge>,
+    #[serde(default)]
+    pub status_history: Vec<OrderStatusTransition>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shipment: Option<Shipment>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shipping_documents: Vec<File>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inspection_report: Option<InspectionReport>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub material_certificates: Vec<File>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub certificate_of_conformance: Option<File>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payment_intent_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub refund: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pending_refund_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supplier_id: Option<SupplierId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payout: Option<Money>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub payout_transfer_id: Option<String>,
     pub update_at: DateTime<Utc>,
 }
 
@@ -125,6 +164,20 @@ impl TryInto<Order> for DynamodbOrder {
             })?,
             shipping_recipient_name: self.shipping_recipient_name,
             shipping_address: self.shipping_address,
+            shipping: self.shipping,
+            status_history: self.status_history,
+            shipment: self.shipment,
+            shipping_documents: self.shipping_documents,
+            inspection_report: self.inspection_report,
+            material_certificates: self.material_certificates,
+            certificate_of_conformance: self.certificate_of_conformance,
+            payment_intent_id: self.payment_intent_id,
+            refund: self.refund,
+            pending_refund_key: self.pending_refund_key,
+            price: self.price,
+            supplier_id: self.supplier_id,
+            payout: self.payout,
+            payout_transfer_id: self.payout_transfer_id,
             created_at: created_at.ok_or_else(|| {
                 tracing::error!(
                     "created_at required but not found for order with id {}",
@@ -180,6 +233,20 @@ impl From<Order> for DynamodbOrder {
             deadline: value.deadline,
             shipping_recipient_name: value.shipping_recipient_name,
             shipping_address: value.shipping_address,
+            shipping: value.shipping,
+            status_history: value.status_history,
+            shipment: value.shipment,
+            shipping_documents: value.shipping_documents,
+            inspection_report: value.inspection_report,
+            material_certificates: value.material_certificates,
+            certificate_of_conformance: value.certificate_of_conformance,
+            payment_intent_id: value.payment_intent_id,
+            refund: value.refund,
+            pending_refund_key: value.pending_refund_key,
+            price: value.price,
+            supplier_id: value.supplier_id,
+            payout: value.payout,
+            payout_transfer_id: value.payout_transfer_id,
             update_at: value.updated_at,
         }
     }
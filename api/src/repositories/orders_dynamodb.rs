@@ -1,12 +1,17 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
 use crate::orders::models::order::{Order, OrderStatus};
-use crate::repositories::orders::{DynamodbOrder, OrdersRepository, QueryBy};
+use crate::repositories::orders::{DynamodbOrder, OrdersRepository, QueryBy, ATTRIBUTES_SEPARATOR};
 use crate::shared::error::Error;
-use crate::shared::{CustomerId, OrderId, PartId, ProjectId, QueryResponse, QuoteId, Result};
+use crate::shared::{
+    CustomerId, OrderId, PartId, ProjectId, QueryResponse, QuoteId, Result, SupplierId,
+};
 use crate::utils::dynamodb_key_codec::DynamodbKeyCodec;
 use async_trait::async_trait;
 use aws_sdk_dynamodb::operation::query::builders::QueryFluentBuilder;
-use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem};
+use aws_sdk_dynamodb::operation::update_item::UpdateItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, Put, ReturnValue, TransactWriteItem, Update};
 use chrono::{DateTime, Utc};
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
 use serde_dynamo::{from_items, to_item};
 use serde_enum_str::Serialize_enum_str;
 use std::collections::HashMap;
@@ -19,8 +24,11 @@ enum TableIndex {
     LSI2ProjectAndQuoteAndPart,
     #[serde(rename = "GSI1_OrderStatus")]
     GSI1OrderStatus,
-    #[serde(rename = "GSI1_OrderIsOpen")]
+    #[serde(rename = "GSI2_OrderIsOpen")]
     GSI2IsOpen,
+    /// Keyed by `supplier_id` and `sk`, only orders assigned to a supplier are in it.
+    #[serde(rename = "GSI3_SupplierOrders")]
+    GSI3SupplierOrders,
 }
 
 #[derive(Clone)]
@@ -59,7 +67,7 @@ impl OrdersRepository for DynamodbOrders {
                         .ok_or(Error::MissingRequiredParameter(String::from("customer_id")))?;
 
                     if let Some(project_id) = project_id {
-                        self.project_quote_part_query(project_id, quote_id, part_id)
+                        self.project_quote_part_query(customer_id, project_id, quote_id, part_id)
                     } else if let Some(status) = status {
                         self.status_query(customer_id, status)
                     } else {
@@ -67,6 +75,7 @@ impl OrdersRepository for DynamodbOrders {
                     }
                 }
                 QueryBy::IsOpen => self.is_open_query(),
+                QueryBy::Supplier(supplier_id) => self.supplier_query(supplier_id),
             }
         };
 
@@ -105,8 +114,116 @@ impl OrdersRepository for DynamodbOrders {
         }
     }
 
-    async fn update(&self, _customer_id: CustomerId, _order_id: OrderId) -> Result<()> {
-        todo!("Not implemented")
+    async fn get(&self, customer_id: CustomerId, order_id: OrderId) -> Result<Order> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(customer_id))
+            .key(String::from("sk"), AttributeValue::S(order_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbOrder>(item) {
+                    Ok(dynamodb_order) => dynamodb_order.try_into(),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn get_by_supplier(&self, supplier_id: SupplierId, order_id: OrderId) -> Result<Order> {
+        let response = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .index_name(TableIndex::GSI3SupplierOrders.to_string())
+            .key_condition_expression("supplier_id = :supplier_id AND sk = :order_id")
+            .expression_attribute_values(":supplier_id", AttributeValue::S(supplier_id))
+            .expression_attribute_values(":order_id", AttributeValue::S(order_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.items().first() {
+                Some(item) => match from_item::<DynamodbOrder>(item.clone()) {
+                    Ok(dynamodb_order) => dynamodb_order.try_into(),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn update(&self, updatable_order: UpdatableOrder) -> Result<Order> {
+        let order_id = updatable_order.id.clone();
+        let OrderUpdate {
+            key,
+            update_expression,
+            condition_expression,
+            expression_attribute_values,
+            transition_statuses,
+            updates_payout,
+        } = OrderUpdate::from(updatable_order);
+        let response = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .set_key(Some(key))
+            .condition_expression(condition_expression)
+            .update_expression(update_expression)
+            .set_expression_attribute_values(Some(expression_attribute_values))
+            .return_values(ReturnValue::AllNew)
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.attributes {
+                Some(item) => match from_item::<DynamodbOrder>(item) {
+                    Ok(dynamodb_order) => dynamodb_order.try_into(),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    UpdateItemError::ConditionalCheckFailedException(_) => {
+                        match transition_statuses {
+                            Some((from, to)) => Err(Error::InvalidOrderStatusTransition(from, to)),
+                            None if updates_payout => {
+                                Err(Error::OrderPayoutAlreadyTransferred(order_id))
+                            }
+                            None => Err(Error::ItemNotFoundError),
+                        }
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
     }
 
     fn transaction_create(&self, order: Order) -> TransactWriteItem {
@@ -124,11 +241,229 @@ impl OrdersRepository for DynamodbOrders {
             )
             .build()
     }
+
+    fn transaction_update(&self, updatable_order: UpdatableOrder) -> TransactWriteItem {
+        let OrderUpdate {
+            key,
+            update_expression,
+            condition_expression,
+            expression_attribute_values,
+            ..
+        } = OrderUpdate::from(updatable_order);
+
+        TransactWriteItem::builder()
+            .update(
+                Update::builder()
+                    .table_name(&self.table)
+                    .set_key(Some(key))
+                    .condition_expression(condition_expression)
+                    .update_expression(update_expression)
+                    .set_expression_attribute_values(Some(expression_attribute_values))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    }
+}
+
+/// Update expression of an `UpdatableOrder`, shared by single and transactional updates.
+struct OrderUpdate {
+    key: HashMap<String, AttributeValue>,
+    update_expression: String,
+    condition_expression: String,
+    expression_attribute_values: HashMap<String, AttributeValue>,
+    transition_statuses: Option<(OrderStatus, OrderStatus)>,
+    updates_payout: bool,
+}
+
+impl From<UpdatableOrder> for OrderUpdate {
+    fn from(updatable_order: UpdatableOrder) -> Self {
+        let mut update_expression = String::from("SET update_at = :updated_at");
+        let mut expression_attribute_values: HashMap<String, AttributeValue> = [(
+            String::from(":updated_at"),
+            AttributeValue::S(Utc::now().to_rfc3339()),
+        )]
+        .into_iter()
+        .collect();
+        let mut condition_expression = String::from("attribute_exists(pk)");
+        let mut remove_attributes = Vec::<&str>::new();
+        let mut transition_statuses = None::<(OrderStatus, OrderStatus)>;
+
+        if let Some(transition) = updatable_order.status_transition {
+            let gsi1_sk = format!(
+                "{}{ATTRIBUTES_SEPARATOR}{}{ATTRIBUTES_SEPARATOR}{}",
+                transition.to,
+                updatable_order.created_at.to_rfc3339(),
+                updatable_order.id,
+            );
+
+            // The transition is only applied if nobody else moved the order in the meantime.
+            condition_expression.push_str(" AND begins_with(gsi1_sk, :current_status)");
+            expression_attribute_values.insert(
+                String::from(":current_status"),
+                AttributeValue::S(format!("{}{ATTRIBUTES_SEPARATOR}", transition.from)),
+            );
+
+            update_expression.push_str(", gsi1_sk = :gsi1_sk");
+            expression_attribute_values
+                .insert(String::from(":gsi1_sk"), AttributeValue::S(gsi1_sk));
+
+            if transition.to == OrderStatus::Open {
+                update_expression.push_str(", gsi2_pk = :is_open");
+                expression_attribute_values.insert(
+                    String::from(":is_open"),
+                    AttributeValue::S(String::from("true")),
+                );
+            } else {
+                remove_attributes.push("gsi2_pk");
+            }
+
+            update_expression.push_str(
+                ", status_history = list_append(if_not_exists(status_history, :empty_list), :transition)",
+            );
+            expression_attribute_values
+                .insert(String::from(":empty_list"), AttributeValue::L(Vec::new()));
+
+            transition_statuses = Some((transition.from.clone(), transition.to.clone()));
+            expression_attribute_values.insert(
+                String::from(":transition"),
+                AttributeValue::L(vec![AttributeValue::M(to_item(transition).unwrap())]),
+            );
+        }
+
+        if let Some(shipment) = updatable_order.shipment {
+            update_expression.push_str(", shipment = :shipment");
+            expression_attribute_values.insert(
+                String::from(":shipment"),
+                AttributeValue::M(to_item(shipment).unwrap()),
+            );
+        }
+
+        if let Some(shipping_document) = updatable_order.shipping_document {
+            update_expression.push_str(
+                ", shipping_documents = list_append(if_not_exists(shipping_documents, :empty_documents), :shipping_document)",
+            );
+            expression_attribute_values.insert(
+                String::from(":empty_documents"),
+                AttributeValue::L(Vec::new()),
+            );
+            expression_attribute_values.insert(
+                String::from(":shipping_document"),
+                AttributeValue::L(vec![AttributeValue::M(to_item(shipping_document).unwrap())]),
+            );
+        }
+
+        if let Some(inspection_report) = updatable_order.inspection_report {
+            update_expression.push_str(", inspection_report = :inspection_report");
+            expression_attribute_values.insert(
+                String::from(":inspection_report"),
+                AttributeValue::M(to_item(inspection_report).unwrap()),
+            );
+
+            match updatable_order.certificate_of_conformance {
+                Some(certificate_of_conformance) => {
+                    update_expression
+                        .push_str(", certificate_of_conformance = :certificate_of_conformance");
+                    expression_attribute_values.insert(
+                        String::from(":certificate_of_conformance"),
+                        AttributeValue::M(to_item(certificate_of_conformance).unwrap()),
+                    );
+                }
+                None => remove_attributes.push("certificate_of_conformance"),
+            }
+        }
+
+        if let Some(material_certificate) = updatable_order.material_certificate {
+            update_expression.push_str(
+                ", material_certificates = list_append(if_not_exists(material_certificates, :empty_certificates), :material_certificate)",
+            );
+            expression_attribute_values.insert(
+                String::from(":empty_certificates"),
+                AttributeValue::L(Vec::new()),
+            );
+            expression_attribute_values.insert(
+                String::from(":material_certificate"),
+                AttributeValue::L(vec![AttributeValue::M(
+                    to_item(material_certificate).unwrap(),
+                )]),
+            );
+        }
+
+        if let Some(refund) = updatable_order.refund {
+            update_expression.push_str(", refund = :refund");
+            expression_attribute_values.insert(
+                String::from(":refund"),
+                AttributeValue::M(to_item(refund).unwrap()),
+            );
+        }
+
+        if let Some(pending_refund_key) = updatable_order.pending_refund_key {
+            update_expression.push_str(", pending_refund_key = :pending_refund_key");
+            expression_attribute_values.insert(
+                String::from(":pending_refund_key"),
+                AttributeValue::S(pending_refund_key),
+            );
+        }
+
+        if updatable_order.is_refund_issued {
+            remove_attributes.push("pending_refund_key");
+        }
+
+        // Payouts can't change once they were transferred to the supplier.
+        let updates_payout = updatable_order.supplier_id.is_some()
+            || updatable_order.payout.is_some()
+            || updatable_order.payout_transfer_id.is_some();
+        if updates_payout {
+            condition_expression.push_str(" AND attribute_not_exists(payout_transfer_id)");
+        }
+
+        if let Some(supplier_id) = updatable_order.supplier_id {
+            update_expression.push_str(", supplier_id = :supplier_id");
+            expression_attribute_values
+                .insert(String::from(":supplier_id"), AttributeValue::S(supplier_id));
+        }
+
+        if let Some(payout) = updatable_order.payout {
+            update_expression.push_str(", payout = :payout");
+            expression_attribute_values.insert(
+                String::from(":payout"),
+                AttributeValue::M(to_item(payout).unwrap()),
+            );
+        }
+
+        if let Some(payout_transfer_id) = updatable_order.payout_transfer_id {
+            update_expression.push_str(", payout_transfer_id = :payout_transfer_id");
+            expression_attribute_values.insert(
+                String::from(":payout_transfer_id"),
+                AttributeValue::S(payout_transfer_id),
+            );
+        }
+
+        if !remove_attributes.is_empty() {
+            update_expression.push_str(&format!(" REMOVE {}", remove_attributes.join(", ")));
+        }
+
+        Self {
+            key: HashMap::from([
+                (
+                    String::from("pk"),
+                    AttributeValue::S(updatable_order.customer_id),
+                ),
+                (String::from("sk"), AttributeValue::S(updatable_order.id)),
+            ]),
+            update_expression,
+            condition_expression,
+            expression_attribute_values,
+            transition_statuses,
+            updates_payout,
+        }
+    }
 }
 
 impl DynamodbOrders {
     fn project_quote_part_query(
         &self,
+        customer_id: CustomerId,
         project_id: ProjectId,
         quote_id: Option<QuoteId>,
         part_id: Option<PartId>,
@@ -145,7 +480,8 @@ impl DynamodbOrders {
         self.client
             .query()
             .index_name(TableIndex::LSI2ProjectAndQuoteAndPart.to_string())
-            .key_condition_expression("begins_with(lsi2_sk, :lsi2_sk)")
+            .key_condition_expression("pk = :customer_id AND begins_with(lsi2_sk, :lsi2_sk)")
+            .expression_attribute_values(":customer_id", AttributeValue::S(customer_id))
             .expression_attribute_values(":lsi2_sk", AttributeValue::S(lsi2_sk))
     }
 
@@ -192,6 +528,14 @@ impl DynamodbOrders {
             .set_expression_attribute_values(Some(expression_attribute_values))
     }
 
+    fn supplier_query(&self, supplier_id: SupplierId) -> QueryFluentBuilder {
+        self.client
+            .query()
+            .index_name(TableIndex::GSI3SupplierOrders.to_string())
+            .key_condition_expression("supplier_id = :supplier_id")
+            .expression_attribute_values(":supplier_id", AttributeValue::S(supplier_id))
+    }
+
     fn is_open_query(&self) -> QueryFluentBuilder {
         self.client
             .query()
@@ -1,6 +1,7 @@
 use crate::parts::models::dynamodb_requests::{BatchDeletePartObject, UpdatablePart};
 use crate::parts::models::part::{Part, PartProcess, PartQuote};
 use crate::parts::models::part_attributes::PartAttributes;
+use crate::parts::models::part_geometry::PartGeometry;
 use crate::shared::error::Error;
 use crate::shared::error::Error::UnknownError;
 use crate::shared::file::File;
@@ -28,7 +29,10 @@ pub trait PartsRepository: Send + Sync + 'static {
     async fn update(&self, updatable_part: UpdatablePart) -> Result<Part>;
     async fn batch_create(&self, parts: Vec<Part>) -> Result<()>;
     async fn batch_delete(&self, data: Vec<BatchDeletePartObject>) -> Result<()>;
-    async fn batch_get(&self, quotation_and_part_ids: Vec<(QuoteId, PartId)>) -> Result<Vec<Part>>;
+    async fn batch_get(
+        &self,
+        customer_and_part_ids: Vec<(CustomerId, PartId)>,
+    ) -> Result<Vec<Part>>;
     fn transaction_create_part_quotes(
         &self,
         customer_id: CustomerId,
@@ -47,9 +51,13 @@ pub struct DynamodbPart {
     pub project_id: ProjectId,
     pub model_file: File,
     pub render_file: Option<File>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conversion_error: Option<String>,
     pub drawing_file: Option<File>,
     pub process: PartProcess,
     pub attributes: PartAttributes,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub geometry: Option<PartGeometry>,
     pub quantity: u64,
     #[serde(default)]
     pub additional_notes: String,
@@ -88,9 +96,11 @@ impl TryInto<Part> for DynamodbPart {
             })?,
             model_file: self.model_file,
             render_file: self.render_file,
+            conversion_error: self.conversion_error,
             drawing_file: self.drawing_file,
             process: self.process,
             attributes: self.attributes,
+            geometry: self.geometry,
             quantity: self.quantity,
             additional_notes: self.additional_notes,
             selected_part_quote_id: self.selected_part_quote_id,
@@ -125,9 +135,11 @@ impl From<Part> for DynamodbPart {
             project_id: value.project_id,
             model_file: value.model_file,
             render_file: value.render_file,
+            conversion_error: value.conversion_error,
             drawing_file: value.drawing_file,
             process: value.process,
             attributes: value.attributes,
+            geometry: value.geometry,
             quantity: value.quantity,
             additional_notes: value.additional_notes,
             selected_part_quote_id: value.selected_part_quote_id,
@@ -189,6 +189,22 @@ impl PartsRepository for DynamodbParts {
         .into_iter()
         .collect();
 
+        let mut remove_expression = String::new();
+        if let Some(render_file) = updatable_part.render_file {
+            update_expression.push_str(", render_file = :render_file");
+            expression_attribute_values.insert(
+                String::from(":render_file"),
+                AttributeValue::M(to_item(render_file).unwrap()),
+            );
+            remove_expression.push_str(" REMOVE conversion_error");
+        }
+        if let Some(conversion_error) = updatable_part.conversion_error {
+            update_expression.push_str(", conversion_error = :conversion_error");
+            expression_attribute_values.insert(
+                String::from(":conversion_error"),
+                AttributeValue::S(conversion_error),
+            );
+        }
         if let Some(drawing_file) = updatable_part.drawing_file {
             update_expression.push_str(", drawing_file = :drawing_file");
             expression_attribute_values.insert(
@@ -210,6 +226,13 @@ impl PartsRepository for DynamodbParts {
                 AttributeValue::M(to_item(attributes).unwrap()),
             );
         }
+        if let Some(geometry) = updatable_part.geometry {
+            update_expression.push_str(", geometry = :geometry");
+            expression_attribute_values.insert(
+                String::from(":geometry"),
+                AttributeValue::M(to_item(geometry).unwrap()),
+            );
+        }
         if let Some(quantity) = updatable_part.quantity {
             update_expression.push_str(", quantity = :quantity");
             expression_attribute_values.insert(
@@ -242,6 +265,7 @@ impl PartsRepository for DynamodbParts {
             expression_attribute_values
                 .insert(String::from(":part_quotes"), AttributeValue::Null(true));
         }
+        update_expression.push_str(&remove_expression);
 
         let response = self
             .client
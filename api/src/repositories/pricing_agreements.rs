@@ -0,0 +1,42 @@
+use crate::promotions::models::pricing_agreement::PricingAgreement;
+use crate::shared::{CustomerId, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait PricingAgreementsRepository: Send + Sync + 'static {
+    async fn get(&self, customer_id: CustomerId) -> Result<PricingAgreement>;
+    /// Creates the pricing agreement of the customer or replaces the existing one.
+    async fn put(&self, pricing_agreement: PricingAgreement) -> Result<()>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbPricingAgreement {
+    pub pk: CustomerId,
+    pub discount_rate: f64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DynamodbPricingAgreement> for PricingAgreement {
+    fn from(value: DynamodbPricingAgreement) -> Self {
+        Self {
+            customer_id: value.pk,
+            discount_rate: value.discount_rate,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<PricingAgreement> for DynamodbPricingAgreement {
+    fn from(value: PricingAgreement) -> Self {
+        Self {
+            pk: value.customer_id,
+            discount_rate: value.discount_rate,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
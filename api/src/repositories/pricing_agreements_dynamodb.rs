@@ -0,0 +1,72 @@
+use crate::promotions::models::pricing_agreement::PricingAgreement;
+use crate::repositories::pricing_agreements::{
+    DynamodbPricingAgreement, PricingAgreementsRepository,
+};
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, Result};
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
+use serde_dynamo::to_item;
+
+#[derive(Clone)]
+pub struct DynamodbPricingAgreements {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbPricingAgreements {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl PricingAgreementsRepository for DynamodbPricingAgreements {
+    async fn get(&self, customer_id: CustomerId) -> Result<PricingAgreement> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(customer_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbPricingAgreement>(item) {
+                    Ok(dynamodb_pricing_agreement) => Ok(dynamodb_pricing_agreement.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn put(&self, pricing_agreement: PricingAgreement) -> Result<()> {
+        let item = to_item(DynamodbPricingAgreement::from(pricing_agreement))
+            .expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(item))
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+}
@@ -25,10 +25,12 @@ pub trait ProjectsRepository: Send + Sync + 'static {
         cursor: Option<String>,
         limit: i32,
     ) -> Result<QueryResponse<Vec<Project>, String>>;
+    /// Locked projects can't be deleted, they have paid quotations.
     fn transaction_update(
         &self,
         customer_id: CustomerId,
         project_id: ProjectId,
+        is_locked: bool,
     ) -> Self::TransactionItem;
 }
 
@@ -179,6 +179,7 @@ impl ProjectsRepository for DynamodbProjects {
         &self,
         customer_id: CustomerId,
         project_id: ProjectId,
+        is_locked: bool,
     ) -> TransactWriteItem {
         TransactWriteItem::builder()
             .update(
@@ -190,7 +191,7 @@ impl ProjectsRepository for DynamodbProjects {
                     ])))
                     .update_expression("SET is_locked = :is_locked, updated_at = :updated_at")
                     .set_expression_attribute_values(Some(HashMap::from([
-                        (String::from(":is_locked"), AttributeValue::Bool(true)),
+                        (String::from(":is_locked"), AttributeValue::Bool(is_locked)),
                         (
                             String::from(":updated_at"),
                             AttributeValue::S(chrono::Utc::now().to_rfc3339()),
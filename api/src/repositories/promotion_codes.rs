@@ -0,0 +1,62 @@
+use crate::promotions::models::discount::Discount;
+use crate::promotions::models::promotion_code::PromotionCode;
+use crate::shared::{CustomerId, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait PromotionCodesRepository: Send + Sync + 'static {
+    type TransactionItem;
+    async fn get(&self, code: String) -> Result<PromotionCode>;
+    /// Fails if a promotion code with the same code already exists.
+    async fn create(&self, promotion_code: PromotionCode) -> Result<()>;
+    /// Counts a paid quotation the code was used in. The usage limit was checked at checkout, it
+    /// isn't checked again so a payment already made is always confirmed.
+    fn transaction_redeem(&self, code: String) -> Self::TransactionItem;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbPromotionCode {
+    pub pk: String,
+    pub discount: Discount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub customer_id: Option<CustomerId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_uses: Option<u64>,
+    pub uses: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DynamodbPromotionCode> for PromotionCode {
+    fn from(value: DynamodbPromotionCode) -> Self {
+        Self {
+            code: value.pk,
+            discount: value.discount,
+            customer_id: value.customer_id,
+            expires_at: value.expires_at,
+            max_uses: value.max_uses,
+            uses: value.uses,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<PromotionCode> for DynamodbPromotionCode {
+    fn from(value: PromotionCode) -> Self {
+        Self {
+            pk: value.code,
+            discount: value.discount,
+            customer_id: value.customer_id,
+            expires_at: value.expires_at,
+            max_uses: value.max_uses,
+            uses: value.uses,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::promotions::models::promotion_code::PromotionCode;
+use crate::repositories::promotion_codes::{DynamodbPromotionCode, PromotionCodesRepository};
+use crate::shared::error::Error;
+use crate::shared::Result;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::put_item::PutItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, TransactWriteItem, Update};
+use chrono::Utc;
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
+use serde_dynamo::to_item;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct DynamodbPromotionCodes {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbPromotionCodes {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl PromotionCodesRepository for DynamodbPromotionCodes {
+    type TransactionItem = TransactWriteItem;
+
+    async fn get(&self, code: String) -> Result<PromotionCode> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(code))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbPromotionCode>(item) {
+                    Ok(dynamodb_promotion_code) => Ok(dynamodb_promotion_code.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn create(&self, promotion_code: PromotionCode) -> Result<()> {
+        let code = promotion_code.code.clone();
+        let item = to_item(DynamodbPromotionCode::from(promotion_code))
+            .expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(item))
+            .condition_expression("attribute_not_exists(pk)")
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    PutItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::PromotionCodeAlreadyExists(code))
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+
+    fn transaction_redeem(&self, code: String) -> TransactWriteItem {
+        TransactWriteItem::builder()
+            .update(
+                Update::builder()
+                    .table_name(&self.table)
+                    .key(String::from("pk"), AttributeValue::S(code))
+                    .condition_expression("attribute_exists(pk)")
+                    .update_expression("SET uses = uses + :one, updated_at = :updated_at")
+                    .set_expression_attribute_values(Some(HashMap::from([
+                        (String::from(":one"), AttributeValue::N(String::from("1"))),
+                        (
+                            String::from(":updated_at"),
+                            AttributeValue::S(Utc::now().to_rfc3339()),
+                        ),
+                    ])))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    }
+}
@@ -0,0 +1,79 @@
+use crate::quotations::models::quotation_revision::{PartRevision, QuotationRevision};
+use crate::shared::error::Error;
+use crate::shared::error::Error::UnknownError;
+use crate::shared::{CustomerId, ProjectId, QueryResponse, QuotationRevisionId, QuoteId, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+pub const ATTRIBUTES_SEPARATOR: &str = "&";
+
+#[async_trait]
+pub trait QuotationRevisionsRepository: Send + Sync + 'static {
+    type TransactionItem;
+    async fn get(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        revision_id: QuotationRevisionId,
+    ) -> Result<QuotationRevision>;
+    /// Query revisions of a quotation, newest first.
+    async fn query(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<QuotationRevision>, String>>;
+    /// Revisions are written in the same transaction as the pricing they snapshot.
+    fn transaction_create(&self, revision: QuotationRevision) -> Self::TransactionItem;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbQuotationRevision {
+    pub pk: CustomerId,
+    /// quotation_id&revision_id
+    pub sk: String,
+    pub project_id: ProjectId,
+    pub parts: Vec<PartRevision>,
+    pub restored_from: Option<QuotationRevisionId>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl TryInto<QuotationRevision> for DynamodbQuotationRevision {
+    type Error = Error;
+
+    fn try_into(self) -> std::result::Result<QuotationRevision, Self::Error> {
+        let (quotation_id, revision_id) =
+            self.sk.split_once(ATTRIBUTES_SEPARATOR).ok_or_else(|| {
+                tracing::error!(
+                    "quotation id and revision id are required but not found in {}",
+                    self.sk
+                );
+                UnknownError
+            })?;
+
+        Ok(QuotationRevision {
+            id: revision_id.to_string(),
+            customer_id: self.pk,
+            project_id: self.project_id,
+            quotation_id: quotation_id.to_string(),
+            parts: self.parts,
+            restored_from: self.restored_from,
+            created_at: self.created_at,
+        })
+    }
+}
+
+impl From<QuotationRevision> for DynamodbQuotationRevision {
+    fn from(value: QuotationRevision) -> Self {
+        Self {
+            pk: value.customer_id,
+            sk: format!("{}{ATTRIBUTES_SEPARATOR}{}", value.quotation_id, value.id),
+            project_id: value.project_id,
+            parts: value.parts,
+            restored_from: value.restored_from,
+            created_at: value.created_at,
+        }
+    }
+}
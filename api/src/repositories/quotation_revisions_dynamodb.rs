@@ -0,0 +1,133 @@
+use crate::quotations::models::quotation_revision::QuotationRevision;
+use crate::repositories::quotation_revisions::{
+    DynamodbQuotationRevision, QuotationRevisionsRepository, ATTRIBUTES_SEPARATOR,
+};
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, QueryResponse, QuotationRevisionId, QuoteId, Result};
+use crate::utils::dynamodb_key_codec::DynamodbKeyCodec;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem};
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
+use serde_dynamo::{from_items, to_item};
+
+#[derive(Clone)]
+pub struct DynamodbQuotationRevisions {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbQuotationRevisions {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl QuotationRevisionsRepository for DynamodbQuotationRevisions {
+    type TransactionItem = TransactWriteItem;
+
+    async fn get(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        revision_id: QuotationRevisionId,
+    ) -> Result<QuotationRevision> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(customer_id))
+            .key(
+                String::from("sk"),
+                AttributeValue::S(format!("{quotation_id}{ATTRIBUTES_SEPARATOR}{revision_id}")),
+            )
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbQuotationRevision>(item) {
+                    Ok(dynamodb_revision) => dynamodb_revision.try_into(),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn query(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<QuotationRevision>, String>> {
+        let response = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression("pk = :customer_id AND begins_with(sk, :quotation_id)")
+            .expression_attribute_values(":customer_id", AttributeValue::S(customer_id))
+            .expression_attribute_values(
+                ":quotation_id",
+                AttributeValue::S(format!("{quotation_id}{ATTRIBUTES_SEPARATOR}")),
+            )
+            // Revision ids are time ordered.
+            .scan_index_forward(false)
+            .limit(limit)
+            .set_exclusive_start_key(DynamodbKeyCodec::decode_from_base64(cursor))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => {
+                let items = output.items().to_vec();
+                match from_items::<_, DynamodbQuotationRevision>(items) {
+                    Ok(dynamodb_revisions) => {
+                        let mut revisions = Vec::with_capacity(dynamodb_revisions.len());
+                        for dynamodb_revision in dynamodb_revisions {
+                            revisions.push(dynamodb_revision.try_into()?);
+                        }
+                        Ok(QueryResponse {
+                            data: revisions,
+                            cursor: DynamodbKeyCodec::encode_to_base64(output.last_evaluated_key()),
+                        })
+                    }
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    fn transaction_create(&self, revision: QuotationRevision) -> TransactWriteItem {
+        let dynamodb_revision = DynamodbQuotationRevision::from(revision);
+
+        TransactWriteItem::builder()
+            .put(
+                Put::builder()
+                    .set_item(Some(
+                        to_item(dynamodb_revision).expect("error converting to dynamodb item"),
+                    ))
+                    .table_name(&self.table)
+                    // Revisions are immutable.
+                    .condition_expression("attribute_not_exists(pk)")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    }
+}
@@ -1,10 +1,13 @@
+use crate::promotions::models::promotion_code::AppliedPromotion;
 use crate::quotations::models::dynamodb_requests::BatchDeleteQuotationObject;
 use crate::quotations::models::quotation::{Quotation, QuoteStatus};
 use crate::shared::error::Error;
 use crate::shared::error::Error::UnknownError;
+use crate::shared::money::default_currency;
 use crate::shared::{CustomerId, ProjectId, QueryResponse, QuoteId, Result};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
 use std::str::FromStr;
 
@@ -13,6 +16,7 @@ pub const ATTRIBUTES_SEPARATOR: &str = "&";
 pub enum QueryBy {
     Customer,
     IsPendingReview,
+    IsPendingPayment,
 }
 
 #[async_trait]
@@ -42,6 +46,13 @@ pub trait QuotesRepository: Send + Sync + 'static {
         quotation_id: QuoteId,
         status: QuoteStatus,
     ) -> Result<Quotation>;
+    /// Applies a promotion code, or removes it when `None`, ONLY if the quotation is not locked.
+    async fn update_promotion(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        promotion: Option<AppliedPromotion>,
+    ) -> Result<Quotation>;
     async fn batch_delete(&self, data: Vec<BatchDeleteQuotationObject>) -> Result<()>;
     fn transaction_update(
         &self,
@@ -66,7 +77,14 @@ pub struct DynamodbQuote {
     pub gsi2_pk: Option<String>,
     /// created_at&quote_id
     pub gsi2_sk: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// is_pending_payment
+    pub gsi3_pk: Option<String>,
     pub name: String,
+    #[serde(default = "default_currency")]
+    pub currency: Currency,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub promotion: Option<AppliedPromotion>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -105,6 +123,8 @@ impl TryInto<Quotation> for DynamodbQuote {
                 UnknownError
             })?,
             name: self.name.clone(),
+            currency: self.currency,
+            promotion: self.promotion.clone(),
             status: status.ok_or_else(|| {
                 tracing::error!(
                     "status is required but not found for quote with id {}",
@@ -150,6 +170,11 @@ impl From<Quotation> for DynamodbQuote {
         } else {
             None
         };
+        let gsi3_pk = if value.status == QuoteStatus::PendingPayment {
+            Some(String::from("true"))
+        } else {
+            None
+        };
 
         Self {
             pk: value.customer_id,
@@ -158,7 +183,10 @@ impl From<Quotation> for DynamodbQuote {
             gsi1_sk,
             gsi2_pk,
             gsi2_sk,
+            gsi3_pk,
             name: value.name,
+            currency: value.currency,
+            promotion: value.promotion,
             updated_at: value.updated_at,
         }
     }
@@ -1,3 +1,4 @@
+use crate::promotions::models::promotion_code::AppliedPromotion;
 use crate::quotations::models::dynamodb_requests::BatchDeleteQuotationObject;
 use crate::quotations::models::quotation::{Quotation, QuoteStatus};
 use crate::repositories::quotes::{DynamodbQuote, QueryBy, QuotesRepository, ATTRIBUTES_SEPARATOR};
@@ -13,7 +14,7 @@ use aws_sdk_dynamodb::types::{
 };
 use chrono::{DateTime, Utc};
 use serde_dynamo::aws_sdk_dynamodb_1::from_item;
-use serde_dynamo::{from_items, to_item};
+use serde_dynamo::{from_items, to_attribute_value, to_item};
 use serde_enum_str::Serialize_enum_str;
 use std::collections::HashMap;
 
@@ -25,6 +26,32 @@ enum TableIndex {
     GSI1QuoteStatus,
     #[serde(rename = "GSI2_QuoteIsPendingReview")]
     GSI2IsPendingReview,
+    #[serde(rename = "GSI3_QuoteIsPendingPayment")]
+    GSI3IsPendingPayment,
+}
+
+/// Quotations that were paid, or are being paid, can't be modified nor deleted.
+const LOCKED_STATUS_CONDITION: &str = "NOT begins_with(gsi1_sk, :payedStatus) AND NOT begins_with(gsi1_sk, :paymentProcessingStatus) AND NOT begins_with(gsi1_sk, :refundedStatus) AND NOT begins_with(gsi1_sk, :cancelledStatus)";
+
+fn locked_status_attribute_values() -> HashMap<String, AttributeValue> {
+    HashMap::from([
+        (
+            String::from(":payedStatus"),
+            AttributeValue::S(QuoteStatus::Payed.to_string()),
+        ),
+        (
+            String::from(":paymentProcessingStatus"),
+            AttributeValue::S(QuoteStatus::PaymentProcessing.to_string()),
+        ),
+        (
+            String::from(":refundedStatus"),
+            AttributeValue::S(QuoteStatus::Refunded.to_string()),
+        ),
+        (
+            String::from(":cancelledStatus"),
+            AttributeValue::S(QuoteStatus::Cancelled.to_string()),
+        ),
+    ])
 }
 
 #[derive(Clone)]
@@ -70,11 +97,8 @@ impl QuotesRepository for DynamodbQuotes {
             .table_name(&self.table)
             .key("pk", AttributeValue::S(customer_id))
             .key("sk", AttributeValue::S(quotation_id))
-            .condition_expression("NOT begins_with(gsi1_sk, :payedStatus)")
-            .set_expression_attribute_values(Some(HashMap::from([(
-                String::from(":payedStatus"),
-                AttributeValue::S(QuoteStatus::Payed.to_string()),
-            )])))
+            .condition_expression(LOCKED_STATUS_CONDITION)
+            .set_expression_attribute_values(Some(locked_status_attribute_values()))
             .send()
             .await;
 
@@ -155,6 +179,7 @@ impl QuotesRepository for DynamodbQuotes {
                     }
                 }
                 QueryBy::IsPendingReview => self.is_pending_review_query(),
+                QueryBy::IsPendingPayment => self.is_pending_payment_query(),
             }
         };
 
@@ -211,15 +236,13 @@ impl QuotesRepository for DynamodbQuotes {
                 String::from(":updated_at"),
                 AttributeValue::S(Utc::now().to_rfc3339()),
             ),
-            (
-                String::from(":payedStatus"),
-                AttributeValue::S(QuoteStatus::Payed.to_string()),
-            ),
             (String::from(":gsi1_sk"), AttributeValue::S(gsi1_sk)),
         ]
         .into_iter()
+        .chain(locked_status_attribute_values())
         .collect();
 
+        let mut remove_attributes = Vec::new();
         if status == QuoteStatus::PendingReview {
             update_expression.push_str(", gsi2_pk = :is_pending_review");
             expression_attribute_values.insert(
@@ -227,8 +250,18 @@ impl QuotesRepository for DynamodbQuotes {
                 AttributeValue::S(String::from("true")),
             );
         } else {
-            update_expression.push_str(" REMOVE gsi2_pk");
+            remove_attributes.push("gsi2_pk");
+        }
+        if status == QuoteStatus::PendingPayment {
+            update_expression.push_str(", gsi3_pk = :is_pending_payment");
+            expression_attribute_values.insert(
+                String::from(":is_pending_payment"),
+                AttributeValue::S(String::from("true")),
+            );
+        } else {
+            remove_attributes.push("gsi3_pk");
         }
+        update_expression.push_str(&format!(" REMOVE {}", remove_attributes.join(", ")));
 
         let response = self
             .client
@@ -236,7 +269,72 @@ impl QuotesRepository for DynamodbQuotes {
             .table_name(&self.table)
             .key("pk", AttributeValue::S(customer_id))
             .key("sk", AttributeValue::S(quotation_id))
-            .condition_expression("NOT begins_with(gsi1_sk, :payedStatus)")
+            .condition_expression(LOCKED_STATUS_CONDITION)
+            .update_expression(update_expression)
+            .set_expression_attribute_values(Some(expression_attribute_values))
+            .return_values(ReturnValue::AllNew)
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.attributes {
+                Some(item) => match from_item::<DynamodbQuote>(item) {
+                    Ok(dynamodb_quotation) => dynamodb_quotation.try_into(),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    UpdateItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::QuoteIsInPayedStatus)
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+
+    async fn update_promotion(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        promotion: Option<AppliedPromotion>,
+    ) -> Result<Quotation> {
+        let mut expression_attribute_values: HashMap<String, AttributeValue> = [(
+            String::from(":updated_at"),
+            AttributeValue::S(Utc::now().to_rfc3339()),
+        )]
+        .into_iter()
+        .chain(locked_status_attribute_values())
+        .collect();
+        let update_expression = match promotion {
+            Some(promotion) => {
+                expression_attribute_values.insert(
+                    String::from(":promotion"),
+                    to_attribute_value(promotion).expect("error converting to dynamodb item"),
+                );
+                "SET promotion = :promotion, updated_at = :updated_at"
+            }
+            None => "SET updated_at = :updated_at REMOVE promotion",
+        };
+
+        let response = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key("pk", AttributeValue::S(customer_id))
+            .key("sk", AttributeValue::S(quotation_id))
+            .condition_expression(format!(
+                "attribute_exists(pk) AND {LOCKED_STATUS_CONDITION}"
+            ))
             .update_expression(update_expression)
             .set_expression_attribute_values(Some(expression_attribute_values))
             .return_values(ReturnValue::AllNew)
@@ -321,6 +419,27 @@ impl QuotesRepository for DynamodbQuotes {
             "{new_status}{ATTRIBUTES_SEPARATOR}{project_id}{ATTRIBUTES_SEPARATOR}{quote_id}",
         );
 
+        let mut expression_attribute_values = HashMap::from([
+            (String::from(":gsi1_sk"), AttributeValue::S(gsi1_sk)),
+            (
+                String::from(":old_status"),
+                AttributeValue::S(old_status.to_string()),
+            ),
+            (
+                String::from(":updated_at"),
+                AttributeValue::S(chrono::Utc::now().to_rfc3339()),
+            ),
+        ]);
+        let update_expression = if new_status == QuoteStatus::PendingPayment {
+            expression_attribute_values.insert(
+                String::from(":is_pending_payment"),
+                AttributeValue::S(String::from("true")),
+            );
+            "SET gsi1_sk = :gsi1_sk, updated_at = :updated_at, gsi3_pk = :is_pending_payment REMOVE gsi2_pk"
+        } else {
+            "SET gsi1_sk = :gsi1_sk, updated_at = :updated_at REMOVE gsi2_pk, gsi3_pk"
+        };
+
         TransactWriteItem::builder()
             .update(
                 Update::builder()
@@ -330,20 +449,8 @@ impl QuotesRepository for DynamodbQuotes {
                         (String::from("sk"), AttributeValue::S(quote_id)),
                     ])))
                     .condition_expression("begins_with(gsi1_sk, :old_status)")
-                    .update_expression(
-                        "SET gsi1_sk = :gsi1_sk, updated_at = :updated_at REMOVE gsi2_pk",
-                    )
-                    .set_expression_attribute_values(Some(HashMap::from([
-                        (String::from(":gsi1_sk"), AttributeValue::S(gsi1_sk)),
-                        (
-                            String::from(":old_status"),
-                            AttributeValue::S(old_status.to_string()),
-                        ),
-                        (
-                            String::from(":updated_at"),
-                            AttributeValue::S(chrono::Utc::now().to_rfc3339()),
-                        ),
-                    ])))
+                    .update_expression(update_expression)
+                    .set_expression_attribute_values(Some(expression_attribute_values))
                     .build()
                     .unwrap(),
             )
@@ -421,4 +528,15 @@ impl DynamodbQuotes {
                 AttributeValue::S(String::from("true")),
             )
     }
+
+    fn is_pending_payment_query(&self) -> QueryFluentBuilder {
+        self.client
+            .query()
+            .index_name(TableIndex::GSI3IsPendingPayment.to_string())
+            .key_condition_expression("gsi3_pk = :isPendingPayment")
+            .expression_attribute_values(
+                ":isPendingPayment",
+                AttributeValue::S(String::from("true")),
+            )
+    }
 }
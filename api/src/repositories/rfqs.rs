@@ -0,0 +1,71 @@
+use crate::shared::{CustomerId, ProjectId, QuoteId, Result, RfqId, SupplierId};
+use crate::suppliers::models::dynamodb_requests::UpdatableRfq;
+use crate::suppliers::models::rfq::{Rfq, RfqBid, RfqPart, RfqStatus};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use iso_currency::Currency;
+use serde_derive::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait RfqsRepository: Send + Sync + 'static {
+    async fn create(&self, rfq: Rfq) -> Result<()>;
+    async fn get(&self, quotation_id: QuoteId, rfq_id: RfqId) -> Result<Rfq>;
+    /// Every RFQ sent for a quotation, oldest first.
+    async fn query(&self, quotation_id: QuoteId) -> Result<Vec<Rfq>>;
+    async fn update(&self, updatable_rfq: UpdatableRfq) -> Result<Rfq>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbRfq {
+    pub pk: QuoteId,
+    pub sk: RfqId,
+    pub customer_id: CustomerId,
+    pub project_id: ProjectId,
+    pub supplier_id: SupplierId,
+    pub status: RfqStatus,
+    pub currency: Currency,
+    pub parts: Vec<RfqPart>,
+    #[serde(default)]
+    pub bids: Vec<RfqBid>,
+    pub access_token: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DynamodbRfq> for Rfq {
+    fn from(value: DynamodbRfq) -> Self {
+        Self {
+            id: value.sk,
+            customer_id: value.customer_id,
+            project_id: value.project_id,
+            quotation_id: value.pk,
+            supplier_id: value.supplier_id,
+            status: value.status,
+            currency: value.currency,
+            parts: value.parts,
+            bids: value.bids,
+            access_token: value.access_token,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<Rfq> for DynamodbRfq {
+    fn from(value: Rfq) -> Self {
+        Self {
+            pk: value.quotation_id,
+            sk: value.id,
+            customer_id: value.customer_id,
+            project_id: value.project_id,
+            supplier_id: value.supplier_id,
+            status: value.status,
+            currency: value.currency,
+            parts: value.parts,
+            bids: value.bids,
+            access_token: value.access_token,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
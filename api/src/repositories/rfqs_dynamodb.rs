@@ -0,0 +1,194 @@
+use crate::repositories::rfqs::{DynamodbRfq, RfqsRepository};
+use crate::shared::error::Error;
+use crate::shared::{QuoteId, Result, RfqId};
+use crate::suppliers::models::dynamodb_requests::UpdatableRfq;
+use crate::suppliers::models::rfq::{Rfq, RfqStatus};
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::update_item::UpdateItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use chrono::Utc;
+use serde_dynamo::aws_sdk_dynamodb_1::{from_item, from_items};
+use serde_dynamo::to_item;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct DynamodbRfqs {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbRfqs {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl RfqsRepository for DynamodbRfqs {
+    async fn create(&self, rfq: Rfq) -> Result<()> {
+        let item = to_item(DynamodbRfq::from(rfq)).expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(item))
+            .condition_expression("attribute_not_exists(pk) AND attribute_not_exists(sk)")
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn get(&self, quotation_id: QuoteId, rfq_id: RfqId) -> Result<Rfq> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(quotation_id))
+            .key(String::from("sk"), AttributeValue::S(rfq_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbRfq>(item) {
+                    Ok(dynamodb_rfq) => Ok(dynamodb_rfq.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    /// A quotation is sent to a handful of suppliers, so a single page holds all of them.
+    async fn query(&self, quotation_id: QuoteId) -> Result<Vec<Rfq>> {
+        let response = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .key_condition_expression("pk = :quotation_id")
+            .expression_attribute_values(":quotation_id", AttributeValue::S(quotation_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => {
+                let items = output.items().to_vec();
+                match from_items::<DynamodbRfq>(items) {
+                    Ok(dynamodb_rfqs) => Ok(dynamodb_rfqs.into_iter().map(Rfq::from).collect()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn update(&self, updatable_rfq: UpdatableRfq) -> Result<Rfq> {
+        let mut update_expression = String::from("SET updated_at = :updated_at");
+        let mut expression_attribute_values: HashMap<String, AttributeValue> = [(
+            String::from(":updated_at"),
+            AttributeValue::S(Utc::now().to_rfc3339()),
+        )]
+        .into_iter()
+        .collect();
+        let mut condition_expression = String::from("attribute_exists(pk)");
+        // `status` is a reserved word, so it's referenced through a placeholder.
+        let expression_attribute_names = (updatable_rfq.status.is_some()
+            || updatable_rfq.bids.is_some())
+        .then(|| HashMap::from([(String::from("#status"), String::from("status"))]));
+
+        if let Some(status) = updatable_rfq.status {
+            update_expression.push_str(", #status = :status");
+            expression_attribute_values.insert(
+                String::from(":status"),
+                AttributeValue::S(status.to_string()),
+            );
+        }
+
+        // Bids that arrive after the quotation was priced are rejected.
+        let updates_bids = updatable_rfq.bids.is_some();
+        if let Some(bids) = updatable_rfq.bids {
+            condition_expression.push_str(" AND #status IN (:open, :bid)");
+            expression_attribute_values.insert(
+                String::from(":open"),
+                AttributeValue::S(RfqStatus::Open.to_string()),
+            );
+            expression_attribute_values.insert(
+                String::from(":bid"),
+                AttributeValue::S(RfqStatus::Bid.to_string()),
+            );
+
+            update_expression.push_str(", bids = :bids");
+            expression_attribute_values.insert(
+                String::from(":bids"),
+                AttributeValue::L(
+                    bids.into_iter()
+                        .map(|bid| AttributeValue::M(to_item(bid).unwrap()))
+                        .collect(),
+                ),
+            );
+        }
+
+        let rfq_id = updatable_rfq.id.clone();
+        let response = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key("pk", AttributeValue::S(updatable_rfq.quotation_id))
+            .key("sk", AttributeValue::S(updatable_rfq.id))
+            .condition_expression(condition_expression)
+            .update_expression(update_expression)
+            .set_expression_attribute_names(expression_attribute_names)
+            .set_expression_attribute_values(Some(expression_attribute_values))
+            .return_values(ReturnValue::AllNew)
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.attributes {
+                Some(item) => match from_item::<DynamodbRfq>(item) {
+                    Ok(dynamodb_rfq) => Ok(dynamodb_rfq.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    UpdateItemError::ConditionalCheckFailedException(_) if updates_bids => {
+                        Err(Error::RfqClosed(rfq_id))
+                    }
+                    UpdateItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::ItemNotFoundError)
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+}
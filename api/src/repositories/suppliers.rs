@@ -0,0 +1,67 @@
+use crate::auth::models::session::IdentityId;
+use crate::shared::{QueryResponse, Result, SupplierId};
+use crate::suppliers::models::dynamodb_requests::UpdatableSupplier;
+use crate::suppliers::models::supplier::Supplier;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait SuppliersRepository: Send + Sync + 'static {
+    async fn create(&self, supplier: Supplier) -> Result<()>;
+    async fn get(&self, supplier_id: SupplierId) -> Result<Supplier>;
+    async fn update(&self, updatable_supplier: UpdatableSupplier) -> Result<Supplier>;
+    async fn query(
+        &self,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<Supplier>, String>>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbSupplier {
+    pub pk: SupplierId,
+    pub name: String,
+    pub email: String,
+    pub country: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stripe_account_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_id: Option<IdentityId>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invitation_token: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DynamodbSupplier> for Supplier {
+    fn from(value: DynamodbSupplier) -> Self {
+        Self {
+            id: value.pk,
+            name: value.name,
+            email: value.email,
+            country: value.country,
+            stripe_account_id: value.stripe_account_id,
+            identity_id: value.identity_id,
+            invitation_token: value.invitation_token,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<Supplier> for DynamodbSupplier {
+    fn from(value: Supplier) -> Self {
+        Self {
+            pk: value.id,
+            name: value.name,
+            email: value.email,
+            country: value.country,
+            stripe_account_id: value.stripe_account_id,
+            identity_id: value.identity_id,
+            invitation_token: value.invitation_token,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
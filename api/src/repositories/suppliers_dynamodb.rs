@@ -0,0 +1,180 @@
+use crate::repositories::suppliers::{DynamodbSupplier, SuppliersRepository};
+use crate::shared::error::Error;
+use crate::shared::{QueryResponse, Result, SupplierId};
+use crate::suppliers::models::dynamodb_requests::UpdatableSupplier;
+use crate::suppliers::models::supplier::Supplier;
+use crate::utils::dynamodb_key_codec::DynamodbKeyCodec;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::update_item::UpdateItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use chrono::Utc;
+use serde_dynamo::aws_sdk_dynamodb_1::{from_item, from_items};
+use serde_dynamo::to_item;
+use std::collections::HashMap;
+
+#[derive(Clone)]
+pub struct DynamodbSuppliers {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbSuppliers {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl SuppliersRepository for DynamodbSuppliers {
+    async fn create(&self, supplier: Supplier) -> Result<()> {
+        let item =
+            to_item(DynamodbSupplier::from(supplier)).expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(item))
+            .condition_expression("attribute_not_exists(pk)")
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn get(&self, supplier_id: SupplierId) -> Result<Supplier> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(supplier_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbSupplier>(item) {
+                    Ok(dynamodb_supplier) => Ok(dynamodb_supplier.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn update(&self, updatable_supplier: UpdatableSupplier) -> Result<Supplier> {
+        let mut update_expression = String::from("SET updated_at = :updated_at");
+        let mut expression_attribute_values: HashMap<String, AttributeValue> = [(
+            String::from(":updated_at"),
+            AttributeValue::S(Utc::now().to_rfc3339()),
+        )]
+        .into_iter()
+        .collect();
+        let mut condition_expression = String::from("attribute_exists(pk)");
+
+        if let Some(invitation_token) = updatable_supplier.invitation_token {
+            update_expression.push_str(", invitation_token = :invitation_token");
+            expression_attribute_values.insert(
+                String::from(":invitation_token"),
+                AttributeValue::S(invitation_token),
+            );
+        }
+
+        // The invitation can only be used by one identity.
+        let links_identity = updatable_supplier.identity_id.is_some();
+        if let Some(identity_id) = updatable_supplier.identity_id {
+            condition_expression.push_str(" AND attribute_not_exists(identity_id)");
+            update_expression.push_str(", identity_id = :identity_id REMOVE invitation_token");
+            expression_attribute_values
+                .insert(String::from(":identity_id"), AttributeValue::S(identity_id));
+        }
+
+        let response = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key("pk", AttributeValue::S(updatable_supplier.id))
+            .condition_expression(condition_expression)
+            .update_expression(update_expression)
+            .set_expression_attribute_values(Some(expression_attribute_values))
+            .return_values(ReturnValue::AllNew)
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.attributes {
+                Some(item) => match from_item::<DynamodbSupplier>(item) {
+                    Ok(dynamodb_supplier) => Ok(dynamodb_supplier.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    UpdateItemError::ConditionalCheckFailedException(_) if links_identity => {
+                        Err(Error::Forbidden)
+                    }
+                    UpdateItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::ItemNotFoundError)
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Suppliers are few, so they're scanned instead of kept in an index.
+    async fn query(
+        &self,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<Supplier>, String>> {
+        let response = self
+            .client
+            .scan()
+            .table_name(&self.table)
+            .limit(limit)
+            .set_exclusive_start_key(DynamodbKeyCodec::decode_from_base64(cursor))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => {
+                let items = output.items().to_vec();
+                match from_items::<DynamodbSupplier>(items) {
+                    Ok(dynamodb_suppliers) => Ok(QueryResponse {
+                        data: dynamodb_suppliers.into_iter().map(Supplier::from).collect(),
+                        cursor: DynamodbKeyCodec::encode_to_base64(output.last_evaluated_key()),
+                    }),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+}
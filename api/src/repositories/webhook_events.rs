@@ -0,0 +1,81 @@
+use crate::payments::models::webhook_event::{WebhookEvent, WebhookEventStatus};
+use crate::shared::{QueryResponse, Result, WebhookEventId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+pub const ATTRIBUTES_SEPARATOR: &str = "&";
+
+#[async_trait]
+pub trait WebhookEventsRepository: Send + Sync + 'static {
+    type TransactionItem;
+    async fn get(&self, event_id: WebhookEventId) -> Result<WebhookEvent>;
+    /// Records a failed event ONLY if it hasn't been processed already, otherwise fails with
+    /// `WebhookEventAlreadyProcessed`.
+    async fn save_failed(&self, event: WebhookEvent) -> Result<()>;
+    /// Query failed events, newest first.
+    async fn query_failed(
+        &self,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<WebhookEvent>, String>>;
+    /// Records a processed event ONLY if it hasn't been processed already, failing the whole
+    /// transaction on replays.
+    fn transaction_create_processed(&self, event: WebhookEvent) -> Self::TransactionItem;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbWebhookEvent {
+    pub pk: WebhookEventId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// is_failed
+    pub gsi1_pk: Option<String>,
+    /// created_at&event_id
+    pub gsi1_sk: String,
+    pub event_type: String,
+    pub status: WebhookEventStatus,
+    pub payload: String,
+    pub error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DynamodbWebhookEvent> for WebhookEvent {
+    fn from(value: DynamodbWebhookEvent) -> Self {
+        Self {
+            id: value.pk,
+            event_type: value.event_type,
+            status: value.status,
+            payload: value.payload,
+            error: value.error,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<WebhookEvent> for DynamodbWebhookEvent {
+    fn from(value: WebhookEvent) -> Self {
+        let gsi1_pk = if value.status == WebhookEventStatus::Failed {
+            Some(String::from("true"))
+        } else {
+            None
+        };
+
+        Self {
+            gsi1_pk,
+            gsi1_sk: format!(
+                "{}{ATTRIBUTES_SEPARATOR}{}",
+                value.created_at.to_rfc3339(),
+                value.id
+            ),
+            pk: value.id,
+            event_type: value.event_type,
+            status: value.status,
+            payload: value.payload,
+            error: value.error,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
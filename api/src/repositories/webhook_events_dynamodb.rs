@@ -0,0 +1,159 @@
+use crate::payments::models::webhook_event::{WebhookEvent, WebhookEventStatus};
+use crate::repositories::webhook_events::{DynamodbWebhookEvent, WebhookEventsRepository};
+use crate::shared::error::Error;
+use crate::shared::{QueryResponse, Result, WebhookEventId};
+use crate::utils::dynamodb_key_codec::DynamodbKeyCodec;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::put_item::PutItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem};
+use serde_dynamo::aws_sdk_dynamodb_1::from_item;
+use serde_dynamo::{from_items, to_item};
+use serde_enum_str::Serialize_enum_str;
+
+/// Processed events are final, replays can only overwrite failed ones.
+const NOT_PROCESSED_CONDITION: &str = "attribute_not_exists(pk) OR #status <> :processed";
+
+#[derive(Serialize_enum_str)]
+enum TableIndex {
+    #[serde(rename = "GSI1_WebhookEventIsFailed")]
+    GSI1IsFailed,
+}
+
+#[derive(Clone)]
+pub struct DynamodbWebhookEvents {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbWebhookEvents {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl WebhookEventsRepository for DynamodbWebhookEvents {
+    type TransactionItem = TransactWriteItem;
+
+    async fn get(&self, event_id: WebhookEventId) -> Result<WebhookEvent> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(event_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbWebhookEvent>(item) {
+                    Ok(dynamodb_event) => Ok(dynamodb_event.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn save_failed(&self, event: WebhookEvent) -> Result<()> {
+        let event_id = event.id.clone();
+        let item =
+            to_item(DynamodbWebhookEvent::from(event)).expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(item))
+            .condition_expression(NOT_PROCESSED_CONDITION)
+            .expression_attribute_names("#status", "status")
+            .expression_attribute_values(
+                ":processed",
+                AttributeValue::S(WebhookEventStatus::Processed.to_string()),
+            )
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    PutItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::WebhookEventAlreadyProcessed(event_id))
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+
+    async fn query_failed(
+        &self,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<WebhookEvent>, String>> {
+        let response = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .index_name(TableIndex::GSI1IsFailed.to_string())
+            .key_condition_expression("gsi1_pk = :isFailed")
+            .expression_attribute_values(":isFailed", AttributeValue::S(String::from("true")))
+            .scan_index_forward(false)
+            .limit(limit)
+            .set_exclusive_start_key(DynamodbKeyCodec::decode_from_base64(cursor))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => {
+                let items = output.items().to_vec();
+                match from_items::<_, DynamodbWebhookEvent>(items) {
+                    Ok(dynamodb_events) => Ok(QueryResponse {
+                        data: dynamodb_events.into_iter().map(Into::into).collect(),
+                        cursor: DynamodbKeyCodec::encode_to_base64(output.last_evaluated_key()),
+                    }),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    fn transaction_create_processed(&self, event: WebhookEvent) -> TransactWriteItem {
+        let item = to_item(DynamodbWebhookEvent::from(event.processed()))
+            .expect("error converting to dynamodb item");
+
+        TransactWriteItem::builder()
+            .put(
+                Put::builder()
+                    .table_name(&self.table)
+                    .set_item(Some(item))
+                    .condition_expression(NOT_PROCESSED_CONDITION)
+                    .expression_attribute_names("#status", "status")
+                    .expression_attribute_values(
+                        ":processed",
+                        AttributeValue::S(WebhookEventStatus::Processed.to_string()),
+                    )
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+    }
+}
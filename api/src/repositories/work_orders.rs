@@ -0,0 +1,77 @@
+use crate::orders::models::dynamodb_requests::UpdatableWorkOrder;
+use crate::orders::models::work_order::{Operation, WorkOrder};
+use crate::parts::models::part::PartProcess;
+use crate::repositories::orders::ATTRIBUTES_SEPARATOR;
+use crate::shared::{CustomerId, OrderId, PartId, QueryResponse, Result, WorkOrderId};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+#[async_trait]
+pub trait WorkOrdersRepository: Send + Sync + 'static {
+    async fn create(&self, work_order: WorkOrder) -> Result<()>;
+    async fn get(&self, customer_id: CustomerId, order_id: OrderId) -> Result<WorkOrder>;
+    /// Work orders with operations left to do, newest first.
+    async fn query_open(
+        &self,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<WorkOrder>, String>>;
+    async fn update(&self, updatable_work_order: UpdatableWorkOrder) -> Result<WorkOrder>;
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct DynamodbWorkOrder {
+    pub pk: CustomerId,
+    pub sk: OrderId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// is_open
+    pub gsi1_pk: Option<String>,
+    /// created_at&order_id
+    pub gsi1_sk: String,
+    pub id: WorkOrderId,
+    pub part_id: PartId,
+    pub process: PartProcess,
+    pub quantity: u64,
+    pub operations: Vec<Operation>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DynamodbWorkOrder> for WorkOrder {
+    fn from(value: DynamodbWorkOrder) -> Self {
+        Self {
+            id: value.id,
+            customer_id: value.pk,
+            order_id: value.sk,
+            part_id: value.part_id,
+            process: value.process,
+            quantity: value.quantity,
+            operations: value.operations,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
+impl From<WorkOrder> for DynamodbWorkOrder {
+    fn from(value: WorkOrder) -> Self {
+        Self {
+            gsi1_pk: (!value.is_completed()).then(|| String::from("true")),
+            gsi1_sk: format!(
+                "{}{ATTRIBUTES_SEPARATOR}{}",
+                value.created_at.to_rfc3339(),
+                value.order_id
+            ),
+            pk: value.customer_id,
+            sk: value.order_id,
+            id: value.id,
+            part_id: value.part_id,
+            process: value.process,
+            quantity: value.quantity,
+            operations: value.operations,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
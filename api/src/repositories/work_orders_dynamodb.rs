@@ -0,0 +1,213 @@
+use crate::orders::models::dynamodb_requests::UpdatableWorkOrder;
+use crate::orders::models::work_order::{OperationStatus, WorkOrder};
+use crate::repositories::work_orders::{DynamodbWorkOrder, WorkOrdersRepository};
+use crate::shared::error::Error;
+use crate::shared::{CustomerId, OrderId, QueryResponse, Result};
+use crate::utils::dynamodb_key_codec::DynamodbKeyCodec;
+use async_trait::async_trait;
+use aws_sdk_dynamodb::operation::put_item::PutItemError;
+use aws_sdk_dynamodb::operation::update_item::UpdateItemError;
+use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use chrono::Utc;
+use serde_dynamo::aws_sdk_dynamodb_1::{from_item, from_items};
+use serde_dynamo::{to_attribute_value, to_item};
+use serde_enum_str::Serialize_enum_str;
+use std::collections::HashMap;
+
+#[derive(Serialize_enum_str)]
+enum TableIndex {
+    /// Only work orders with operations left to do are in it.
+    #[serde(rename = "GSI1_WorkOrderIsOpen")]
+    GSI1IsOpen,
+}
+
+#[derive(Clone)]
+pub struct DynamodbWorkOrders {
+    client: aws_sdk_dynamodb::Client,
+    table: String,
+}
+
+impl DynamodbWorkOrders {
+    pub fn new(client: aws_sdk_dynamodb::Client, table: String) -> Self {
+        Self { client, table }
+    }
+}
+
+#[async_trait]
+impl WorkOrdersRepository for DynamodbWorkOrders {
+    async fn create(&self, work_order: WorkOrder) -> Result<()> {
+        let order_id = work_order.order_id.clone();
+        let item = to_item(DynamodbWorkOrder::from(work_order))
+            .expect("error converting to dynamodb item");
+        let response = self
+            .client
+            .put_item()
+            .table_name(&self.table)
+            .set_item(Some(item))
+            .condition_expression("attribute_not_exists(pk) AND attribute_not_exists(sk)")
+            .send()
+            .await;
+
+        match response {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    PutItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::WorkOrderAlreadyExists(order_id))
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+
+    async fn get(&self, customer_id: CustomerId, order_id: OrderId) -> Result<WorkOrder> {
+        let response = self
+            .client
+            .get_item()
+            .table_name(&self.table)
+            .key(String::from("pk"), AttributeValue::S(customer_id))
+            .key(String::from("sk"), AttributeValue::S(order_id))
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.item {
+                Some(item) => match from_item::<DynamodbWorkOrder>(item) {
+                    Ok(dynamodb_work_order) => Ok(dynamodb_work_order.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn query_open(
+        &self,
+        cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<WorkOrder>, String>> {
+        let response = self
+            .client
+            .query()
+            .table_name(&self.table)
+            .index_name(TableIndex::GSI1IsOpen.to_string())
+            .key_condition_expression("gsi1_pk = :is_open")
+            .expression_attribute_values(":is_open", AttributeValue::S(String::from("true")))
+            .limit(limit)
+            .set_exclusive_start_key(DynamodbKeyCodec::decode_from_base64(cursor))
+            .scan_index_forward(false)
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => {
+                let items = output.items().to_vec();
+                match from_items::<DynamodbWorkOrder>(items) {
+                    Ok(dynamodb_work_orders) => Ok(QueryResponse {
+                        data: dynamodb_work_orders
+                            .into_iter()
+                            .map(WorkOrder::from)
+                            .collect(),
+                        cursor: DynamodbKeyCodec::encode_to_base64(output.last_evaluated_key()),
+                    }),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn update(&self, updatable_work_order: UpdatableWorkOrder) -> Result<WorkOrder> {
+        let is_completed = updatable_work_order
+            .operations
+            .iter()
+            .all(|operation| operation.status == OperationStatus::Completed);
+        let mut update_expression =
+            String::from("SET updated_at = :updated_at, operations = :operations");
+        let mut expression_attribute_values: HashMap<String, AttributeValue> = [
+            (
+                String::from(":updated_at"),
+                to_attribute_value(Utc::now()).unwrap(),
+            ),
+            (
+                String::from(":read_updated_at"),
+                to_attribute_value(updatable_work_order.read_updated_at).unwrap(),
+            ),
+            (
+                String::from(":operations"),
+                to_attribute_value(updatable_work_order.operations).unwrap(),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        // Completed work orders leave the shop-floor queue.
+        if is_completed {
+            update_expression.push_str(" REMOVE gsi1_pk");
+        } else {
+            update_expression.push_str(", gsi1_pk = :is_open");
+            expression_attribute_values.insert(
+                String::from(":is_open"),
+                AttributeValue::S(String::from("true")),
+            );
+        }
+
+        let work_order_id = updatable_work_order.id;
+        let response = self
+            .client
+            .update_item()
+            .table_name(&self.table)
+            .key("pk", AttributeValue::S(updatable_work_order.customer_id))
+            .key("sk", AttributeValue::S(updatable_work_order.order_id))
+            // Operators updating the same work order at once would overwrite each other.
+            .condition_expression("attribute_exists(pk) AND updated_at = :read_updated_at")
+            .update_expression(update_expression)
+            .set_expression_attribute_values(Some(expression_attribute_values))
+            .return_values(ReturnValue::AllNew)
+            .send()
+            .await;
+
+        match response {
+            Ok(output) => match output.attributes {
+                Some(item) => match from_item::<DynamodbWorkOrder>(item) {
+                    Ok(dynamodb_work_order) => Ok(dynamodb_work_order.into()),
+                    Err(err) => {
+                        tracing::error!("{err:?}");
+                        Err(Error::UnknownError)
+                    }
+                },
+                None => Err(Error::ItemNotFoundError),
+            },
+            Err(err) => {
+                let err_str = format!("{err:?}");
+                match err.into_service_error() {
+                    UpdateItemError::ConditionalCheckFailedException(_) => {
+                        Err(Error::WorkOrderChanged(work_order_id))
+                    }
+                    _ => {
+                        tracing::error!("{err_str}");
+                        Err(Error::UnknownError)
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::orders::models::inspection_report::InspectionReport;
+use crate::orders::models::order::Order;
+use crate::parts::models::part::Part;
+use crate::shared::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait CertificateRenderer: Send + Sync + 'static {
+    /// Renders the certificate of conformance of an order whose parts passed `inspection_report`.
+    async fn render_certificate_of_conformance(
+        &self,
+        order: &Order,
+        part: &Part,
+        inspection_report: &InspectionReport,
+    ) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,229 @@
+use crate::config::ConfigQuotesDocument;
+use crate::orders::models::inspection_report::{InspectionReport, Measurement};
+use crate::orders::models::order::Order;
+use crate::parts::models::part::Part;
+use crate::services::certificate_renderer::CertificateRenderer;
+use crate::shared::Result;
+use crate::utils::pdf_canvas::{
+    truncate, wrap, Font, PdfCanvas, CELL_PADDING, CONTENT_RIGHT, DATE_FORMAT, MARGIN,
+};
+use async_trait::async_trait;
+
+/// The feature column starts at the left margin, the other columns are aligned to their right
+/// edge.
+const FEATURE_COLUMN_WIDTH: f32 = 200.0;
+const NOMINAL_COLUMN_RIGHT: f32 = 330.0;
+const TOLERANCE_COLUMN_RIGHT: f32 = 420.0;
+const MEASURED_COLUMN_RIGHT: f32 = 490.0;
+const RESULT_COLUMN_RIGHT: f32 = CONTENT_RIGHT - CELL_PADDING;
+
+const CONFORMANCE_STATEMENT: &str = "We certify that the parts listed above were manufactured \
+    and inspected in accordance with the requirements of the order and conform to the drawings \
+    and specifications provided.";
+
+/// Renders certificates of conformance as PDF documents, with the same layout as the quotation
+/// documents.
+#[derive(Clone)]
+pub struct PdfCertificateRenderer {
+    document: ConfigQuotesDocument,
+}
+
+impl PdfCertificateRenderer {
+    pub fn new(document: ConfigQuotesDocument) -> Self {
+        Self { document }
+    }
+
+    fn draw(&self, order: &Order, part: &Part, inspection_report: &InspectionReport) -> Vec<u8> {
+        let mut canvas = PdfCanvas::new();
+        self.draw_header(&mut canvas, order, inspection_report);
+        Self::draw_part(&mut canvas, order, part);
+        Self::draw_measurements_header(&mut canvas);
+        for measurement in &inspection_report.measurements {
+            Self::draw_measurement(&mut canvas, measurement);
+        }
+        Self::draw_statement(&mut canvas, inspection_report);
+
+        canvas.finish(&format!(
+            "{} · Certificate of Conformance {}",
+            self.document.company_name, order.id
+        ))
+    }
+
+    fn draw_header(
+        &self,
+        canvas: &mut PdfCanvas,
+        order: &Order,
+        inspection_report: &InspectionReport,
+    ) {
+        let top = canvas.y;
+
+        canvas.text(
+            MARGIN,
+            top - 20.0,
+            Font::Bold,
+            22.0,
+            &self.document.company_name,
+        );
+        let mut issuer_y = top - 36.0;
+        for issuer_line in &self.document.company_address {
+            canvas.text(MARGIN, issuer_y, Font::Regular, 9.0, issuer_line);
+            issuer_y -= 12.0;
+        }
+
+        canvas.text_right(
+            CONTENT_RIGHT,
+            top - 20.0,
+            Font::Bold,
+            14.0,
+            "CERTIFICATE OF CONFORMANCE",
+        );
+        let details = [
+            format!("Order: {}", order.id),
+            format!(
+                "Issued: {}",
+                inspection_report.inspected_at.format(DATE_FORMAT)
+            ),
+            format!("Quotation: {}", order.quotation_id),
+        ];
+        let mut details_y = top - 38.0;
+        for detail in details {
+            canvas.text_right(CONTENT_RIGHT, details_y, Font::Regular, 9.0, &detail);
+            details_y -= 12.0;
+        }
+
+        canvas.y = issuer_y.min(details_y) - 16.0;
+    }
+
+    fn draw_part(canvas: &mut PdfCanvas, order: &Order, part: &Part) {
+        canvas.text(MARGIN, canvas.y, Font::Bold, 10.0, "Customer");
+        canvas.y -= 13.0;
+        canvas.text(
+            MARGIN,
+            canvas.y,
+            Font::Regular,
+            10.0,
+            &order.shipping_recipient_name,
+        );
+        canvas.y -= 24.0;
+
+        canvas.text(MARGIN, canvas.y, Font::Bold, 10.0, "Part");
+        canvas.y -= 13.0;
+        let name = truncate(
+            &part.model_file.name,
+            Font::Regular,
+            10.0,
+            CONTENT_RIGHT - MARGIN,
+        );
+        canvas.text(MARGIN, canvas.y, Font::Regular, 10.0, &name);
+        canvas.y -= 13.0;
+        canvas.text(
+            MARGIN,
+            canvas.y,
+            Font::Regular,
+            10.0,
+            &format!("Quantity: {}", part.quantity),
+        );
+        for description_line in wrap(
+            &part.attributes.to_string(),
+            Font::Regular,
+            9.0,
+            CONTENT_RIGHT - MARGIN,
+        ) {
+            canvas.y -= 12.0;
+            canvas.text(MARGIN, canvas.y, Font::Regular, 9.0, &description_line);
+        }
+        canvas.y -= 28.0;
+    }
+
+    fn draw_measurements_header(canvas: &mut PdfCanvas) {
+        let y = canvas.y;
+        canvas.fill_rect(MARGIN, y - 6.0, CONTENT_RIGHT - MARGIN, 20.0, 0.93);
+        canvas.text(MARGIN + CELL_PADDING, y, Font::Bold, 9.0, "Feature");
+        canvas.text_right(NOMINAL_COLUMN_RIGHT, y, Font::Bold, 9.0, "Nominal (mm)");
+        canvas.text_right(TOLERANCE_COLUMN_RIGHT, y, Font::Bold, 9.0, "Tolerance");
+        canvas.text_right(MEASURED_COLUMN_RIGHT, y, Font::Bold, 9.0, "Measured");
+        canvas.text_right(RESULT_COLUMN_RIGHT, y, Font::Bold, 9.0, "Result");
+        canvas.y -= 24.0;
+    }
+
+    fn draw_measurement(canvas: &mut PdfCanvas, measurement: &Measurement) {
+        if canvas.ensure_space(20.0) {
+            Self::draw_measurements_header(canvas);
+        }
+
+        let y = canvas.y;
+        let feature = truncate(
+            &measurement.feature,
+            Font::Regular,
+            9.0,
+            FEATURE_COLUMN_WIDTH,
+        );
+        canvas.text(MARGIN + CELL_PADDING, y, Font::Regular, 9.0, &feature);
+        let nominal = format!("{:.3}", measurement.nominal_mm);
+        canvas.text_right(NOMINAL_COLUMN_RIGHT, y, Font::Regular, 9.0, &nominal);
+        let tolerance = if measurement.upper_tolerance_mm == measurement.lower_tolerance_mm {
+            format!("+/- {:.3}", measurement.upper_tolerance_mm)
+        } else {
+            format!(
+                "+{:.3} / -{:.3}",
+                measurement.upper_tolerance_mm, measurement.lower_tolerance_mm
+            )
+        };
+        canvas.text_right(TOLERANCE_COLUMN_RIGHT, y, Font::Regular, 9.0, &tolerance);
+        let measured = format!("{:.3}", measurement.measured_mm);
+        canvas.text_right(MEASURED_COLUMN_RIGHT, y, Font::Regular, 9.0, &measured);
+        let result = if measurement.passed() { "PASS" } else { "FAIL" };
+        canvas.text_right(RESULT_COLUMN_RIGHT, y, Font::Bold, 9.0, result);
+
+        canvas.y -= 20.0;
+        canvas.rule(canvas.y + 10.0);
+    }
+
+    fn draw_statement(canvas: &mut PdfCanvas, inspection_report: &InspectionReport) {
+        canvas.y -= 12.0;
+        for statement_line in wrap(
+            CONFORMANCE_STATEMENT,
+            Font::Regular,
+            10.0,
+            CONTENT_RIGHT - MARGIN,
+        ) {
+            canvas.ensure_space(13.0);
+            canvas.text(MARGIN, canvas.y, Font::Regular, 10.0, &statement_line);
+            canvas.y -= 13.0;
+        }
+
+        canvas.ensure_space(40.0);
+        canvas.y -= 14.0;
+        canvas.text(
+            MARGIN,
+            canvas.y,
+            Font::Bold,
+            10.0,
+            &format!("Inspected by: {}", inspection_report.inspector_email),
+        );
+        canvas.y -= 13.0;
+        canvas.text(
+            MARGIN,
+            canvas.y,
+            Font::Regular,
+            10.0,
+            &format!(
+                "Date: {}",
+                inspection_report.inspected_at.format(DATE_FORMAT)
+            ),
+        );
+        canvas.y -= 13.0;
+    }
+}
+
+#[async_trait]
+impl CertificateRenderer for PdfCertificateRenderer {
+    async fn render_certificate_of_conformance(
+        &self,
+        order: &Order,
+        part: &Part,
+        inspection_report: &InspectionReport,
+    ) -> Result<Vec<u8>> {
+        Ok(self.draw(order, part, inspection_report))
+    }
+}
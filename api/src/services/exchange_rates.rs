@@ -0,0 +1,22 @@
+use crate::shared::money::Money;
+use crate::shared::Result;
+use iso_currency::Currency;
+
+pub trait ExchangeRates: Send + Sync + 'static {
+    /// How much one unit of `from` is worth in `to`.
+    fn rate(&self, from: Currency, to: Currency) -> Result<f64>;
+
+    /// Converts an amount to another currency, rounding to the nearest minor unit.
+    fn convert(&self, money: &Money, to: Currency) -> Result<Money> {
+        if money.currency == to {
+            return Ok(money.clone());
+        }
+
+        let rate = self.rate(money.currency, to)?;
+        let from_exponent = money.currency.exponent().unwrap_or_default() as i32;
+        let to_exponent = to.exponent().unwrap_or_default() as i32;
+        let amount = money.amount as f64 * rate * 10_f64.powi(to_exponent - from_exponent);
+
+        Ok(Money::new(amount.round() as i64, to))
+    }
+}
@@ -0,0 +1,38 @@
+use crate::config::ConfigExchangeRates;
+use crate::services::exchange_rates::ExchangeRates;
+use crate::shared::error::Error;
+use crate::shared::Result;
+use iso_currency::Currency;
+
+/// Exchange rates read from the config, updated on every deploy.
+pub struct FixedExchangeRates {
+    config: ConfigExchangeRates,
+}
+
+impl FixedExchangeRates {
+    pub fn new(config: ConfigExchangeRates) -> Self {
+        Self { config }
+    }
+
+    fn rate_from_base(&self, currency: Currency) -> Result<f64> {
+        if currency == self.config.base {
+            return Ok(1.0);
+        }
+
+        self.config
+            .rates
+            .get(&currency)
+            .copied()
+            .filter(|rate| *rate > 0.0)
+            .ok_or_else(|| {
+                tracing::error!("no exchange rate configured for {currency}");
+                Error::UnsupportedCurrency(currency)
+            })
+    }
+}
+
+impl ExchangeRates for FixedExchangeRates {
+    fn rate(&self, from: Currency, to: Currency) -> Result<f64> {
+        Ok(self.rate_from_base(to)? / self.rate_from_base(from)?)
+    }
+}
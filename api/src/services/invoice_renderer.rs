@@ -0,0 +1,8 @@
+use crate::billing::models::invoice::Invoice;
+use crate::shared::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait InvoiceRenderer: Send + Sync + 'static {
+    async fn render_invoice(&self, invoice: &Invoice) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,219 @@
+use crate::billing::models::invoice::{Invoice, InvoiceLine};
+use crate::config::{ConfigBilling, ConfigQuotesDocument};
+use crate::services::invoice_renderer::InvoiceRenderer;
+use crate::shared::money::Money;
+use crate::shared::Result;
+use crate::utils::pdf_canvas::{
+    truncate, wrap, Font, PdfCanvas, CELL_PADDING, CONTENT_RIGHT, DATE_FORMAT, MARGIN,
+};
+use async_trait::async_trait;
+
+/// Generic RFC and CFDI use of invoices issued to the general public.
+const GENERAL_PUBLIC_RFC: &str = "XAXX010101000";
+const GENERAL_PUBLIC_CFDI_USE: &str = "S01";
+
+/// The part column starts at the left margin, the other columns are aligned to their right edge.
+const PART_COLUMN_WIDTH: f32 = 300.0;
+const QUANTITY_COLUMN_RIGHT: f32 = 400.0;
+const UNIT_PRICE_COLUMN_RIGHT: f32 = 480.0;
+const AMOUNT_COLUMN_RIGHT: f32 = CONTENT_RIGHT - CELL_PADDING;
+
+/// Renders invoices as PDF documents, with the same layout as the quotation documents.
+#[derive(Clone)]
+pub struct PdfInvoiceRenderer {
+    document: ConfigQuotesDocument,
+    billing: ConfigBilling,
+}
+
+impl PdfInvoiceRenderer {
+    pub fn new(document: ConfigQuotesDocument, billing: ConfigBilling) -> Self {
+        Self { document, billing }
+    }
+
+    fn draw(&self, invoice: &Invoice) -> Vec<u8> {
+        let mut canvas = PdfCanvas::new();
+        self.draw_header(&mut canvas, invoice);
+        Self::draw_customer(&mut canvas, invoice);
+        Self::draw_lines_header(&mut canvas);
+        for line in &invoice.lines {
+            Self::draw_line(&mut canvas, line);
+        }
+        Self::draw_totals(&mut canvas, invoice);
+        Self::draw_notes(&mut canvas, invoice);
+
+        canvas.finish(&format!(
+            "{} · Invoice {}",
+            self.document.company_name, invoice.id
+        ))
+    }
+
+    fn draw_header(&self, canvas: &mut PdfCanvas, invoice: &Invoice) {
+        let top = canvas.y;
+
+        canvas.text(
+            MARGIN,
+            top - 20.0,
+            Font::Bold,
+            22.0,
+            &self.document.company_name,
+        );
+        let issuer = self.document.company_address.iter().cloned().chain([
+            format!("RFC: {}", self.billing.issuer_rfc),
+            format!("Tax regime: {}", self.billing.issuer_tax_regime),
+        ]);
+        let mut issuer_y = top - 36.0;
+        for issuer_line in issuer {
+            canvas.text(MARGIN, issuer_y, Font::Regular, 9.0, &issuer_line);
+            issuer_y -= 12.0;
+        }
+
+        canvas.text_right(CONTENT_RIGHT, top - 20.0, Font::Bold, 18.0, "INVOICE");
+        let details = [
+            format!("Number: {}", invoice.id),
+            format!("Issued: {}", invoice.created_at.format(DATE_FORMAT)),
+            format!("Quotation: {}", invoice.quotation_id),
+        ];
+        let mut details_y = top - 38.0;
+        for detail in details {
+            canvas.text_right(CONTENT_RIGHT, details_y, Font::Regular, 9.0, &detail);
+            details_y -= 12.0;
+        }
+
+        canvas.y = issuer_y.min(details_y) - 16.0;
+    }
+
+    fn draw_customer(canvas: &mut PdfCanvas, invoice: &Invoice) {
+        let customer = match &invoice.billing_profile {
+            Some(billing_profile) => [
+                billing_profile.legal_name.clone(),
+                format!("RFC: {}", billing_profile.rfc),
+                format!("Tax regime: {}", billing_profile.tax_regime),
+                format!("CFDI use: {}", billing_profile.cfdi_use),
+                format!("Postal code: {}", billing_profile.postal_code),
+            ],
+            None => [
+                String::from("Público en general"),
+                format!("RFC: {GENERAL_PUBLIC_RFC}"),
+                String::from("Tax regime: 616"),
+                format!("CFDI use: {GENERAL_PUBLIC_CFDI_USE}"),
+                String::new(),
+            ],
+        };
+
+        canvas.text(MARGIN, canvas.y, Font::Bold, 10.0, "Billed to");
+        for customer_line in customer.iter().filter(|line| !line.is_empty()) {
+            canvas.y -= 13.0;
+            canvas.text(MARGIN, canvas.y, Font::Regular, 10.0, customer_line);
+        }
+        canvas.y -= 28.0;
+    }
+
+    fn draw_lines_header(canvas: &mut PdfCanvas) {
+        let y = canvas.y;
+        canvas.fill_rect(MARGIN, y - 6.0, CONTENT_RIGHT - MARGIN, 20.0, 0.93);
+        canvas.text(MARGIN + CELL_PADDING, y, Font::Bold, 9.0, "Part");
+        canvas.text_right(QUANTITY_COLUMN_RIGHT, y, Font::Bold, 9.0, "Qty");
+        canvas.text_right(UNIT_PRICE_COLUMN_RIGHT, y, Font::Bold, 9.0, "Unit price");
+        canvas.text_right(AMOUNT_COLUMN_RIGHT, y, Font::Bold, 9.0, "Amount");
+        canvas.y -= 24.0;
+    }
+
+    fn draw_line(canvas: &mut PdfCanvas, line: &InvoiceLine) {
+        let description = wrap(&line.description, Font::Regular, 8.0, PART_COLUMN_WIDTH);
+        let height = 20.0 + description.len() as f32 * 10.0;
+        if canvas.ensure_space(height) {
+            Self::draw_lines_header(canvas);
+        }
+
+        let y = canvas.y;
+        let name = truncate(&line.name, Font::Bold, 9.0, PART_COLUMN_WIDTH);
+        canvas.text(MARGIN + CELL_PADDING, y, Font::Bold, 9.0, &name);
+        let quantity = line.quantity.to_string();
+        canvas.text_right(QUANTITY_COLUMN_RIGHT, y, Font::Regular, 9.0, &quantity);
+        let unit_price = line.unit_price.to_string();
+        canvas.text_right(UNIT_PRICE_COLUMN_RIGHT, y, Font::Regular, 9.0, &unit_price);
+        let amount = line.amount.to_string();
+        canvas.text_right(AMOUNT_COLUMN_RIGHT, y, Font::Regular, 9.0, &amount);
+
+        let mut description_y = y - 12.0;
+        for description_line in description {
+            canvas.text(
+                MARGIN + CELL_PADDING,
+                description_y,
+                Font::Regular,
+                8.0,
+                &description_line,
+            );
+            description_y -= 10.0;
+        }
+
+        canvas.y -= height;
+        canvas.rule(canvas.y + 10.0);
+    }
+
+    fn draw_totals(canvas: &mut PdfCanvas, invoice: &Invoice) {
+        canvas.ensure_space(60.0);
+        canvas.y -= 6.0;
+
+        // Discounts are printed as negative amounts, subtracted from the subtotal.
+        let discount = invoice.discount.as_ref().map(|discount| {
+            (
+                format!("Discount ({})", discount.code),
+                Money::new(-discount.amount.amount, discount.amount.currency),
+            )
+        });
+        let mut totals = vec![(String::from("Subtotal"), &invoice.subtotal, Font::Regular)];
+        if let Some((label, amount)) = &discount {
+            totals.push((label.clone(), amount, Font::Regular));
+        }
+        for tax_line in &invoice.tax_lines {
+            totals.push((
+                format!("{} ({}%)", tax_line.name, tax_line.percentage()),
+                &tax_line.amount,
+                Font::Regular,
+            ));
+        }
+        if let Some(shipping) = &invoice.shipping {
+            totals.push((
+                String::from(shipping.method.display_name()),
+                &shipping.cost,
+                Font::Regular,
+            ));
+        }
+        totals.push((
+            format!("Total ({})", invoice.total.currency.code()),
+            &invoice.total,
+            Font::Bold,
+        ));
+        for (label, amount, font) in totals {
+            canvas.text_right(UNIT_PRICE_COLUMN_RIGHT, canvas.y, font, 10.0, &label);
+            let amount = amount.to_string();
+            canvas.text_right(AMOUNT_COLUMN_RIGHT, canvas.y, font, 10.0, &amount);
+            canvas.y -= 16.0;
+        }
+        canvas.y -= 12.0;
+    }
+
+    fn draw_notes(canvas: &mut PdfCanvas, invoice: &Invoice) {
+        let mut notes = vec![String::from(
+            "This document is a receipt of the payment and is not a CFDI.",
+        )];
+        if let Some(payment_intent_id) = &invoice.payment_intent_id {
+            notes.push(format!("Payment reference: {payment_intent_id}."));
+        }
+        for note in notes {
+            for note_line in wrap(&note, Font::Regular, 8.0, CONTENT_RIGHT - MARGIN) {
+                canvas.ensure_space(10.0);
+                canvas.text(MARGIN, canvas.y, Font::Regular, 8.0, &note_line);
+                canvas.y -= 10.0;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl InvoiceRenderer for PdfInvoiceRenderer {
+    async fn render_invoice(&self, invoice: &Invoice) -> Result<Vec<u8>> {
+        Ok(self.draw(invoice))
+    }
+}
@@ -1,8 +1,24 @@
+pub mod certificate_renderer;
+pub mod certificate_renderer_pdf;
 pub mod emailer;
 pub mod emailer_ses;
+pub mod exchange_rates;
+pub mod exchange_rates_fixed;
 pub mod identity_manager;
 pub mod identity_manager_ory;
+pub mod invoice_renderer;
+pub mod invoice_renderer_pdf;
+pub mod model_converter;
+pub mod model_converter_freecad;
 pub mod object_storage;
 pub mod object_storage_s3;
+pub mod pricing_engine;
+pub mod pricing_engine_rate_table;
+pub mod quote_renderer;
+pub mod quote_renderer_pdf;
+pub mod shipping_calculator;
+pub mod shipping_calculator_rate_table;
 pub mod stripe;
 pub mod stripe_client;
+pub mod tax_calculator;
+pub mod tax_calculator_rate_table;
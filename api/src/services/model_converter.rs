@@ -0,0 +1,8 @@
+use crate::shared::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ModelConverter: Send + Sync + 'static {
+    /// Tessellates a CAD model (e.g., STEP) into a binary or ASCII STL mesh.
+    async fn convert_to_stl(&self, model: &[u8], extension: &str) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,91 @@
+use crate::services::model_converter::ModelConverter;
+use crate::shared::error::Error;
+use crate::shared::Result;
+use async_trait::async_trait;
+use std::path::{Path, PathBuf};
+use tokio::process::Command;
+use uuid::{ContextV7, Timestamp, Uuid};
+
+const FREECAD_COMMAND: &str = "FreeCADCmd";
+/// Maximum deviation, in millimeters, between the model surfaces and the generated mesh.
+const TESSELLATION_TOLERANCE_MM: f64 = 2.0;
+const CONVERSION_SCRIPT: &str = r#"
+import os
+import Mesh
+import Part
+
+shape = Part.Shape()
+shape.read(os.environ["MODEL_CONVERTER_INPUT"])
+mesh = Mesh.Mesh()
+mesh.addFacets(shape.tessellate(float(os.environ["MODEL_CONVERTER_TOLERANCE"])))
+mesh.write(os.environ["MODEL_CONVERTER_OUTPUT"])
+"#;
+
+/// Converts models by running FreeCAD headless, it must be installed where the converter runs.
+#[derive(Clone, Default)]
+pub struct FreeCadModelConverter;
+
+impl FreeCadModelConverter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    async fn convert_in(&self, directory: &Path, model: &[u8], extension: &str) -> Result<Vec<u8>> {
+        let input_path = directory.join(format!("model.{extension}"));
+        let output_path = directory.join("model.stl");
+        let script_path = directory.join("convert.py");
+
+        tokio::fs::write(&input_path, model)
+            .await
+            .map_err(Self::io_error)?;
+        tokio::fs::write(&script_path, CONVERSION_SCRIPT)
+            .await
+            .map_err(Self::io_error)?;
+
+        let output = Command::new(FREECAD_COMMAND)
+            .arg(&script_path)
+            .env("MODEL_CONVERTER_INPUT", &input_path)
+            .env("MODEL_CONVERTER_OUTPUT", &output_path)
+            .env(
+                "MODEL_CONVERTER_TOLERANCE",
+                TESSELLATION_TOLERANCE_MM.to_string(),
+            )
+            .output()
+            .await
+            .map_err(Self::io_error)?;
+
+        // FreeCAD exits successfully even when the script fails, so the output file is the only
+        // reliable signal.
+        match tokio::fs::read(&output_path).await {
+            Ok(stl) => Ok(stl),
+            Err(_) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                tracing::error!("{stderr}");
+                Err(Error::InvalidModelFile(format!(
+                    "the `{extension}` model couldn't be converted to STL"
+                )))
+            }
+        }
+    }
+
+    fn io_error(err: std::io::Error) -> Error {
+        tracing::error!("{err:?}");
+        Error::UnknownError
+    }
+}
+
+#[async_trait]
+impl ModelConverter for FreeCadModelConverter {
+    async fn convert_to_stl(&self, model: &[u8], extension: &str) -> Result<Vec<u8>> {
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let directory: PathBuf = std::env::temp_dir().join(id.to_string());
+        tokio::fs::create_dir_all(&directory)
+            .await
+            .map_err(Self::io_error)?;
+
+        let result = self.convert_in(&directory, model, extension).await;
+        let _ = tokio::fs::remove_dir_all(&directory).await;
+
+        result
+    }
+}
@@ -13,6 +13,8 @@ pub enum ObjectStorageOperation {
 pub trait ObjectStorage: Send + Sync + 'static {
     async fn put_object_presigned_url(&self, key: &str, expires_in: Duration) -> Result<String>;
     async fn get_object_presigned_url(&self, key: &str, expires_in: Duration) -> Result<String>;
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>>;
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Result<()>;
     async fn delete_object(&self, key: &str) -> Result<()>;
     async fn bulk_delete_objects(&self, keys: Vec<&str>) -> Result<()>;
 }
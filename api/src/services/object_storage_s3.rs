@@ -1,6 +1,7 @@
 use crate::shared::Result;
 use async_trait::async_trait;
 use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::ByteStream;
 use aws_sdk_s3::types::builders::DeleteBuilder;
 use aws_sdk_s3::types::ObjectIdentifier;
 use std::time::Duration;
@@ -52,6 +53,51 @@ impl ObjectStorage for S3ObjectStorage {
         }
     }
 
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>> {
+        let result = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await;
+
+        let output = match result {
+            Ok(output) => output,
+            Err(err) => {
+                tracing::error!("{err:?}");
+                return Err(Error::UnknownError);
+            }
+        };
+
+        match output.body.collect().await {
+            Ok(body) => Ok(body.into_bytes().to_vec()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Result<()> {
+        let result = self
+            .client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from(body))
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
     async fn delete_object(&self, key: &str) -> Result<()> {
         let result = self
             .client
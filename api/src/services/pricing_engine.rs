@@ -0,0 +1,15 @@
+use crate::parts::models::part::{Part, PartQuote};
+use crate::shared::PartId;
+use iso_currency::Currency;
+use std::collections::HashMap;
+
+pub trait PricingEngine: Send + Sync + 'static {
+    /// Quotes every part of a quotation at once. Returns `None` when any of the parts, or the
+    /// quotation as a whole, is too risky to price without a manual review. Part quotes are
+    /// priced in the currency of the quotation.
+    fn instant_quotes(
+        &self,
+        parts: &[Part],
+        currency: Currency,
+    ) -> Option<HashMap<PartId, Vec<PartQuote>>>;
+}
@@ -0,0 +1,146 @@
+use crate::config::ConfigPricing;
+use crate::parts::models::part::{Part, PartQuote};
+use crate::parts::models::part_attributes::{
+    CNCAttributes, InspectionLevel, PartAttributes, Tolerance,
+};
+use crate::services::exchange_rates::ExchangeRates;
+use crate::services::pricing_engine::PricingEngine;
+use crate::shared::money::Money;
+use crate::shared::PartId;
+use iso_currency::Currency;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+const MM3_PER_CM3: f64 = 1_000.0;
+const MM2_PER_CM2: f64 = 100.0;
+
+pub struct RateTablePricingEngine {
+    rates: ConfigPricing,
+    exchange_rates: Arc<dyn ExchangeRates>,
+}
+
+impl RateTablePricingEngine {
+    pub fn new(rates: ConfigPricing, exchange_rates: Arc<dyn ExchangeRates>) -> Self {
+        Self {
+            rates,
+            exchange_rates,
+        }
+    }
+
+    /// Part quotes are priced in MXN, like the rate tables.
+    fn quote_part(&self, part: &Part) -> Option<Vec<PartQuote>> {
+        let PartAttributes::CNC(attributes) = &part.attributes else {
+            return None;
+        };
+        if !Self::is_low_risk(part, attributes) {
+            return None;
+        }
+
+        let geometry = part.geometry.as_ref()?;
+        if geometry.bounding_box.max_dimension_mm() > self.rates.max_dimension_mm {
+            return None;
+        }
+        let material = self.rates.materials.get(&attributes.material)?;
+
+        let stock_cm3 = geometry.bounding_box.volume_mm3() / MM3_PER_CM3;
+        let removed_cm3 = (stock_cm3 - geometry.volume_mm3 / MM3_PER_CM3).max(0.0);
+        let surface_cm2 = geometry.surface_area_mm2 / MM2_PER_CM2;
+        let quantity = part.quantity.max(1);
+
+        let base_unit_price = stock_cm3 * material.stock_cost_per_cm3 as f64
+            + removed_cm3 * self.rates.removal_cost_per_cm3 as f64
+            + surface_cm2 * self.rates.surface_cost_per_cm2 as f64
+            + self.rates.setup_cost as f64 / quantity as f64;
+
+        // Quantities too large to price go through manual review.
+        let part_quotes = self
+            .rates
+            .lead_times
+            .iter()
+            .map(|lead_time| {
+                let unit_price = Money::new(
+                    (base_unit_price * lead_time.multiplier).ceil() as i64,
+                    Currency::MXN,
+                );
+                let sub_total = unit_price.checked_mul(quantity).ok()?;
+                Some(PartQuote::new(
+                    unit_price,
+                    sub_total,
+                    lead_time.workdays,
+                    None,
+                ))
+            })
+            .collect::<Option<Vec<PartQuote>>>()?;
+
+        if part_quotes.is_empty() {
+            None
+        } else {
+            Some(part_quotes)
+        }
+    }
+
+    /// The unit price is converted and the subtotal recalculated from it, so both stay
+    /// consistent after rounding. Quotations in currencies without an exchange rate go through
+    /// manual review.
+    fn convert_part_quote(
+        &self,
+        part_quote: &mut PartQuote,
+        quantity: u64,
+        currency: Currency,
+    ) -> Option<()> {
+        part_quote.unit_price = self
+            .exchange_rates
+            .convert(&part_quote.unit_price, currency)
+            .ok()?;
+        part_quote.sub_total = part_quote.unit_price.checked_mul(quantity).ok()?;
+
+        Some(())
+    }
+
+    /// Anything beyond stock material, standard tolerance and standard inspection needs a
+    /// human to read the drawing or the customer's notes.
+    fn is_low_risk(part: &Part, attributes: &CNCAttributes) -> bool {
+        part.drawing_file.is_none()
+            && part.additional_notes.trim().is_empty()
+            && attributes.tolerance == Tolerance::PlusMinus005Inch013mm
+            && attributes.surface_finish.is_none()
+            && attributes.heat_treatment.is_none()
+            && attributes.threads.is_empty()
+            && attributes.inspection_level == InspectionLevel::Standard
+    }
+}
+
+impl PricingEngine for RateTablePricingEngine {
+    fn instant_quotes(
+        &self,
+        parts: &[Part],
+        currency: Currency,
+    ) -> Option<HashMap<PartId, Vec<PartQuote>>> {
+        if parts.is_empty() {
+            return None;
+        }
+
+        let mut part_quotes_by_part = HashMap::new();
+        let mut subtotal = Money::new(0, Currency::MXN);
+        for part in parts {
+            let part_quotes = self.quote_part(part)?;
+            // The first part quote is the one selected by default.
+            subtotal = subtotal.checked_add(&part_quotes[0].sub_total).ok()?;
+            part_quotes_by_part.insert(part.id.clone(), part_quotes);
+        }
+
+        if subtotal.amount > self.rates.max_instant_subtotal {
+            return None;
+        }
+        if currency != Currency::MXN {
+            for part in parts {
+                let quantity = part.quantity.max(1);
+                for part_quote in part_quotes_by_part.get_mut(&part.id)? {
+                    self.convert_part_quote(part_quote, quantity, currency)?;
+                }
+            }
+        }
+
+        Some(part_quotes_by_part)
+    }
+}
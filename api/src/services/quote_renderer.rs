@@ -0,0 +1,18 @@
+use crate::parts::models::part::Part;
+use crate::quotations::models::quotation::Quotation;
+use crate::shared::tax::TaxJurisdiction;
+use crate::shared::Result;
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait QuoteRenderer: Send + Sync + 'static {
+    /// Renders the document of a quotation priced with the selected part quotes of its parts,
+    /// taxed as if shipped to `tax_jurisdiction`.
+    async fn render_quote(
+        &self,
+        quotation: &Quotation,
+        parts: &[Part],
+        customer_email: &str,
+        tax_jurisdiction: TaxJurisdiction,
+    ) -> Result<Vec<u8>>;
+}
@@ -0,0 +1,233 @@
+use crate::config::ConfigQuotesDocument;
+use crate::parts::models::part::Part;
+use crate::quotations::models::quotation::Quotation;
+use crate::quotations::models::quote_document::{QuoteDocument, QuoteDocumentLine};
+use crate::services::quote_renderer::QuoteRenderer;
+use crate::services::tax_calculator::TaxCalculator;
+use crate::shared::money::Money;
+use crate::shared::tax::TaxJurisdiction;
+use crate::shared::Result;
+use crate::utils::pdf_canvas::{
+    truncate, wrap, Font, PdfCanvas, CELL_PADDING, CONTENT_RIGHT, DATE_FORMAT, MARGIN,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+/// The part column starts at the left margin, the other columns are aligned to their right edge.
+const PART_COLUMN_WIDTH: f32 = 240.0;
+const QUANTITY_COLUMN_RIGHT: f32 = 350.0;
+const LEAD_TIME_COLUMN_RIGHT: f32 = 420.0;
+const UNIT_PRICE_COLUMN_RIGHT: f32 = 491.0;
+const AMOUNT_COLUMN_RIGHT: f32 = CONTENT_RIGHT - CELL_PADDING;
+
+/// Renders quotations as PDF documents. Only the standard Helvetica fonts are used, so no font
+/// files have to be embedded.
+#[derive(Clone)]
+pub struct PdfQuoteRenderer {
+    config: ConfigQuotesDocument,
+    tax_calculator: Arc<dyn TaxCalculator>,
+}
+
+impl PdfQuoteRenderer {
+    pub fn new(config: ConfigQuotesDocument, tax_calculator: Arc<dyn TaxCalculator>) -> Self {
+        Self {
+            config,
+            tax_calculator,
+        }
+    }
+
+    fn draw(&self, document: &QuoteDocument) -> Vec<u8> {
+        let mut canvas = PdfCanvas::new();
+        self.draw_header(&mut canvas, document);
+        Self::draw_lines_header(&mut canvas);
+        for line in &document.lines {
+            Self::draw_line(&mut canvas, line);
+        }
+        Self::draw_totals(&mut canvas, document);
+        Self::draw_notes(&mut canvas, document);
+
+        canvas.finish(&format!(
+            "{} · Quotation {}",
+            self.config.company_name, document.quotation_id
+        ))
+    }
+
+    fn draw_header(&self, canvas: &mut PdfCanvas, document: &QuoteDocument) {
+        let top = canvas.y;
+
+        canvas.text(
+            MARGIN,
+            top - 20.0,
+            Font::Bold,
+            22.0,
+            &self.config.company_name,
+        );
+        let mut address_y = top - 36.0;
+        for address_line in &self.config.company_address {
+            canvas.text(MARGIN, address_y, Font::Regular, 9.0, address_line);
+            address_y -= 12.0;
+        }
+
+        canvas.text_right(CONTENT_RIGHT, top - 20.0, Font::Bold, 18.0, "QUOTATION");
+        let details = [
+            format!("Number: {}", document.quotation_id),
+            format!("Issued: {}", document.issued_at.format(DATE_FORMAT)),
+            format!("Valid until: {}", document.valid_until.format(DATE_FORMAT)),
+        ];
+        let mut details_y = top - 38.0;
+        for detail in details {
+            canvas.text_right(CONTENT_RIGHT, details_y, Font::Regular, 9.0, &detail);
+            details_y -= 12.0;
+        }
+
+        canvas.y = address_y.min(details_y) - 16.0;
+        canvas.text(MARGIN, canvas.y, Font::Bold, 10.0, "Prepared for");
+        canvas.y -= 13.0;
+        canvas.text(
+            MARGIN,
+            canvas.y,
+            Font::Regular,
+            10.0,
+            &document.customer_email,
+        );
+        canvas.y -= 13.0;
+        canvas.text(
+            MARGIN,
+            canvas.y,
+            Font::Regular,
+            10.0,
+            &format!("Quote: {}", document.quotation_name),
+        );
+        canvas.y -= 28.0;
+    }
+
+    fn draw_lines_header(canvas: &mut PdfCanvas) {
+        let y = canvas.y;
+        canvas.fill_rect(MARGIN, y - 6.0, CONTENT_RIGHT - MARGIN, 20.0, 0.93);
+        canvas.text(MARGIN + CELL_PADDING, y, Font::Bold, 9.0, "Part");
+        canvas.text_right(QUANTITY_COLUMN_RIGHT, y, Font::Bold, 9.0, "Qty");
+        canvas.text_right(LEAD_TIME_COLUMN_RIGHT, y, Font::Bold, 9.0, "Lead time");
+        canvas.text_right(UNIT_PRICE_COLUMN_RIGHT, y, Font::Bold, 9.0, "Unit price");
+        canvas.text_right(AMOUNT_COLUMN_RIGHT, y, Font::Bold, 9.0, "Amount");
+        canvas.y -= 24.0;
+    }
+
+    fn draw_line(canvas: &mut PdfCanvas, line: &QuoteDocumentLine) {
+        let description = wrap(&line.description, Font::Regular, 8.0, PART_COLUMN_WIDTH);
+        let height = 20.0 + description.len() as f32 * 10.0;
+        if canvas.ensure_space(height) {
+            Self::draw_lines_header(canvas);
+        }
+
+        let y = canvas.y;
+        let name = truncate(&line.name, Font::Bold, 9.0, PART_COLUMN_WIDTH);
+        canvas.text(MARGIN + CELL_PADDING, y, Font::Bold, 9.0, &name);
+        let quantity = line.quantity.to_string();
+        canvas.text_right(QUANTITY_COLUMN_RIGHT, y, Font::Regular, 9.0, &quantity);
+        let lead_time = format!("{} workdays", line.workdays_to_complete);
+        canvas.text_right(LEAD_TIME_COLUMN_RIGHT, y, Font::Regular, 9.0, &lead_time);
+        let unit_price = line.unit_price.to_string();
+        canvas.text_right(UNIT_PRICE_COLUMN_RIGHT, y, Font::Regular, 9.0, &unit_price);
+        let amount = line.amount.to_string();
+        canvas.text_right(AMOUNT_COLUMN_RIGHT, y, Font::Regular, 9.0, &amount);
+
+        let mut description_y = y - 12.0;
+        for description_line in description {
+            canvas.text(
+                MARGIN + CELL_PADDING,
+                description_y,
+                Font::Regular,
+                8.0,
+                &description_line,
+            );
+            description_y -= 10.0;
+        }
+
+        canvas.y -= height;
+        canvas.rule(canvas.y + 10.0);
+    }
+
+    fn draw_totals(canvas: &mut PdfCanvas, document: &QuoteDocument) {
+        canvas.ensure_space(60.0);
+        canvas.y -= 6.0;
+
+        // Discounts are printed as negative amounts, subtracted from the subtotal.
+        let discount = document.discount.as_ref().map(|discount| {
+            (
+                format!("Discount ({})", discount.code),
+                Money::new(-discount.amount.amount, discount.amount.currency),
+            )
+        });
+        let mut totals = vec![(String::from("Subtotal"), &document.subtotal, Font::Regular)];
+        if let Some((label, amount)) = &discount {
+            totals.push((label.clone(), amount, Font::Regular));
+        }
+        for tax_line in &document.tax_lines {
+            totals.push((
+                format!("{} ({}%)", tax_line.name, tax_line.percentage()),
+                &tax_line.amount,
+                Font::Regular,
+            ));
+        }
+        totals.push((
+            format!("Total ({})", document.total.currency.code()),
+            &document.total,
+            Font::Bold,
+        ));
+        for (label, amount, font) in totals {
+            canvas.text_right(UNIT_PRICE_COLUMN_RIGHT, canvas.y, font, 10.0, &label);
+            let amount = amount.to_string();
+            canvas.text_right(AMOUNT_COLUMN_RIGHT, canvas.y, font, 10.0, &amount);
+            canvas.y -= 16.0;
+        }
+        canvas.y -= 12.0;
+    }
+
+    fn draw_notes(canvas: &mut PdfCanvas, document: &QuoteDocument) {
+        let notes = [
+            format!(
+                "Prices are valid until {}.",
+                document.valid_until.format(DATE_FORMAT)
+            ),
+            format!(
+                "Lead times are in workdays and start once the payment is confirmed. All parts are \
+                 completed in {} workdays.",
+                document.workdays_to_complete()
+            ),
+            format!(
+                "Taxes are calculated for shipping to {}.",
+                document.tax_jurisdiction
+            ),
+        ];
+        for note in notes {
+            for note_line in wrap(&note, Font::Regular, 8.0, CONTENT_RIGHT - MARGIN) {
+                canvas.ensure_space(10.0);
+                canvas.text(MARGIN, canvas.y, Font::Regular, 8.0, &note_line);
+                canvas.y -= 10.0;
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl QuoteRenderer for PdfQuoteRenderer {
+    async fn render_quote(
+        &self,
+        quotation: &Quotation,
+        parts: &[Part],
+        customer_email: &str,
+        tax_jurisdiction: TaxJurisdiction,
+    ) -> Result<Vec<u8>> {
+        let document = QuoteDocument::new(
+            quotation,
+            parts,
+            customer_email.to_string(),
+            self.tax_calculator.as_ref(),
+            tax_jurisdiction,
+            Utc::now(),
+        )?;
+
+        Ok(self.draw(&document))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::parts::models::part::Part;
+use crate::shared::shipping::ShippingOption;
+use crate::shared::Result;
+use iso_currency::Currency;
+
+pub trait ShippingCalculator: Send + Sync + 'static {
+    /// Estimated weight of every unit of every part, in kilograms.
+    fn estimated_weight_kg(&self, parts: &[Part]) -> f64;
+
+    /// Methods available for shipping the parts to `country`, cheapest first.
+    fn shipping_options(
+        &self,
+        country: &str,
+        currency: Currency,
+        parts: &[Part],
+    ) -> Result<Vec<ShippingOption>>;
+}
@@ -0,0 +1,85 @@
+use crate::config::ConfigShipping;
+use crate::parts::models::part::Part;
+use crate::services::exchange_rates::ExchangeRates;
+use crate::services::shipping_calculator::ShippingCalculator;
+use crate::shared::error::Error;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingOption;
+use crate::shared::Result;
+use iso_currency::Currency;
+use std::sync::Arc;
+
+static MM3_PER_CM3: f64 = 1_000.0;
+static G_PER_KG: f64 = 1_000.0;
+
+pub struct RateTableShippingCalculator {
+    shipping: ConfigShipping,
+    exchange_rates: Arc<dyn ExchangeRates>,
+}
+
+impl RateTableShippingCalculator {
+    pub fn new(shipping: ConfigShipping, exchange_rates: Arc<dyn ExchangeRates>) -> Self {
+        Self {
+            shipping,
+            exchange_rates,
+        }
+    }
+
+    fn unit_weight_kg(&self, part: &Part) -> f64 {
+        let Some(geometry) = &part.geometry else {
+            return self.shipping.default_part_weight_kg;
+        };
+        let density = self
+            .shipping
+            .material_densities
+            .get(part.attributes.material())
+            .copied()
+            .unwrap_or(self.shipping.default_density);
+
+        geometry.volume_mm3 / MM3_PER_CM3 * density / G_PER_KG
+    }
+}
+
+impl ShippingCalculator for RateTableShippingCalculator {
+    fn estimated_weight_kg(&self, parts: &[Part]) -> f64 {
+        parts
+            .iter()
+            .map(|part| self.unit_weight_kg(part) * part.quantity as f64)
+            .sum()
+    }
+
+    fn shipping_options(
+        &self,
+        country: &str,
+        currency: Currency,
+        parts: &[Part],
+    ) -> Result<Vec<ShippingOption>> {
+        let weight_kg = self.estimated_weight_kg(parts);
+
+        let mut shipping_options = self
+            .shipping
+            .rates
+            .iter()
+            .filter(|rate| rate.country.eq_ignore_ascii_case(country))
+            .map(|rate| {
+                // Carriers charge every started kilogram.
+                let cost = rate.base_cost + rate.cost_per_kg * weight_kg.ceil() as i64;
+
+                Ok(ShippingOption {
+                    method: rate.method.clone(),
+                    cost: self
+                        .exchange_rates
+                        .convert(&Money::new(cost, Currency::MXN), currency)?,
+                    min_workdays: rate.min_workdays,
+                    max_workdays: rate.max_workdays,
+                })
+            })
+            .collect::<Result<Vec<ShippingOption>>>()?;
+        if shipping_options.is_empty() {
+            return Err(Error::UnsupportedShippingCountry(country.to_string()));
+        }
+        shipping_options.sort_by_key(|shipping_option| shipping_option.cost.amount);
+
+        Ok(shipping_options)
+    }
+}
@@ -1,45 +1,105 @@
-use crate::config::ConfigPaymentsTaxIds;
 use crate::parts::models::part::Part;
-use crate::services::stripe_client::{Quote, QuoteLineItem, StripeClient, StripeQuote};
+use crate::promotions::models::discount::DiscountLine;
+use crate::services::stripe_client::{QuotationPayment, StripeClient};
+use crate::services::tax_calculator::TaxCalculator;
 use crate::shared;
 use crate::shared::error::Error;
+use crate::shared::money::Money;
+use crate::shared::shipping::{ShippingMethod, ShippingOption};
+use crate::shared::tax::TaxJurisdiction;
 use async_trait::async_trait;
-use axum::body::Bytes;
 use iso_currency::Currency;
 use shared::Result;
+use std::sync::Arc;
 use stripe::{
-    CheckoutSession, CheckoutSessionBillingAddressCollection, CheckoutSessionMode, Client,
-    CreateCheckoutSession, CreateCheckoutSessionLineItems, CreateCheckoutSessionLineItemsPriceData,
-    CreateCheckoutSessionLineItemsPriceDataProductData,
+    Account, AccountId, AccountLink, AccountLinkType, AccountType, CheckoutSession,
+    CheckoutSessionBillingAddressCollection, CheckoutSessionMode, Client, Coupon, CouponDuration,
+    CreateAccount, CreateAccountCapabilities, CreateAccountCapabilitiesTransfers,
+    CreateAccountLink, CreateCheckoutSession, CreateCheckoutSessionDiscounts,
+    CreateCheckoutSessionLineItems, CreateCheckoutSessionLineItemsPriceData,
+    CreateCheckoutSessionLineItemsPriceDataProductData, CreateCheckoutSessionPaymentIntentData,
     CreateCheckoutSessionShippingAddressCollection,
-    CreateCheckoutSessionShippingAddressCollectionAllowedCountries, CreateCustomer, CreateProduct,
-    Customer, Product,
+    CreateCheckoutSessionShippingAddressCollectionAllowedCountries,
+    CreateCheckoutSessionShippingOptions, CreateCoupon, CreateCustomer, CreateProduct,
+    CreateRefund, CreateTransfer, Customer, ErrorCode, PaymentIntent, PaymentIntentId, Product,
+    Refund, RequestError, RequestStrategy, StripeError, Transfer,
+};
+use stripe::{
+    CreateCheckoutSessionShippingOptionsShippingRateData as ShippingRateData,
+    CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimate as DeliveryEstimate,
+    CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateMaximum as DeliveryEstimateMaximum,
+    CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateMaximumUnit as DeliveryEstimateMaximumUnit,
+    CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateMinimum as DeliveryEstimateMinimum,
+    CreateCheckoutSessionShippingOptionsShippingRateDataDeliveryEstimateMinimumUnit as DeliveryEstimateMinimumUnit,
+    CreateCheckoutSessionShippingOptionsShippingRateDataFixedAmount as ShippingRateDataFixedAmount,
+    CreateCheckoutSessionShippingOptionsShippingRateDataType as ShippingRateDataType,
 };
 
 const CUSTOMER_ID: &str = "customer_id";
 const PROJECT_ID: &str = "project_id";
 const QUOTATION_ID: &str = "quotation_id";
+/// Metadata key of the country the taxes of the session were calculated for.
+pub const TAX_COUNTRY: &str = "tax_country";
+/// Metadata key of the state the taxes of the session were calculated for, if any.
+pub const TAX_STATE: &str = "tax_state";
+/// Metadata key of the promotion code applied to the session, if any.
+pub const PROMOTION_CODE: &str = "promotion_code";
+/// Metadata key of the shipping method of a shipping rate.
+pub const SHIPPING_METHOD: &str = "shipping_method";
+/// Metadata key of the shipping methods offered, in the order of the session's shipping options.
+pub const SHIPPING_METHODS: &str = "shipping_methods";
 
 #[derive(Clone)]
 pub struct Stripe {
     client: Client,
-    files_client: reqwest::Client,
     success_url: String,
-    tax_ids: ConfigPaymentsTaxIds,
+    connect_return_url: String,
+    tax_calculator: Arc<dyn TaxCalculator>,
 }
 
 impl Stripe {
     pub fn new(
         client: Client,
-        files_client: reqwest::Client,
         success_url: String,
-        tax_ids: ConfigPaymentsTaxIds,
+        connect_return_url: String,
+        tax_calculator: Arc<dyn TaxCalculator>,
     ) -> Self {
         Self {
             client,
-            files_client,
             success_url,
-            tax_ids,
+            connect_return_url,
+            tax_calculator,
+        }
+    }
+}
+
+impl Stripe {
+    /// Coupon for the amount already calculated for the quotation, so Stripe charges the same
+    /// total shown to the customer. Promotion codes are managed by the API, not Stripe, so the
+    /// coupon of a code and amount is created once and reused by later checkouts.
+    async fn coupon(&self, discount: &DiscountLine) -> Result<String> {
+        let currency = stripe_currency(discount.amount.currency)?;
+        let coupon_id = format!("{}_{}_{currency}", discount.code, discount.amount.amount);
+        let mut create_coupon = CreateCoupon::new();
+        create_coupon.id = Some(&coupon_id);
+        create_coupon.amount_off = Some(discount.amount.amount);
+        create_coupon.currency = Some(currency);
+        create_coupon.duration = Some(CouponDuration::Once);
+        create_coupon.name = Some(&discount.code);
+
+        let client = self.client.clone();
+        let result = Coupon::create(&client, create_coupon).await;
+
+        match result {
+            Ok(coupon) => Ok(coupon.id.to_string()),
+            Err(StripeError::Stripe(RequestError {
+                code: Some(ErrorCode::ResourceAlreadyExists),
+                ..
+            })) => Ok(coupon_id),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
         }
     }
 }
@@ -79,98 +139,207 @@ impl StripeClient for Stripe {
         }
     }
 
-    async fn create_quote(
+    #[allow(clippy::too_many_arguments)]
+    async fn create_checkout_session(
         &self,
-        stripe_customer_id: String,
-        line_items: Vec<QuoteLineItem>,
-    ) -> Result<StripeQuote> {
-        let quote = Quote {
-            customer: stripe_customer_id,
-            line_items,
-            default_tax_rates: self.tax_ids.mexico.clone(),
-        };
+        customer_id: String,
+        project_id: String,
+        quotation_id: String,
+        currency: Currency,
+        parts: Vec<Part>,
+        jurisdiction: TaxJurisdiction,
+        discount: Option<DiscountLine>,
+        shipping_options: Vec<ShippingOption>,
+    ) -> Result<String> {
+        let tax_rate_ids = self.tax_calculator.stripe_tax_rate_ids(&jurisdiction)?;
+        let line_items = checkout_line_items(&parts, currency, &tax_rate_ids)?;
+        let success_url = format!("{}/orders", self.success_url,);
+
+        let mut params = CreateCheckoutSession::new();
+        params.line_items = Some(line_items);
+        params.success_url = Some(&success_url);
+        params.mode = Some(CheckoutSessionMode::Payment);
+        params.billing_address_collection = Some(CheckoutSessionBillingAddressCollection::Required);
+        params.shipping_address_collection = Some(CreateCheckoutSessionShippingAddressCollection {
+            // Taxes were calculated for this country, shipping anywhere else would change them.
+            allowed_countries: vec![allowed_shipping_country(&jurisdiction)?],
+        });
+
+        let mut metadata = stripe::Metadata::from([
+            (String::from(CUSTOMER_ID), customer_id),
+            (String::from(PROJECT_ID), project_id),
+            (String::from(QUOTATION_ID), quotation_id),
+            (String::from(TAX_COUNTRY), jurisdiction.country.clone()),
+        ]);
+        if let Some(state) = &jurisdiction.state {
+            metadata.insert(String::from(TAX_STATE), state.clone());
+        }
+        if let Some(discount) = &discount {
+            params.discounts = Some(vec![CreateCheckoutSessionDiscounts {
+                coupon: Some(self.coupon(discount).await?),
+                promotion_code: None,
+            }]);
+            metadata.insert(String::from(PROMOTION_CODE), discount.code.clone());
+        }
+        // The webhook finds out the method chosen by the position of the shipping rate charged,
+        // the rates are only created with the session.
+        metadata.insert(
+            String::from(SHIPPING_METHODS),
+            shipping_options
+                .iter()
+                .map(|shipping_option| shipping_option.method.to_string())
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+        params.shipping_options = Some(checkout_shipping_options(&shipping_options)?);
+        // Refunds and other charge events only carry the payment intent.
+        params.payment_intent_data = Some(CreateCheckoutSessionPaymentIntentData {
+            metadata: Some(metadata.clone()),
+            ..Default::default()
+        });
+        params.metadata = Some(metadata);
 
         let client = self.client.clone();
-        let response = client
-            .post_form::<StripeQuote, Quote>("/quotes", quote)
-            .await;
+        let result = CheckoutSession::create(&client, params).await;
 
-        match response {
-            Ok(response) => Ok(response),
+        match result {
+            Ok(checkout_session) => Ok(checkout_session.url.unwrap()),
             Err(err) => {
-                tracing::error!("Failed to create stripe quote: {}", err);
+                tracing::error!("{err:?}");
                 Err(Error::UnknownError)
             }
         }
     }
 
-    async fn finalize_quote(&self, stripe_quote_id: String) -> Result<()> {
+    async fn get_quotation_payment(&self, payment_intent_id: String) -> Result<QuotationPayment> {
+        let payment_intent_id = payment_intent_id
+            .parse::<PaymentIntentId>()
+            .map_err(|err| {
+                tracing::error!("{err:?}");
+                Error::UnknownError
+            })?;
+
         let client = self.client.clone();
-        let path = format!("quotes/{stripe_quote_id}/finalize");
-        let response = client.post::<StripeQuote>(&path).await;
+        let payment_intent = PaymentIntent::retrieve(&client, &payment_intent_id, &[])
+            .await
+            .map_err(|err| {
+                tracing::error!("{err:?}");
+                Error::UnknownError
+            })?;
+
+        let metadata_value = |key: &str| {
+            payment_intent.metadata.get(key).cloned().ok_or_else(|| {
+                tracing::error!("payment intent {payment_intent_id} is missing {key}");
+                Error::UnknownError
+            })
+        };
+
+        Ok(QuotationPayment {
+            customer_id: metadata_value(CUSTOMER_ID)?,
+            project_id: metadata_value(PROJECT_ID)?,
+            quotation_id: metadata_value(QUOTATION_ID)?,
+        })
+    }
+
+    async fn create_refund(
+        &self,
+        payment_intent_id: String,
+        amount: Money,
+        idempotency_key: String,
+    ) -> Result<()> {
+        let payment_intent_id = payment_intent_id
+            .parse::<PaymentIntentId>()
+            .map_err(|err| {
+                tracing::error!("{err:?}");
+                Error::UnknownError
+            })?;
 
-        match response {
+        let mut params = CreateRefund::new();
+        params.payment_intent = Some(payment_intent_id);
+        params.amount = Some(amount.amount);
+
+        let client = self
+            .client
+            .clone()
+            .with_strategy(RequestStrategy::Idempotent(idempotency_key));
+        let result = Refund::create(&client, params).await;
+
+        match result {
             Ok(_) => Ok(()),
             Err(err) => {
-                tracing::error!("Failed to finalize stripe quote: {}", err);
+                tracing::error!("{err:?}");
                 Err(Error::UnknownError)
             }
         }
     }
 
-    async fn download_quote_pdf(&self, stripe_quote_id: String) -> Result<Bytes> {
-        let url = format!("https://files.stripe.com/v1/quotes/{stripe_quote_id}/pdf");
-        let result = self.files_client.get(&url).send().await;
+    async fn create_connected_account(&self, email: String, country: String) -> Result<String> {
+        let mut create_account = CreateAccount::new();
+        create_account.type_ = Some(AccountType::Express);
+        create_account.email = Some(&email);
+        create_account.country = Some(&country);
+        create_account.capabilities = Some(CreateAccountCapabilities {
+            transfers: Some(CreateAccountCapabilitiesTransfers {
+                requested: Some(true),
+            }),
+            ..Default::default()
+        });
+
+        let client = self.client.clone();
+        let result = Account::create(&client, create_account).await;
 
         match result {
-            Ok(response) => match response.bytes().await {
-                Ok(bytes) => Ok(bytes),
-                Err(err) => {
-                    tracing::error!("Error parsing pdf file to bytes: {}", err);
-                    Err(Error::UnknownError)
-                }
-            },
+            Ok(account) => Ok(account.id.to_string()),
             Err(err) => {
-                tracing::error!("Failed to download stripe quote: {}", err);
+                tracing::error!("{err:?}");
                 Err(Error::UnknownError)
             }
         }
     }
 
-    async fn create_checkout_session(
-        &self,
-        customer_id: String,
-        project_id: String,
-        quotation_id: String,
-        parts: Vec<Part>,
-    ) -> Result<String> {
-        let line_items = self.line_items_from_parts_data(&parts);
-        let success_url = format!("{}/orders", self.success_url,);
+    async fn create_account_onboarding_link(&self, account_id: String) -> Result<String> {
+        let account_id = account_id.parse::<AccountId>().map_err(|err| {
+            tracing::error!("{err:?}");
+            Error::UnknownError
+        })?;
 
-        let mut params = CreateCheckoutSession::new();
-        params.line_items = Some(line_items);
-        params.success_url = Some(&success_url);
-        params.mode = Some(CheckoutSessionMode::Payment);
-        params.billing_address_collection = Some(CheckoutSessionBillingAddressCollection::Required);
-        params.shipping_address_collection = Some(CreateCheckoutSessionShippingAddressCollection {
-            allowed_countries: vec![
-                CreateCheckoutSessionShippingAddressCollectionAllowedCountries::Mx,
-                CreateCheckoutSessionShippingAddressCollectionAllowedCountries::Us,
-            ],
-        });
-
-        let metadata = stripe::Metadata::from([
-            (String::from(CUSTOMER_ID), customer_id),
-            (String::from(PROJECT_ID), project_id),
-            (String::from(QUOTATION_ID), quotation_id),
-        ]);
-        params.metadata = Some(metadata);
+        let mut create_account_link =
+            CreateAccountLink::new(account_id, AccountLinkType::AccountOnboarding);
+        create_account_link.refresh_url = Some(&self.connect_return_url);
+        create_account_link.return_url = Some(&self.connect_return_url);
 
         let client = self.client.clone();
-        let result = CheckoutSession::create(&client, params).await;
+        let result = AccountLink::create(&client, create_account_link).await;
 
         match result {
-            Ok(checkout_session) => Ok(checkout_session.url.unwrap()),
+            Ok(account_link) => Ok(account_link.url),
+            Err(err) => {
+                tracing::error!("{err:?}");
+                Err(Error::UnknownError)
+            }
+        }
+    }
+
+    async fn create_transfer(
+        &self,
+        account_id: String,
+        amount: Money,
+        transfer_group: String,
+        idempotency_key: String,
+    ) -> Result<String> {
+        let mut create_transfer =
+            CreateTransfer::new(stripe_currency(amount.currency)?, account_id);
+        create_transfer.amount = Some(amount.amount);
+        create_transfer.transfer_group = Some(&transfer_group);
+
+        let client = self
+            .client
+            .clone()
+            .with_strategy(RequestStrategy::Idempotent(idempotency_key));
+        let result = Transfer::create(&client, create_transfer).await;
+
+        match result {
+            Ok(transfer) => Ok(transfer.id.to_string()),
             Err(err) => {
                 tracing::error!("{err:?}");
                 Err(Error::UnknownError)
@@ -179,48 +348,108 @@ impl StripeClient for Stripe {
     }
 }
 
-impl Stripe {
-    fn line_items_from_parts_data(&self, parts: &Vec<Part>) -> Vec<CreateCheckoutSessionLineItems> {
-        parts
-            .iter()
-            .map(|part| {
-                let selected_part_quote = part
-                    .part_quotes
-                    .clone()
-                    .expect("expecting part quotes")
-                    .into_iter()
-                    .find(|part_quote| {
-                        part_quote.id == part.selected_part_quote_id.clone().unwrap()
-                    })
-                    .expect("could not find a selected quote for part");
-
-                CreateCheckoutSessionLineItems {
-                    adjustable_quantity: None,
-                    dynamic_tax_rates: None,
-                    price: None,
-                    price_data: Some(CreateCheckoutSessionLineItemsPriceData {
-                        currency: match selected_part_quote.sub_total.currency.clone() {
-                            Currency::MXN => stripe::Currency::MXN,
-                            Currency::USD => stripe::Currency::USD,
-                            _ => stripe::Currency::USD,
-                        },
-                        product: None,
-                        product_data: Some(CreateCheckoutSessionLineItemsPriceDataProductData {
-                            description: Some(part.attributes.to_string()),
-                            images: None,
-                            metadata: None,
-                            name: part.model_file.name.clone(),
-                            tax_code: None,
-                        }),
-                        recurring: None,
-                        tax_behavior: None,
-                        unit_amount: Some(selected_part_quote.unit_price.amount),
-                        unit_amount_decimal: None,
+/// Stripe only charges the currencies quotations can be priced in, anything else is rejected
+/// instead of being charged in another currency.
+pub fn stripe_currency(currency: Currency) -> Result<stripe::Currency> {
+    match currency {
+        Currency::MXN => Ok(stripe::Currency::MXN),
+        Currency::USD => Ok(stripe::Currency::USD),
+        _ => Err(Error::UnsupportedCurrency(currency)),
+    }
+}
+
+fn allowed_shipping_country(
+    jurisdiction: &TaxJurisdiction,
+) -> Result<CreateCheckoutSessionShippingAddressCollectionAllowedCountries> {
+    serde_json::from_value(serde_json::Value::String(jurisdiction.country.clone()))
+        .map_err(|_| Error::UnsupportedShippingCountry(jurisdiction.country.clone()))
+}
+
+/// One line item per part, all of them in the currency of the quotation.
+pub fn checkout_line_items(
+    parts: &[Part],
+    currency: Currency,
+    tax_rates: &[String],
+) -> Result<Vec<CreateCheckoutSessionLineItems>> {
+    let stripe_currency = stripe_currency(currency)?;
+
+    parts
+        .iter()
+        .map(|part| {
+            let selected_part_quote = part
+                .selected_part_quote()
+                .ok_or(Error::NoSelectedQuoteAvailableForPart(part.id.clone()))?;
+            let unit_price = selected_part_quote.unit_price_for(part.quantity);
+            if unit_price.currency != currency {
+                return Err(Error::CurrencyMismatch(currency, unit_price.currency));
+            }
+
+            Ok(CreateCheckoutSessionLineItems {
+                adjustable_quantity: None,
+                dynamic_tax_rates: None,
+                price: None,
+                price_data: Some(CreateCheckoutSessionLineItemsPriceData {
+                    currency: stripe_currency,
+                    product: None,
+                    product_data: Some(CreateCheckoutSessionLineItemsPriceDataProductData {
+                        description: Some(part.attributes.to_string()),
+                        images: None,
+                        metadata: None,
+                        name: part.model_file.name.clone(),
+                        tax_code: None,
                     }),
-                    quantity: Some(part.quantity),
-                    tax_rates: Some(self.tax_ids.mexico.clone()),
-                }
+                    recurring: None,
+                    tax_behavior: None,
+                    unit_amount: Some(unit_price.amount),
+                    unit_amount_decimal: None,
+                }),
+                quantity: Some(part.quantity),
+                tax_rates: Some(tax_rates.to_vec()),
             })
-            .collect()
-    }
+        })
+        .collect()
+}
+
+/// Shipping rates for the costs already calculated for the quotation, created with the checkout
+/// session. Stripe doesn't tax them, as the tax rates only apply to line items.
+pub fn checkout_shipping_options(
+    shipping_options: &[ShippingOption],
+) -> Result<Vec<CreateCheckoutSessionShippingOptions>> {
+    shipping_options
+        .iter()
+        .map(|shipping_option| {
+            // Pickups don't go through a carrier.
+            let delivery_estimate =
+                (shipping_option.method != ShippingMethod::Pickup).then(|| DeliveryEstimate {
+                    minimum: Some(DeliveryEstimateMinimum {
+                        unit: DeliveryEstimateMinimumUnit::BusinessDay,
+                        value: shipping_option.min_workdays.max(1) as i64,
+                    }),
+                    maximum: Some(DeliveryEstimateMaximum {
+                        unit: DeliveryEstimateMaximumUnit::BusinessDay,
+                        value: shipping_option.max_workdays.max(1) as i64,
+                    }),
+                });
+
+            Ok(CreateCheckoutSessionShippingOptions {
+                shipping_rate: None,
+                shipping_rate_data: Some(ShippingRateData {
+                    delivery_estimate,
+                    display_name: shipping_option.method.display_name().to_string(),
+                    fixed_amount: Some(ShippingRateDataFixedAmount {
+                        amount: shipping_option.cost.amount,
+                        currency: stripe_currency(shipping_option.cost.currency)?,
+                        currency_options: None,
+                    }),
+                    metadata: Some(stripe::Metadata::from([(
+                        String::from(SHIPPING_METHOD),
+                        shipping_option.method.to_string(),
+                    )])),
+                    tax_behavior: None,
+                    tax_code: None,
+                    type_: Some(ShippingRateDataType::FixedAmount),
+                }),
+            })
+        })
+        .collect()
 }
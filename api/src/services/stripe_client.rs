@@ -1,54 +1,58 @@
 use crate::parts::models::part::Part;
+use crate::promotions::models::discount::DiscountLine;
 use crate::shared;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingOption;
+use crate::shared::tax::TaxJurisdiction;
 use async_trait::async_trait;
-use axum::body::Bytes;
+use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
 use shared::Result;
-use stripe::{Currency, Customer};
+use stripe::Customer;
 
 #[async_trait]
 pub trait StripeClient: Send + Sync + 'static {
     async fn create_customer(&self, name: String, email: String) -> Result<Customer>;
     async fn create_product(&self, name: String, id: String) -> Result<()>;
-    async fn create_quote(
-        &self,
-        stripe_customer_id: String,
-        line_items: Vec<QuoteLineItem>,
-    ) -> Result<StripeQuote>;
-    async fn finalize_quote(&self, stripe_quote_id: String) -> Result<()>;
-    async fn download_quote_pdf(&self, stripe_quote_id: String) -> Result<Bytes>;
+    #[allow(clippy::too_many_arguments)]
     async fn create_checkout_session(
         &self,
         customer_id: String,
         project_id: String,
         quotation_id: String,
+        currency: Currency,
         parts: Vec<Part>,
+        jurisdiction: TaxJurisdiction,
+        discount: Option<DiscountLine>,
+        shipping_options: Vec<ShippingOption>,
+    ) -> Result<String>;
+    /// Gets the quotation paid by a payment intent, for events that aren't about checkout sessions.
+    async fn get_quotation_payment(&self, payment_intent_id: String) -> Result<QuotationPayment>;
+    /// Refunds part of a payment. Retries with the same `idempotency_key` don't refund twice.
+    async fn create_refund(
+        &self,
+        payment_intent_id: String,
+        amount: Money,
+        idempotency_key: String,
+    ) -> Result<()>;
+    /// Creates the Express connected account a supplier gets paid out to.
+    async fn create_connected_account(&self, email: String, country: String) -> Result<String>;
+    /// Link where the supplier sets up their connected account, it expires within minutes.
+    async fn create_account_onboarding_link(&self, account_id: String) -> Result<String>;
+    /// Moves money from the platform balance to a connected account. Retries with the same
+    /// `idempotency_key` don't transfer twice.
+    async fn create_transfer(
+        &self,
+        account_id: String,
+        amount: Money,
+        transfer_group: String,
+        idempotency_key: String,
     ) -> Result<String>;
 }
 
 #[derive(Deserialize, Serialize, Debug)]
-pub struct StripeQuote {
-    pub id: String,
-}
-
-#[derive(Deserialize, Serialize, Debug)]
-pub struct Quote {
-    /// Stipe's customer id.
-    pub customer: String,
-    pub line_items: Vec<QuoteLineItem>,
-    pub default_tax_rates: Vec<String>,
-}
-
-#[derive(Deserialize, Serialize, Debug)]
-pub struct QuoteLineItem {
-    pub price_data: PriceData,
-    pub quantity: u64,
-}
-
-#[derive(Deserialize, Serialize, Debug)]
-pub struct PriceData {
-    pub currency: Currency,
-    /// Stripe's product id which is the same as the part id.
-    pub product: String,
-    pub unit_amount: u64,
+pub struct QuotationPayment {
+    pub customer_id: String,
+    pub project_id: String,
+    pub quotation_id: String,
 }
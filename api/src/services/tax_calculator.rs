@@ -0,0 +1,23 @@
+use crate::orders::models::order::Address;
+use crate::shared::money::Money;
+use crate::shared::tax::{TaxJurisdiction, TaxLine};
+use crate::shared::Result;
+use iso_currency::Currency;
+
+pub trait TaxCalculator: Send + Sync + 'static {
+    /// Jurisdiction of a shipping address. Quotations without a shipping address yet are taxed
+    /// as if they were shipped within the default country.
+    fn jurisdiction(&self, address: Option<&Address>) -> Result<TaxJurisdiction>;
+
+    /// One tax line per tax of the jurisdiction. Taxes are rounded per line amount, like Stripe
+    /// does, so the total matches the one charged at checkout.
+    fn tax_lines(
+        &self,
+        jurisdiction: &TaxJurisdiction,
+        currency: Currency,
+        line_amounts: &[Money],
+    ) -> Result<Vec<TaxLine>>;
+
+    /// Stripe tax rates of the same taxes, applied to every line item at checkout.
+    fn stripe_tax_rate_ids(&self, jurisdiction: &TaxJurisdiction) -> Result<Vec<String>>;
+}
@@ -0,0 +1,120 @@
+use crate::config::{ConfigTaxRate, ConfigTaxes};
+use crate::orders::models::order::Address;
+use crate::services::tax_calculator::TaxCalculator;
+use crate::shared::error::Error;
+use crate::shared::money::Money;
+use crate::shared::tax::{TaxJurisdiction, TaxLine};
+use crate::shared::Result;
+use iso_currency::Currency;
+
+pub struct RateTableTaxCalculator {
+    taxes: ConfigTaxes,
+}
+
+impl RateTableTaxCalculator {
+    pub fn new(taxes: ConfigTaxes) -> Self {
+        Self { taxes }
+    }
+
+    /// Rates of the country and of every state within it.
+    fn country_rates(&self, country: &str) -> Result<Vec<&ConfigTaxRate>> {
+        let country_rates = self
+            .taxes
+            .rates
+            .iter()
+            .filter(|rate| rate.country.eq_ignore_ascii_case(country))
+            .collect::<Vec<&ConfigTaxRate>>();
+        if country_rates.is_empty() {
+            return Err(Error::UnsupportedShippingCountry(country.to_string()));
+        }
+
+        Ok(country_rates)
+    }
+
+    /// Rates for the state of the jurisdiction replace the country rate with the same name.
+    fn rates(&self, jurisdiction: &TaxJurisdiction) -> Result<Vec<&ConfigTaxRate>> {
+        let country_rates = self.country_rates(&jurisdiction.country)?;
+        let is_state_rate = |rate: &ConfigTaxRate| match (&rate.state, &jurisdiction.state) {
+            (Some(rate_state), Some(state)) => rate_state.eq_ignore_ascii_case(state),
+            _ => false,
+        };
+        let rates = country_rates
+            .iter()
+            .filter(|rate| {
+                if rate.state.is_some() {
+                    return is_state_rate(rate);
+                }
+                !country_rates
+                    .iter()
+                    .any(|other| other.name == rate.name && is_state_rate(other))
+            })
+            .copied()
+            .collect();
+
+        Ok(rates)
+    }
+}
+
+impl TaxCalculator for RateTableTaxCalculator {
+    fn jurisdiction(&self, address: Option<&Address>) -> Result<TaxJurisdiction> {
+        let normalize = |value: &Option<String>| {
+            value
+                .as_deref()
+                .map(|value| value.trim().to_uppercase())
+                .filter(|value| !value.is_empty())
+        };
+        let country = address
+            .and_then(|address| normalize(&address.country))
+            .unwrap_or_else(|| self.taxes.default_country.to_uppercase());
+        let country_rates = self.country_rates(&country)?;
+
+        // States without rates of their own are taxed like the rest of the country.
+        let state = address
+            .and_then(|address| normalize(&address.state))
+            .filter(|state| {
+                country_rates.iter().any(|rate| {
+                    rate.state
+                        .as_ref()
+                        .is_some_and(|rate_state| rate_state.eq_ignore_ascii_case(state))
+                })
+            });
+
+        Ok(TaxJurisdiction { country, state })
+    }
+
+    fn tax_lines(
+        &self,
+        jurisdiction: &TaxJurisdiction,
+        currency: Currency,
+        line_amounts: &[Money],
+    ) -> Result<Vec<TaxLine>> {
+        self.rates(jurisdiction)?
+            .into_iter()
+            .map(|rate| {
+                let line_taxes = line_amounts
+                    .iter()
+                    .map(|line_amount| {
+                        Money::new(
+                            (line_amount.amount as f64 * rate.rate).round() as i64,
+                            line_amount.currency,
+                        )
+                    })
+                    .collect::<Vec<Money>>();
+
+                Ok(TaxLine {
+                    name: rate.name.clone(),
+                    rate: rate.rate,
+                    amount: Money::checked_sum(currency, &line_taxes)?,
+                })
+            })
+            .collect()
+    }
+
+    fn stripe_tax_rate_ids(&self, jurisdiction: &TaxJurisdiction) -> Result<Vec<String>> {
+        Ok(self
+            .rates(jurisdiction)?
+            .into_iter()
+            .map(|rate| rate.stripe_tax_rate_id.clone())
+            .collect())
+    }
+}
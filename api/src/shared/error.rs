@@ -1,7 +1,9 @@
+use crate::orders::models::order::OrderStatus;
 use crate::shared::api_error::ApiError;
 use crate::shared::into_error_response::IntoError;
 use axum::Json;
 use http::StatusCode;
+use iso_currency::Currency;
 use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
 
 #[derive(thiserror::Error, Debug)]
@@ -34,6 +36,70 @@ pub enum Error {
     Unauthorized,
     #[error("Invalid part attributes: {0}")]
     InvalidPartAttributes(String),
+    #[error("Invalid model file: {0}")]
+    InvalidModelFile(String),
+    #[error("Invalid quantity tiers: {0}")]
+    InvalidQuantityTiers(String),
+    #[error("`{0}` already exists in the catalog")]
+    CatalogItemAlreadyExists(String),
+    #[error("Cannot move order from `{0}` to `{1}`")]
+    InvalidOrderStatusTransition(OrderStatus, OrderStatus),
+    #[error("Part `{0}` changed since the revision was priced")]
+    RevisionPartChanged(String),
+    #[error("The quote for part `{0}` expired, request a new quote")]
+    PartQuoteExpired(String),
+    #[error("Quote `{0}` expired, send it for review again")]
+    QuotationExpired(String),
+    #[error("Quote `{0}` can't have more than {1} parts")]
+    TooManyParts(String, usize),
+    #[error("Webhook event `{0}` has already been processed")]
+    WebhookEventAlreadyProcessed(String),
+    #[error("Order `{0}` can't be cancelled")]
+    OrderCannotBeCancelled(String),
+    #[error("The refund for order `{0}` exceeds what was paid for it")]
+    InvalidRefundAmount(String),
+    #[error("Invalid billing profile: {0}")]
+    InvalidBillingProfile(String),
+    #[error("Cannot combine amounts in `{0}` and `{1}`")]
+    CurrencyMismatch(Currency, Currency),
+    #[error("`{0}` is not a supported currency")]
+    UnsupportedCurrency(Currency),
+    #[error("Shipping to `{0}` is not supported")]
+    UnsupportedShippingCountry(String),
+    #[error("Invalid promotion: {0}")]
+    InvalidPromotion(String),
+    #[error("Promotion code `{0}` already exists")]
+    PromotionCodeAlreadyExists(String),
+    #[error("Promotion code `{0}` expired")]
+    PromotionCodeExpired(String),
+    #[error("Promotion code `{0}` reached its usage limit")]
+    PromotionCodeUsedUp(String),
+    #[error("Invalid supplier: {0}")]
+    InvalidSupplier(String),
+    #[error("Supplier `{0}` hasn't finished setting up their Stripe account")]
+    SupplierNotOnboarded(String),
+    #[error("Invalid payout: {0}")]
+    InvalidPayout(String),
+    #[error("The payout of order `{0}` was already transferred")]
+    OrderPayoutAlreadyTransferred(String),
+    #[error("Order `{0}` needs a supplier, a payout and to be finished before paying out")]
+    OrderNotReadyForPayout(String),
+    #[error("Invalid RFQ: {0}")]
+    InvalidRfq(String),
+    #[error("Invalid bid: {0}")]
+    InvalidRfqBid(String),
+    #[error("RFQ `{0}` no longer accepts bids")]
+    RfqClosed(String),
+    #[error("Order `{0}` already has a work order")]
+    WorkOrderAlreadyExists(String),
+    #[error("Work order `{0}` was updated by someone else, try again")]
+    WorkOrderChanged(String),
+    #[error("Invalid operation: {0}")]
+    InvalidWorkOrderOperation(String),
+    #[error("Invalid time log: {0}")]
+    InvalidTimeLog(String),
+    #[error("Invalid inspection report: {0}")]
+    InvalidInspectionReport(String),
     #[error("An unexpected error occurred")]
     UnknownError,
 }
@@ -145,6 +211,262 @@ impl IntoError for Error {
                     message,
                 },
             ),
+            Error::InvalidModelFile(message) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidModelFile(message).to_string(),
+                },
+            ),
+            Error::InvalidQuantityTiers(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidQuantityTiers(message).to_string(),
+                },
+            ),
+            Error::CatalogItemAlreadyExists(name) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::CatalogItemAlreadyExists(name).to_string(),
+                },
+            ),
+            Error::RevisionPartChanged(part_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::RevisionPartChanged(part_id).to_string(),
+                },
+            ),
+            Error::PartQuoteExpired(part_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::PartQuoteExpired(part_id).to_string(),
+                },
+            ),
+            Error::QuotationExpired(quotation_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::QuotationExpired(quotation_id).to_string(),
+                },
+            ),
+            Error::TooManyParts(quotation_id, max_parts) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::TooManyParts(quotation_id, max_parts).to_string(),
+                },
+            ),
+            Error::WebhookEventAlreadyProcessed(event_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::WebhookEventAlreadyProcessed(event_id).to_string(),
+                },
+            ),
+            Error::OrderCannotBeCancelled(order_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::OrderCannotBeCancelled(order_id).to_string(),
+                },
+            ),
+            Error::InvalidRefundAmount(order_id) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidRefundAmount(order_id).to_string(),
+                },
+            ),
+            Error::InvalidBillingProfile(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidBillingProfile(message).to_string(),
+                },
+            ),
+            Error::UnsupportedCurrency(currency) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::UnsupportedCurrency(currency).to_string(),
+                },
+            ),
+            Error::UnsupportedShippingCountry(country) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::UnsupportedShippingCountry(country).to_string(),
+                },
+            ),
+            Error::InvalidPromotion(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidPromotion(message).to_string(),
+                },
+            ),
+            Error::PromotionCodeAlreadyExists(code) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::PromotionCodeAlreadyExists(code).to_string(),
+                },
+            ),
+            Error::PromotionCodeExpired(code) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::PromotionCodeExpired(code).to_string(),
+                },
+            ),
+            Error::PromotionCodeUsedUp(code) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::PromotionCodeUsedUp(code).to_string(),
+                },
+            ),
+            Error::InvalidSupplier(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidSupplier(message).to_string(),
+                },
+            ),
+            Error::SupplierNotOnboarded(supplier_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::SupplierNotOnboarded(supplier_id).to_string(),
+                },
+            ),
+            Error::InvalidPayout(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidPayout(message).to_string(),
+                },
+            ),
+            Error::OrderPayoutAlreadyTransferred(order_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::OrderPayoutAlreadyTransferred(order_id).to_string(),
+                },
+            ),
+            Error::OrderNotReadyForPayout(order_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::OrderNotReadyForPayout(order_id).to_string(),
+                },
+            ),
+            Error::InvalidRfq(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidRfq(message).to_string(),
+                },
+            ),
+            Error::InvalidRfqBid(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidRfqBid(message).to_string(),
+                },
+            ),
+            Error::RfqClosed(rfq_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::RfqClosed(rfq_id).to_string(),
+                },
+            ),
+            Error::WorkOrderAlreadyExists(order_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::WorkOrderAlreadyExists(order_id).to_string(),
+                },
+            ),
+            Error::WorkOrderChanged(work_order_id) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::WorkOrderChanged(work_order_id).to_string(),
+                },
+            ),
+            Error::InvalidWorkOrderOperation(message) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::InvalidWorkOrderOperation(message).to_string(),
+                },
+            ),
+            Error::InvalidTimeLog(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidTimeLog(message).to_string(),
+                },
+            ),
+            Error::InvalidInspectionReport(message) => (
+                StatusCode::BAD_REQUEST,
+                ApiError {
+                    status_code: StatusCode::BAD_REQUEST.as_u16(),
+                    code: ErrorCode::BadInput,
+                    message: Error::InvalidInspectionReport(message).to_string(),
+                },
+            ),
+            Error::CurrencyMismatch(expected, actual) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::CurrencyMismatch(expected, actual).to_string(),
+                },
+            ),
+            Error::InvalidOrderStatusTransition(from, to) => (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                ApiError {
+                    status_code: StatusCode::UNPROCESSABLE_ENTITY.as_u16(),
+                    code: ErrorCode::NotAllowed,
+                    message: Error::InvalidOrderStatusTransition(from, to).to_string(),
+                },
+            ),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, ApiError::default()),
         };
 
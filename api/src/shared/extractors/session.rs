@@ -1,4 +1,5 @@
 use crate::auth::models::session::Session;
+use crate::shared::SupplierId;
 use async_trait::async_trait;
 use axum::extract::FromRequestParts;
 use http::request::Parts;
@@ -8,6 +9,9 @@ use http::StatusCode;
 pub struct AdminSession(pub Session);
 #[derive(Clone, Debug)]
 pub struct CustomerSession(pub Session);
+/// Session of a supplier along with the supplier it signs in for.
+#[derive(Clone, Debug)]
+pub struct SupplierSession(pub Session, pub SupplierId);
 
 #[async_trait]
 impl<S> FromRequestParts<S> for CustomerSession
@@ -52,3 +56,25 @@ where
         Ok(admin_session)
     }
 }
+
+#[async_trait]
+impl<S> FromRequestParts<S> for SupplierSession
+where
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let supplier_session = parts
+            .extensions
+            .get::<SupplierSession>()
+            .cloned()
+            .ok_or((StatusCode::UNAUTHORIZED, "Missing supplier session"))?;
+
+        if !supplier_session.0.active {
+            return Err((StatusCode::UNAUTHORIZED, "Expired session"));
+        }
+
+        Ok(supplier_session)
+    }
+}
@@ -7,6 +7,8 @@ pub mod extractors;
 pub mod file;
 pub mod into_error_response;
 pub mod money;
+pub mod shipping;
+pub mod tax;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -27,3 +29,10 @@ pub type PartId = String;
 pub type PartQuoteId = String;
 pub type OrderId = String;
 pub type FileId = String;
+pub type CatalogItemId = String;
+pub type QuotationRevisionId = String;
+pub type WebhookEventId = String;
+pub type InvoiceId = String;
+pub type SupplierId = String;
+pub type RfqId = String;
+pub type WorkOrderId = String;
@@ -1,7 +1,12 @@
+use crate::shared::error::Error;
+use crate::shared::Result;
 use iso_currency::Currency;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt::Display;
 
+/// Currencies quotations can be priced, and paid, in.
+pub const SUPPORTED_CURRENCIES: [Currency; 2] = [Currency::MXN, Currency::USD];
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Money {
     pub amount: i64,
@@ -12,6 +17,66 @@ impl Money {
     pub const fn new(amount: i64, currency: Currency) -> Self {
         Self { amount, currency }
     }
+
+    pub const fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    /// Adds two amounts of the same currency. Converting between currencies has to be done
+    /// explicitly with an exchange rate.
+    pub fn checked_add(&self, other: &Money) -> Result<Money> {
+        self.ensure_same_currency(other)?;
+        let amount = self
+            .amount
+            .checked_add(other.amount)
+            .ok_or_else(|| Self::overflow("add", self, other.amount))?;
+
+        Ok(Money::new(amount, self.currency))
+    }
+
+    pub fn checked_sub(&self, other: &Money) -> Result<Money> {
+        self.ensure_same_currency(other)?;
+        let amount = self
+            .amount
+            .checked_sub(other.amount)
+            .ok_or_else(|| Self::overflow("subtract", self, other.amount))?;
+
+        Ok(Money::new(amount, self.currency))
+    }
+
+    pub fn checked_mul(&self, quantity: u64) -> Result<Money> {
+        let amount = i64::try_from(quantity)
+            .ok()
+            .and_then(|quantity| self.amount.checked_mul(quantity))
+            .ok_or_else(|| Self::overflow("multiply", self, quantity as i64))?;
+
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// Sums amounts that must all be in `currency`, an empty iterator sums to zero.
+    pub fn checked_sum<'a>(
+        currency: Currency,
+        amounts: impl IntoIterator<Item = &'a Money>,
+    ) -> Result<Money> {
+        amounts
+            .into_iter()
+            .try_fold(Money::zero(currency), |total, amount| {
+                total.checked_add(amount)
+            })
+    }
+
+    fn ensure_same_currency(&self, other: &Money) -> Result<()> {
+        if self.currency != other.currency {
+            return Err(Error::CurrencyMismatch(self.currency, other.currency));
+        }
+
+        Ok(())
+    }
+
+    fn overflow(operation: &str, money: &Money, operand: i64) -> Error {
+        tracing::error!("{operation} overflowed for {money:?} and {operand}");
+        Error::UnknownError
+    }
 }
 
 impl Default for Money {
@@ -36,3 +101,16 @@ impl Display for Money {
         )
     }
 }
+
+/// Quotations created before they had a currency were all priced in MXN.
+pub fn default_currency() -> Currency {
+    Currency::MXN
+}
+
+pub fn validate_currency(currency: Currency) -> Result<Currency> {
+    if SUPPORTED_CURRENCIES.contains(&currency) {
+        Ok(currency)
+    } else {
+        Err(Error::UnsupportedCurrency(currency))
+    }
+}
@@ -0,0 +1,41 @@
+use crate::shared::money::Money;
+use serde_derive::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ShippingMethod {
+    Standard,
+    Express,
+    /// The customer picks the parts up at the shop.
+    Pickup,
+}
+
+impl ShippingMethod {
+    /// Name shown to customers at checkout and on documents.
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            ShippingMethod::Standard => "Standard shipping",
+            ShippingMethod::Express => "Express shipping",
+            ShippingMethod::Pickup => "Pickup at the shop",
+        }
+    }
+}
+
+/// Shipping method offered at checkout for a quotation, priced in the currency of the
+/// quotation.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ShippingOption {
+    pub method: ShippingMethod,
+    pub cost: Money,
+    /// Workdays the carrier takes once the parts are ready, not counting production.
+    pub min_workdays: u64,
+    pub max_workdays: u64,
+}
+
+/// Shipping method chosen at checkout and what the customer paid for it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ShippingCharge {
+    pub method: ShippingMethod,
+    pub cost: Money,
+}
@@ -0,0 +1,47 @@
+use crate::shared::money::Money;
+use crate::shared::Result;
+use serde_derive::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Country, and state within it, a quotation is shipped to. It decides the taxes added on top
+/// of the subtotal.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TaxJurisdiction {
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2)).
+    pub country: String,
+    pub state: Option<String>,
+}
+
+impl Display for TaxJurisdiction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.state {
+            Some(state) => write!(f, "{state}, {}", self.country),
+            None => write!(f, "{}", self.country),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct TaxLine {
+    pub name: String,
+    /// 0.16 for 16%.
+    pub rate: f64,
+    pub amount: Money,
+}
+
+impl TaxLine {
+    /// Rate as a percentage rounded to two decimals, for documents.
+    pub fn percentage(&self) -> f64 {
+        (self.rate * 10_000.0).round() / 100.0
+    }
+}
+
+/// Subtotal plus every tax line.
+pub fn total_with_taxes(subtotal: &Money, tax_lines: &[TaxLine]) -> Result<Money> {
+    let taxes = Money::checked_sum(
+        subtotal.currency,
+        tax_lines.iter().map(|tax_line| &tax_line.amount),
+    )?;
+
+    subtotal.checked_add(&taxes)
+}
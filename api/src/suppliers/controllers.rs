@@ -0,0 +1,455 @@
+use crate::app_state::AppState;
+use crate::orders::models::order::{OrderStatus, Shipment};
+use crate::orders::usecases::admin_update_order_status::AdminUpdateOrderStatus;
+use crate::parts::usecases::create_part_quotes::CreatePartQuotes;
+use crate::shared::extractors::session::{AdminSession, SupplierSession};
+use crate::shared::into_error_response::IntoError;
+use crate::shared::money::Money;
+use crate::shared::{CustomerId, OrderId, QuoteId, RfqId, SupplierId, UseCase};
+use crate::suppliers::models::inputs::{
+    AdminAssignOrderSupplierInput, AdminAwardRfqBidsInput, AdminCreateRfqsInput,
+    AdminCreateSupplierInput, AdminCreateSupplierInvitationInput,
+    AdminCreateSupplierOnboardingLinkInput, AdminQueryOrderMarginsInput, AdminQueryRfqsInput,
+    AdminQuerySuppliersInput, AdminTransferOrderPayoutInput, AdminUpdateOrderPayoutInput,
+    GetSupplierOrderInput, GetSupplierRfqInput, QuerySupplierOrdersInput, SubmitRfqBidsInput,
+    SupplierUpdateOrderStatusInput, UploadShippingDocumentInput,
+};
+use crate::suppliers::models::rfq::RfqBid;
+use crate::suppliers::usecases::admin_assign_order_supplier::AdminAssignOrderSupplier;
+use crate::suppliers::usecases::admin_award_rfq_bids::AdminAwardRfqBids;
+use crate::suppliers::usecases::admin_create_rfqs::AdminCreateRfqs;
+use crate::suppliers::usecases::admin_create_supplier::AdminCreateSupplier;
+use crate::suppliers::usecases::admin_create_supplier_invitation::AdminCreateSupplierInvitation;
+use crate::suppliers::usecases::admin_create_supplier_onboarding_link::AdminCreateSupplierOnboardingLink;
+use crate::suppliers::usecases::admin_query_order_margins::AdminQueryOrderMargins;
+use crate::suppliers::usecases::admin_query_rfqs::AdminQueryRfqs;
+use crate::suppliers::usecases::admin_query_suppliers::AdminQuerySuppliers;
+use crate::suppliers::usecases::admin_transfer_order_payout::AdminTransferOrderPayout;
+use crate::suppliers::usecases::admin_update_order_payout::AdminUpdateOrderPayout;
+use crate::suppliers::usecases::get_supplier_order::GetSupplierOrder;
+use crate::suppliers::usecases::get_supplier_rfq::GetSupplierRfq;
+use crate::suppliers::usecases::query_supplier_orders::QuerySupplierOrders;
+use crate::suppliers::usecases::submit_rfq_bids::SubmitRfqBids;
+use crate::suppliers::usecases::supplier_update_order_status::SupplierUpdateOrderStatus;
+use crate::suppliers::usecases::upload_shipping_document::UploadShippingDocument;
+use axum::extract::{Path, Query, State};
+use axum::response::IntoResponse;
+use axum::Json;
+use http::StatusCode;
+use serde_derive::{Deserialize, Serialize};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Deserialize)]
+pub struct AdminQuerySuppliersQueryParameters {
+    pub cursor: Option<String>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminAssignOrderSupplierRequest {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub supplier_id: SupplierId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdateOrderPayoutRequest {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub payout: Money,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminTransferOrderPayoutRequest {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+}
+
+#[derive(Deserialize)]
+pub struct AdminQueryOrderMarginsQueryParameters {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+}
+
+#[derive(Deserialize)]
+pub struct SupplierRfqQueryParameters {
+    pub token: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SubmitRfqBidsRequest {
+    pub bids: Vec<RfqBid>,
+}
+
+#[derive(Deserialize)]
+pub struct QuerySupplierOrdersQueryParameters {
+    pub cursor: Option<String>,
+    pub limit: Option<i32>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SupplierUpdateOrderStatusRequest {
+    pub order_id: OrderId,
+    pub status: OrderStatus,
+    pub shipment: Option<Shipment>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UploadShippingDocumentRequest {
+    pub file_name: String,
+}
+
+pub async fn admin_create_supplier(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminCreateSupplierInput>,
+) -> impl IntoResponse {
+    let usecase = AdminCreateSupplier::new(
+        app_state.suppliers.dynamodb_suppliers,
+        app_state.payments.stripe_client,
+    );
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::CREATED, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_query_suppliers(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Query(params): Query<AdminQuerySuppliersQueryParameters>,
+) -> impl IntoResponse {
+    let input = AdminQuerySuppliersInput {
+        cursor: params.cursor,
+        limit: params.limit.unwrap_or(10),
+    };
+    let usecase = AdminQuerySuppliers::new(app_state.suppliers.dynamodb_suppliers);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_create_supplier_onboarding_link(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Path(supplier_id): Path<SupplierId>,
+) -> impl IntoResponse {
+    let input = AdminCreateSupplierOnboardingLinkInput { supplier_id };
+    let usecase = AdminCreateSupplierOnboardingLink::new(
+        app_state.suppliers.dynamodb_suppliers,
+        app_state.payments.stripe_client,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_create_supplier_invitation(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Path(supplier_id): Path<SupplierId>,
+) -> impl IntoResponse {
+    let input = AdminCreateSupplierInvitationInput { supplier_id };
+    let usecase = AdminCreateSupplierInvitation::new(
+        app_state.suppliers.dynamodb_suppliers,
+        app_state.services.emailer.ses,
+        app_state.suppliers.invitation_url,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_assign_order_supplier(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminAssignOrderSupplierRequest>,
+) -> impl IntoResponse {
+    let input = AdminAssignOrderSupplierInput {
+        customer_id: request.customer_id,
+        order_id: request.order_id,
+        supplier_id: request.supplier_id,
+    };
+    let usecase = AdminAssignOrderSupplier::new(
+        app_state.orders.dynamodb_orders,
+        app_state.suppliers.dynamodb_suppliers,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_update_order_payout(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminUpdateOrderPayoutRequest>,
+) -> impl IntoResponse {
+    let input = AdminUpdateOrderPayoutInput {
+        customer_id: request.customer_id,
+        order_id: request.order_id,
+        payout: request.payout,
+    };
+    let usecase = AdminUpdateOrderPayout::new(app_state.orders.dynamodb_orders);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_transfer_order_payout(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminTransferOrderPayoutRequest>,
+) -> impl IntoResponse {
+    let input = AdminTransferOrderPayoutInput {
+        customer_id: request.customer_id,
+        order_id: request.order_id,
+    };
+    let usecase = AdminTransferOrderPayout::new(
+        app_state.orders.dynamodb_orders,
+        app_state.suppliers.dynamodb_suppliers,
+        app_state.payments.stripe_client,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_query_order_margins(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Query(params): Query<AdminQueryOrderMarginsQueryParameters>,
+) -> impl IntoResponse {
+    let input = AdminQueryOrderMarginsInput {
+        customer_id: params.customer_id,
+        quotation_id: params.quotation_id,
+    };
+    let usecase = AdminQueryOrderMargins::new(
+        app_state.orders.dynamodb_orders,
+        app_state.quotes.dynamodb_quotes,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_create_rfqs(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminCreateRfqsInput>,
+) -> impl IntoResponse {
+    let usecase = AdminCreateRfqs::new(
+        app_state.quotes.dynamodb_quotes,
+        app_state.parts.dynamodb_parts,
+        app_state.suppliers.dynamodb_suppliers,
+        app_state.suppliers.dynamodb_rfqs,
+        app_state.services.emailer.ses,
+        app_state.suppliers.rfq_url,
+    );
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::CREATED, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_query_rfqs(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Path((customer_id, quotation_id)): Path<(CustomerId, QuoteId)>,
+) -> impl IntoResponse {
+    let input = AdminQueryRfqsInput {
+        customer_id,
+        quotation_id,
+    };
+    let usecase = AdminQueryRfqs::new(app_state.suppliers.dynamodb_rfqs);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn admin_award_rfq_bids(
+    State(app_state): State<AppState>,
+    AdminSession(_): AdminSession,
+    Json(request): Json<AdminAwardRfqBidsInput>,
+) -> impl IntoResponse {
+    let create_part_quotes = CreatePartQuotes::new(
+        app_state.parts.dynamodb_parts,
+        app_state.quotes.dynamodb_quotes,
+        app_state.quotes.dynamodb_quotation_revisions,
+        app_state.promotions.dynamodb_pricing_agreements,
+        Arc::new(Mutex::new(app_state.payments.transaction)),
+        app_state.services.exchange_rates,
+    );
+    let usecase = AdminAwardRfqBids::new(
+        app_state.suppliers.dynamodb_rfqs,
+        Arc::new(create_part_quotes),
+        app_state.suppliers.rfq_margin_rate,
+    );
+    let result = usecase.execute(request).await;
+
+    match result {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn get_supplier_rfq(
+    State(app_state): State<AppState>,
+    Path((quotation_id, rfq_id)): Path<(QuoteId, RfqId)>,
+    Query(params): Query<SupplierRfqQueryParameters>,
+) -> impl IntoResponse {
+    let input = GetSupplierRfqInput {
+        quotation_id,
+        rfq_id,
+        access_token: params.token,
+    };
+    let usecase = GetSupplierRfq::new(app_state.suppliers.dynamodb_rfqs, app_state.parts.s3);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn submit_rfq_bids(
+    State(app_state): State<AppState>,
+    Path((quotation_id, rfq_id)): Path<(QuoteId, RfqId)>,
+    Query(params): Query<SupplierRfqQueryParameters>,
+    Json(request): Json<SubmitRfqBidsRequest>,
+) -> impl IntoResponse {
+    let input = SubmitRfqBidsInput {
+        quotation_id,
+        rfq_id,
+        access_token: params.token,
+        bids: request.bids,
+    };
+    let usecase = SubmitRfqBids::new(
+        app_state.suppliers.dynamodb_rfqs,
+        app_state.services.emailer.ses,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(_) => Ok(StatusCode::NO_CONTENT),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn query_supplier_orders(
+    State(app_state): State<AppState>,
+    SupplierSession(_, supplier_id): SupplierSession,
+    Query(params): Query<QuerySupplierOrdersQueryParameters>,
+) -> impl IntoResponse {
+    let input = QuerySupplierOrdersInput {
+        supplier_id,
+        cursor: params.cursor,
+        limit: params.limit.unwrap_or(10),
+    };
+    let usecase = QuerySupplierOrders::new(app_state.orders.dynamodb_orders);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn get_supplier_order(
+    State(app_state): State<AppState>,
+    SupplierSession(_, supplier_id): SupplierSession,
+    Path(order_id): Path<OrderId>,
+) -> impl IntoResponse {
+    let input = GetSupplierOrderInput {
+        supplier_id,
+        order_id,
+    };
+    let usecase = GetSupplierOrder::new(
+        app_state.orders.dynamodb_orders,
+        app_state.parts.dynamodb_parts,
+        app_state.parts.s3,
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn supplier_update_order_status(
+    State(app_state): State<AppState>,
+    SupplierSession(session, supplier_id): SupplierSession,
+    Json(request): Json<SupplierUpdateOrderStatusRequest>,
+) -> impl IntoResponse {
+    let input = SupplierUpdateOrderStatusInput {
+        identity: session.identity,
+        supplier_id,
+        order_id: request.order_id,
+        status: request.status,
+        shipment: request.shipment,
+    };
+    let update_order_status = AdminUpdateOrderStatus::new(
+        app_state.orders.dynamodb_orders.clone(),
+        app_state.auth.ory_kratos,
+        app_state.services.emailer.ses,
+    );
+    let usecase = SupplierUpdateOrderStatus::new(
+        app_state.orders.dynamodb_orders,
+        Arc::new(update_order_status),
+    );
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
+
+pub async fn upload_shipping_document(
+    State(app_state): State<AppState>,
+    SupplierSession(_, supplier_id): SupplierSession,
+    Path(order_id): Path<OrderId>,
+    Json(request): Json<UploadShippingDocumentRequest>,
+) -> impl IntoResponse {
+    let input = UploadShippingDocumentInput {
+        supplier_id,
+        order_id,
+        file_name: request.file_name,
+    };
+    let usecase = UploadShippingDocument::new(app_state.orders.dynamodb_orders, app_state.parts.s3);
+    let result = usecase.execute(input).await;
+
+    match result {
+        Ok(response) => Ok((StatusCode::CREATED, Json(response))),
+        Err(err) => Err(err.into_error_response()),
+    }
+}
@@ -0,0 +1,21 @@
+use crate::auth::models::session::IdentityId;
+use crate::shared::{QuoteId, RfqId, SupplierId};
+use crate::suppliers::models::rfq::{RfqBid, RfqStatus};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdatableRfq {
+    pub quotation_id: QuoteId,
+    pub id: RfqId,
+    pub status: Option<RfqStatus>,
+    /// Only saved while the RFQ still accepts bids.
+    pub bids: Option<Vec<RfqBid>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct UpdatableSupplier {
+    pub id: SupplierId,
+    pub invitation_token: Option<String>,
+    /// Linking the identity uses up the invitation, and only happens once.
+    pub identity_id: Option<IdentityId>,
+}
@@ -0,0 +1,128 @@
+use crate::auth::models::session::Identity;
+use crate::orders::models::order::{OrderStatus, Shipment};
+use crate::shared::money::Money;
+use crate::shared::{CustomerId, OrderId, PartId, QuoteId, RfqId, SupplierId};
+use crate::suppliers::models::rfq::RfqBid;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCreateSupplierInput {
+    pub name: String,
+    pub email: String,
+    pub country: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminQuerySuppliersInput {
+    pub cursor: Option<String>,
+    pub limit: i32,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCreateSupplierOnboardingLinkInput {
+    pub supplier_id: SupplierId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminAssignOrderSupplierInput {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub supplier_id: SupplierId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminUpdateOrderPayoutInput {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+    pub payout: Money,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminTransferOrderPayoutInput {
+    pub customer_id: CustomerId,
+    pub order_id: OrderId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminQueryOrderMarginsInput {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCreateRfqsInput {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+    pub supplier_ids: Vec<SupplierId>,
+    /// Parts to bid on, every part of the quotation when not given.
+    pub part_ids: Option<Vec<PartId>>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminQueryRfqsInput {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminAwardRfqBidsInput {
+    pub customer_id: CustomerId,
+    pub quotation_id: QuoteId,
+    pub awards: Vec<RfqAward>,
+}
+
+/// Bid of the RFQ chosen for a part.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RfqAward {
+    pub part_id: PartId,
+    pub rfq_id: RfqId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetSupplierRfqInput {
+    pub quotation_id: QuoteId,
+    pub rfq_id: RfqId,
+    pub access_token: String,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SubmitRfqBidsInput {
+    pub quotation_id: QuoteId,
+    pub rfq_id: RfqId,
+    pub access_token: String,
+    pub bids: Vec<RfqBid>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminCreateSupplierInvitationInput {
+    pub supplier_id: SupplierId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QuerySupplierOrdersInput {
+    pub supplier_id: SupplierId,
+    pub cursor: Option<String>,
+    pub limit: i32,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GetSupplierOrderInput {
+    pub supplier_id: SupplierId,
+    pub order_id: OrderId,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SupplierUpdateOrderStatusInput {
+    pub identity: Identity,
+    pub supplier_id: SupplierId,
+    pub order_id: OrderId,
+    pub status: OrderStatus,
+    pub shipment: Option<Shipment>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UploadShippingDocumentInput {
+    pub supplier_id: SupplierId,
+    pub order_id: OrderId,
+    pub file_name: String,
+}
@@ -0,0 +1,8 @@
+pub mod dynamodb_requests;
+pub mod inputs;
+pub mod order_margins;
+pub mod responses;
+pub mod rfq;
+pub mod rfq_bid_comparison;
+pub mod supplier;
+pub mod supplier_order;
@@ -0,0 +1,71 @@
+use crate::orders::models::order::{Order, OrderStatus};
+use crate::shared::money::Money;
+use crate::shared::{OrderId, PartId, Result, SupplierId};
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OrderMargin {
+    pub order_id: OrderId,
+    pub part_id: PartId,
+    pub status: OrderStatus,
+    pub supplier_id: Option<SupplierId>,
+    pub price: Option<Money>,
+    pub payout: Option<Money>,
+    /// Price minus payout, known once both are set.
+    pub margin: Option<Money>,
+}
+
+/// Margin of every order of a quotation. Totals only count orders with a known margin that
+/// weren't cancelled or refunded, and are empty when there are none.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct OrderMargins {
+    pub orders: Vec<OrderMargin>,
+    pub price: Option<Money>,
+    pub payout: Option<Money>,
+    pub margin: Option<Money>,
+}
+
+impl OrderMargins {
+    pub fn new(orders: &[Order]) -> Result<Self> {
+        let mut order_margins = Vec::with_capacity(orders.len());
+        let mut totals = None::<(Money, Money, Money)>;
+        for order in orders {
+            let margin = order.margin()?;
+            let is_active = !matches!(order.status, OrderStatus::Cancelled | OrderStatus::Refunded);
+            if let (Some(price), Some(payout), Some(margin), true) =
+                (&order.price, &order.payout, &margin, is_active)
+            {
+                totals = Some(match totals {
+                    Some((total_price, total_payout, total_margin)) => (
+                        total_price.checked_add(price)?,
+                        total_payout.checked_add(payout)?,
+                        total_margin.checked_add(margin)?,
+                    ),
+                    None => (price.clone(), payout.clone(), margin.clone()),
+                });
+            }
+
+            order_margins.push(OrderMargin {
+                order_id: order.id.clone(),
+                part_id: order.part_id.clone(),
+                status: order.status.clone(),
+                supplier_id: order.supplier_id.clone(),
+                price: order.price.clone(),
+                payout: order.payout.clone(),
+                margin,
+            });
+        }
+
+        let (price, payout, margin) = match totals {
+            Some((price, payout, margin)) => (Some(price), Some(payout), Some(margin)),
+            None => (None, None, None),
+        };
+
+        Ok(Self {
+            orders: order_margins,
+            price,
+            payout,
+            margin,
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::parts::models::part::{PartAttributes, PartProcess};
+use crate::shared::file::File;
+use crate::shared::{PartId, QuoteId, RfqId};
+use crate::suppliers::models::rfq::{Rfq, RfqBid, RfqStatus};
+use crate::suppliers::models::rfq_bid_comparison::RfqBidComparison;
+use crate::suppliers::models::supplier::Supplier;
+use crate::suppliers::models::supplier_order::SupplierOrder;
+use chrono::{DateTime, Utc};
+use iso_currency::Currency;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminQuerySuppliersResponse {
+    pub suppliers: Vec<Supplier>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct CreateSupplierOnboardingLinkResponse {
+    pub url: String,
+}
+
+impl CreateSupplierOnboardingLinkResponse {
+    pub const fn new(url: String) -> Self {
+        Self { url }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AdminQueryRfqsResponse {
+    pub rfqs: Vec<Rfq>,
+    pub comparison: RfqBidComparison,
+}
+
+/// RFQ as the supplier sees it, without the customer or the access token.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SupplierRfqResponse {
+    pub id: RfqId,
+    pub quotation_id: QuoteId,
+    pub status: RfqStatus,
+    pub currency: Currency,
+    pub parts: Vec<SupplierPart>,
+    pub bids: Vec<RfqBid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Part as suppliers see it, with links to download its files.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SupplierPart {
+    pub part_id: PartId,
+    pub process: PartProcess,
+    pub attributes: PartAttributes,
+    pub quantity: u64,
+    pub additional_notes: String,
+    pub model_file_url: String,
+    pub drawing_file_url: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct QuerySupplierOrdersResponse {
+    pub orders: Vec<SupplierOrder>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SupplierOrderResponse {
+    pub order: SupplierOrder,
+    pub part: SupplierPart,
+}
+
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UploadShippingDocumentResponse {
+    pub upload_url: String,
+    pub file: File,
+}
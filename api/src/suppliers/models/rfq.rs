@@ -0,0 +1,185 @@
+use crate::parts::models::part::{Part, PartAttributes, PartProcess};
+use crate::quotations::models::quotation::Quotation;
+use crate::shared::error::Error;
+use crate::shared::file::File;
+use crate::shared::money::Money;
+use crate::shared::{CustomerId, PartId, ProjectId, QuoteId, Result, RfqId, SupplierId};
+use chrono::{DateTime, Utc};
+use iso_currency::Currency;
+use serde_derive::{Deserialize, Serialize};
+use serde_enum_str::{Deserialize_enum_str, Serialize_enum_str};
+use std::collections::HashSet;
+use uuid::{ContextV7, Timestamp, Uuid};
+
+/// Request for quotation sent to one supplier with the parts of a quotation pending review.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Rfq {
+    pub id: RfqId,
+    pub customer_id: CustomerId,
+    pub project_id: ProjectId,
+    pub quotation_id: QuoteId,
+    pub supplier_id: SupplierId,
+    pub status: RfqStatus,
+    /// Bids are made in the quotation's currency so they can be compared and priced as is.
+    pub currency: Currency,
+    pub parts: Vec<RfqPart>,
+    /// Latest bid of the supplier, at most one per part.
+    pub bids: Vec<RfqBid>,
+    /// Secret sent to the supplier to see the RFQ and bid on it.
+    pub access_token: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize_enum_str, Deserialize_enum_str, Clone, Debug, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RfqStatus {
+    Open,
+    Bid,
+    /// At least one of its bids was turned into the customer's part quotes.
+    Awarded,
+    /// The quotation was priced with bids of other suppliers.
+    Closed,
+}
+
+/// What the supplier needs to know about a part to bid on it, without the customer's identity
+/// or prices.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RfqPart {
+    pub part_id: PartId,
+    pub process: PartProcess,
+    pub attributes: PartAttributes,
+    pub quantity: u64,
+    pub model_file: File,
+    pub drawing_file: Option<File>,
+    pub additional_notes: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RfqBid {
+    pub part_id: PartId,
+    pub unit_price: Money,
+    pub sub_total: Money,
+    pub workdays_to_complete: u64,
+}
+
+impl Rfq {
+    pub fn new(quotation: &Quotation, supplier_id: SupplierId, parts: &[Part]) -> Self {
+        let now = Utc::now();
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let encoded_id = format!("rfq_{}", bs58::encode(id).into_string());
+        let access_token = bs58::encode(Uuid::new_v4()).into_string();
+
+        Self {
+            id: encoded_id,
+            customer_id: quotation.customer_id.clone(),
+            project_id: quotation.project_id.clone(),
+            quotation_id: quotation.id.clone(),
+            supplier_id,
+            status: RfqStatus::Open,
+            currency: quotation.currency,
+            parts: parts.iter().map(RfqPart::from).collect(),
+            bids: Vec::new(),
+            access_token,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn verify_access_token(&self, access_token: &str) -> Result<()> {
+        if self.access_token != access_token {
+            return Err(Error::Forbidden);
+        }
+
+        Ok(())
+    }
+
+    pub fn accepts_bids(&self) -> bool {
+        matches!(self.status, RfqStatus::Open | RfqStatus::Bid)
+    }
+
+    pub fn bid_for(&self, part_id: &PartId) -> Option<&RfqBid> {
+        self.bids.iter().find(|bid| &bid.part_id == part_id)
+    }
+
+    pub fn part(&self, part_id: &PartId) -> Option<&RfqPart> {
+        self.parts.iter().find(|part| &part.part_id == part_id)
+    }
+
+    /// Bids replace the previous ones, so the supplier can leave out parts it doesn't make.
+    pub fn validate_bids(&self, bids: &[RfqBid]) -> Result<()> {
+        if !self.accepts_bids() {
+            return Err(Error::RfqClosed(self.id.clone()));
+        }
+        if bids.is_empty() {
+            return Err(Error::InvalidRfqBid(String::from(
+                "At least one part has to be bid on",
+            )));
+        }
+
+        let mut part_ids = HashSet::new();
+        for bid in bids {
+            if !self.parts.iter().any(|part| part.part_id == bid.part_id) {
+                return Err(Error::InvalidRfqBid(format!(
+                    "Part `{}` isn't part of the RFQ",
+                    bid.part_id
+                )));
+            }
+            if !part_ids.insert(&bid.part_id) {
+                return Err(Error::InvalidRfqBid(format!(
+                    "Part `{}` was bid on more than once",
+                    bid.part_id
+                )));
+            }
+            for price in [&bid.unit_price, &bid.sub_total] {
+                if price.currency != self.currency {
+                    return Err(Error::CurrencyMismatch(self.currency, price.currency));
+                }
+                if price.amount <= 0 {
+                    return Err(Error::InvalidRfqBid(String::from(
+                        "Prices must be greater than zero",
+                    )));
+                }
+            }
+            if bid.workdays_to_complete == 0 {
+                return Err(Error::InvalidRfqBid(String::from(
+                    "The lead time must be at least one workday",
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl RfqBid {
+    /// Customer price of the bid for `quantity` parts, the supplier's unit price marked up by
+    /// `margin_rate` and rounded to the minor unit. The subtotal is recalculated from it.
+    pub fn with_margin(&self, margin_rate: f64, quantity: u64) -> Result<RfqBid> {
+        let unit_price = Money::new(
+            (self.unit_price.amount as f64 * (1.0 + margin_rate)).round() as i64,
+            self.unit_price.currency,
+        );
+
+        Ok(RfqBid {
+            part_id: self.part_id.clone(),
+            sub_total: unit_price.checked_mul(quantity)?,
+            unit_price,
+            workdays_to_complete: self.workdays_to_complete,
+        })
+    }
+}
+
+impl From<&Part> for RfqPart {
+    fn from(part: &Part) -> Self {
+        Self {
+            part_id: part.id.clone(),
+            process: part.process.clone(),
+            attributes: part.attributes.clone(),
+            quantity: part.quantity,
+            model_file: part.model_file.clone(),
+            drawing_file: part.drawing_file.clone(),
+            additional_notes: part.additional_notes.clone(),
+        }
+    }
+}
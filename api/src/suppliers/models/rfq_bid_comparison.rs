@@ -0,0 +1,75 @@
+use crate::shared::money::Money;
+use crate::shared::{PartId, RfqId, SupplierId};
+use crate::suppliers::models::rfq::Rfq;
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SupplierBid {
+    pub rfq_id: RfqId,
+    pub supplier_id: SupplierId,
+    pub unit_price: Money,
+    pub sub_total: Money,
+    pub workdays_to_complete: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PartBids {
+    pub part_id: PartId,
+    /// Cheapest first, ties go to the shortest lead time.
+    pub bids: Vec<SupplierBid>,
+}
+
+/// Bids of every supplier of a quotation side by side, per part.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RfqBidComparison {
+    pub parts: Vec<PartBids>,
+    /// Suppliers that haven't bid yet.
+    pub pending_suppliers: Vec<SupplierId>,
+}
+
+impl RfqBidComparison {
+    pub fn new(rfqs: &[Rfq]) -> Self {
+        let mut parts: Vec<PartBids> = Vec::new();
+        let mut pending_suppliers = Vec::new();
+
+        for rfq in rfqs {
+            if rfq.bids.is_empty() {
+                pending_suppliers.push(rfq.supplier_id.clone());
+            }
+            for rfq_part in &rfq.parts {
+                let index = match parts
+                    .iter()
+                    .position(|part| part.part_id == rfq_part.part_id)
+                {
+                    Some(index) => index,
+                    None => {
+                        parts.push(PartBids {
+                            part_id: rfq_part.part_id.clone(),
+                            bids: Vec::new(),
+                        });
+                        parts.len() - 1
+                    }
+                };
+                if let Some(bid) = rfq.bid_for(&rfq_part.part_id) {
+                    parts[index].bids.push(SupplierBid {
+                        rfq_id: rfq.id.clone(),
+                        supplier_id: rfq.supplier_id.clone(),
+                        unit_price: bid.unit_price.clone(),
+                        sub_total: bid.sub_total.clone(),
+                        workdays_to_complete: bid.workdays_to_complete,
+                    });
+                }
+            }
+        }
+
+        for part in parts.iter_mut() {
+            part.bids
+                .sort_by_key(|bid| (bid.sub_total.amount, bid.workdays_to_complete));
+        }
+
+        Self {
+            parts,
+            pending_suppliers,
+        }
+    }
+}
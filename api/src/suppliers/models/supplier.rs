@@ -0,0 +1,75 @@
+use crate::auth::models::session::IdentityId;
+use crate::shared::error::Error;
+use crate::shared::{Result, SupplierId};
+use chrono::{DateTime, Utc};
+use serde_derive::{Deserialize, Serialize};
+use uuid::{ContextV7, Timestamp, Uuid};
+
+/// Shop that makes the parts of orders assigned to it, paid out through Stripe Connect.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Supplier {
+    pub id: SupplierId,
+    pub name: String,
+    /// Where Stripe sends the link to set up the connected account.
+    pub email: String,
+    /// Two-letter country code ([ISO 3166-1 alpha-2](https://en.wikipedia.org/wiki/ISO_3166-1_alpha-2))
+    /// of the connected account, it can't change once the account exists.
+    pub country: String,
+    /// Express connected account payouts are transferred to.
+    pub stripe_account_id: Option<String>,
+    /// Identity the supplier signs in to the supplier portal with.
+    pub identity_id: Option<IdentityId>,
+    /// Secret sent to the supplier to create its portal account, cleared once it's used.
+    pub invitation_token: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Supplier {
+    pub fn new(name: String, email: String, country: String) -> Self {
+        let now = Utc::now();
+        let id = Uuid::new_v7(Timestamp::now(ContextV7::new()));
+        let encoded_id = format!("sup_{}", bs58::encode(id).into_string());
+
+        Self {
+            id: encoded_id,
+            name: name.trim().to_string(),
+            email: email.trim().to_lowercase(),
+            country: country.trim().to_uppercase(),
+            stripe_account_id: None,
+            identity_id: None,
+            invitation_token: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        if self.name.is_empty() {
+            return Err(Error::InvalidSupplier(String::from("The name is required")));
+        }
+        let is_email = self
+            .email
+            .split_once('@')
+            .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'));
+        if !is_email {
+            return Err(Error::InvalidSupplier(String::from(
+                "The email must be a valid email address",
+            )));
+        }
+        if self.country.len() != 2 || !self.country.chars().all(|c| c.is_ascii_uppercase()) {
+            return Err(Error::InvalidSupplier(String::from(
+                "The country must be a two-letter country code",
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn verify_invitation_token(&self, invitation_token: &str) -> Result<()> {
+        match &self.invitation_token {
+            Some(token) if self.identity_id.is_none() && token == invitation_token => Ok(()),
+            _ => Err(Error::Forbidden),
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use crate::orders::models::order::{Address, Order, OrderStatus, Shipment};
+use crate::shared::file::File;
+use crate::shared::money::Money;
+use crate::shared::shipping::ShippingMethod;
+use crate::shared::{OrderId, PartId};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_derive::{Deserialize, Serialize};
+
+/// Order as the supplier making it sees it. The customer is only known through the shipping
+/// data, and what the customer paid is left out.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct SupplierOrder {
+    pub id: OrderId,
+    pub part_id: PartId,
+    pub deadline: NaiveDate,
+    pub status: OrderStatus,
+    pub shipping_recipient_name: String,
+    pub shipping_address: Address,
+    pub shipping_method: Option<ShippingMethod>,
+    pub shipment: Option<Shipment>,
+    pub shipping_documents: Vec<File>,
+    /// What the supplier gets paid for the order.
+    pub payout: Option<Money>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Order> for SupplierOrder {
+    fn from(order: Order) -> Self {
+        Self {
+            id: order.id,
+            part_id: order.part_id,
+            deadline: order.deadline,
+            status: order.status,
+            shipping_recipient_name: order.shipping_recipient_name,
+            shipping_address: order.shipping_address,
+            shipping_method: order.shipping.map(|shipping| shipping.method),
+            shipment: order.shipment,
+            shipping_documents: order.shipping_documents,
+            payout: order.payout,
+            created_at: order.created_at,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use axum::routing::{get, patch, post, put};
+use axum::Router;
+
+use crate::app_state::AppState;
+use crate::suppliers::controllers::{
+    admin_assign_order_supplier, admin_award_rfq_bids, admin_create_rfqs, admin_create_supplier,
+    admin_create_supplier_invitation, admin_create_supplier_onboarding_link,
+    admin_query_order_margins, admin_query_rfqs, admin_query_suppliers,
+    admin_transfer_order_payout, admin_update_order_payout, get_supplier_order, get_supplier_rfq,
+    query_supplier_orders, submit_rfq_bids, supplier_update_order_status, upload_shipping_document,
+};
+
+/// RFQ routes suppliers reach with the access token sent to them.
+pub fn create_public_router() -> Router<AppState> {
+    Router::new()
+        .route("/rfqs/:quotation_id/:rfq_id", get(get_supplier_rfq))
+        .route("/rfqs/:quotation_id/:rfq_id/bids", put(submit_rfq_bids))
+}
+
+pub fn create_private_router() -> Router<AppState> {
+    Router::new()
+        .route("/admin/suppliers", post(admin_create_supplier))
+        .route("/admin/suppliers", get(admin_query_suppliers))
+        .route(
+            "/admin/suppliers/:supplier_id/onboarding_link",
+            post(admin_create_supplier_onboarding_link),
+        )
+        .route(
+            "/admin/suppliers/:supplier_id/invitation",
+            post(admin_create_supplier_invitation),
+        )
+        .route("/admin/orders/supplier", patch(admin_assign_order_supplier))
+        .route("/admin/orders/payout", patch(admin_update_order_payout))
+        .route(
+            "/admin/orders/payout/transfer",
+            post(admin_transfer_order_payout),
+        )
+        .route("/admin/orders/margins", get(admin_query_order_margins))
+        .route("/admin/rfqs", post(admin_create_rfqs))
+        .route("/admin/rfqs/award", post(admin_award_rfq_bids))
+        .route(
+            "/admin/customers/:customer_id/quotations/:quotation_id/rfqs",
+            get(admin_query_rfqs),
+        )
+        .route("/supplier/orders", get(query_supplier_orders))
+        .route(
+            "/supplier/orders/status",
+            patch(supplier_update_order_status),
+        )
+        .route("/supplier/orders/:order_id", get(get_supplier_order))
+        .route(
+            "/supplier/orders/:order_id/shipping_documents",
+            post(upload_shipping_document),
+        )
+}
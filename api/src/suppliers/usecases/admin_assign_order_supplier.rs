@@ -0,0 +1,56 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::order::Order;
+use crate::repositories::orders::OrdersRepository;
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminAssignOrderSupplierInput;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Assigns the order to another supplier until its payout is transferred.
+pub struct AdminAssignOrderSupplier<O, S>
+where
+    O: OrdersRepository,
+    S: SuppliersRepository,
+{
+    orders_repository: Arc<O>,
+    suppliers_repository: Arc<S>,
+}
+
+impl<O, S> AdminAssignOrderSupplier<O, S>
+where
+    O: OrdersRepository,
+    S: SuppliersRepository,
+{
+    pub fn new(orders_repository: Arc<O>, suppliers_repository: Arc<S>) -> Self {
+        Self {
+            orders_repository,
+            suppliers_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, S> UseCase<AdminAssignOrderSupplierInput, Order> for AdminAssignOrderSupplier<O, S>
+where
+    O: OrdersRepository,
+    S: SuppliersRepository,
+{
+    async fn execute(&self, input: AdminAssignOrderSupplierInput) -> Result<Order> {
+        let order = self
+            .orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+        if order.payout_transfer_id.is_some() {
+            return Err(Error::OrderPayoutAlreadyTransferred(order.id));
+        }
+        let supplier = self.suppliers_repository.get(input.supplier_id).await?;
+
+        let mut updatable_order =
+            UpdatableOrder::partial_new(order.customer_id, order.id, order.created_at);
+        updatable_order.supplier_id = Some(supplier.id);
+
+        self.orders_repository.update(updatable_order).await
+    }
+}
@@ -0,0 +1,132 @@
+use crate::parts::models::inputs::{CreatePartQuotesInput, CreatePartQuotesInputData};
+use crate::repositories::rfqs::RfqsRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::dynamodb_requests::UpdatableRfq;
+use crate::suppliers::models::inputs::AdminAwardRfqBidsInput;
+use crate::suppliers::models::rfq::RfqStatus;
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Prices a quotation with the chosen bids plus the margin, the same way admins price it by
+/// hand, then closes the RFQs of the quotation.
+pub struct AdminAwardRfqBids<R>
+where
+    R: RfqsRepository,
+{
+    rfqs_repository: Arc<R>,
+    create_part_quotes: Arc<dyn UseCase<CreatePartQuotesInput, ()> + Send + Sync>,
+    margin_rate: f64,
+}
+
+impl<R> AdminAwardRfqBids<R>
+where
+    R: RfqsRepository,
+{
+    pub fn new(
+        rfqs_repository: Arc<R>,
+        create_part_quotes: Arc<dyn UseCase<CreatePartQuotesInput, ()> + Send + Sync>,
+        margin_rate: f64,
+    ) -> Self {
+        Self {
+            rfqs_repository,
+            create_part_quotes,
+            margin_rate,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase<AdminAwardRfqBidsInput, ()> for AdminAwardRfqBids<R>
+where
+    R: RfqsRepository,
+{
+    async fn execute(&self, input: AdminAwardRfqBidsInput) -> Result<()> {
+        let rfqs: Vec<_> = self
+            .rfqs_repository
+            .query(input.quotation_id.clone())
+            .await?
+            .into_iter()
+            .filter(|rfq| rfq.customer_id == input.customer_id)
+            .collect();
+        if input.awards.is_empty() {
+            return Err(Error::InvalidRfqBid(String::from(
+                "At least one bid has to be awarded",
+            )));
+        }
+
+        let mut part_ids = HashSet::new();
+        let mut awarded_rfq_ids = HashSet::new();
+        let mut data = Vec::with_capacity(input.awards.len());
+        for award in &input.awards {
+            if !part_ids.insert(&award.part_id) {
+                return Err(Error::InvalidRfqBid(format!(
+                    "Part `{}` was awarded more than once",
+                    award.part_id
+                )));
+            }
+            let rfq = rfqs
+                .iter()
+                .find(|rfq| rfq.id == award.rfq_id)
+                .ok_or(Error::ItemNotFoundError)?;
+            if !rfq.accepts_bids() {
+                return Err(Error::RfqClosed(rfq.id.clone()));
+            }
+            // Bids are only accepted on the parts of the RFQ.
+            let quantity = rfq
+                .part(&award.part_id)
+                .map(|part| part.quantity)
+                .ok_or(Error::ItemNotFoundError)?;
+            let bid = rfq
+                .bid_for(&award.part_id)
+                .ok_or_else(|| {
+                    Error::InvalidRfqBid(format!(
+                        "Supplier `{}` didn't bid on part `{}`",
+                        rfq.supplier_id, award.part_id
+                    ))
+                })?
+                .with_margin(self.margin_rate, quantity)?;
+
+            awarded_rfq_ids.insert(rfq.id.clone());
+            data.push(CreatePartQuotesInputData {
+                part_id: bid.part_id,
+                unit_price: bid.unit_price,
+                sub_total: bid.sub_total,
+                workdays_to_complete: bid.workdays_to_complete,
+                quantity_tiers: None,
+            });
+        }
+
+        let project_id = rfqs
+            .first()
+            .map(|rfq| rfq.project_id.clone())
+            .ok_or(Error::ItemNotFoundError)?;
+        self.create_part_quotes
+            .execute(CreatePartQuotesInput {
+                customer_id: input.customer_id,
+                project_id,
+                quotation_id: input.quotation_id,
+                data,
+            })
+            .await?;
+
+        for rfq in rfqs.into_iter().filter(|rfq| rfq.accepts_bids()) {
+            let status = if awarded_rfq_ids.contains(&rfq.id) {
+                RfqStatus::Awarded
+            } else {
+                RfqStatus::Closed
+            };
+            self.rfqs_repository
+                .update(UpdatableRfq {
+                    quotation_id: rfq.quotation_id,
+                    id: rfq.id,
+                    status: Some(status),
+                    bids: None,
+                })
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,153 @@
+use crate::quotations::models::quotation::QuoteStatus;
+use crate::repositories::parts::PartsRepository;
+use crate::repositories::quotes::QuotesRepository;
+use crate::repositories::rfqs::RfqsRepository;
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::services::emailer::Emailer;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminCreateRfqsInput;
+use crate::suppliers::models::rfq::Rfq;
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// Sends the parts of a quotation pending review to suppliers so they bid on them.
+pub struct AdminCreateRfqs<Q, P, S, R>
+where
+    Q: QuotesRepository,
+    P: PartsRepository,
+    S: SuppliersRepository,
+    R: RfqsRepository,
+{
+    quotes_repository: Arc<Q>,
+    parts_repository: Arc<P>,
+    suppliers_repository: Arc<S>,
+    rfqs_repository: Arc<R>,
+    emailer_service: Arc<dyn Emailer>,
+    rfq_url: String,
+}
+
+impl<Q, P, S, R> AdminCreateRfqs<Q, P, S, R>
+where
+    Q: QuotesRepository,
+    P: PartsRepository,
+    S: SuppliersRepository,
+    R: RfqsRepository,
+{
+    pub fn new(
+        quotes_repository: Arc<Q>,
+        parts_repository: Arc<P>,
+        suppliers_repository: Arc<S>,
+        rfqs_repository: Arc<R>,
+        emailer_service: Arc<dyn Emailer>,
+        rfq_url: String,
+    ) -> Self {
+        Self {
+            quotes_repository,
+            parts_repository,
+            suppliers_repository,
+            rfqs_repository,
+            emailer_service,
+            rfq_url,
+        }
+    }
+}
+
+#[async_trait]
+impl<Q, P, S, R> UseCase<AdminCreateRfqsInput, Vec<Rfq>> for AdminCreateRfqs<Q, P, S, R>
+where
+    Q: QuotesRepository,
+    P: PartsRepository,
+    S: SuppliersRepository,
+    R: RfqsRepository,
+{
+    async fn execute(&self, input: AdminCreateRfqsInput) -> Result<Vec<Rfq>> {
+        let quotation = self
+            .quotes_repository
+            .get(input.customer_id.clone(), input.quotation_id.clone())
+            .await?;
+        if quotation.status != QuoteStatus::PendingReview {
+            return Err(Error::InvalidRfq(String::from(
+                "Only quotations pending review can be sent out for bids",
+            )));
+        }
+
+        let mut parts = self
+            .parts_repository
+            .query(
+                input.customer_id.clone(),
+                input.quotation_id.clone(),
+                None,
+                100,
+            )
+            .await?
+            .data;
+        if let Some(part_ids) = &input.part_ids {
+            if let Some(part_id) = part_ids
+                .iter()
+                .find(|part_id| !parts.iter().any(|part| &part.id == *part_id))
+            {
+                return Err(Error::InvalidRfq(format!(
+                    "Part `{part_id}` doesn't belong to the quotation"
+                )));
+            }
+            parts.retain(|part| part_ids.contains(&part.id));
+        }
+        if parts.is_empty() {
+            return Err(Error::InvalidRfq(String::from(
+                "At least one part has to be sent out",
+            )));
+        }
+
+        let supplier_ids: HashSet<_> = input.supplier_ids.into_iter().collect();
+        if supplier_ids.is_empty() {
+            return Err(Error::InvalidRfq(String::from(
+                "At least one supplier has to be selected",
+            )));
+        }
+
+        // A supplier only bids once per quotation until its RFQ is awarded or closed.
+        let existing_rfqs = self.rfqs_repository.query(quotation.id.clone()).await?;
+        if let Some(rfq) = existing_rfqs
+            .iter()
+            .find(|rfq| rfq.accepts_bids() && supplier_ids.contains(&rfq.supplier_id))
+        {
+            return Err(Error::InvalidRfq(format!(
+                "Supplier `{}` already has an open RFQ for the quotation",
+                rfq.supplier_id
+            )));
+        }
+
+        let mut suppliers = Vec::with_capacity(supplier_ids.len());
+        for supplier_id in supplier_ids {
+            suppliers.push(self.suppliers_repository.get(supplier_id).await?);
+        }
+
+        let mut rfqs = Vec::with_capacity(suppliers.len());
+        for supplier in suppliers {
+            let rfq = Rfq::new(&quotation, supplier.id.clone(), &parts);
+            self.rfqs_repository.create(rfq.clone()).await?;
+
+            let _ = self
+                .emailer_service
+                .send_email(
+                    &supplier.email,
+                    "New request for quotation",
+                    &format!(
+                        "You've been invited to bid on {} part(s). Send your prices and lead times at {}/{}/{}?token={}",
+                        rfq.parts.len(),
+                        self.rfq_url,
+                        rfq.quotation_id,
+                        rfq.id,
+                        rfq.access_token
+                    ),
+                )
+                .await;
+
+            rfqs.push(rfq);
+        }
+
+        Ok(rfqs)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::services::stripe_client::StripeClient;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminCreateSupplierInput;
+use crate::suppliers::models::supplier::Supplier;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminCreateSupplier<S>
+where
+    S: SuppliersRepository,
+{
+    suppliers_repository: Arc<S>,
+    stripe_client: Arc<dyn StripeClient>,
+}
+
+impl<S> AdminCreateSupplier<S>
+where
+    S: SuppliersRepository,
+{
+    pub fn new(suppliers_repository: Arc<S>, stripe_client: Arc<dyn StripeClient>) -> Self {
+        Self {
+            suppliers_repository,
+            stripe_client,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> UseCase<AdminCreateSupplierInput, Supplier> for AdminCreateSupplier<S>
+where
+    S: SuppliersRepository,
+{
+    async fn execute(&self, input: AdminCreateSupplierInput) -> Result<Supplier> {
+        let mut supplier = Supplier::new(input.name, input.email, input.country);
+        supplier.validate()?;
+
+        supplier.stripe_account_id = Some(
+            self.stripe_client
+                .create_connected_account(supplier.email.clone(), supplier.country.clone())
+                .await?,
+        );
+        self.suppliers_repository.create(supplier.clone()).await?;
+
+        Ok(supplier)
+    }
+}
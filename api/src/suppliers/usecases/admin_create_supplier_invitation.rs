@@ -0,0 +1,72 @@
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::services::emailer::Emailer;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::dynamodb_requests::UpdatableSupplier;
+use crate::suppliers::models::inputs::AdminCreateSupplierInvitationInput;
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Invites a supplier to create its supplier portal account. A new invitation replaces the
+/// previous one.
+pub struct AdminCreateSupplierInvitation<S>
+where
+    S: SuppliersRepository,
+{
+    suppliers_repository: Arc<S>,
+    emailer_service: Arc<dyn Emailer>,
+    invitation_url: String,
+}
+
+impl<S> AdminCreateSupplierInvitation<S>
+where
+    S: SuppliersRepository,
+{
+    pub fn new(
+        suppliers_repository: Arc<S>,
+        emailer_service: Arc<dyn Emailer>,
+        invitation_url: String,
+    ) -> Self {
+        Self {
+            suppliers_repository,
+            emailer_service,
+            invitation_url,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> UseCase<AdminCreateSupplierInvitationInput, ()> for AdminCreateSupplierInvitation<S>
+where
+    S: SuppliersRepository,
+{
+    async fn execute(&self, input: AdminCreateSupplierInvitationInput) -> Result<()> {
+        let supplier = self.suppliers_repository.get(input.supplier_id).await?;
+        if supplier.identity_id.is_some() {
+            return Err(Error::InvalidSupplier(String::from(
+                "The supplier already has a portal account",
+            )));
+        }
+
+        let invitation_token = bs58::encode(Uuid::new_v4()).into_string();
+        self.suppliers_repository
+            .update(UpdatableSupplier {
+                id: supplier.id.clone(),
+                invitation_token: Some(invitation_token.clone()),
+                identity_id: None,
+            })
+            .await?;
+
+        self.emailer_service
+            .send_email(
+                &supplier.email,
+                "Create your supplier portal account",
+                &format!(
+                    "You've been invited to the supplier portal. Create your account at {}?supplier_id={}&token={}",
+                    self.invitation_url, supplier.id, invitation_token
+                ),
+            )
+            .await
+    }
+}
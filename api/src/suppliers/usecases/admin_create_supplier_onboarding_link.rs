@@ -0,0 +1,54 @@
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::services::stripe_client::StripeClient;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminCreateSupplierOnboardingLinkInput;
+use crate::suppliers::models::responses::CreateSupplierOnboardingLinkResponse;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Links expire within minutes, admins create a new one every time they share it with the
+/// supplier.
+pub struct AdminCreateSupplierOnboardingLink<S>
+where
+    S: SuppliersRepository,
+{
+    suppliers_repository: Arc<S>,
+    stripe_client: Arc<dyn StripeClient>,
+}
+
+impl<S> AdminCreateSupplierOnboardingLink<S>
+where
+    S: SuppliersRepository,
+{
+    pub fn new(suppliers_repository: Arc<S>, stripe_client: Arc<dyn StripeClient>) -> Self {
+        Self {
+            suppliers_repository,
+            stripe_client,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> UseCase<AdminCreateSupplierOnboardingLinkInput, CreateSupplierOnboardingLinkResponse>
+    for AdminCreateSupplierOnboardingLink<S>
+where
+    S: SuppliersRepository,
+{
+    async fn execute(
+        &self,
+        input: AdminCreateSupplierOnboardingLinkInput,
+    ) -> Result<CreateSupplierOnboardingLinkResponse> {
+        let supplier = self.suppliers_repository.get(input.supplier_id).await?;
+        let stripe_account_id = supplier
+            .stripe_account_id
+            .ok_or(Error::SupplierNotOnboarded(supplier.id))?;
+
+        let url = self
+            .stripe_client
+            .create_account_onboarding_link(stripe_account_id)
+            .await?;
+
+        Ok(CreateSupplierOnboardingLinkResponse::new(url))
+    }
+}
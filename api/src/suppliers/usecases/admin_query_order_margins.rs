@@ -0,0 +1,61 @@
+use crate::repositories::orders::{OrdersRepository, QueryBy};
+use crate::repositories::quotes::QuotesRepository;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminQueryOrderMarginsInput;
+use crate::suppliers::models::order_margins::OrderMargins;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminQueryOrderMargins<O, Q>
+where
+    O: OrdersRepository,
+    Q: QuotesRepository,
+{
+    orders_repository: Arc<O>,
+    quotes_repository: Arc<Q>,
+}
+
+impl<O, Q> AdminQueryOrderMargins<O, Q>
+where
+    O: OrdersRepository,
+    Q: QuotesRepository,
+{
+    pub fn new(orders_repository: Arc<O>, quotes_repository: Arc<Q>) -> Self {
+        Self {
+            orders_repository,
+            quotes_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, Q> UseCase<AdminQueryOrderMarginsInput, OrderMargins> for AdminQueryOrderMargins<O, Q>
+where
+    O: OrdersRepository,
+    Q: QuotesRepository,
+{
+    async fn execute(&self, input: AdminQueryOrderMarginsInput) -> Result<OrderMargins> {
+        let quotation = self
+            .quotes_repository
+            .get(input.customer_id.clone(), input.quotation_id)
+            .await?;
+        let orders = self
+            .orders_repository
+            .query(
+                Some(input.customer_id),
+                Some(quotation.project_id),
+                Some(quotation.id),
+                None,
+                None,
+                None,
+                None,
+                QueryBy::Customer,
+                None,
+                100,
+            )
+            .await?
+            .data;
+
+        OrderMargins::new(&orders)
+    }
+}
@@ -0,0 +1,42 @@
+use crate::repositories::rfqs::RfqsRepository;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminQueryRfqsInput;
+use crate::suppliers::models::responses::AdminQueryRfqsResponse;
+use crate::suppliers::models::rfq_bid_comparison::RfqBidComparison;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminQueryRfqs<R>
+where
+    R: RfqsRepository,
+{
+    rfqs_repository: Arc<R>,
+}
+
+impl<R> AdminQueryRfqs<R>
+where
+    R: RfqsRepository,
+{
+    pub fn new(rfqs_repository: Arc<R>) -> Self {
+        Self { rfqs_repository }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase<AdminQueryRfqsInput, AdminQueryRfqsResponse> for AdminQueryRfqs<R>
+where
+    R: RfqsRepository,
+{
+    async fn execute(&self, input: AdminQueryRfqsInput) -> Result<AdminQueryRfqsResponse> {
+        let rfqs: Vec<_> = self
+            .rfqs_repository
+            .query(input.quotation_id)
+            .await?
+            .into_iter()
+            .filter(|rfq| rfq.customer_id == input.customer_id)
+            .collect();
+        let comparison = RfqBidComparison::new(&rfqs);
+
+        Ok(AdminQueryRfqsResponse { rfqs, comparison })
+    }
+}
@@ -0,0 +1,45 @@
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminQuerySuppliersInput;
+use crate::suppliers::models::responses::AdminQuerySuppliersResponse;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct AdminQuerySuppliers<S>
+where
+    S: SuppliersRepository,
+{
+    suppliers_repository: Arc<S>,
+}
+
+impl<S> AdminQuerySuppliers<S>
+where
+    S: SuppliersRepository,
+{
+    pub fn new(suppliers_repository: Arc<S>) -> Self {
+        Self {
+            suppliers_repository,
+        }
+    }
+}
+
+#[async_trait]
+impl<S> UseCase<AdminQuerySuppliersInput, AdminQuerySuppliersResponse> for AdminQuerySuppliers<S>
+where
+    S: SuppliersRepository,
+{
+    async fn execute(
+        &self,
+        input: AdminQuerySuppliersInput,
+    ) -> Result<AdminQuerySuppliersResponse> {
+        let response = self
+            .suppliers_repository
+            .query(input.cursor, input.limit)
+            .await?;
+
+        Ok(AdminQuerySuppliersResponse {
+            suppliers: response.data,
+            cursor: response.cursor,
+        })
+    }
+}
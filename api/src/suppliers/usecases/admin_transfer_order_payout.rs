@@ -0,0 +1,88 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::order::{Order, OrderStatus};
+use crate::repositories::orders::OrdersRepository;
+use crate::repositories::suppliers::SuppliersRepository;
+use crate::services::stripe_client::StripeClient;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminTransferOrderPayoutInput;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Transfers the payout of a finished order to the connected account of its supplier.
+pub struct AdminTransferOrderPayout<O, S>
+where
+    O: OrdersRepository,
+    S: SuppliersRepository,
+{
+    orders_repository: Arc<O>,
+    suppliers_repository: Arc<S>,
+    stripe_client: Arc<dyn StripeClient>,
+}
+
+impl<O, S> AdminTransferOrderPayout<O, S>
+where
+    O: OrdersRepository,
+    S: SuppliersRepository,
+{
+    pub fn new(
+        orders_repository: Arc<O>,
+        suppliers_repository: Arc<S>,
+        stripe_client: Arc<dyn StripeClient>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            suppliers_repository,
+            stripe_client,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, S> UseCase<AdminTransferOrderPayoutInput, Order> for AdminTransferOrderPayout<O, S>
+where
+    O: OrdersRepository,
+    S: SuppliersRepository,
+{
+    async fn execute(&self, input: AdminTransferOrderPayoutInput) -> Result<Order> {
+        let order = self
+            .orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+        if order.payout_transfer_id.is_some() {
+            return Err(Error::OrderPayoutAlreadyTransferred(order.id));
+        }
+        let is_finished = matches!(
+            order.status,
+            OrderStatus::Ready | OrderStatus::Shipped | OrderStatus::Delivered
+        );
+        let (Some(supplier_id), Some(payout), true) =
+            (order.supplier_id.clone(), order.payout.clone(), is_finished)
+        else {
+            return Err(Error::OrderNotReadyForPayout(order.id));
+        };
+
+        let supplier = self.suppliers_repository.get(supplier_id).await?;
+        let stripe_account_id = supplier
+            .stripe_account_id
+            .ok_or(Error::SupplierNotOnboarded(supplier.id))?;
+
+        // Retrying after the transfer went through but the order wasn't updated must not pay
+        // the supplier twice.
+        let transfer_id = self
+            .stripe_client
+            .create_transfer(
+                stripe_account_id,
+                payout,
+                order.quotation_id.clone(),
+                format!("payout_{}", order.id),
+            )
+            .await?;
+
+        let mut updatable_order =
+            UpdatableOrder::partial_new(order.customer_id, order.id, order.created_at);
+        updatable_order.payout_transfer_id = Some(transfer_id);
+
+        self.orders_repository.update(updatable_order).await
+    }
+}
@@ -0,0 +1,64 @@
+use crate::orders::models::dynamodb_requests::UpdatableOrder;
+use crate::orders::models::order::Order;
+use crate::repositories::orders::OrdersRepository;
+use crate::shared::error::Error;
+use crate::shared::money::validate_currency;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::AdminUpdateOrderPayoutInput;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Sets what the supplier gets paid for the order, in the currency the order was paid in.
+/// Payouts above the price are allowed, they show up as a negative margin.
+pub struct AdminUpdateOrderPayout<O>
+where
+    O: OrdersRepository,
+{
+    orders_repository: Arc<O>,
+}
+
+impl<O> AdminUpdateOrderPayout<O>
+where
+    O: OrdersRepository,
+{
+    pub fn new(orders_repository: Arc<O>) -> Self {
+        Self { orders_repository }
+    }
+}
+
+#[async_trait]
+impl<O> UseCase<AdminUpdateOrderPayoutInput, Order> for AdminUpdateOrderPayout<O>
+where
+    O: OrdersRepository,
+{
+    async fn execute(&self, input: AdminUpdateOrderPayoutInput) -> Result<Order> {
+        validate_currency(input.payout.currency)?;
+        if input.payout.amount <= 0 {
+            return Err(Error::InvalidPayout(String::from(
+                "The amount must be greater than 0",
+            )));
+        }
+
+        let order = self
+            .orders_repository
+            .get(input.customer_id, input.order_id)
+            .await?;
+        if order.payout_transfer_id.is_some() {
+            return Err(Error::OrderPayoutAlreadyTransferred(order.id));
+        }
+        if let Some(price) = &order.price {
+            if price.currency != input.payout.currency {
+                return Err(Error::CurrencyMismatch(
+                    price.currency,
+                    input.payout.currency,
+                ));
+            }
+        }
+
+        let mut updatable_order =
+            UpdatableOrder::partial_new(order.customer_id, order.id, order.created_at);
+        updatable_order.payout = Some(input.payout);
+
+        self.orders_repository.update(updatable_order).await
+    }
+}
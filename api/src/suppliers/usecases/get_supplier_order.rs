@@ -0,0 +1,64 @@
+use crate::repositories::orders::OrdersRepository;
+use crate::repositories::parts::PartsRepository;
+use crate::services::object_storage::ObjectStorage;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::GetSupplierOrderInput;
+use crate::suppliers::models::responses::SupplierOrderResponse;
+use crate::suppliers::models::rfq::RfqPart;
+use crate::suppliers::models::supplier_order::SupplierOrder;
+use crate::suppliers::usecases::get_supplier_rfq::presign_supplier_part;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct GetSupplierOrder<O, P>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+{
+    orders_repository: Arc<O>,
+    parts_repository: Arc<P>,
+    object_storage: Arc<dyn ObjectStorage>,
+}
+
+impl<O, P> GetSupplierOrder<O, P>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+{
+    pub fn new(
+        orders_repository: Arc<O>,
+        parts_repository: Arc<P>,
+        object_storage: Arc<dyn ObjectStorage>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            parts_repository,
+            object_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<O, P> UseCase<GetSupplierOrderInput, SupplierOrderResponse> for GetSupplierOrder<O, P>
+where
+    O: OrdersRepository,
+    P: PartsRepository,
+{
+    async fn execute(&self, input: GetSupplierOrderInput) -> Result<SupplierOrderResponse> {
+        let order = self
+            .orders_repository
+            .get_by_supplier(input.supplier_id, input.order_id)
+            .await?;
+        let part = self
+            .parts_repository
+            .get(order.customer_id.clone(), order.part_id.clone())
+            .await?;
+        let part =
+            presign_supplier_part(self.object_storage.as_ref(), RfqPart::from(&part)).await?;
+
+        Ok(SupplierOrderResponse {
+            order: SupplierOrder::from(order),
+            part,
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use crate::repositories::rfqs::RfqsRepository;
+use crate::services::object_storage::ObjectStorage;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::GetSupplierRfqInput;
+use crate::suppliers::models::responses::{SupplierPart, SupplierRfqResponse};
+use crate::suppliers::models::rfq::RfqPart;
+use async_trait::async_trait;
+use std::sync::Arc;
+use std::time::Duration;
+
+static PRESIGNED_URL_DURATION_SECONDS: u64 = 3600;
+
+pub struct GetSupplierRfq<R>
+where
+    R: RfqsRepository,
+{
+    rfqs_repository: Arc<R>,
+    object_storage: Arc<dyn ObjectStorage>,
+}
+
+impl<R> GetSupplierRfq<R>
+where
+    R: RfqsRepository,
+{
+    pub fn new(rfqs_repository: Arc<R>, object_storage: Arc<dyn ObjectStorage>) -> Self {
+        Self {
+            rfqs_repository,
+            object_storage,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase<GetSupplierRfqInput, SupplierRfqResponse> for GetSupplierRfq<R>
+where
+    R: RfqsRepository,
+{
+    async fn execute(&self, input: GetSupplierRfqInput) -> Result<SupplierRfqResponse> {
+        let rfq = self
+            .rfqs_repository
+            .get(input.quotation_id, input.rfq_id)
+            .await?;
+        rfq.verify_access_token(&input.access_token)?;
+
+        let mut parts = Vec::with_capacity(rfq.parts.len());
+        for part in rfq.parts {
+            parts.push(presign_supplier_part(self.object_storage.as_ref(), part).await?);
+        }
+
+        Ok(SupplierRfqResponse {
+            id: rfq.id,
+            quotation_id: rfq.quotation_id,
+            status: rfq.status,
+            currency: rfq.currency,
+            parts,
+            bids: rfq.bids,
+            created_at: rfq.created_at,
+        })
+    }
+}
+
+/// Part with short-lived links to download its model and drawing.
+pub(crate) async fn presign_supplier_part(
+    object_storage: &dyn ObjectStorage,
+    part: RfqPart,
+) -> Result<SupplierPart> {
+    let expires_in = Duration::from_secs(PRESIGNED_URL_DURATION_SECONDS);
+    let model_file_url = object_storage
+        .get_object_presigned_url(&part.model_file.key, expires_in)
+        .await?;
+    let drawing_file_url = match &part.drawing_file {
+        Some(drawing_file) => Some(
+            object_storage
+                .get_object_presigned_url(&drawing_file.key, expires_in)
+                .await?,
+        ),
+        None => None,
+    };
+
+    Ok(SupplierPart {
+        part_id: part.part_id,
+        process: part.process,
+        attributes: part.attributes,
+        quantity: part.quantity,
+        additional_notes: part.additional_notes,
+        model_file_url,
+        drawing_file_url,
+    })
+}
@@ -0,0 +1,17 @@
+pub mod admin_assign_order_supplier;
+pub mod admin_award_rfq_bids;
+pub mod admin_create_rfqs;
+pub mod admin_create_supplier;
+pub mod admin_create_supplier_invitation;
+pub mod admin_create_supplier_onboarding_link;
+pub mod admin_query_order_margins;
+pub mod admin_query_rfqs;
+pub mod admin_query_suppliers;
+pub mod admin_transfer_order_payout;
+pub mod admin_update_order_payout;
+pub mod get_supplier_order;
+pub mod get_supplier_rfq;
+pub mod query_supplier_orders;
+pub mod submit_rfq_bids;
+pub mod supplier_update_order_status;
+pub mod upload_shipping_document;
@@ -0,0 +1,55 @@
+use crate::repositories::orders::{OrdersRepository, QueryBy};
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::QuerySupplierOrdersInput;
+use crate::suppliers::models::responses::QuerySupplierOrdersResponse;
+use crate::suppliers::models::supplier_order::SupplierOrder;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct QuerySupplierOrders<O>
+where
+    O: OrdersRepository,
+{
+    orders_repository: Arc<O>,
+}
+
+impl<O> QuerySupplierOrders<O>
+where
+    O: OrdersRepository,
+{
+    pub fn new(orders_repository: Arc<O>) -> Self {
+        Self { orders_repository }
+    }
+}
+
+#[async_trait]
+impl<O> UseCase<QuerySupplierOrdersInput, QuerySupplierOrdersResponse> for QuerySupplierOrders<O>
+where
+    O: OrdersRepository,
+{
+    async fn execute(
+        &self,
+        input: QuerySupplierOrdersInput,
+    ) -> Result<QuerySupplierOrdersResponse> {
+        let response = self
+            .orders_repository
+            .query(
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                QueryBy::Supplier(input.supplier_id),
+                input.cursor,
+                input.limit,
+            )
+            .await?;
+
+        Ok(QuerySupplierOrdersResponse {
+            orders: response.data.into_iter().map(SupplierOrder::from).collect(),
+            cursor: response.cursor,
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use crate::repositories::rfqs::RfqsRepository;
+use crate::services::emailer::Emailer;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::dynamodb_requests::UpdatableRfq;
+use crate::suppliers::models::inputs::SubmitRfqBidsInput;
+use crate::suppliers::models::rfq::RfqStatus;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub struct SubmitRfqBids<R>
+where
+    R: RfqsRepository,
+{
+    rfqs_repository: Arc<R>,
+    emailer_service: Arc<dyn Emailer>,
+}
+
+impl<R> SubmitRfqBids<R>
+where
+    R: RfqsRepository,
+{
+    pub fn new(rfqs_repository: Arc<R>, emailer_service: Arc<dyn Emailer>) -> Self {
+        Self {
+            rfqs_repository,
+            emailer_service,
+        }
+    }
+}
+
+#[async_trait]
+impl<R> UseCase<SubmitRfqBidsInput, ()> for SubmitRfqBids<R>
+where
+    R: RfqsRepository,
+{
+    async fn execute(&self, input: SubmitRfqBidsInput) -> Result<()> {
+        let rfq = self
+            .rfqs_repository
+            .get(input.quotation_id.clone(), input.rfq_id.clone())
+            .await?;
+        rfq.verify_access_token(&input.access_token)?;
+        rfq.validate_bids(&input.bids)?;
+
+        self.rfqs_repository
+            .update(UpdatableRfq {
+                quotation_id: input.quotation_id,
+                id: input.rfq_id,
+                status: Some(RfqStatus::Bid),
+                bids: Some(input.bids),
+            })
+            .await?;
+
+        let _ = self
+            .emailer_service
+            .send_email_to_admins(
+                "A supplier bid on an RFQ",
+                &format!(
+                    "Supplier with id {} bid on the RFQ with id {} of the quote with id {}.",
+                    rfq.supplier_id, rfq.id, rfq.quotation_id
+                ),
+            )
+            .await;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use crate::orders::models::inputs::AdminUpdateOrderStatusInput;
+use crate::orders::models::order::{Order, OrderStatus};
+use crate::repositories::orders::OrdersRepository;
+use crate::shared::error::Error;
+use crate::shared::{Result, UseCase};
+use crate::suppliers::models::inputs::SupplierUpdateOrderStatusInput;
+use crate::suppliers::models::supplier_order::SupplierOrder;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Lets suppliers move their orders through production and ship them, the rest of the
+/// lifecycle stays with admins.
+pub struct SupplierUpdateOrderStatus<O>
+where
+    O: OrdersRepository,
+{
+    orders_repository: Arc<O>,
+    update_order_status: Arc<dyn UseCase<AdminUpdateOrderStatusInput, Order> + Send + Sync>,
+}
+
+impl<O> SupplierUpdateOrderStatus<O>
+where
+    O: OrdersRepository,
+{
+    pub fn new(
+        orders_repository: Arc<O>,
+        update_order_status: Arc<dyn UseCase<AdminUpdateOrderStatusInput, Order> + Send + Sync>,
+    ) -> Self {
+        Self {
+            orders_repository,
+            update_order_status,
+        }
+    }
+}
+
+#[async_trait]
+impl<O> UseCase<SupplierUpdateOrderStatusInput, SupplierOrder> for SupplierUpdateOrderStatus<O>
+where
+    O: OrdersRepository,
+{
+    async fn execute(&self, input: SupplierUpdateOrderStatusInput) -> Result<SupplierOrder> {
+        if !matches!(
+            input.status,
+            OrderStatus::InProgress | OrderStatus::Ready | OrderStatus::Shipped
+        ) {
+            return Err(Error::Forbidden);
+        }
+
+        let order = self
+            .orders_repository
+            .get_by_supplier(input.supplier_id, input.order_id)
+            .await?;
+        let order = self
+            .update_order_status
+            .execute(AdminUpdateOrderStatusInput {
+                identity: input.identity,
+                customer_id: order.customer_id,
+                order_id: order.id,
+                status: input.status,
+                shipment: input.shipment,
+            })
+            .await?;
+
+        Ok(SupplierOrder::from(order))
+    }
+}
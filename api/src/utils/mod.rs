@@ -1,2 +1,3 @@
 pub mod dynamodb_key_codec;
+pub mod pdf_canvas;
 pub mod workdays;
@@ -0,0 +1,261 @@
+/// US Letter, in points.
+pub const PAGE_WIDTH: f32 = 612.0;
+pub const PAGE_HEIGHT: f32 = 792.0;
+pub const MARGIN: f32 = 50.0;
+/// Space kept above the bottom margin of every page for the footer.
+pub const FOOTER_HEIGHT: f32 = 30.0;
+pub const CONTENT_RIGHT: f32 = PAGE_WIDTH - MARGIN;
+pub const CELL_PADDING: f32 = 4.0;
+
+pub const DATE_FORMAT: &str = "%B %-d, %Y";
+
+#[derive(Clone, Copy)]
+pub enum Font {
+    Regular,
+    Bold,
+}
+
+/// Glyph widths of the printable ASCII characters, in thousandths of the font size.
+const HELVETICA_WIDTHS: [u16; 95] = [
+    278, 278, 355, 556, 556, 889, 667, 191, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 278, 278, 584, 584, 584, 556, 1015, 667, 667, 722, 722, 667,
+    611, 778, 722, 278, 500, 667, 556, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 278, 278, 278, 469, 556, 333, 556, 556, 500, 556, 556, 278, 556, 556, 222, 222, 500,
+    222, 833, 556, 556, 556, 556, 333, 500, 278, 556, 500, 722, 500, 500, 500, 334, 260, 334, 584,
+];
+const HELVETICA_BOLD_WIDTHS: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278, 278, 556, 556, 556,
+    556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584, 584, 611, 975, 722, 722, 722, 722, 667,
+    611, 778, 722, 278, 556, 722, 611, 833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667,
+    667, 611, 333, 278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278, 556,
+    278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556, 500, 389, 280, 389, 584,
+];
+/// Used for the characters outside of printable ASCII.
+const DEFAULT_WIDTH: u16 = 556;
+
+impl Font {
+    fn resource_name(&self) -> &'static str {
+        match self {
+            Font::Regular => "F1",
+            Font::Bold => "F2",
+        }
+    }
+
+    fn base_font(&self) -> &'static str {
+        match self {
+            Font::Regular => "Helvetica",
+            Font::Bold => "Helvetica-Bold",
+        }
+    }
+
+    /// Width of the text in points.
+    pub fn text_width(&self, text: &str, size: f32) -> f32 {
+        let widths = match self {
+            Font::Regular => &HELVETICA_WIDTHS,
+            Font::Bold => &HELVETICA_BOLD_WIDTHS,
+        };
+        let width = encode(text)
+            .into_iter()
+            .map(|byte| match byte {
+                32..=126 => u32::from(widths[usize::from(byte - 32)]),
+                _ => u32::from(DEFAULT_WIDTH),
+            })
+            .sum::<u32>();
+
+        width as f32 * size / 1000.0
+    }
+}
+
+/// Content streams of the pages of a document, written top to bottom.
+pub struct PdfCanvas {
+    pages: Vec<Vec<u8>>,
+    current_page: Vec<u8>,
+    /// Baseline of the next line of text in the current page.
+    pub y: f32,
+}
+
+impl PdfCanvas {
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            current_page: Vec::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    /// Starts a new page when `height` doesn't fit in the current one, returns whether it did.
+    pub fn ensure_space(&mut self, height: f32) -> bool {
+        if self.y - height >= MARGIN + FOOTER_HEIGHT {
+            return false;
+        }
+
+        self.pages.push(std::mem::take(&mut self.current_page));
+        self.y = PAGE_HEIGHT - MARGIN;
+        true
+    }
+
+    pub fn text(&mut self, x: f32, y: f32, font: Font, size: f32, text: &str) {
+        self.current_page.extend_from_slice(
+            format!("BT /{} {size} Tf {x:.2} {y:.2} Td (", font.resource_name()).as_bytes(),
+        );
+        for byte in encode(text) {
+            if matches!(byte, b'(' | b')' | b'\\') {
+                self.current_page.push(b'\\');
+            }
+            self.current_page.push(byte);
+        }
+        self.current_page.extend_from_slice(b") Tj ET\n");
+    }
+
+    pub fn text_right(&mut self, right: f32, y: f32, font: Font, size: f32, text: &str) {
+        self.text(right - font.text_width(text, size), y, font, size, text);
+    }
+
+    /// Horizontal line across the content area.
+    pub fn rule(&mut self, y: f32) {
+        self.current_page.extend_from_slice(
+            format!("0.8 G 0.5 w {MARGIN} {y:.2} m {CONTENT_RIGHT} {y:.2} l S 0 G\n").as_bytes(),
+        );
+    }
+
+    pub fn fill_rect(&mut self, x: f32, y: f32, width: f32, height: f32, gray: f32) {
+        self.current_page.extend_from_slice(
+            format!("{gray} g {x:.2} {y:.2} {width:.2} {height:.2} re f 0 g\n").as_bytes(),
+        );
+    }
+
+    /// Adds the footer to every page and serializes the document.
+    pub fn finish(mut self, footer: &str) -> Vec<u8> {
+        let last_page = std::mem::take(&mut self.current_page);
+        self.pages.push(last_page);
+
+        let page_count = self.pages.len();
+        for index in 0..page_count {
+            self.current_page = std::mem::take(&mut self.pages[index]);
+            self.rule(MARGIN + 12.0);
+            self.text(MARGIN, MARGIN, Font::Regular, 8.0, footer);
+            let page_number = format!("Page {} of {page_count}", index + 1);
+            self.text_right(CONTENT_RIGHT, MARGIN, Font::Regular, 8.0, &page_number);
+            self.pages[index] = std::mem::take(&mut self.current_page);
+        }
+
+        // Objects 1 to 4 are the catalog, the page tree and the fonts, followed by each page and
+        // its content stream.
+        let page_ids = (0..page_count)
+            .map(|index| format!("{} 0 R", 5 + index * 2))
+            .collect::<Vec<String>>();
+        let mut objects = vec![
+            b"<< /Type /Catalog /Pages 2 0 R >>".to_vec(),
+            format!(
+                "<< /Type /Pages /Kids [{}] /Count {page_count} >>",
+                page_ids.join(" ")
+            )
+            .into_bytes(),
+        ];
+        for font in [Font::Regular, Font::Bold] {
+            objects.push(
+                format!(
+                    "<< /Type /Font /Subtype /Type1 /BaseFont /{} /Encoding /WinAnsiEncoding >>",
+                    font.base_font()
+                )
+                .into_bytes(),
+            );
+        }
+        for (index, content) in self.pages.iter().enumerate() {
+            objects.push(
+                format!(
+                    "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {PAGE_WIDTH} {PAGE_HEIGHT}] \
+                     /Resources << /Font << /F1 3 0 R /F2 4 0 R >> >> /Contents {} 0 R >>",
+                    6 + index * 2
+                )
+                .into_bytes(),
+            );
+            let mut stream = format!("<< /Length {} >>\nstream\n", content.len()).into_bytes();
+            stream.extend_from_slice(content);
+            stream.extend_from_slice(b"\nendstream");
+            objects.push(stream);
+        }
+
+        let mut pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+        let mut offsets = Vec::with_capacity(objects.len());
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend_from_slice(format!("{} 0 obj\n", index + 1).as_bytes());
+            pdf.extend_from_slice(object);
+            pdf.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = pdf.len();
+        pdf.extend_from_slice(
+            format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes(),
+        );
+        for offset in offsets {
+            pdf.extend_from_slice(format!("{offset:010} 00000 n \n").as_bytes());
+        }
+        pdf.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+                objects.len() + 1
+            )
+            .as_bytes(),
+        );
+
+        pdf
+    }
+}
+
+/// Encodes text as WinAnsi, characters without an equivalent are replaced by "?".
+fn encode(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|char| match char {
+            ' '..='~' | '\u{A0}'..='\u{FF}' => char as u8,
+            '€' => 0x80,
+            '‘' => 0x91,
+            '’' => 0x92,
+            '“' => 0x93,
+            '”' => 0x94,
+            '•' => 0x95,
+            '–' => 0x96,
+            '—' => 0x97,
+            _ => b'?',
+        })
+        .collect()
+}
+
+/// Splits text into lines that fit in `width`, breaking between words.
+pub fn wrap(text: &str, font: Font, size: f32, width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if line.is_empty() {
+            line.push_str(word);
+        } else if font.text_width(&format!("{line} {word}"), size) > width {
+            lines.push(std::mem::replace(&mut line, word.to_string()));
+        } else {
+            line.push(' ');
+            line.push_str(word);
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+
+    lines
+}
+
+/// Shortens text that doesn't fit in `width`, ending it with an ellipsis.
+pub fn truncate(text: &str, font: Font, size: f32, width: f32) -> String {
+    if font.text_width(text, size) <= width {
+        return text.to_string();
+    }
+
+    let mut truncated = String::new();
+    for char in text.chars() {
+        if font.text_width(&format!("{truncated}{char}..."), size) > width {
+            break;
+        }
+        truncated.push(char);
+    }
+
+    format!("{truncated}...")
+}
@@ -0,0 +1,203 @@
+mod validate_billing_profile {
+    use api::billing::models::billing_profile::BillingProfile;
+    use api::shared::error::Error;
+
+    fn billing_profile(rfc: &str) -> BillingProfile {
+        BillingProfile::new(
+            String::from("customer_id"),
+            String::from(rfc),
+            String::from("Maquinados del Norte SA de CV"),
+            String::from("601"),
+            String::from("g03"),
+            String::from("64000"),
+        )
+    }
+
+    #[test]
+    fn it_should_accept_company_and_individual_rfcs() {
+        assert!(billing_profile("MNO010101AB1").validate().is_ok());
+        assert!(billing_profile(" gomj800101h23 ").validate().is_ok());
+    }
+
+    #[test]
+    fn it_should_normalize_codes() {
+        let billing_profile = billing_profile(" gomj800101h23 ");
+
+        assert_eq!(billing_profile.rfc, "GOMJ800101H23");
+        assert_eq!(billing_profile.cfdi_use, "G03");
+    }
+
+    #[test]
+    fn it_should_reject_malformed_rfcs() {
+        for rfc in [
+            "",
+            "MNO0101AB1",
+            "MNO01010AAB1",
+            "1NO010101AB1",
+            "MNO010101A-1",
+        ] {
+            assert!(matches!(
+                billing_profile(rfc).validate(),
+                Err(Error::InvalidBillingProfile(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_reject_malformed_postal_codes() {
+        let mut billing_profile = billing_profile("MNO010101AB1");
+        billing_profile.postal_code = String::from("640");
+
+        assert!(matches!(
+            billing_profile.validate(),
+            Err(Error::InvalidBillingProfile(_))
+        ));
+    }
+}
+
+mod invoice {
+    use api::billing::models::invoice::Invoice;
+    use api::config::{ConfigTaxRate, ConfigTaxes};
+    use api::parts::models::part::{Part, PartProcess, PartQuote};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::quotations::models::quotation::Quotation;
+    use api::services::tax_calculator::TaxCalculator;
+    use api::services::tax_calculator_rate_table::RateTableTaxCalculator;
+    use api::shared::error::Error;
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use api::shared::shipping::{ShippingCharge, ShippingMethod};
+    use iso_currency::Currency;
+
+    fn tax_calculator() -> RateTableTaxCalculator {
+        RateTableTaxCalculator::new(ConfigTaxes {
+            default_country: String::from("MX"),
+            rates: vec![ConfigTaxRate {
+                country: String::from("MX"),
+                state: None,
+                name: String::from("IVA"),
+                rate: 0.16,
+                stripe_tax_rate_id: String::from("txr_iva"),
+            }],
+        })
+    }
+
+    fn quoted_part(quotation: &Quotation, unit_price: i64, quantity: u64) -> Part {
+        let mut part = Part::new(
+            quotation.customer_id.clone(),
+            quotation.project_id.clone(),
+            quotation.id.clone(),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes::default()),
+            File::new(String::from("part.stl"), String::from("key")),
+        );
+        part.quantity = quantity;
+        let part_quote = PartQuote::new(
+            Money::new(unit_price, Currency::MXN),
+            Money::new(unit_price * quantity as i64, Currency::MXN),
+            10,
+            None,
+        );
+        part.selected_part_quote_id = Some(part_quote.id.clone());
+        part.part_quotes = Some(vec![part_quote]);
+        part
+    }
+
+    #[test]
+    fn it_should_break_down_taxes() {
+        let quotation = Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets"),
+            Currency::MXN,
+        );
+        let parts = vec![
+            quoted_part(&quotation, 10_000, 3),
+            quoted_part(&quotation, 1_999, 1),
+        ];
+
+        let tax_calculator = tax_calculator();
+        let invoice = Invoice::new(
+            &quotation,
+            &parts,
+            None,
+            Some(String::from("pi_123")),
+            &tax_calculator,
+            tax_calculator.jurisdiction(None).unwrap(),
+            None,
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(invoice.quotation_id, quotation.id);
+        assert_eq!(invoice.lines.len(), 2);
+        assert_eq!(invoice.subtotal, Money::new(31_999, Currency::MXN));
+        assert_eq!(invoice.tax_lines.len(), 1);
+        assert_eq!(invoice.tax_lines[0].name, "IVA");
+        assert_eq!(
+            invoice.tax_lines[0].amount,
+            Money::new(5_120, Currency::MXN)
+        );
+        assert_eq!(invoice.total, Money::new(37_119, Currency::MXN));
+    }
+
+    #[test]
+    fn it_should_add_the_shipping_charged_after_taxes() {
+        let quotation = Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets"),
+            Currency::MXN,
+        );
+        let parts = vec![quoted_part(&quotation, 10_000, 1)];
+
+        let tax_calculator = tax_calculator();
+        let invoice = Invoice::new(
+            &quotation,
+            &parts,
+            None,
+            Some(String::from("pi_123")),
+            &tax_calculator,
+            tax_calculator.jurisdiction(None).unwrap(),
+            None,
+            Some(ShippingCharge {
+                method: ShippingMethod::Express,
+                cost: Money::new(25_000, Currency::MXN),
+            }),
+        )
+        .unwrap();
+
+        assert_eq!(
+            invoice.tax_lines[0].amount,
+            Money::new(1_600, Currency::MXN)
+        );
+        assert_eq!(invoice.total, Money::new(36_600, Currency::MXN));
+    }
+
+    #[test]
+    fn it_should_reject_parts_without_a_selected_part_quote() {
+        let quotation = Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets"),
+            Currency::MXN,
+        );
+        let mut part = quoted_part(&quotation, 10_000, 1);
+        part.selected_part_quote_id = None;
+        let tax_calculator = tax_calculator();
+
+        assert!(matches!(
+            Invoice::new(
+                &quotation,
+                &[part.clone()],
+                None,
+                None,
+                &tax_calculator,
+                tax_calculator.jurisdiction(None).unwrap(),
+                None,
+                None,
+            ),
+            Err(Error::NoSelectedQuoteAvailableForPart(part_id)) if part_id == part.id
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod create_checkout_session {
+    use crate::common::fakes::{
+        identity, InMemoryParts, InMemoryPromotionCodes, InMemoryQuotes, RecordingStripeClient,
+    };
+    use crate::common::fixtures::{quotation, quoted_part, shipping_calculator, tax_calculator};
+    use api::auth::models::session::Role;
+    use api::payments::models::inputs::CreateCheckoutSessionInput;
+    use api::payments::models::responses::CreateCheckoutSessionResponse;
+    use api::payments::usecases::create_checkout_session::CreateCheckoutSession;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::quotations::models::quotation::MAX_PARTS_PER_QUOTATION;
+    use api::shared::error::Error;
+    use api::shared::{Result, UseCase};
+    use std::sync::Arc;
+
+    async fn check_out(
+        status: QuoteStatus,
+    ) -> (
+        Result<CreateCheckoutSessionResponse>,
+        Arc<RecordingStripeClient>,
+    ) {
+        check_out_parts(status, 1).await
+    }
+
+    async fn check_out_parts(
+        status: QuoteStatus,
+        part_count: usize,
+    ) -> (
+        Result<CreateCheckoutSessionResponse>,
+        Arc<RecordingStripeClient>,
+    ) {
+        let quotation = quotation(status);
+        let parts = (0..part_count)
+            .map(|_| quoted_part(&quotation, 2, 10_000))
+            .collect();
+        let stripe_client = Arc::new(RecordingStripeClient::default());
+        let usecase = CreateCheckoutSession::new(
+            stripe_client.clone(),
+            Arc::new(InMemoryParts::new(parts)),
+            Arc::new(InMemoryQuotes::new(vec![quotation.clone()])),
+            Arc::new(InMemoryPromotionCodes::default()),
+            tax_calculator(),
+            shipping_calculator(),
+        );
+
+        let result = usecase
+            .execute(CreateCheckoutSessionInput {
+                identity: identity(&quotation.customer_id, Role::Customer),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+                shipping_address: None,
+            })
+            .await;
+
+        (result, stripe_client)
+    }
+
+    #[tokio::test]
+    async fn it_should_check_out_quotations_pending_payment() {
+        for status in [QuoteStatus::PendingPayment, QuoteStatus::PaymentFailed] {
+            let (result, stripe_client) = check_out(status).await;
+
+            assert!(result.is_ok());
+            assert_eq!(stripe_client.checkout_sessions.lock().unwrap().len(), 1);
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_not_check_out_locked_quotations() {
+        for status in [
+            QuoteStatus::Payed,
+            QuoteStatus::PaymentProcessing,
+            QuoteStatus::Refunded,
+            QuoteStatus::Cancelled,
+        ] {
+            let (result, stripe_client) = check_out(status).await;
+
+            assert!(matches!(result, Err(Error::QuoteIsInPayedStatus)));
+            assert!(stripe_client.checkout_sessions.lock().unwrap().is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_not_check_out_expired_quotations() {
+        let (result, stripe_client) = check_out(QuoteStatus::Expired).await;
+
+        assert!(matches!(result, Err(Error::QuotationExpired(_))));
+        assert!(stripe_client.checkout_sessions.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_not_check_out_quotations_with_too_many_parts_to_pay_for() {
+        let (result, stripe_client) =
+            check_out_parts(QuoteStatus::PendingPayment, MAX_PARTS_PER_QUOTATION + 1).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::TooManyParts(_, MAX_PARTS_PER_QUOTATION))
+        ));
+        assert!(stripe_client.checkout_sessions.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,1560 @@
+//! In-memory stand-ins for the repositories and services used cases depend on, so use cases can
+//! be exercised without DynamoDB, Ory or SES.
+
+use api::auth::models::session::{
+    Identity, IdentityId, MetadataPublic, Role, Session, SessionToken, SessionWithToken, Traits,
+};
+use api::billing::models::billing_profile::BillingProfile;
+use api::billing::models::invoice::Invoice;
+use api::orders::models::dynamodb_requests::{UpdatableOrder, UpdatableWorkOrder};
+use api::orders::models::order::{Order, OrderStatus};
+use api::orders::models::work_order::WorkOrder;
+use api::parts::models::dynamodb_requests::{BatchDeletePartObject, UpdatablePart};
+use api::parts::models::part::{Part, PartQuote};
+use api::payments::models::webhook_event::{WebhookEvent, WebhookEventStatus};
+use api::projects::models::project::Project;
+use api::promotions::models::discount::DiscountLine;
+use api::promotions::models::pricing_agreement::PricingAgreement;
+use api::promotions::models::promotion_code::{AppliedPromotion, PromotionCode};
+use api::quotations::models::dynamodb_requests::BatchDeleteQuotationObject;
+use api::quotations::models::quotation::{Quotation, QuoteStatus};
+use api::quotations::models::quotation_revision::QuotationRevision;
+use api::repositories::billing_profiles::BillingProfilesRepository;
+use api::repositories::invoices::InvoicesRepository;
+use api::repositories::orders::{OrdersRepository, QueryBy};
+use api::repositories::parts::PartsRepository;
+use api::repositories::pricing_agreements::PricingAgreementsRepository;
+use api::repositories::projects::ProjectsRepository;
+use api::repositories::promotion_codes::PromotionCodesRepository;
+use api::repositories::quotation_revisions::QuotationRevisionsRepository;
+use api::repositories::quotes::{self, QuotesRepository};
+use api::repositories::rfqs::RfqsRepository;
+use api::repositories::suppliers::SuppliersRepository;
+use api::repositories::transaction::Transaction;
+use api::repositories::webhook_events::WebhookEventsRepository;
+use api::repositories::work_orders::WorkOrdersRepository;
+use api::services::emailer::Emailer;
+use api::services::identity_manager::IdentityManager;
+use api::services::object_storage::ObjectStorage;
+use api::services::pricing_engine::PricingEngine;
+use api::services::stripe_client::{QuotationPayment, StripeClient};
+use api::shared::error::Error;
+use api::shared::money::Money;
+use api::shared::shipping::ShippingOption;
+use api::shared::tax::TaxJurisdiction;
+use api::shared::{
+    CustomerId, OrderId, PartId, PartQuoteId, ProjectId, QueryResponse, QuotationRevisionId,
+    QuoteId, Result, RfqId, SupplierId, WebhookEventId,
+};
+use api::suppliers::models::dynamodb_requests::{UpdatableRfq, UpdatableSupplier};
+use api::suppliers::models::rfq::Rfq;
+use api::suppliers::models::supplier::Supplier;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use iso_currency::Currency;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use stripe::Customer;
+
+/// Writes of the in-memory repositories, applied together by `InMemoryTransaction`.
+#[derive(Clone, Debug)]
+pub enum FakeWrite {
+    CreateOrder(Box<Order>),
+    UpdateOrder(Box<UpdatableOrder>),
+    CreateInvoice(Box<Invoice>),
+    CreateRevision(Box<QuotationRevision>),
+    RedeemPromotionCode(String),
+    UpdateProject {
+        customer_id: CustomerId,
+        project_id: ProjectId,
+        is_locked: bool,
+    },
+    CreateProcessedWebhookEvent(Box<WebhookEvent>),
+    CreatePartQuotes {
+        customer_id: CustomerId,
+        part_id: PartId,
+        selected_part_quote_id: PartQuoteId,
+        part_quotes: Vec<PartQuote>,
+    },
+    UpdateQuoteStatus {
+        customer_id: CustomerId,
+        quote_id: QuoteId,
+        old_status: QuoteStatus,
+        new_status: QuoteStatus,
+    },
+}
+
+/// DynamoDB rejects transactions with more items.
+const MAX_TRANSACTION_ITEMS: usize = 100;
+
+/// Applies every write or none, checking conditions before writing like DynamoDB transactions.
+/// Repositories that aren't passed in start empty.
+pub struct InMemoryTransaction {
+    parts: Arc<InMemoryParts>,
+    quotes: Arc<InMemoryQuotes>,
+    orders: Arc<InMemoryOrders>,
+    revisions: Arc<InMemoryQuotationRevisions>,
+    promotion_codes: Arc<InMemoryPromotionCodes>,
+    projects: Arc<InMemoryProjects>,
+    webhook_events: Arc<InMemoryWebhookEvents>,
+    invoices: Arc<InMemoryInvoices>,
+    items: Vec<FakeWrite>,
+}
+
+impl InMemoryTransaction {
+    pub fn new(parts: Arc<InMemoryParts>, quotes: Arc<InMemoryQuotes>) -> Self {
+        Self {
+            parts,
+            quotes,
+            orders: Arc::default(),
+            revisions: Arc::default(),
+            promotion_codes: Arc::default(),
+            projects: Arc::default(),
+            webhook_events: Arc::default(),
+            invoices: Arc::default(),
+            items: Vec::new(),
+        }
+    }
+
+    pub fn with_orders(mut self, orders: Arc<InMemoryOrders>) -> Self {
+        self.orders = orders;
+        self
+    }
+
+    pub fn with_revisions(mut self, revisions: Arc<InMemoryQuotationRevisions>) -> Self {
+        self.revisions = revisions;
+        self
+    }
+
+    pub fn with_promotion_codes(mut self, promotion_codes: Arc<InMemoryPromotionCodes>) -> Self {
+        self.promotion_codes = promotion_codes;
+        self
+    }
+
+    pub fn with_projects(mut self, projects: Arc<InMemoryProjects>) -> Self {
+        self.projects = projects;
+        self
+    }
+
+    pub fn with_webhook_events(mut self, webhook_events: Arc<InMemoryWebhookEvents>) -> Self {
+        self.webhook_events = webhook_events;
+        self
+    }
+
+    pub fn with_invoices(mut self, invoices: Arc<InMemoryInvoices>) -> Self {
+        self.invoices = invoices;
+        self
+    }
+}
+
+#[async_trait]
+impl Transaction for InMemoryTransaction {
+    type TransactionItem = FakeWrite;
+
+    fn add_item(&mut self, item: FakeWrite) {
+        self.items.push(item);
+    }
+
+    fn add_items(&mut self, items: Vec<FakeWrite>) {
+        self.items.extend(items);
+    }
+
+    async fn execute(&mut self) -> Result<()> {
+        let items = std::mem::take(&mut self.items);
+        if items.len() > MAX_TRANSACTION_ITEMS {
+            return Err(Error::UnknownError);
+        }
+        for item in &items {
+            if let FakeWrite::UpdateOrder(updatable_order) = item {
+                let mut order = self
+                    .orders
+                    .get(
+                        updatable_order.customer_id.clone(),
+                        updatable_order.id.clone(),
+                    )
+                    .await?;
+                apply_order_update(&mut order, updatable_order.as_ref().clone())?;
+            }
+            if let FakeWrite::UpdateQuoteStatus {
+                customer_id,
+                quote_id,
+                old_status,
+                ..
+            } = item
+            {
+                let quotation = self
+                    .quotes
+                    .get(customer_id.clone(), quote_id.clone())
+                    .await?;
+                if &quotation.status != old_status {
+                    return Err(Error::UnknownError);
+                }
+            }
+            if let FakeWrite::CreateInvoice(invoice) = item {
+                if self
+                    .invoices
+                    .get(invoice.customer_id.clone(), invoice.quotation_id.clone())
+                    .await
+                    .is_ok()
+                {
+                    return Err(Error::UnknownError);
+                }
+            }
+            if let FakeWrite::CreateProcessedWebhookEvent(event) = item {
+                if self
+                    .webhook_events
+                    .get(event.id.clone())
+                    .await
+                    .is_ok_and(|event| event.status == WebhookEventStatus::Processed)
+                {
+                    return Err(Error::WebhookEventAlreadyProcessed(event.id.clone()));
+                }
+            }
+        }
+
+        for item in items {
+            match item {
+                FakeWrite::CreateOrder(order) => self.orders.orders.lock().unwrap().push(*order),
+                FakeWrite::UpdateOrder(updatable_order) => {
+                    self.orders.update(*updatable_order).await?;
+                }
+                FakeWrite::CreateInvoice(invoice) => {
+                    self.invoices.invoices.lock().unwrap().push(*invoice)
+                }
+                FakeWrite::CreateRevision(revision) => {
+                    self.revisions.revisions.lock().unwrap().push(*revision)
+                }
+                FakeWrite::RedeemPromotionCode(code) => {
+                    let mut promotion_codes = self.promotion_codes.promotion_codes.lock().unwrap();
+                    let promotion_code = promotion_codes
+                        .iter_mut()
+                        .find(|promotion_code| promotion_code.code == code)
+                        .ok_or(Error::ItemNotFoundError)?;
+                    promotion_code.uses += 1;
+                }
+                FakeWrite::UpdateProject {
+                    customer_id,
+                    project_id,
+                    is_locked,
+                } => {
+                    let mut projects = self.projects.projects.lock().unwrap();
+                    let project = projects
+                        .iter_mut()
+                        .find(|project| {
+                            project.customer_id == customer_id && project.id == project_id
+                        })
+                        .ok_or(Error::ItemNotFoundError)?;
+                    project.is_locked = is_locked;
+                    project.updated_at = Utc::now();
+                }
+                FakeWrite::CreateProcessedWebhookEvent(event) => {
+                    let mut events = self.webhook_events.events.lock().unwrap();
+                    events.retain(|existing| existing.id != event.id);
+                    events.push(event.processed());
+                }
+                FakeWrite::CreatePartQuotes {
+                    customer_id,
+                    part_id,
+                    selected_part_quote_id,
+                    part_quotes,
+                } => {
+                    let mut parts = self.parts.parts.lock().unwrap();
+                    let part = parts
+                        .iter_mut()
+                        .find(|part| part.customer_id == customer_id && part.id == part_id)
+                        .ok_or(Error::ItemNotFoundError)?;
+                    part.selected_part_quote_id = Some(selected_part_quote_id);
+                    part.part_quotes = Some(part_quotes);
+                }
+                FakeWrite::UpdateQuoteStatus {
+                    customer_id,
+                    quote_id,
+                    new_status,
+                    ..
+                } => self.quotes.set_status(&customer_id, &quote_id, new_status),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Keeps orders in memory, applying updates with the same conditions as the DynamoDB
+/// repository.
+#[derive(Default)]
+pub struct InMemoryOrders {
+    pub orders: Mutex<Vec<Order>>,
+}
+
+impl InMemoryOrders {
+    pub fn new(orders: Vec<Order>) -> Self {
+        Self {
+            orders: Mutex::new(orders),
+        }
+    }
+
+    pub fn order(&self, order_id: &str) -> Order {
+        self.orders
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|order| order.id == order_id)
+            .cloned()
+            .unwrap()
+    }
+}
+
+#[async_trait]
+impl OrdersRepository for InMemoryOrders {
+    type TransactionItem = FakeWrite;
+
+    async fn query(
+        &self,
+        customer_id: Option<CustomerId>,
+        _project_id: Option<ProjectId>,
+        quote_id: Option<QuoteId>,
+        _part_id: Option<PartId>,
+        status: Option<OrderStatus>,
+        _from: Option<DateTime<Utc>>,
+        _to: Option<DateTime<Utc>>,
+        query_by: QueryBy,
+        _cursor: Option<String>,
+        _limit: i32,
+    ) -> Result<QueryResponse<Vec<Order>, String>> {
+        let orders = self
+            .orders
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|order| match &query_by {
+                QueryBy::Customer => customer_id.as_ref() == Some(&order.customer_id),
+                QueryBy::IsOpen => order.status == OrderStatus::Open,
+                QueryBy::Supplier(supplier_id) => order.supplier_id.as_ref() == Some(supplier_id),
+            })
+            .filter(|order| quote_id.as_ref().is_none_or(|id| id == &order.quotation_id))
+            .filter(|order| status.as_ref().is_none_or(|status| status == &order.status))
+            .cloned()
+            .collect();
+
+        Ok(QueryResponse {
+            data: orders,
+            cursor: None,
+        })
+    }
+
+    async fn get(&self, customer_id: CustomerId, order_id: OrderId) -> Result<Order> {
+        self.orders
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|order| order.customer_id == customer_id && order.id == order_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn get_by_supplier(&self, supplier_id: SupplierId, order_id: OrderId) -> Result<Order> {
+        self.orders
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|order| order.supplier_id.as_ref() == Some(&supplier_id) && order.id == order_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn update(&self, updatable_order: UpdatableOrder) -> Result<Order> {
+        let mut orders = self.orders.lock().unwrap();
+        let order = orders
+            .iter_mut()
+            .find(|order| {
+                order.customer_id == updatable_order.customer_id && order.id == updatable_order.id
+            })
+            .ok_or(Error::ItemNotFoundError)?;
+        apply_order_update(order, updatable_order)?;
+
+        Ok(order.clone())
+    }
+
+    fn transaction_create(&self, order: Order) -> FakeWrite {
+        FakeWrite::CreateOrder(Box::new(order))
+    }
+
+    fn transaction_update(&self, updatable_order: UpdatableOrder) -> FakeWrite {
+        FakeWrite::UpdateOrder(Box::new(updatable_order))
+    }
+}
+
+/// Applies an update to an order, failing on the same conditions as the DynamoDB repository.
+fn apply_order_update(order: &mut Order, updatable_order: UpdatableOrder) -> Result<()> {
+    let updates_payout = updatable_order.supplier_id.is_some()
+        || updatable_order.payout.is_some()
+        || updatable_order.payout_transfer_id.is_some();
+    if updates_payout && order.payout_transfer_id.is_some() {
+        return Err(Error::OrderPayoutAlreadyTransferred(order.id.clone()));
+    }
+    if let Some(transition) = updatable_order.status_transition {
+        if order.status != transition.from {
+            return Err(Error::InvalidOrderStatusTransition(
+                transition.from,
+                transition.to,
+            ));
+        }
+        order.status = transition.to.clone();
+        order.status_history.push(transition);
+    }
+    if let Some(shipment) = updatable_order.shipment {
+        order.shipment = Some(shipment);
+    }
+    if let Some(shipping_document) = updatable_order.shipping_document {
+        order.shipping_documents.push(shipping_document);
+    }
+    if let Some(inspection_report) = updatable_order.inspection_report {
+        order.inspection_report = Some(inspection_report);
+        order.certificate_of_conformance = updatable_order.certificate_of_conformance;
+    }
+    if let Some(material_certificate) = updatable_order.material_certificate {
+        order.material_certificates.push(material_certificate);
+    }
+    if let Some(refund) = updatable_order.refund {
+        order.refund = Some(refund);
+    }
+    if let Some(pending_refund_key) = updatable_order.pending_refund_key {
+        order.pending_refund_key = Some(pending_refund_key);
+    }
+    if updatable_order.is_refund_issued {
+        order.pending_refund_key = None;
+    }
+    if let Some(supplier_id) = updatable_order.supplier_id {
+        order.supplier_id = Some(supplier_id);
+    }
+    if let Some(payout) = updatable_order.payout {
+        order.payout = Some(payout);
+    }
+    if let Some(payout_transfer_id) = updatable_order.payout_transfer_id {
+        order.payout_transfer_id = Some(payout_transfer_id);
+    }
+    order.updated_at = Utc::now();
+
+    Ok(())
+}
+
+#[derive(Default)]
+pub struct InMemoryQuotes {
+    pub quotations: Mutex<Vec<Quotation>>,
+}
+
+impl InMemoryQuotes {
+    pub fn new(quotations: Vec<Quotation>) -> Self {
+        Self {
+            quotations: Mutex::new(quotations),
+        }
+    }
+
+    pub fn quotation(&self, quotation_id: &str) -> Quotation {
+        self.quotations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|quotation| quotation.id == quotation_id)
+            .cloned()
+            .unwrap()
+    }
+
+    fn set_status(&self, customer_id: &str, quotation_id: &str, status: QuoteStatus) {
+        let mut quotations = self.quotations.lock().unwrap();
+        if let Some(quotation) = quotations
+            .iter_mut()
+            .find(|quotation| quotation.customer_id == customer_id && quotation.id == quotation_id)
+        {
+            quotation.status = status;
+            quotation.updated_at = Utc::now();
+        }
+    }
+}
+
+#[async_trait]
+impl QuotesRepository for InMemoryQuotes {
+    type TransactionItem = FakeWrite;
+
+    async fn create(&self, quotation: Quotation) -> Result<()> {
+        self.quotations.lock().unwrap().push(quotation);
+        Ok(())
+    }
+
+    async fn delete(&self, customer_id: CustomerId, quotation_id: QuoteId) -> Result<()> {
+        self.quotations.lock().unwrap().retain(|quotation| {
+            quotation.customer_id != customer_id
+                || quotation.id != quotation_id
+                || quotation.status.is_locked()
+        });
+        Ok(())
+    }
+
+    async fn get(&self, customer_id: CustomerId, quotation_id: QuoteId) -> Result<Quotation> {
+        self.quotations
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|quotation| quotation.customer_id == customer_id && quotation.id == quotation_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn query(
+        &self,
+        customer_id: Option<CustomerId>,
+        project_id: Option<ProjectId>,
+        _from: Option<DateTime<Utc>>,
+        _to: Option<DateTime<Utc>>,
+        status: Option<QuoteStatus>,
+        query_by: quotes::QueryBy,
+        _limit: i32,
+        _cursor: Option<String>,
+    ) -> Result<QueryResponse<Vec<Quotation>, String>> {
+        let quotations = self
+            .quotations
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|quotation| match query_by {
+                quotes::QueryBy::Customer => customer_id.as_ref() == Some(&quotation.customer_id),
+                quotes::QueryBy::IsPendingReview => quotation.status == QuoteStatus::PendingReview,
+                quotes::QueryBy::IsPendingPayment => {
+                    quotation.status == QuoteStatus::PendingPayment
+                }
+            })
+            .filter(|quotation| {
+                project_id
+                    .as_ref()
+                    .is_none_or(|id| id == &quotation.project_id)
+            })
+            .filter(|quotation| {
+                status
+                    .as_ref()
+                    .is_none_or(|status| status == &quotation.status)
+            })
+            .cloned()
+            .collect();
+
+        Ok(QueryResponse {
+            data: quotations,
+            cursor: None,
+        })
+    }
+
+    async fn update_status(
+        &self,
+        customer_id: CustomerId,
+        _project_id: ProjectId,
+        quotation_id: QuoteId,
+        status: QuoteStatus,
+    ) -> Result<Quotation> {
+        let quotation = self.get(customer_id.clone(), quotation_id.clone()).await?;
+        if quotation.status.is_locked() {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+        self.set_status(&customer_id, &quotation_id, status);
+
+        self.get(customer_id, quotation_id).await
+    }
+
+    async fn update_promotion(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        promotion: Option<AppliedPromotion>,
+    ) -> Result<Quotation> {
+        let mut quotations = self.quotations.lock().unwrap();
+        let quotation = quotations
+            .iter_mut()
+            .find(|quotation| quotation.customer_id == customer_id && quotation.id == quotation_id)
+            .ok_or(Error::ItemNotFoundError)?;
+        if quotation.status.is_locked() {
+            return Err(Error::QuoteIsInPayedStatus);
+        }
+        quotation.promotion = promotion;
+
+        Ok(quotation.clone())
+    }
+
+    async fn batch_delete(&self, data: Vec<BatchDeleteQuotationObject>) -> Result<()> {
+        for object in data {
+            self.delete(object.customer_id, object.quotation_id).await?;
+        }
+        Ok(())
+    }
+
+    fn transaction_update(
+        &self,
+        customer_id: CustomerId,
+        _project_id: ProjectId,
+        quote_id: QuoteId,
+        old_status: QuoteStatus,
+        new_status: QuoteStatus,
+    ) -> FakeWrite {
+        FakeWrite::UpdateQuoteStatus {
+            customer_id,
+            quote_id,
+            old_status,
+            new_status,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct InMemoryParts {
+    pub parts: Mutex<Vec<Part>>,
+}
+
+impl InMemoryParts {
+    pub fn new(parts: Vec<Part>) -> Self {
+        Self {
+            parts: Mutex::new(parts),
+        }
+    }
+
+    pub fn part(&self, part_id: &str) -> Part {
+        self.parts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|part| part.id == part_id)
+            .cloned()
+            .unwrap()
+    }
+}
+
+#[async_trait]
+impl PartsRepository for InMemoryParts {
+    type TransactionItem = FakeWrite;
+
+    async fn delete(&self, customer_id: CustomerId, part_id: PartId) -> Result<Part> {
+        let part = self.get(customer_id, part_id.clone()).await?;
+        self.parts.lock().unwrap().retain(|part| part.id != part_id);
+        Ok(part)
+    }
+
+    async fn delete_drawing_file(&self, customer_id: CustomerId, part_id: PartId) -> Result<Part> {
+        let mut parts = self.parts.lock().unwrap();
+        let part = parts
+            .iter_mut()
+            .find(|part| part.customer_id == customer_id && part.id == part_id)
+            .ok_or(Error::ItemNotFoundError)?;
+        part.drawing_file = None;
+        Ok(part.clone())
+    }
+
+    async fn get(&self, customer_id: CustomerId, part_id: PartId) -> Result<Part> {
+        self.parts
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|part| part.customer_id == customer_id && part.id == part_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn query(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        _cursor: Option<String>,
+        _limit: i32,
+    ) -> Result<QueryResponse<Vec<Part>, String>> {
+        let parts = self
+            .parts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|part| part.customer_id == customer_id && part.quotation_id == quotation_id)
+            .cloned()
+            .collect();
+
+        Ok(QueryResponse {
+            data: parts,
+            cursor: None,
+        })
+    }
+
+    async fn update(&self, updatable_part: UpdatablePart) -> Result<Part> {
+        let mut parts = self.parts.lock().unwrap();
+        let part = parts
+            .iter_mut()
+            .find(|part| {
+                part.customer_id == updatable_part.customer_id && part.id == updatable_part.id
+            })
+            .ok_or(Error::ItemNotFoundError)?;
+
+        if let Some(render_file) = updatable_part.render_file {
+            part.render_file = Some(render_file);
+            part.conversion_error = None;
+        }
+        if let Some(conversion_error) = updatable_part.conversion_error {
+            part.conversion_error = Some(conversion_error);
+        }
+        if let Some(drawing_file) = updatable_part.drawing_file {
+            part.drawing_file = Some(drawing_file);
+        }
+        if let Some(process) = updatable_part.process {
+            part.process = process;
+        }
+        if let Some(attributes) = updatable_part.attributes {
+            part.attributes = attributes;
+        }
+        if let Some(geometry) = updatable_part.geometry {
+            part.geometry = Some(geometry);
+        }
+        if let Some(quantity) = updatable_part.quantity {
+            part.quantity = quantity;
+        }
+        if let Some(additional_notes) = updatable_part.additional_notes {
+            part.additional_notes = additional_notes;
+        }
+        if let Some(selected_part_quote_id) = updatable_part.selected_part_quote_id {
+            part.selected_part_quote_id = Some(selected_part_quote_id);
+        }
+        if updatable_part.clear_part_quotes == Some(true) {
+            part.part_quotes = None;
+        }
+        part.updated_at = Utc::now();
+
+        Ok(part.clone())
+    }
+
+    async fn batch_create(&self, parts: Vec<Part>) -> Result<()> {
+        self.parts.lock().unwrap().extend(parts);
+        Ok(())
+    }
+
+    async fn batch_delete(&self, data: Vec<BatchDeletePartObject>) -> Result<()> {
+        for object in data {
+            self.delete(object.customer_id, object.part_id).await?;
+        }
+        Ok(())
+    }
+
+    async fn batch_get(
+        &self,
+        customer_and_part_ids: Vec<(CustomerId, PartId)>,
+    ) -> Result<Vec<Part>> {
+        Ok(self
+            .parts
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|part| {
+                customer_and_part_ids.iter().any(|(customer_id, part_id)| {
+                    &part.customer_id == customer_id && &part.id == part_id
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn transaction_create_part_quotes(
+        &self,
+        customer_id: CustomerId,
+        part_id: PartId,
+        selected_part_quote_id: PartQuoteId,
+        part_quotes: Vec<PartQuote>,
+    ) -> FakeWrite {
+        FakeWrite::CreatePartQuotes {
+            customer_id,
+            part_id,
+            selected_part_quote_id,
+            part_quotes,
+        }
+    }
+}
+
+/// Keeps quotation revisions in memory, oldest first.
+#[derive(Default)]
+pub struct InMemoryQuotationRevisions {
+    pub revisions: Mutex<Vec<QuotationRevision>>,
+}
+
+#[async_trait]
+impl QuotationRevisionsRepository for InMemoryQuotationRevisions {
+    type TransactionItem = FakeWrite;
+
+    async fn get(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        revision_id: QuotationRevisionId,
+    ) -> Result<QuotationRevision> {
+        self.revisions
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|revision| {
+                revision.customer_id == customer_id
+                    && revision.quotation_id == quotation_id
+                    && revision.id == revision_id
+            })
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn query(
+        &self,
+        customer_id: CustomerId,
+        quotation_id: QuoteId,
+        _cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<QuotationRevision>, String>> {
+        let data = self
+            .revisions
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|revision| {
+                revision.customer_id == customer_id && revision.quotation_id == quotation_id
+            })
+            .take(limit as usize)
+            .cloned()
+            .collect();
+        Ok(QueryResponse { data, cursor: None })
+    }
+
+    fn transaction_create(&self, revision: QuotationRevision) -> FakeWrite {
+        FakeWrite::CreateRevision(Box::new(revision))
+    }
+}
+
+/// Keeps projects in memory.
+#[derive(Default)]
+pub struct InMemoryProjects {
+    pub projects: Mutex<Vec<Project>>,
+}
+
+impl InMemoryProjects {
+    pub fn new(projects: Vec<Project>) -> Self {
+        Self {
+            projects: Mutex::new(projects),
+        }
+    }
+
+    pub fn project(&self, project_id: &str) -> Project {
+        self.projects
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|project| project.id == project_id)
+            .cloned()
+            .unwrap()
+    }
+}
+
+#[async_trait]
+impl ProjectsRepository for InMemoryProjects {
+    type TransactionItem = FakeWrite;
+
+    async fn create(&self, project: Project) -> Result<()> {
+        self.projects.lock().unwrap().push(project);
+        Ok(())
+    }
+
+    async fn delete(&self, customer_id: CustomerId, project_id: ProjectId) -> Result<()> {
+        self.projects.lock().unwrap().retain(|project| {
+            project.customer_id != customer_id || project.id != project_id || project.is_locked
+        });
+        Ok(())
+    }
+
+    async fn get(&self, customer_id: CustomerId, project_id: ProjectId) -> Result<Project> {
+        self.projects
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|project| project.customer_id == customer_id && project.id == project_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn query(
+        &self,
+        customer_id: CustomerId,
+        _from: Option<DateTime<Utc>>,
+        _to: Option<DateTime<Utc>>,
+        _name: Option<String>,
+        _cursor: Option<String>,
+        _limit: i32,
+    ) -> Result<QueryResponse<Vec<Project>, String>> {
+        let data = self
+            .projects
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|project| project.customer_id == customer_id)
+            .cloned()
+            .collect();
+        Ok(QueryResponse { data, cursor: None })
+    }
+
+    fn transaction_update(
+        &self,
+        customer_id: CustomerId,
+        project_id: ProjectId,
+        is_locked: bool,
+    ) -> FakeWrite {
+        FakeWrite::UpdateProject {
+            customer_id,
+            project_id,
+            is_locked,
+        }
+    }
+}
+
+/// Keeps the ledger of webhook events in memory.
+#[derive(Default)]
+pub struct InMemoryWebhookEvents {
+    pub events: Mutex<Vec<WebhookEvent>>,
+}
+
+#[async_trait]
+impl WebhookEventsRepository for InMemoryWebhookEvents {
+    type TransactionItem = FakeWrite;
+
+    async fn get(&self, event_id: WebhookEventId) -> Result<WebhookEvent> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|event| event.id == event_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn save_failed(&self, event: WebhookEvent) -> Result<()> {
+        let mut events = self.events.lock().unwrap();
+        if events.iter().any(|existing| {
+            existing.id == event.id && existing.status == WebhookEventStatus::Processed
+        }) {
+            return Err(Error::WebhookEventAlreadyProcessed(event.id));
+        }
+        events.retain(|existing| existing.id != event.id);
+        events.push(event);
+        Ok(())
+    }
+
+    async fn query_failed(
+        &self,
+        _cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<WebhookEvent>, String>> {
+        let data = self
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|event| event.status == WebhookEventStatus::Failed)
+            .take(limit as usize)
+            .cloned()
+            .collect();
+        Ok(QueryResponse { data, cursor: None })
+    }
+
+    fn transaction_create_processed(&self, event: WebhookEvent) -> FakeWrite {
+        FakeWrite::CreateProcessedWebhookEvent(Box::new(event))
+    }
+}
+
+/// Keeps invoices in memory.
+#[derive(Default)]
+pub struct InMemoryInvoices {
+    pub invoices: Mutex<Vec<Invoice>>,
+}
+
+#[async_trait]
+impl InvoicesRepository for InMemoryInvoices {
+    type TransactionItem = FakeWrite;
+
+    async fn get(&self, customer_id: CustomerId, quotation_id: QuoteId) -> Result<Invoice> {
+        self.invoices
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|invoice| {
+                invoice.customer_id == customer_id && invoice.quotation_id == quotation_id
+            })
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    fn transaction_create(&self, invoice: Invoice) -> FakeWrite {
+        FakeWrite::CreateInvoice(Box::new(invoice))
+    }
+}
+
+/// Keeps billing profiles in memory, customers without one are invoiced as the general public.
+#[derive(Default)]
+pub struct InMemoryBillingProfiles {
+    pub billing_profiles: Mutex<Vec<BillingProfile>>,
+}
+
+#[async_trait]
+impl BillingProfilesRepository for InMemoryBillingProfiles {
+    async fn get(&self, customer_id: CustomerId) -> Result<BillingProfile> {
+        self.billing_profiles
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|billing_profile| billing_profile.customer_id == customer_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn put(&self, billing_profile: BillingProfile) -> Result<()> {
+        let mut billing_profiles = self.billing_profiles.lock().unwrap();
+        billing_profiles.retain(|existing| existing.customer_id != billing_profile.customer_id);
+        billing_profiles.push(billing_profile);
+        Ok(())
+    }
+}
+
+/// Keeps work orders in memory, rejecting a second work order for an order and updates of work
+/// orders that changed since they were read like the DynamoDB repository.
+#[derive(Default)]
+pub struct InMemoryWorkOrders {
+    pub work_orders: Mutex<Vec<WorkOrder>>,
+    /// Makes `create` fail, as when DynamoDB is unreachable.
+    pub fails_creates: Mutex<bool>,
+}
+
+impl InMemoryWorkOrders {
+    pub fn new(work_orders: Vec<WorkOrder>) -> Self {
+        Self {
+            work_orders: Mutex::new(work_orders),
+            fails_creates: Mutex::new(false),
+        }
+    }
+}
+
+#[async_trait]
+impl WorkOrdersRepository for InMemoryWorkOrders {
+    async fn create(&self, work_order: WorkOrder) -> Result<()> {
+        if *self.fails_creates.lock().unwrap() {
+            return Err(Error::UnknownError);
+        }
+        let mut work_orders = self.work_orders.lock().unwrap();
+        if work_orders.iter().any(|existing| {
+            existing.customer_id == work_order.customer_id
+                && existing.order_id == work_order.order_id
+        }) {
+            return Err(Error::WorkOrderAlreadyExists(work_order.order_id));
+        }
+        work_orders.push(work_order);
+        Ok(())
+    }
+
+    async fn get(&self, customer_id: CustomerId, order_id: OrderId) -> Result<WorkOrder> {
+        self.work_orders
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|work_order| {
+                work_order.customer_id == customer_id && work_order.order_id == order_id
+            })
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn query_open(
+        &self,
+        _cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<WorkOrder>, String>> {
+        let data = self
+            .work_orders
+            .lock()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|work_order| !work_order.is_completed())
+            .take(limit as usize)
+            .cloned()
+            .collect();
+        Ok(QueryResponse { data, cursor: None })
+    }
+
+    async fn update(&self, updatable_work_order: UpdatableWorkOrder) -> Result<WorkOrder> {
+        let mut work_orders = self.work_orders.lock().unwrap();
+        let work_order = work_orders
+            .iter_mut()
+            .find(|work_order| {
+                work_order.customer_id == updatable_work_order.customer_id
+                    && work_order.order_id == updatable_work_order.order_id
+            })
+            .ok_or(Error::ItemNotFoundError)?;
+        if work_order.updated_at != updatable_work_order.read_updated_at {
+            return Err(Error::WorkOrderChanged(updatable_work_order.id));
+        }
+        work_order.operations = updatable_work_order.operations;
+        work_order.updated_at = Utc::now();
+        Ok(work_order.clone())
+    }
+}
+
+/// Keeps RFQs in memory, rejecting bids on RFQs that were already priced like the DynamoDB
+/// repository.
+#[derive(Default)]
+pub struct InMemoryRfqs {
+    pub rfqs: Mutex<Vec<Rfq>>,
+}
+
+impl InMemoryRfqs {
+    pub fn new(rfqs: Vec<Rfq>) -> Self {
+        Self {
+            rfqs: Mutex::new(rfqs),
+        }
+    }
+
+    pub fn rfq(&self, rfq_id: &str) -> Rfq {
+        self.rfqs
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|rfq| rfq.id == rfq_id)
+            .cloned()
+            .unwrap()
+    }
+}
+
+#[async_trait]
+impl RfqsRepository for InMemoryRfqs {
+    async fn create(&self, rfq: Rfq) -> Result<()> {
+        self.rfqs.lock().unwrap().push(rfq);
+        Ok(())
+    }
+
+    async fn get(&self, quotation_id: QuoteId, rfq_id: RfqId) -> Result<Rfq> {
+        self.rfqs
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|rfq| rfq.quotation_id == quotation_id && rfq.id == rfq_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn query(&self, quotation_id: QuoteId) -> Result<Vec<Rfq>> {
+        Ok(self
+            .rfqs
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|rfq| rfq.quotation_id == quotation_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, updatable_rfq: UpdatableRfq) -> Result<Rfq> {
+        let mut rfqs = self.rfqs.lock().unwrap();
+        let rfq = rfqs
+            .iter_mut()
+            .find(|rfq| {
+                rfq.quotation_id == updatable_rfq.quotation_id && rfq.id == updatable_rfq.id
+            })
+            .ok_or(Error::ItemNotFoundError)?;
+        if let Some(bids) = updatable_rfq.bids {
+            if !rfq.accepts_bids() {
+                return Err(Error::RfqClosed(updatable_rfq.id));
+            }
+            rfq.bids = bids;
+        }
+        if let Some(status) = updatable_rfq.status {
+            rfq.status = status;
+        }
+        rfq.updated_at = Utc::now();
+        Ok(rfq.clone())
+    }
+}
+
+/// Keeps suppliers in memory, linking an identity only once like the DynamoDB repository.
+#[derive(Default)]
+pub struct InMemorySuppliers {
+    pub suppliers: Mutex<Vec<Supplier>>,
+}
+
+impl InMemorySuppliers {
+    pub fn new(suppliers: Vec<Supplier>) -> Self {
+        Self {
+            suppliers: Mutex::new(suppliers),
+        }
+    }
+}
+
+#[async_trait]
+impl SuppliersRepository for InMemorySuppliers {
+    async fn create(&self, supplier: Supplier) -> Result<()> {
+        self.suppliers.lock().unwrap().push(supplier);
+        Ok(())
+    }
+
+    async fn get(&self, supplier_id: SupplierId) -> Result<Supplier> {
+        self.suppliers
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|supplier| supplier.id == supplier_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn update(&self, updatable_supplier: UpdatableSupplier) -> Result<Supplier> {
+        let mut suppliers = self.suppliers.lock().unwrap();
+        let supplier = suppliers
+            .iter_mut()
+            .find(|supplier| supplier.id == updatable_supplier.id)
+            .ok_or(Error::ItemNotFoundError)?;
+        if let Some(invitation_token) = updatable_supplier.invitation_token {
+            supplier.invitation_token = Some(invitation_token);
+        }
+        if let Some(identity_id) = updatable_supplier.identity_id {
+            if supplier.identity_id.is_some() {
+                return Err(Error::Forbidden);
+            }
+            supplier.identity_id = Some(identity_id);
+            supplier.invitation_token = None;
+        }
+        supplier.updated_at = Utc::now();
+        Ok(supplier.clone())
+    }
+
+    async fn query(
+        &self,
+        _cursor: Option<String>,
+        limit: i32,
+    ) -> Result<QueryResponse<Vec<Supplier>, String>> {
+        let data = self
+            .suppliers
+            .lock()
+            .unwrap()
+            .iter()
+            .take(limit as usize)
+            .cloned()
+            .collect();
+        Ok(QueryResponse { data, cursor: None })
+    }
+}
+
+/// Keeps promotion codes in memory.
+#[derive(Default)]
+pub struct InMemoryPromotionCodes {
+    pub promotion_codes: Mutex<Vec<PromotionCode>>,
+}
+
+#[async_trait]
+impl PromotionCodesRepository for InMemoryPromotionCodes {
+    type TransactionItem = FakeWrite;
+
+    async fn get(&self, code: String) -> Result<PromotionCode> {
+        self.promotion_codes
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|promotion_code| promotion_code.code == code)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn create(&self, promotion_code: PromotionCode) -> Result<()> {
+        let mut promotion_codes = self.promotion_codes.lock().unwrap();
+        if promotion_codes
+            .iter()
+            .any(|existing| existing.code == promotion_code.code)
+        {
+            return Err(Error::PromotionCodeAlreadyExists(promotion_code.code));
+        }
+        promotion_codes.push(promotion_code);
+        Ok(())
+    }
+
+    fn transaction_redeem(&self, code: String) -> FakeWrite {
+        FakeWrite::RedeemPromotionCode(code)
+    }
+}
+
+/// Keeps pricing agreements in memory.
+#[derive(Default)]
+pub struct InMemoryPricingAgreements {
+    pub pricing_agreements: Mutex<Vec<PricingAgreement>>,
+}
+
+#[async_trait]
+impl PricingAgreementsRepository for InMemoryPricingAgreements {
+    async fn get(&self, customer_id: CustomerId) -> Result<PricingAgreement> {
+        self.pricing_agreements
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|pricing_agreement| pricing_agreement.customer_id == customer_id)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn put(&self, pricing_agreement: PricingAgreement) -> Result<()> {
+        let mut pricing_agreements = self.pricing_agreements.lock().unwrap();
+        pricing_agreements.retain(|existing| existing.customer_id != pricing_agreement.customer_id);
+        pricing_agreements.push(pricing_agreement);
+        Ok(())
+    }
+}
+
+/// Prices every part at `unit_price` per unit, or sends everything to review when `None`.
+pub struct FixedPricingEngine {
+    pub unit_price: Option<i64>,
+}
+
+impl PricingEngine for FixedPricingEngine {
+    fn instant_quotes(
+        &self,
+        parts: &[Part],
+        currency: Currency,
+    ) -> Option<HashMap<PartId, Vec<PartQuote>>> {
+        let unit_price = self.unit_price?;
+        Some(
+            parts
+                .iter()
+                .map(|part| {
+                    let part_quote = PartQuote::new(
+                        Money::new(unit_price, currency),
+                        Money::new(unit_price * part.quantity as i64, currency),
+                        5,
+                        None,
+                    );
+                    (part.id.clone(), vec![part_quote])
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Checkout session the use cases asked Stripe to create.
+#[derive(Clone, Debug)]
+pub struct RecordedCheckoutSession {
+    pub customer_id: String,
+    pub quotation_id: String,
+    pub currency: Currency,
+    pub parts: Vec<Part>,
+    pub jurisdiction: TaxJurisdiction,
+    pub discount: Option<DiscountLine>,
+    pub shipping_options: Vec<ShippingOption>,
+}
+
+/// Refund the use cases asked Stripe to make.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RecordedRefund {
+    pub payment_intent_id: String,
+    pub amount: Money,
+    pub idempotency_key: String,
+}
+
+/// Records what would be sent to Stripe. Payment intents are looked up in `quotation_payments`.
+#[derive(Default)]
+pub struct RecordingStripeClient {
+    pub checkout_sessions: Mutex<Vec<RecordedCheckoutSession>>,
+    pub refunds: Mutex<Vec<RecordedRefund>>,
+    /// Makes `create_refund` fail, as when Stripe is unreachable.
+    pub fails_refunds: Mutex<bool>,
+    pub quotation_payments: Mutex<HashMap<String, QuotationPayment>>,
+}
+
+#[async_trait]
+impl StripeClient for RecordingStripeClient {
+    async fn create_customer(&self, _name: String, _email: String) -> Result<Customer> {
+        Err(Error::UnknownError)
+    }
+
+    async fn create_product(&self, _name: String, _id: String) -> Result<()> {
+        Ok(())
+    }
+
+    async fn create_checkout_session(
+        &self,
+        customer_id: String,
+        _project_id: String,
+        quotation_id: String,
+        currency: Currency,
+        parts: Vec<Part>,
+        jurisdiction: TaxJurisdiction,
+        discount: Option<DiscountLine>,
+        shipping_options: Vec<ShippingOption>,
+    ) -> Result<String> {
+        let url = format!("https://checkout.test/{quotation_id}");
+        self.checkout_sessions
+            .lock()
+            .unwrap()
+            .push(RecordedCheckoutSession {
+                customer_id,
+                quotation_id,
+                currency,
+                parts,
+                jurisdiction,
+                discount,
+                shipping_options,
+            });
+        Ok(url)
+    }
+
+    async fn get_quotation_payment(&self, payment_intent_id: String) -> Result<QuotationPayment> {
+        self.quotation_payments
+            .lock()
+            .unwrap()
+            .get(&payment_intent_id)
+            .map(|payment| QuotationPayment {
+                customer_id: payment.customer_id.clone(),
+                project_id: payment.project_id.clone(),
+                quotation_id: payment.quotation_id.clone(),
+            })
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn create_refund(
+        &self,
+        payment_intent_id: String,
+        amount: Money,
+        idempotency_key: String,
+    ) -> Result<()> {
+        if *self.fails_refunds.lock().unwrap() {
+            return Err(Error::UnknownError);
+        }
+        self.refunds.lock().unwrap().push(RecordedRefund {
+            payment_intent_id,
+            amount,
+            idempotency_key,
+        });
+        Ok(())
+    }
+
+    async fn create_connected_account(&self, email: String, _country: String) -> Result<String> {
+        Ok(format!("acct_{email}"))
+    }
+
+    async fn create_account_onboarding_link(&self, account_id: String) -> Result<String> {
+        Ok(format!("https://connect.test/{account_id}"))
+    }
+
+    async fn create_transfer(
+        &self,
+        account_id: String,
+        _amount: Money,
+        _transfer_group: String,
+        idempotency_key: String,
+    ) -> Result<String> {
+        Ok(format!("tr_{account_id}_{idempotency_key}"))
+    }
+}
+
+/// Keeps objects in memory, presigned URLs are the key behind a fake host.
+#[derive(Default)]
+pub struct InMemoryObjectStorage {
+    pub objects: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+#[async_trait]
+impl ObjectStorage for InMemoryObjectStorage {
+    async fn put_object_presigned_url(&self, key: &str, _expires_in: Duration) -> Result<String> {
+        Ok(format!("https://storage.test/put/{key}"))
+    }
+
+    async fn get_object_presigned_url(&self, key: &str, _expires_in: Duration) -> Result<String> {
+        Ok(format!("https://storage.test/get/{key}"))
+    }
+
+    async fn get_object(&self, key: &str) -> Result<Vec<u8>> {
+        self.objects
+            .lock()
+            .unwrap()
+            .get(key)
+            .cloned()
+            .ok_or(Error::ItemNotFoundError)
+    }
+
+    async fn put_object(&self, key: &str, body: Vec<u8>) -> Result<()> {
+        self.objects.lock().unwrap().insert(String::from(key), body);
+        Ok(())
+    }
+
+    async fn delete_object(&self, key: &str) -> Result<()> {
+        self.objects.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    async fn bulk_delete_objects(&self, keys: Vec<&str>) -> Result<()> {
+        for key in keys {
+            self.delete_object(key).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Knows every identity as a customer with a `@test.com` email.
+pub struct FakeIdentityManager;
+
+#[async_trait]
+impl IdentityManager for FakeIdentityManager {
+    async fn register(
+        &self,
+        _email: String,
+        _password: String,
+        _metadata: MetadataPublic,
+    ) -> Result<SessionWithToken> {
+        Err(Error::UnknownError)
+    }
+
+    async fn login(&self, _email: String, _password: String) -> Result<SessionWithToken> {
+        Err(Error::UnknownError)
+    }
+
+    async fn logout(&self, _session_token: SessionToken) -> Result<()> {
+        Ok(())
+    }
+
+    async fn get_session(&self, _session_token: SessionToken) -> Result<Session> {
+        Err(Error::UnknownError)
+    }
+
+    async fn get_identity(&self, identity_id: IdentityId) -> Result<Identity> {
+        Ok(identity(&identity_id, Role::Customer))
+    }
+}
+
+/// Keeps the emails sent as (receiver, subject) pairs.
+#[derive(Default)]
+pub struct RecordingEmailer {
+    pub emails: Mutex<Vec<(String, String)>>,
+}
+
+#[async_trait]
+impl Emailer for RecordingEmailer {
+    async fn send_email(&self, receiver: &str, subject: &str, _message: &str) -> Result<()> {
+        self.emails
+            .lock()
+            .unwrap()
+            .push((String::from(receiver), String::from(subject)));
+        Ok(())
+    }
+
+    async fn send_email_to_admins(&self, subject: &str, message: &str) -> Result<()> {
+        self.send_email("admins", subject, message).await
+    }
+}
+
+pub fn identity(identity_id: &str, role: Role) -> Identity {
+    Identity {
+        id: String::from(identity_id),
+        traits: Traits {
+            email: format!("{identity_id}@test.com"),
+        },
+        metadata_public: MetadataPublic {
+            stripe_customer_id: None,
+            role,
+            supplier_id: None,
+        },
+    }
+}
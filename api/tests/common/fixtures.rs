@@ -0,0 +1,144 @@
+use api::config::{
+    ConfigExchangeRates, ConfigShipping, ConfigShippingRate, ConfigTaxRate, ConfigTaxes,
+};
+use api::orders::models::order::{Address, Order, OrderStatus};
+use api::parts::models::part::{Part, PartProcess, PartQuote};
+use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+use api::payments::models::webhook_event::{WebhookEvent, WebhookEventStatus};
+use api::projects::models::project::Project;
+use api::quotations::models::quotation::{Quotation, QuoteStatus};
+use api::services::exchange_rates_fixed::FixedExchangeRates;
+use api::services::shipping_calculator_rate_table::RateTableShippingCalculator;
+use api::services::tax_calculator_rate_table::RateTableTaxCalculator;
+use api::shared::file::File;
+use api::shared::money::Money;
+use api::shared::shipping::ShippingMethod;
+use chrono::{NaiveDate, Utc};
+use iso_currency::Currency;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub fn order(status: OrderStatus) -> Order {
+    Order::new(
+        String::from("customer_id"),
+        String::from("project_id"),
+        String::from("quotation_id"),
+        String::from("part_id"),
+        String::from("part_quote_id"),
+        NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+        status,
+        String::from("Jane Doe"),
+        Address::default(),
+        None,
+        None,
+        Money::new(10_000, Currency::MXN),
+    )
+}
+
+/// Project of the `quotation` fixtures.
+pub fn project(is_locked: bool) -> Project {
+    let mut project = Project::new(String::from("customer_id"), String::from("Brackets"));
+    project.id = String::from("project_id");
+    project.is_locked = is_locked;
+    project
+}
+
+pub fn quotation(status: QuoteStatus) -> Quotation {
+    let mut quotation = Quotation::new(
+        String::from("customer_id"),
+        String::from("project_id"),
+        String::from("Brackets"),
+        Currency::MXN,
+    );
+    quotation.status = status;
+    quotation
+}
+
+/// CNC part of `quotation` with its model at `model_key`.
+pub fn part(quotation: &Quotation, model_key: &str) -> Part {
+    Part::new(
+        quotation.customer_id.clone(),
+        quotation.project_id.clone(),
+        quotation.id.clone(),
+        PartProcess::CNC,
+        PartAttributes::CNC(CNCAttributes::default()),
+        File::new(String::from("part.stl"), String::from(model_key)),
+    )
+}
+
+/// Part of `quotation` with a selected part quote of `unit_price` per unit.
+pub fn quoted_part(quotation: &Quotation, quantity: u64, unit_price: i64) -> Part {
+    let mut part = part(quotation, "models/part.stl");
+    part.quantity = quantity;
+    let part_quote = PartQuote::new(
+        Money::new(unit_price, quotation.currency),
+        Money::new(unit_price * quantity as i64, quotation.currency),
+        10,
+        None,
+    );
+    part.selected_part_quote_id = Some(part_quote.id.clone());
+    part.part_quotes = Some(vec![part_quote]);
+    part
+}
+
+/// One MXN is worth 0.05 USD.
+pub fn exchange_rates() -> Arc<FixedExchangeRates> {
+    Arc::new(FixedExchangeRates::new(ConfigExchangeRates {
+        base: Currency::MXN,
+        rates: HashMap::from([(Currency::USD, 0.05)]),
+    }))
+}
+
+/// 16% IVA in Mexico, no sales tax in the US.
+pub fn tax_calculator() -> Arc<RateTableTaxCalculator> {
+    let tax_rate = |country: &str, name: &str, rate: f64| ConfigTaxRate {
+        country: String::from(country),
+        state: None,
+        name: String::from(name),
+        rate,
+        stripe_tax_rate_id: format!("txr_{country}"),
+    };
+
+    Arc::new(RateTableTaxCalculator::new(ConfigTaxes {
+        default_country: String::from("MX"),
+        rates: vec![
+            tax_rate("MX", "IVA", 0.16),
+            tax_rate("US", "Sales tax", 0.0),
+        ],
+    }))
+}
+
+/// Standard shipping to Mexico and the US, a flat 150 MXN.
+pub fn shipping_calculator() -> Arc<RateTableShippingCalculator> {
+    let rate = |country: &str| ConfigShippingRate {
+        country: String::from(country),
+        method: ShippingMethod::Standard,
+        base_cost: 15_000,
+        cost_per_kg: 0,
+        min_workdays: 3,
+        max_workdays: 5,
+    };
+
+    Arc::new(RateTableShippingCalculator::new(
+        ConfigShipping {
+            material_densities: HashMap::new(),
+            default_density: 8.0,
+            default_part_weight_kg: 1.0,
+            rates: vec![rate("MX"), rate("US")],
+        },
+        exchange_rates(),
+    ))
+}
+
+/// Stripe event delivered for the first time.
+pub fn webhook_event(event_id: &str, event_type: &str) -> WebhookEvent {
+    WebhookEvent {
+        id: String::from(event_id),
+        event_type: String::from(event_type),
+        status: WebhookEventStatus::Processed,
+        payload: String::from("{}"),
+        error: None,
+        created_at: Utc::now(),
+        updated_at: Utc::now(),
+    }
+}
@@ -1,2 +1,7 @@
+// Every test crate includes the whole module but only uses part of it.
+#![allow(dead_code)]
+
 pub mod app;
+pub mod fakes;
+pub mod fixtures;
 pub mod user_generator;
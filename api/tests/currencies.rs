@@ -0,0 +1,404 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod money {
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+
+    #[test]
+    fn it_should_add_subtract_and_multiply_amounts_of_the_same_currency() {
+        let money = Money::new(1_050, Currency::USD);
+
+        assert_eq!(
+            money.checked_add(&Money::new(950, Currency::USD)).unwrap(),
+            Money::new(2_000, Currency::USD)
+        );
+        assert_eq!(
+            money
+                .checked_sub(&Money::new(1_100, Currency::USD))
+                .unwrap(),
+            Money::new(-50, Currency::USD)
+        );
+        assert_eq!(
+            money.checked_mul(3).unwrap(),
+            Money::new(3_150, Currency::USD)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_mixed_currencies() {
+        let money = Money::new(1_000, Currency::MXN);
+        let other = Money::new(1_000, Currency::USD);
+
+        assert!(matches!(
+            money.checked_add(&other),
+            Err(Error::CurrencyMismatch(Currency::MXN, Currency::USD))
+        ));
+        assert!(matches!(
+            money.checked_sub(&other),
+            Err(Error::CurrencyMismatch(Currency::MXN, Currency::USD))
+        ));
+        assert!(matches!(
+            Money::checked_sum(Currency::MXN, [&money, &other]),
+            Err(Error::CurrencyMismatch(Currency::MXN, Currency::USD))
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_overflows() {
+        let money = Money::new(i64::MAX, Currency::MXN);
+
+        assert!(money.checked_add(&Money::new(1, Currency::MXN)).is_err());
+        assert!(money.checked_mul(2).is_err());
+    }
+}
+
+mod exchange_rates {
+    use api::config::ConfigExchangeRates;
+    use api::services::exchange_rates::ExchangeRates;
+    use api::services::exchange_rates_fixed::FixedExchangeRates;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+    use std::collections::HashMap;
+
+    fn exchange_rates() -> FixedExchangeRates {
+        FixedExchangeRates::new(ConfigExchangeRates {
+            base: Currency::MXN,
+            rates: HashMap::from([(Currency::USD, 0.05), (Currency::JPY, 8.0)]),
+        })
+    }
+
+    #[test]
+    fn it_should_convert_from_and_to_the_base_currency() {
+        let exchange_rates = exchange_rates();
+
+        assert_eq!(
+            exchange_rates
+                .convert(&Money::new(20_000, Currency::MXN), Currency::USD)
+                .unwrap(),
+            Money::new(1_000, Currency::USD)
+        );
+        assert_eq!(
+            exchange_rates
+                .convert(&Money::new(1_000, Currency::USD), Currency::MXN)
+                .unwrap(),
+            Money::new(20_000, Currency::MXN)
+        );
+    }
+
+    #[test]
+    fn it_should_convert_between_currencies_with_different_minor_units() {
+        // 100.00 MXN are 800 JPY, which has no minor unit.
+        assert_eq!(
+            exchange_rates()
+                .convert(&Money::new(10_000, Currency::MXN), Currency::JPY)
+                .unwrap(),
+            Money::new(800, Currency::JPY)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_currencies_without_a_rate() {
+        assert!(matches!(
+            exchange_rates().convert(&Money::new(10_000, Currency::MXN), Currency::EUR),
+            Err(Error::UnsupportedCurrency(Currency::EUR))
+        ));
+    }
+}
+
+mod checkout {
+    use api::config::{
+        ConfigExchangeRates, ConfigPricing, ConfigPricingLeadTime, ConfigPricingMaterial,
+    };
+    use api::parts::models::part::{Part, PartProcess};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::parts::models::part_geometry::{BoundingBox, PartGeometry};
+    use api::quotations::models::quotation::Quotation;
+    use api::services::exchange_rates_fixed::FixedExchangeRates;
+    use api::services::pricing_engine::PricingEngine;
+    use api::services::pricing_engine_rate_table::RateTablePricingEngine;
+    use api::services::stripe::checkout_line_items;
+    use api::shared::error::Error;
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn engine() -> RateTablePricingEngine {
+        RateTablePricingEngine::new(
+            ConfigPricing {
+                setup_cost: 50_000,
+                removal_cost_per_cm3: 100,
+                surface_cost_per_cm2: 10,
+                max_dimension_mm: 500.0,
+                max_instant_subtotal: 5_000_000,
+                materials: HashMap::from([(
+                    String::from("Aluminum 6061-T6"),
+                    ConfigPricingMaterial {
+                        stock_cost_per_cm3: 20,
+                    },
+                )]),
+                lead_times: vec![ConfigPricingLeadTime {
+                    workdays: 10,
+                    multiplier: 1.0,
+                }],
+            },
+            Arc::new(FixedExchangeRates::new(ConfigExchangeRates {
+                base: Currency::MXN,
+                rates: HashMap::from([(Currency::USD, 0.05)]),
+            })),
+        )
+    }
+
+    /// Prices the quotation instantly and selects the offered part quotes, like sending it
+    /// for review does.
+    pub(super) fn priced_quotation(currency: Currency) -> (Quotation, Vec<Part>) {
+        let quotation = Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets"),
+            currency,
+        );
+        let mut part = Part::new(
+            quotation.customer_id.clone(),
+            quotation.project_id.clone(),
+            quotation.id.clone(),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes::default()),
+            File::new(String::from("bracket.stl"), String::from("key")),
+        );
+        part.quantity = 10;
+        part.geometry = Some(PartGeometry {
+            bounding_box: BoundingBox {
+                x_mm: 100.0,
+                y_mm: 50.0,
+                z_mm: 20.0,
+            },
+            volume_mm3: 60_000.0,
+            surface_area_mm2: 20_000.0,
+            triangle_count: 12,
+        });
+
+        let mut part_quotes_by_part = engine()
+            .instant_quotes(std::slice::from_ref(&part), quotation.currency)
+            .unwrap();
+        let part_quotes = part_quotes_by_part.remove(&part.id).unwrap();
+        part.selected_part_quote_id = Some(part_quotes[0].id.clone());
+        part.part_quotes = Some(part_quotes);
+
+        (quotation, vec![part])
+    }
+
+    #[test]
+    fn it_should_check_out_mxn_quotations_in_mxn() {
+        let (quotation, parts) = priced_quotation(Currency::MXN);
+
+        assert_eq!(
+            quotation.subtotal(&parts).unwrap(),
+            Money::new(130_000, Currency::MXN)
+        );
+
+        let line_items =
+            checkout_line_items(&parts, quotation.currency, &[String::from("txr_iva")]).unwrap();
+        let price_data = line_items[0].price_data.as_ref().unwrap();
+        assert_eq!(price_data.currency, stripe::Currency::MXN);
+        assert_eq!(price_data.unit_amount, Some(13_000));
+        assert_eq!(line_items[0].quantity, Some(10));
+    }
+
+    #[test]
+    fn it_should_check_out_usd_quotations_in_usd() {
+        let (quotation, parts) = priced_quotation(Currency::USD);
+
+        // 130.00 MXN per part at 0.05 USD per MXN.
+        assert_eq!(
+            quotation.subtotal(&parts).unwrap(),
+            Money::new(6_500, Currency::USD)
+        );
+
+        let line_items =
+            checkout_line_items(&parts, quotation.currency, &[String::from("txr_iva")]).unwrap();
+        let price_data = line_items[0].price_data.as_ref().unwrap();
+        assert_eq!(price_data.currency, stripe::Currency::USD);
+        assert_eq!(price_data.unit_amount, Some(650));
+        assert_eq!(line_items[0].quantity, Some(10));
+    }
+
+    #[test]
+    fn it_should_not_check_out_parts_priced_in_another_currency() {
+        let (_, parts) = priced_quotation(Currency::MXN);
+        let quotation = Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets"),
+            Currency::USD,
+        );
+
+        assert!(matches!(
+            quotation.subtotal(&parts),
+            Err(Error::CurrencyMismatch(Currency::USD, Currency::MXN))
+        ));
+        assert!(matches!(
+            checkout_line_items(&parts, quotation.currency, &[]),
+            Err(Error::CurrencyMismatch(Currency::USD, Currency::MXN))
+        ));
+    }
+
+    #[test]
+    fn it_should_not_check_out_unsupported_currencies() {
+        let (_, parts) = priced_quotation(Currency::MXN);
+
+        assert!(matches!(
+            checkout_line_items(&parts, Currency::EUR, &[]),
+            Err(Error::UnsupportedCurrency(Currency::EUR))
+        ));
+    }
+}
+
+mod checkout_and_payment {
+    use super::checkout::priced_quotation;
+    use crate::common::fakes::{
+        identity, InMemoryBillingProfiles, InMemoryInvoices, InMemoryOrders, InMemoryParts,
+        InMemoryProjects, InMemoryPromotionCodes, InMemoryQuotes, InMemoryTransaction,
+        InMemoryWebhookEvents, InMemoryWorkOrders, RecordedCheckoutSession, RecordingEmailer,
+        RecordingStripeClient,
+    };
+    use crate::common::fixtures::{project, shipping_calculator, tax_calculator, webhook_event};
+    use api::auth::models::session::Role;
+    use api::billing::models::invoice::Invoice;
+    use api::orders::models::order::{Address, Order};
+    use api::payments::models::inputs::{
+        CompleteCheckoutSessionWebhookRequest, CreateCheckoutSessionInput, WebhookEventInput,
+    };
+    use api::payments::usecases::create_checkout_session::CreateCheckoutSession;
+    use api::payments::usecases::create_orders_and_confirm_quotation_payment::CreateOrdersAndConfirmQuotationPayment;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::shared::money::Money;
+    use api::shared::shipping::ShippingCharge;
+    use api::shared::UseCase;
+    use iso_currency::Currency;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    /// Checks out an instantly priced quotation shipping to `country`, then completes the
+    /// checkout session with what Stripe was asked to charge.
+    async fn check_out_and_pay(
+        currency: Currency,
+        country: &str,
+    ) -> (RecordedCheckoutSession, Vec<Order>, Invoice) {
+        let (mut quotation, parts) = priced_quotation(currency);
+        quotation.status = QuoteStatus::PendingPayment;
+        let shipping_address = Address {
+            country: Some(String::from(country)),
+            ..Address::default()
+        };
+        let parts = Arc::new(InMemoryParts::new(parts));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+        let stripe_client = Arc::new(RecordingStripeClient::default());
+
+        CreateCheckoutSession::new(
+            stripe_client.clone(),
+            parts.clone(),
+            quotes.clone(),
+            Arc::new(InMemoryPromotionCodes::default()),
+            tax_calculator(),
+            shipping_calculator(),
+        )
+        .execute(CreateCheckoutSessionInput {
+            identity: identity(&quotation.customer_id, Role::Customer),
+            project_id: quotation.project_id.clone(),
+            quotation_id: quotation.id.clone(),
+            shipping_address: Some(shipping_address.clone()),
+        })
+        .await
+        .unwrap();
+        let checkout_session = stripe_client.checkout_sessions.lock().unwrap().remove(0);
+
+        let orders = Arc::new(InMemoryOrders::default());
+        let invoices = Arc::new(InMemoryInvoices::default());
+        let projects = Arc::new(InMemoryProjects::new(vec![project(false)]));
+        let webhook_events = Arc::new(InMemoryWebhookEvents::default());
+        let transaction = InMemoryTransaction::new(parts.clone(), quotes.clone())
+            .with_orders(orders.clone())
+            .with_invoices(invoices.clone())
+            .with_projects(projects.clone())
+            .with_webhook_events(webhook_events.clone());
+        let shipping_option = &checkout_session.shipping_options[0];
+
+        CreateOrdersAndConfirmQuotationPayment::new(
+            projects,
+            quotes,
+            orders.clone(),
+            parts,
+            webhook_events,
+            invoices.clone(),
+            Arc::new(InMemoryBillingProfiles::default()),
+            Arc::new(InMemoryPromotionCodes::default()),
+            Arc::new(InMemoryWorkOrders::default()),
+            Arc::new(Mutex::new(transaction)),
+            Arc::new(RecordingEmailer::default()),
+            tax_calculator(),
+        )
+        .execute(WebhookEventInput {
+            webhook_event: webhook_event("evt_1", "checkout.session.completed"),
+            data: CompleteCheckoutSessionWebhookRequest {
+                customer_id: quotation.customer_id,
+                project_id: quotation.project_id,
+                quotation_id: quotation.id,
+                shipping_recipient_name: String::from("Recipient"),
+                shipping_address,
+                is_paid: true,
+                payment_intent_id: Some(String::from("pi_test")),
+                tax_jurisdiction: Some(checkout_session.jurisdiction.clone()),
+                discount: None,
+                shipping: Some(ShippingCharge {
+                    method: shipping_option.method.clone(),
+                    cost: shipping_option.cost.clone(),
+                }),
+            },
+        })
+        .await
+        .unwrap();
+
+        let orders = orders.orders.lock().unwrap().clone();
+        let invoice = invoices.invoices.lock().unwrap().remove(0);
+        (checkout_session, orders, invoice)
+    }
+
+    #[tokio::test]
+    async fn it_should_charge_and_invoice_mxn_quotations_in_mxn() {
+        let (checkout_session, orders, invoice) = check_out_and_pay(Currency::MXN, "MX").await;
+
+        assert_eq!(checkout_session.currency, Currency::MXN);
+        assert_eq!(
+            checkout_session.shipping_options[0].cost,
+            Money::new(15_000, Currency::MXN)
+        );
+        assert_eq!(orders[0].price, Some(Money::new(130_000, Currency::MXN)));
+        assert_eq!(invoice.subtotal, Money::new(130_000, Currency::MXN));
+        // 16% IVA on the parts, shipping isn't taxed.
+        assert_eq!(
+            invoice.tax_lines[0].amount,
+            Money::new(20_800, Currency::MXN)
+        );
+        assert_eq!(invoice.total, Money::new(165_800, Currency::MXN));
+    }
+
+    #[tokio::test]
+    async fn it_should_charge_and_invoice_usd_quotations_in_usd() {
+        let (checkout_session, orders, invoice) = check_out_and_pay(Currency::USD, "US").await;
+
+        assert_eq!(checkout_session.currency, Currency::USD);
+        // 150.00 MXN at 0.05 USD per MXN.
+        assert_eq!(
+            checkout_session.shipping_options[0].cost,
+            Money::new(750, Currency::USD)
+        );
+        assert_eq!(orders[0].price, Some(Money::new(6_500, Currency::USD)));
+        assert_eq!(invoice.subtotal, Money::new(6_500, Currency::USD));
+        assert_eq!(invoice.total, Money::new(7_250, Currency::USD));
+    }
+}
@@ -0,0 +1,124 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod inspection_reports {
+    use api::orders::models::inspection_report::{InspectionReport, Measurement};
+    use api::shared::error::Error;
+
+    fn measurement(measured_mm: f64) -> Measurement {
+        Measurement {
+            feature: String::from("Bore A"),
+            nominal_mm: 12.0,
+            upper_tolerance_mm: 0.05,
+            lower_tolerance_mm: 0.02,
+            measured_mm,
+        }
+    }
+
+    fn inspect(measurements: Vec<Measurement>) -> Result<InspectionReport, Error> {
+        InspectionReport::new(
+            measurements,
+            String::from("inspector_id"),
+            String::from("inspector@example.com"),
+        )
+    }
+
+    #[test]
+    fn it_should_pass_measurements_within_their_tolerance_limits() {
+        assert!(measurement(12.0).passed());
+        assert!(measurement(12.05).passed());
+        assert!(measurement(11.98).passed());
+        assert!(!measurement(12.051).passed());
+        assert!(!measurement(11.979).passed());
+    }
+
+    #[test]
+    fn it_should_only_pass_reports_whose_measurements_all_passed() {
+        let report = inspect(vec![measurement(12.01), measurement(11.99)]).unwrap();
+        assert!(report.passed);
+        assert_eq!(report.inspector_email, "inspector@example.com");
+
+        let report = inspect(vec![measurement(12.01), measurement(12.2)]).unwrap();
+        assert!(!report.passed);
+        assert_eq!(report.measurements.len(), 2);
+    }
+
+    #[test]
+    fn it_should_require_at_least_one_measurement() {
+        assert!(matches!(
+            inspect(Vec::new()),
+            Err(Error::InvalidInspectionReport(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_invalid_measurements() {
+        let without_feature = Measurement {
+            feature: String::from(" "),
+            ..measurement(12.0)
+        };
+        let negative_tolerance = Measurement {
+            lower_tolerance_mm: -0.02,
+            ..measurement(12.0)
+        };
+        let not_a_number = measurement(f64::NAN);
+
+        for invalid_measurement in [without_feature, negative_tolerance, not_a_number] {
+            assert!(matches!(
+                inspect(vec![invalid_measurement]),
+                Err(Error::InvalidInspectionReport(_))
+            ));
+        }
+    }
+}
+
+mod pdf_certificate_renderer {
+    use crate::common::fixtures::{self, quotation};
+    use api::config::ConfigQuotesDocument;
+    use api::orders::models::inspection_report::{InspectionReport, Measurement};
+    use api::orders::models::order::OrderStatus;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::services::certificate_renderer::CertificateRenderer;
+    use api::services::certificate_renderer_pdf::PdfCertificateRenderer;
+    use api::shared::file::File;
+
+    #[tokio::test]
+    async fn it_should_render_a_pdf_document() {
+        let renderer = PdfCertificateRenderer::new(ConfigQuotesDocument {
+            company_name: String::from("Torvek"),
+            company_address: vec![String::from("Monterrey, N.L.")],
+        });
+        let mut part = fixtures::part(&quotation(QuoteStatus::Payed), "models/part.step");
+        part.model_file = File::new(
+            String::from("bracket (rev. A).step"),
+            String::from("models/part.step"),
+        );
+        let mut order = fixtures::order(OrderStatus::Ready);
+        order.part_id = part.id.clone();
+        let inspection_report = InspectionReport::new(
+            vec![Measurement {
+                feature: String::from("Bore A"),
+                nominal_mm: 12.0,
+                upper_tolerance_mm: 0.05,
+                lower_tolerance_mm: 0.05,
+                measured_mm: 12.01,
+            }],
+            String::from("inspector_id"),
+            String::from("inspector@example.com"),
+        )
+        .unwrap();
+
+        let pdf = renderer
+            .render_certificate_of_conformance(&order, &part, &inspection_report)
+            .await
+            .unwrap();
+        let pdf = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("CERTIFICATE OF CONFORMANCE"));
+        assert!(pdf.contains("bracket \\(rev. A\\).step"));
+        assert!(pdf.contains("+/- 0.050"));
+        assert!(pdf.contains("PASS"));
+    }
+}
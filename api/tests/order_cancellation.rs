@@ -0,0 +1,576 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod order_cancellation {
+    use api::orders::models::order::OrderStatus;
+    use api::quotations::models::quotation::QuoteStatus;
+
+    #[test]
+    fn it_should_only_cancel_orders_that_did_not_leave_the_shop() {
+        assert!(OrderStatus::Open.can_be_cancelled());
+        assert!(OrderStatus::InProgress.can_be_cancelled());
+        assert!(OrderStatus::Ready.can_be_cancelled());
+        assert!(!OrderStatus::Shipped.can_be_cancelled());
+        assert!(!OrderStatus::Delivered.can_be_cancelled());
+        assert!(!OrderStatus::Refunded.can_be_cancelled());
+        assert!(!OrderStatus::Cancelled.can_be_cancelled());
+    }
+
+    #[test]
+    fn it_should_not_move_cancelled_orders_through_the_lifecycle() {
+        assert!(!OrderStatus::Cancelled.can_transition_to(&OrderStatus::Open));
+        assert!(!OrderStatus::Open.can_transition_to(&OrderStatus::Cancelled));
+    }
+
+    #[test]
+    fn it_should_keep_cancelled_quotations_locked() {
+        assert!(QuoteStatus::Cancelled.is_locked());
+        assert!(QuoteStatus::Payed.is_locked());
+        assert!(!QuoteStatus::PaymentFailed.is_locked());
+        assert!(!QuoteStatus::PendingPayment.is_locked());
+    }
+}
+
+mod admin_cancel_orders {
+    use crate::common::fakes::{
+        identity, FakeIdentityManager, InMemoryInvoices, InMemoryOrders, InMemoryParts,
+        InMemoryProjects, InMemoryQuotes, InMemoryTransaction, RecordedRefund, RecordingEmailer,
+        RecordingStripeClient,
+    };
+    use crate::common::fixtures::{order, project, quotation, quoted_part, tax_calculator};
+    use api::auth::models::session::Role;
+    use api::billing::models::invoice::Invoice;
+    use api::orders::models::inputs::{AdminCancelOrdersInput, CancelOrderData};
+    use api::orders::models::order::{Order, OrderStatus};
+    use api::orders::usecases::admin_cancel_orders::AdminCancelOrders;
+    use api::parts::models::part::Part;
+    use api::promotions::models::discount::DiscountLine;
+    use api::quotations::models::quotation::{Quotation, QuoteStatus};
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::tax::TaxJurisdiction;
+    use api::shared::UseCase;
+    use iso_currency::Currency;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    struct Setup {
+        quotation: Quotation,
+        parts: Vec<Part>,
+        invoices: Arc<InMemoryInvoices>,
+        orders: Arc<InMemoryOrders>,
+        quotes: Arc<InMemoryQuotes>,
+        projects: Arc<InMemoryProjects>,
+        stripe_client: Arc<RecordingStripeClient>,
+        usecase: AdminCancelOrders<
+            InMemoryOrders,
+            InMemoryQuotes,
+            InMemoryProjects,
+            InMemoryParts,
+            InMemoryInvoices,
+            InMemoryTransaction,
+            crate::common::fakes::FakeWrite,
+        >,
+    }
+
+    fn paid_order(quotation: &Quotation, part: &Part) -> Order {
+        let mut order = order(OrderStatus::Open);
+        order.quotation_id = quotation.id.clone();
+        order.part_id = part.id.clone();
+        order.part_quote_id = part.selected_part_quote_id.clone().unwrap();
+        order.payment_intent_id = Some(String::from("pi_test"));
+        order
+    }
+
+    /// A paid quotation with an order of 2 parts at 100 MXN each per part, plus `quotations`.
+    fn setup(part_count: usize, quotations: Vec<Quotation>) -> Setup {
+        let quotation = quotation(QuoteStatus::Payed);
+        let parts = (0..part_count)
+            .map(|_| quoted_part(&quotation, 2, 10_000))
+            .collect::<Vec<Part>>();
+        let orders = Arc::new(InMemoryOrders::new(
+            parts
+                .iter()
+                .map(|part| paid_order(&quotation, part))
+                .collect(),
+        ));
+        let invoices = Arc::new(InMemoryInvoices::default());
+        let in_memory_parts = Arc::new(InMemoryParts::new(parts.clone()));
+        let quotes = Arc::new(InMemoryQuotes::new(
+            [vec![quotation.clone()], quotations].concat(),
+        ));
+        let projects = Arc::new(InMemoryProjects::new(vec![project(true)]));
+        let transaction = InMemoryTransaction::new(in_memory_parts.clone(), quotes.clone())
+            .with_orders(orders.clone())
+            .with_projects(projects.clone());
+        let stripe_client = Arc::new(RecordingStripeClient::default());
+
+        Setup {
+            usecase: AdminCancelOrders::new(
+                orders.clone(),
+                quotes.clone(),
+                projects.clone(),
+                in_memory_parts,
+                invoices.clone(),
+                Arc::new(Mutex::new(transaction)),
+                stripe_client.clone(),
+                Arc::new(FakeIdentityManager),
+                Arc::new(RecordingEmailer::default()),
+            ),
+            quotation,
+            parts,
+            invoices,
+            orders,
+            quotes,
+            projects,
+            stripe_client,
+        }
+    }
+
+    /// Invoices the quotation as paid in Mexico, with `discount` taken off before IVA.
+    fn invoice(setup: &Setup, discount: i64) {
+        let invoice = Invoice::new(
+            &setup.quotation,
+            &setup.parts,
+            None,
+            Some(String::from("pi_test")),
+            tax_calculator().as_ref(),
+            TaxJurisdiction {
+                country: String::from("MX"),
+                state: None,
+            },
+            Some(DiscountLine {
+                code: String::from("WELCOME"),
+                amount: Money::new(discount, Currency::MXN),
+            }),
+            None,
+        )
+        .unwrap();
+        setup.invoices.invoices.lock().unwrap().push(invoice);
+    }
+
+    fn input(setup: &Setup, orders: Vec<CancelOrderData>) -> AdminCancelOrdersInput {
+        AdminCancelOrdersInput {
+            identity: identity("admin_id", Role::Admin),
+            customer_id: setup.quotation.customer_id.clone(),
+            quotation_id: setup.quotation.id.clone(),
+            orders,
+        }
+    }
+
+    fn order_ids(setup: &Setup) -> Vec<String> {
+        setup
+            .orders
+            .orders
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|order| order.id.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_refunds_other_than_part_of_what_was_paid() {
+        for refund in [
+            Money::new(20_001, Currency::MXN),
+            Money::new(-1, Currency::MXN),
+            Money::new(100, Currency::USD),
+        ] {
+            let setup = setup(1, vec![]);
+            let order_id = order_ids(&setup)[0].clone();
+
+            let result = setup
+                .usecase
+                .execute(input(
+                    &setup,
+                    vec![CancelOrderData {
+                        order_id: order_id.clone(),
+                        refund: Some(refund),
+                    }],
+                ))
+                .await;
+
+            assert!(matches!(result, Err(Error::InvalidRefundAmount(_))));
+            assert!(setup.stripe_client.refunds.lock().unwrap().is_empty());
+            assert_eq!(setup.orders.order(&order_id).status, OrderStatus::Open);
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_refund_up_to_what_was_charged_for_each_order() {
+        let setup = setup(2, vec![]);
+        // 200.00 MXN per order less half the 40.00 MXN discount, plus 16% IVA.
+        invoice(&setup, 4_000);
+        let order_ids = order_ids(&setup);
+
+        let result = setup
+            .usecase
+            .execute(input(
+                &setup,
+                vec![CancelOrderData {
+                    order_id: order_ids[0].clone(),
+                    refund: Some(Money::new(20_881, Currency::MXN)),
+                }],
+            ))
+            .await;
+        assert!(matches!(result, Err(Error::InvalidRefundAmount(_))));
+
+        setup
+            .usecase
+            .execute(input(
+                &setup,
+                vec![CancelOrderData {
+                    order_id: order_ids[0].clone(),
+                    refund: None,
+                }],
+            ))
+            .await
+            .unwrap();
+        assert_eq!(
+            setup.stripe_client.refunds.lock().unwrap()[0].amount,
+            Money::new(20_880, Currency::MXN)
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_refund_the_orders_at_once_with_a_key_of_the_orders() {
+        let setup = setup(2, vec![]);
+        let mut order_ids = order_ids(&setup);
+
+        setup
+            .usecase
+            .execute(input(
+                &setup,
+                order_ids
+                    .iter()
+                    .rev()
+                    .map(|order_id| CancelOrderData {
+                        order_id: order_id.clone(),
+                        refund: None,
+                    })
+                    .collect(),
+            ))
+            .await
+            .unwrap();
+
+        order_ids.sort();
+        assert_eq!(
+            *setup.stripe_client.refunds.lock().unwrap(),
+            vec![RecordedRefund {
+                payment_intent_id: String::from("pi_test"),
+                amount: Money::new(40_000, Currency::MXN),
+                idempotency_key: format!("cancel_{}", order_ids.join("_")),
+            }]
+        );
+        for order_id in order_ids {
+            let order = setup.orders.order(&order_id);
+            assert_eq!(order.status, OrderStatus::Cancelled);
+            assert_eq!(order.refund, Some(Money::new(20_000, Currency::MXN)));
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_cancel_the_orders_before_refunding_them() {
+        let setup = setup(2, vec![]);
+        let order_ids = order_ids(&setup);
+        *setup.stripe_client.fails_refunds.lock().unwrap() = true;
+
+        let result = setup
+            .usecase
+            .execute(input(
+                &setup,
+                order_ids
+                    .iter()
+                    .map(|order_id| CancelOrderData {
+                        order_id: order_id.clone(),
+                        refund: None,
+                    })
+                    .collect(),
+            ))
+            .await;
+
+        assert!(result.is_err());
+        for order_id in &order_ids {
+            let order = setup.orders.order(order_id);
+            assert_eq!(order.status, OrderStatus::Cancelled);
+            assert_eq!(order.refund, Some(Money::new(20_000, Currency::MXN)));
+            assert!(order.pending_refund_key.is_some());
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_retry_pending_refunds_with_the_key_they_were_recorded_with() {
+        let setup = setup(2, vec![]);
+        let mut order_ids = order_ids(&setup);
+        *setup.stripe_client.fails_refunds.lock().unwrap() = true;
+        let cancel = |order_ids: &[String]| {
+            order_ids
+                .iter()
+                .map(|order_id| CancelOrderData {
+                    order_id: order_id.clone(),
+                    refund: None,
+                })
+                .collect::<Vec<CancelOrderData>>()
+        };
+        let result = setup
+            .usecase
+            .execute(input(&setup, cancel(&order_ids)))
+            .await;
+        assert!(result.is_err());
+        *setup.stripe_client.fails_refunds.lock().unwrap() = false;
+
+        // Retrying part of the cancellation refunds what was recorded for all of it.
+        let cancelled_orders = setup
+            .usecase
+            .execute(input(&setup, cancel(&order_ids[..1])))
+            .await
+            .unwrap();
+        let result = setup
+            .usecase
+            .execute(input(&setup, cancel(&order_ids[1..])))
+            .await;
+        assert!(matches!(result, Err(Error::OrderCannotBeCancelled(_))));
+
+        order_ids.sort();
+        assert_eq!(
+            *setup.stripe_client.refunds.lock().unwrap(),
+            vec![RecordedRefund {
+                payment_intent_id: String::from("pi_test"),
+                amount: Money::new(40_000, Currency::MXN),
+                idempotency_key: format!("cancel_{}", order_ids.join("_")),
+            }]
+        );
+        assert_eq!(cancelled_orders.len(), 1);
+        for order_id in order_ids {
+            let order = setup.orders.order(&order_id);
+            assert_eq!(order.status, OrderStatus::Cancelled);
+            assert_eq!(order.pending_refund_key, None);
+        }
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Cancelled
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_unlock_the_project_once_no_order_is_active() {
+        let setup = setup(2, vec![]);
+        let order_ids = order_ids(&setup);
+        let cancel = |order_id: &String| CancelOrderData {
+            order_id: order_id.clone(),
+            refund: None,
+        };
+
+        setup
+            .usecase
+            .execute(input(&setup, vec![cancel(&order_ids[0])]))
+            .await
+            .unwrap();
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Payed
+        );
+        assert!(setup.projects.project("project_id").is_locked);
+
+        setup
+            .usecase
+            .execute(input(&setup, vec![cancel(&order_ids[1])]))
+            .await
+            .unwrap();
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Cancelled
+        );
+        assert!(!setup.projects.project("project_id").is_locked);
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_the_project_locked_while_another_quotation_is_paid() {
+        let setup = setup(1, vec![quotation(QuoteStatus::Payed)]);
+        let order_id = order_ids(&setup)[0].clone();
+
+        setup
+            .usecase
+            .execute(input(
+                &setup,
+                vec![CancelOrderData {
+                    order_id,
+                    refund: None,
+                }],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Cancelled
+        );
+        assert!(setup.projects.project("project_id").is_locked);
+    }
+
+    #[tokio::test]
+    async fn it_should_cancel_quotations_the_refund_webhook_already_refunded() {
+        let setup = setup(1, vec![]);
+        let order_id = order_ids(&setup)[0].clone();
+        // The `charge.refunded` event of the refund got processed before the orders were
+        // cancelled.
+        setup.quotes.quotations.lock().unwrap()[0].status = QuoteStatus::Refunded;
+
+        setup
+            .usecase
+            .execute(input(
+                &setup,
+                vec![CancelOrderData {
+                    order_id,
+                    refund: None,
+                }],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Cancelled
+        );
+        assert!(!setup.projects.project("project_id").is_locked);
+    }
+}
+
+mod refund_quotation_payment {
+    use crate::common::fakes::{
+        FakeIdentityManager, FakeWrite, InMemoryOrders, InMemoryParts, InMemoryProjects,
+        InMemoryQuotes, InMemoryTransaction, InMemoryWebhookEvents, RecordingEmailer,
+        RecordingStripeClient,
+    };
+    use crate::common::fixtures::{order, project, quotation, webhook_event};
+    use api::orders::models::order::OrderStatus;
+    use api::payments::models::inputs::{ChargeRefundedWebhookRequest, WebhookEventInput};
+    use api::payments::usecases::refund_quotation_payment::RefundQuotationPayment;
+    use api::quotations::models::quotation::{Quotation, QuoteStatus};
+    use api::services::stripe_client::QuotationPayment;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::{Result, UseCase};
+    use iso_currency::Currency;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    struct Setup {
+        quotation: Quotation,
+        order_id: String,
+        orders: Arc<InMemoryOrders>,
+        quotes: Arc<InMemoryQuotes>,
+        projects: Arc<InMemoryProjects>,
+        usecase: RefundQuotationPayment<
+            InMemoryQuotes,
+            InMemoryOrders,
+            InMemoryProjects,
+            InMemoryWebhookEvents,
+            InMemoryTransaction,
+            FakeWrite,
+        >,
+    }
+
+    fn setup() -> Setup {
+        let quotation = quotation(QuoteStatus::Payed);
+        let mut order = order(OrderStatus::InProgress);
+        order.quotation_id = quotation.id.clone();
+        let order_id = order.id.clone();
+        let orders = Arc::new(InMemoryOrders::new(vec![order]));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+        let projects = Arc::new(InMemoryProjects::new(vec![project(true)]));
+        let webhook_events = Arc::new(InMemoryWebhookEvents::default());
+        let transaction =
+            InMemoryTransaction::new(Arc::new(InMemoryParts::default()), quotes.clone())
+                .with_projects(projects.clone())
+                .with_webhook_events(webhook_events.clone());
+        let stripe_client = Arc::new(RecordingStripeClient::default());
+        stripe_client.quotation_payments.lock().unwrap().insert(
+            String::from("pi_test"),
+            QuotationPayment {
+                customer_id: quotation.customer_id.clone(),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+            },
+        );
+
+        Setup {
+            usecase: RefundQuotationPayment::new(
+                quotes.clone(),
+                orders.clone(),
+                projects.clone(),
+                webhook_events,
+                Arc::new(Mutex::new(transaction)),
+                stripe_client,
+                Arc::new(FakeIdentityManager),
+                Arc::new(RecordingEmailer::default()),
+            ),
+            quotation,
+            order_id,
+            orders,
+            quotes,
+            projects,
+        }
+    }
+
+    async fn refund(setup: &Setup, event_id: &str, is_fully_refunded: bool) -> Result<()> {
+        setup
+            .usecase
+            .execute(WebhookEventInput {
+                webhook_event: webhook_event(event_id, "charge.refunded"),
+                data: ChargeRefundedWebhookRequest {
+                    payment_intent_id: String::from("pi_test"),
+                    amount_refunded: Money::new(5_000, Currency::MXN),
+                    is_fully_refunded,
+                },
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_should_refund_the_quotation_and_unlock_the_project_on_full_refunds() {
+        let setup = setup();
+
+        refund(&setup, "evt_1", true).await.unwrap();
+
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Refunded
+        );
+        assert_eq!(
+            setup.orders.order(&setup.order_id).status,
+            OrderStatus::Refunded
+        );
+        assert!(!setup.projects.project("project_id").is_locked);
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_the_quotation_paid_on_partial_refunds() {
+        let setup = setup();
+
+        refund(&setup, "evt_1", false).await.unwrap();
+
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Payed
+        );
+        assert_eq!(
+            setup.orders.order(&setup.order_id).status,
+            OrderStatus::InProgress
+        );
+        assert!(setup.projects.project("project_id").is_locked);
+    }
+
+    #[tokio::test]
+    async fn it_should_not_process_the_same_event_twice() {
+        let setup = setup();
+        refund(&setup, "evt_1", false).await.unwrap();
+
+        let result = refund(&setup, "evt_1", false).await;
+
+        assert!(matches!(
+            result,
+            Err(Error::WebhookEventAlreadyProcessed(_))
+        ));
+    }
+}
@@ -0,0 +1,113 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod admin_update_order_status {
+    use crate::common::fakes::{identity, FakeIdentityManager, InMemoryOrders, RecordingEmailer};
+    use crate::common::fixtures::order;
+    use api::auth::models::session::Role;
+    use api::orders::models::inputs::AdminUpdateOrderStatusInput;
+    use api::orders::models::order::{Order, OrderStatus, Shipment};
+    use api::orders::usecases::admin_update_order_status::AdminUpdateOrderStatus;
+    use api::shared::error::Error;
+    use api::shared::UseCase;
+    use chrono::NaiveDate;
+    use std::sync::Arc;
+
+    struct Setup {
+        order: Order,
+        orders: Arc<InMemoryOrders>,
+        emailer: Arc<RecordingEmailer>,
+        usecase: AdminUpdateOrderStatus<InMemoryOrders>,
+    }
+
+    fn setup() -> Setup {
+        let order = order(OrderStatus::Ready);
+        let orders = Arc::new(InMemoryOrders::new(vec![order.clone()]));
+        let emailer = Arc::new(RecordingEmailer::default());
+        let usecase = AdminUpdateOrderStatus::new(
+            orders.clone(),
+            Arc::new(FakeIdentityManager),
+            emailer.clone(),
+        );
+
+        Setup {
+            order,
+            orders,
+            emailer,
+            usecase,
+        }
+    }
+
+    fn ship(order: &Order, tracking_url: Option<&str>) -> AdminUpdateOrderStatusInput {
+        AdminUpdateOrderStatusInput {
+            identity: identity("admin_id", Role::Admin),
+            customer_id: order.customer_id.clone(),
+            order_id: order.id.clone(),
+            status: OrderStatus::Shipped,
+            shipment: Some(Shipment {
+                carrier: String::from("DHL"),
+                tracking_number: String::from("123"),
+                tracking_url: tracking_url.map(String::from),
+                shipped_at: NaiveDate::from_ymd_opt(2024, 2, 1).unwrap(),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_record_the_shipment_and_notify_the_customer() {
+        let setup = setup();
+
+        let shipped_order = setup
+            .usecase
+            .execute(ship(&setup.order, Some("https://dhl.com/track?id=123")))
+            .await
+            .unwrap();
+
+        assert_eq!(shipped_order.status, OrderStatus::Shipped);
+        let shipment = shipped_order.shipment.unwrap();
+        assert_eq!(shipment.tracking_number, "123");
+        assert_eq!(
+            shipment.tracking_url.as_deref(),
+            Some("https://dhl.com/track?id=123")
+        );
+        assert_eq!(
+            *setup.emailer.emails.lock().unwrap(),
+            vec![(
+                String::from("customer_id@test.com"),
+                String::from("Your order has been shipped")
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_require_a_shipment_when_shipping() {
+        let setup = setup();
+        let mut input = ship(&setup.order, None);
+        input.shipment = None;
+
+        let result = setup.usecase.execute(input).await;
+
+        assert!(matches!(result, Err(Error::MissingRequiredParameter(_))));
+        assert_eq!(
+            setup.orders.order(&setup.order.id).status,
+            OrderStatus::Ready
+        );
+        assert!(setup.emailer.emails.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_invalid_tracking_urls() {
+        let setup = setup();
+
+        let result = setup
+            .usecase
+            .execute(ship(&setup.order, Some("dhl tracking 123")))
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidUrl)));
+        let order = setup.orders.order(&setup.order.id);
+        assert_eq!(order.status, OrderStatus::Ready);
+        assert!(order.shipment.is_none());
+        assert!(setup.emailer.emails.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod order_status {
+    use api::orders::models::order::OrderStatus;
+
+    #[test]
+    fn it_should_only_move_orders_one_step_forward() {
+        let lifecycle = [
+            OrderStatus::Open,
+            OrderStatus::InProgress,
+            OrderStatus::Ready,
+            OrderStatus::Shipped,
+            OrderStatus::Delivered,
+        ];
+
+        for (index, from) in lifecycle.iter().enumerate() {
+            for (next_index, to) in lifecycle.iter().enumerate() {
+                assert_eq!(
+                    from.can_transition_to(to),
+                    next_index == index + 1,
+                    "{from} -> {to}"
+                );
+            }
+        }
+        for terminal in [OrderStatus::Refunded, OrderStatus::Cancelled] {
+            for status in &lifecycle {
+                assert!(!terminal.can_transition_to(status));
+                assert!(!status.can_transition_to(&terminal));
+            }
+        }
+    }
+}
+
+mod admin_update_order_status {
+    use crate::common::fakes::{identity, FakeIdentityManager, InMemoryOrders, RecordingEmailer};
+    use crate::common::fixtures::order;
+    use api::auth::models::session::Role;
+    use api::orders::models::inputs::AdminUpdateOrderStatusInput;
+    use api::orders::models::order::{Order, OrderStatus};
+    use api::orders::usecases::admin_update_order_status::AdminUpdateOrderStatus;
+    use api::shared::error::Error;
+    use api::shared::UseCase;
+    use std::sync::Arc;
+
+    fn input(order: &Order, status: OrderStatus) -> AdminUpdateOrderStatusInput {
+        AdminUpdateOrderStatusInput {
+            identity: identity("admin_id", Role::Admin),
+            customer_id: order.customer_id.clone(),
+            order_id: order.id.clone(),
+            status,
+            shipment: None,
+        }
+    }
+
+    fn usecase(
+        orders: &Arc<InMemoryOrders>,
+        emailer: &Arc<RecordingEmailer>,
+    ) -> AdminUpdateOrderStatus<InMemoryOrders> {
+        AdminUpdateOrderStatus::new(
+            orders.clone(),
+            Arc::new(FakeIdentityManager),
+            emailer.clone(),
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_append_every_transition_to_the_history() {
+        let order = order(OrderStatus::Open);
+        let orders = Arc::new(InMemoryOrders::new(vec![order.clone()]));
+        let emailer = Arc::new(RecordingEmailer::default());
+        let usecase = usecase(&orders, &emailer);
+
+        usecase
+            .execute(input(&order, OrderStatus::InProgress))
+            .await
+            .unwrap();
+        let updated_order = usecase
+            .execute(input(&order, OrderStatus::Ready))
+            .await
+            .unwrap();
+
+        assert_eq!(updated_order.status, OrderStatus::Ready);
+        let history = updated_order
+            .status_history
+            .iter()
+            .map(|transition| (transition.from.clone(), transition.to.clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            history,
+            vec![
+                (OrderStatus::Open, OrderStatus::InProgress),
+                (OrderStatus::InProgress, OrderStatus::Ready),
+            ]
+        );
+        assert!(updated_order
+            .status_history
+            .iter()
+            .all(|transition| transition.updated_by == "admin_id"));
+        assert!(updated_order.updated_at > order.updated_at);
+    }
+
+    #[tokio::test]
+    async fn it_should_reject_skipped_steps_without_touching_the_order() {
+        let order = order(OrderStatus::Open);
+        let orders = Arc::new(InMemoryOrders::new(vec![order.clone()]));
+        let emailer = Arc::new(RecordingEmailer::default());
+
+        let result = usecase(&orders, &emailer)
+            .execute(input(&order, OrderStatus::Shipped))
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::InvalidOrderStatusTransition(
+                OrderStatus::Open,
+                OrderStatus::Shipped
+            ))
+        ));
+        assert!(orders.order(&order.id).status_history.is_empty());
+    }
+}
+
+mod query_orders_by_customer {
+    use crate::common::fakes::{identity, InMemoryOrders, InMemoryParts};
+    use crate::common::fixtures::{order, part, quotation};
+    use api::auth::models::session::Role;
+    use api::orders::models::inputs::QueryOrdersForCustomerInput;
+    use api::orders::models::order::{OrderStatus, OrderStatusTransition};
+    use api::orders::usecases::query_orders_by_customer::QueryOrdersByCustomer;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::shared::money::Money;
+    use api::shared::UseCase;
+    use iso_currency::Currency;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn it_should_hide_the_supplier_and_its_payout_from_customers() {
+        let quotation = quotation(QuoteStatus::Payed);
+        let part = part(&quotation, "models/part.stl");
+        let mut order = order(OrderStatus::InProgress);
+        order.part_id = part.id.clone();
+        order.payment_intent_id = Some(String::from("pi_test"));
+        order.supplier_id = Some(String::from("sup_1"));
+        order.payout = Some(Money::new(6_500, Currency::MXN));
+        order.payout_transfer_id = Some(String::from("tr_1"));
+        order.status_history = vec![OrderStatusTransition::new(
+            OrderStatus::Open,
+            OrderStatus::InProgress,
+            String::from("supplier_identity_id"),
+        )];
+        let usecase = QueryOrdersByCustomer::new(
+            Arc::new(InMemoryOrders::new(vec![order.clone()])),
+            Arc::new(InMemoryParts::new(vec![part.clone()])),
+        );
+
+        let response = usecase
+            .execute(QueryOrdersForCustomerInput {
+                identity: identity("customer_id", Role::Customer),
+                with_part_data: true,
+                cursor: None,
+                limit: 10,
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.data[0].order.id, order.id);
+        assert_eq!(response.data[0].part.as_ref().unwrap().id, part.id);
+        let json = serde_json::to_value(&response.data[0].order).unwrap();
+        for field in [
+            "customer_id",
+            "supplier_id",
+            "payout",
+            "payout_transfer_id",
+            "price",
+            "payment_intent_id",
+        ] {
+            assert!(json.get(field).is_none(), "{field}");
+        }
+        assert!(json["status_history"][0].get("updated_by").is_none());
+    }
+}
@@ -0,0 +1,104 @@
+mod deserialize_part_attributes {
+    use api::parts::models::part_attributes::{
+        CNCAttributes, PartAttributes, PrintingTechnology, ThreeDPrintingAttributes, Tolerance,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn it_should_deserialize_untagged_attributes_as_cnc() {
+        let legacy = json!({
+            "material": "Aluminum 6061-T6",
+            "tolerance": "+/- .005\" (+/- 0.13mm)",
+        });
+
+        let attributes = serde_json::from_value::<PartAttributes>(legacy).unwrap();
+
+        assert_eq!(
+            attributes,
+            PartAttributes::CNC(CNCAttributes {
+                material: String::from("Aluminum 6061-T6"),
+                tolerance: Tolerance::PlusMinus005Inch013mm,
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_round_trip_tagged_attributes() {
+        let attributes = PartAttributes::ThreeDPrinting(ThreeDPrintingAttributes {
+            technology: PrintingTechnology::SLA,
+            material: String::from("Standard Resin"),
+            layer_height_mm: 0.05,
+            infill_percentage: None,
+        });
+
+        let serialized = serde_json::to_value(&attributes).unwrap();
+        assert_eq!(serialized["process"], "3D_PRINTING");
+
+        let deserialized = serde_json::from_value::<PartAttributes>(serialized).unwrap();
+        assert_eq!(deserialized, attributes);
+    }
+
+    #[test]
+    fn it_should_report_errors_of_tagged_attributes() {
+        let malformed = json!({
+            "process": "SHEET_METAL",
+            "material": "Steel 1018",
+        });
+
+        let err = serde_json::from_value::<PartAttributes>(malformed).unwrap_err();
+
+        assert!(err.to_string().contains("missing field"), "{err}");
+    }
+
+    #[test]
+    fn it_should_not_read_unknown_processes_as_cnc() {
+        let unknown = json!({
+            "process": "CASTING",
+            "material": "Aluminum 6061-T6",
+            "tolerance": "+/- .005\" (+/- 0.13mm)",
+        });
+
+        let err = serde_json::from_value::<PartAttributes>(unknown).unwrap_err();
+
+        assert!(
+            err.to_string().contains("unknown variant `CASTING`"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn it_should_keep_catalog_tolerance_grades() {
+        let tolerance = serde_json::from_value::<Tolerance>(json!("ISO 2768-f")).unwrap();
+
+        assert_eq!(tolerance, Tolerance::Grade(String::from("ISO 2768-f")));
+        assert_eq!(tolerance.to_string(), "ISO 2768-f");
+    }
+}
+
+mod display_part_attributes {
+    use api::parts::models::part_attributes::{
+        CNCAttributes, InspectionLevel, PartAttributes, ThreadCallout,
+    };
+
+    #[test]
+    fn it_should_describe_cnc_options() {
+        let attributes = PartAttributes::CNC(CNCAttributes {
+            surface_finish: Some(String::from("Anodized Type II")),
+            color: Some(String::from("Black")),
+            threads: vec![ThreadCallout {
+                specification: String::from("M6x1.0"),
+                count: 4,
+                depth_mm: Some(12.0),
+            }],
+            inspection_level: InspectionLevel::Cmm,
+            ..Default::default()
+        });
+
+        assert_eq!(
+            attributes.to_string(),
+            "Process: CNC, Material: Aluminum 6061-T6, Tolerance: +/- .005\" (+/- 0.13mm), \
+            Finish: Anodized Type II (Black), Threads: 4x M6x1.0 12mm deep, Inspection: CMM"
+        );
+    }
+}
@@ -0,0 +1,48 @@
+mod part_file_key {
+    use api::parts::models::part_file_key::{PartFileFolder, PartFileKey};
+    use std::str::FromStr;
+
+    #[test]
+    fn it_should_round_trip_keys() {
+        let key = PartFileKey::new(
+            PartFileFolder::WebReady,
+            String::from("customer_id"),
+            String::from("part_id"),
+            String::from("file_id.stl"),
+        );
+
+        assert_eq!(
+            key.to_string(),
+            "parts/web_ready/customer_id/part_id/file_id.stl"
+        );
+        assert_eq!(PartFileKey::from_str(&key.to_string()).unwrap(), key);
+    }
+
+    #[test]
+    fn it_should_split_file_names() {
+        let key =
+            PartFileKey::from_str("parts/originals/customer_id/part_id/file_id.STEP").unwrap();
+
+        assert_eq!(key.folder, PartFileFolder::Originals);
+        assert_eq!(key.file_stem(), "file_id");
+        assert_eq!(key.extension(), "step");
+    }
+
+    #[test]
+    fn it_should_decode_event_keys() {
+        let key = PartFileKey::from_event_key(
+            "parts/originals/customer_id/part_id/my+bracket%2B1%28rev%29.STEP",
+        )
+        .unwrap();
+
+        assert_eq!(key.file_name, "my bracket+1(rev).STEP");
+        assert_eq!(key.file_stem(), "my bracket+1(rev)");
+        assert!(PartFileKey::from_event_key("parts/originals/customer_id/part_id/%FF").is_err());
+    }
+
+    #[test]
+    fn it_should_reject_keys_outside_of_parts() {
+        assert!(PartFileKey::from_str("quotes/customer_id/quote.pdf").is_err());
+        assert!(PartFileKey::from_str("parts/renders/customer_id/part_id/file.stl").is_err());
+    }
+}
@@ -0,0 +1,166 @@
+#[path = "common/mod.rs"]
+mod common;
+
+pub(crate) mod analyze_stl {
+    use api::parts::models::part_geometry::{BoundingBox, PartGeometry};
+
+    /// Unit cube scaled to the given size, as 12 triangles with outward normals.
+    pub(crate) fn cube_triangles(size: f32) -> Vec<[[f32; 3]; 3]> {
+        let v = |x: f32, y: f32, z: f32| [x * size, y * size, z * size];
+        vec![
+            [v(0., 0., 0.), v(0., 1., 0.), v(1., 1., 0.)],
+            [v(0., 0., 0.), v(1., 1., 0.), v(1., 0., 0.)],
+            [v(0., 0., 1.), v(1., 0., 1.), v(1., 1., 1.)],
+            [v(0., 0., 1.), v(1., 1., 1.), v(0., 1., 1.)],
+            [v(0., 0., 0.), v(1., 0., 0.), v(1., 0., 1.)],
+            [v(0., 0., 0.), v(1., 0., 1.), v(0., 0., 1.)],
+            [v(0., 1., 0.), v(0., 1., 1.), v(1., 1., 1.)],
+            [v(0., 1., 0.), v(1., 1., 1.), v(1., 1., 0.)],
+            [v(0., 0., 0.), v(0., 0., 1.), v(0., 1., 1.)],
+            [v(0., 0., 0.), v(0., 1., 1.), v(0., 1., 0.)],
+            [v(1., 0., 0.), v(1., 1., 0.), v(1., 1., 1.)],
+            [v(1., 0., 0.), v(1., 1., 1.), v(1., 0., 1.)],
+        ]
+    }
+
+    pub(crate) fn binary_stl(triangles: &[[[f32; 3]; 3]]) -> Vec<u8> {
+        let mut bytes = vec![0u8; 80];
+        bytes.extend((triangles.len() as u32).to_le_bytes());
+        for triangle in triangles {
+            bytes.extend([0u8; 12]);
+            for coordinate in triangle.iter().flatten() {
+                bytes.extend(coordinate.to_le_bytes());
+            }
+            bytes.extend([0u8; 2]);
+        }
+        bytes
+    }
+
+    fn ascii_stl(triangles: &[[[f32; 3]; 3]]) -> Vec<u8> {
+        let mut content = String::from("solid cube\n");
+        for triangle in triangles {
+            content.push_str("  facet normal 0 0 0\n    outer loop\n");
+            for [x, y, z] in triangle {
+                content.push_str(&format!("      vertex {x} {y} {z}\n"));
+            }
+            content.push_str("    endloop\n  endfacet\n");
+        }
+        content.push_str("endsolid cube\n");
+        content.into_bytes()
+    }
+
+    fn assert_cube(geometry: PartGeometry) {
+        assert_eq!(
+            geometry.bounding_box,
+            BoundingBox {
+                x_mm: 10.0,
+                y_mm: 10.0,
+                z_mm: 10.0,
+            }
+        );
+        assert!((geometry.volume_mm3 - 1_000.0).abs() < 1e-6);
+        assert!((geometry.surface_area_mm2 - 600.0).abs() < 1e-6);
+        assert_eq!(geometry.triangle_count, 12);
+    }
+
+    #[test]
+    fn it_should_analyze_binary_stl() {
+        let geometry = PartGeometry::from_stl(&binary_stl(&cube_triangles(10.0))).unwrap();
+
+        assert_cube(geometry);
+    }
+
+    #[test]
+    fn it_should_analyze_ascii_stl() {
+        let geometry = PartGeometry::from_stl(&ascii_stl(&cube_triangles(10.0))).unwrap();
+
+        assert_cube(geometry);
+    }
+
+    #[test]
+    fn it_should_reject_non_finite_coordinates() {
+        let mut triangles = cube_triangles(10.0);
+        triangles[3][1][2] = f32::NAN;
+        triangles[7][0][0] = f32::INFINITY;
+
+        assert!(PartGeometry::from_stl(&binary_stl(&triangles)).is_err());
+        assert!(PartGeometry::from_stl(&ascii_stl(&triangles)).is_err());
+    }
+
+    #[test]
+    fn it_should_reject_files_that_are_not_stl() {
+        assert!(PartGeometry::from_stl(b"ISO-10303-21;\nHEADER;").is_err());
+        assert!(PartGeometry::from_stl(b"solid empty\nendsolid empty\n").is_err());
+    }
+}
+
+mod analyze_part_geometry {
+    use crate::analyze_stl::{binary_stl, cube_triangles};
+    use crate::common::fakes::{InMemoryObjectStorage, InMemoryParts, InMemoryQuotes};
+    use crate::common::fixtures::{quotation, quoted_part};
+    use api::parts::models::inputs::AnalyzePartGeometryInput;
+    use api::parts::usecases::analyze_part_geometry::AnalyzePartGeometry;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::services::object_storage::ObjectStorage;
+    use api::shared::error::Error;
+    use api::shared::UseCase;
+    use std::sync::Arc;
+
+    struct Setup {
+        parts: Arc<InMemoryParts>,
+        quotes: Arc<InMemoryQuotes>,
+        usecase: AnalyzePartGeometry<InMemoryParts, InMemoryQuotes>,
+        input: AnalyzePartGeometryInput,
+    }
+
+    async fn setup(status: QuoteStatus) -> Setup {
+        let quotation = quotation(status);
+        let part = quoted_part(&quotation, 2, 1_000);
+        let object_storage = Arc::new(InMemoryObjectStorage::default());
+        object_storage
+            .put_object(&part.model_file.key, binary_stl(&cube_triangles(10.0)))
+            .await
+            .unwrap();
+        let input = AnalyzePartGeometryInput {
+            customer_id: part.customer_id.clone(),
+            part_id: part.id.clone(),
+        };
+        let parts = Arc::new(InMemoryParts::new(vec![part]));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation]));
+
+        Setup {
+            usecase: AnalyzePartGeometry::new(parts.clone(), quotes.clone(), object_storage),
+            parts,
+            quotes,
+            input,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_send_the_quotation_back_for_review() {
+        let setup = setup(QuoteStatus::PendingPayment).await;
+        let part_id = setup.input.part_id.clone();
+
+        let part = setup.usecase.execute(setup.input).await.unwrap();
+
+        assert!(part.geometry.is_some());
+        assert!(setup.parts.part(&part_id).part_quotes.is_none());
+        assert_eq!(
+            setup.quotes.quotation(&part.quotation_id).status,
+            QuoteStatus::Created
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_keep_the_part_quotes_of_locked_quotations() {
+        let setup = setup(QuoteStatus::Payed).await;
+        let part_id = setup.input.part_id.clone();
+
+        let result = setup.usecase.execute(setup.input).await;
+
+        assert!(matches!(result, Err(Error::QuoteIsInPayedStatus)));
+        let part = setup.parts.part(&part_id);
+        assert!(part.part_quotes.is_some());
+        assert!(part.geometry.is_none());
+    }
+}
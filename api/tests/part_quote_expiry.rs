@@ -0,0 +1,227 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod validate_part_quote_expiry {
+    use api::parts::models::part::{Part, PartProcess, PartQuote};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::shared::error::Error;
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use chrono::{Days, Utc};
+    use iso_currency::Currency;
+
+    fn quoted_part() -> Part {
+        let mut part = Part::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("quotation_id"),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes::default()),
+            File::new(String::from("part.stl"), String::from("key")),
+        );
+        let part_quote = PartQuote::new(
+            Money::new(10_000, Currency::MXN),
+            Money::new(10_000, Currency::MXN),
+            10,
+            None,
+        );
+        part.selected_part_quote_id = Some(part_quote.id.clone());
+        part.part_quotes = Some(vec![part_quote]);
+        part
+    }
+
+    #[test]
+    fn it_should_accept_part_quotes_within_their_validity() {
+        let part = quoted_part();
+
+        assert!(part.validate_part_quote_expiry(Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_part_quotes_past_valid_until() {
+        let part = quoted_part();
+        let after_validity = Utc::now().checked_add_days(Days::new(31)).unwrap();
+
+        assert!(matches!(
+            part.validate_part_quote_expiry(after_validity),
+            Err(Error::PartQuoteExpired(part_id)) if part_id == part.id
+        ));
+    }
+
+    #[test]
+    fn it_should_ignore_parts_without_part_quotes() {
+        let mut part = quoted_part();
+        part.part_quotes = None;
+        let after_validity = Utc::now().checked_add_days(Days::new(31)).unwrap();
+
+        assert!(part.validate_part_quote_expiry(after_validity).is_ok());
+    }
+}
+
+mod expire_quotations {
+    use crate::common::fakes::{
+        FakeIdentityManager, FakeWrite, InMemoryParts, InMemoryQuotes, InMemoryTransaction,
+        RecordingEmailer,
+    };
+    use crate::common::fixtures::{quotation, quoted_part};
+    use api::parts::models::part::Part;
+    use api::promotions::models::promotion_code::AppliedPromotion;
+    use api::quotations::models::dynamodb_requests::BatchDeleteQuotationObject;
+    use api::quotations::models::quotation::{Quotation, QuoteStatus};
+    use api::quotations::usecases::expire_quotations::ExpireQuotations;
+    use api::repositories::quotes::{QueryBy, QuotesRepository};
+    use api::shared::{CustomerId, ProjectId, QueryResponse, QuoteId, Result, UseCase};
+    use async_trait::async_trait;
+    use chrono::{DateTime, Days, Utc};
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    /// Answers queries with the quotations as they were before `paid_quotation_ids` were paid,
+    /// like a query racing with checkouts.
+    struct StaleQuotes {
+        quotes: Arc<InMemoryQuotes>,
+        paid_quotation_ids: Vec<QuoteId>,
+    }
+
+    #[async_trait]
+    impl QuotesRepository for StaleQuotes {
+        type TransactionItem = FakeWrite;
+
+        async fn create(&self, quotation: Quotation) -> Result<()> {
+            self.quotes.create(quotation).await
+        }
+
+        async fn delete(&self, customer_id: CustomerId, quotation_id: QuoteId) -> Result<()> {
+            self.quotes.delete(customer_id, quotation_id).await
+        }
+
+        async fn get(&self, customer_id: CustomerId, quotation_id: QuoteId) -> Result<Quotation> {
+            self.quotes.get(customer_id, quotation_id).await
+        }
+
+        async fn query(
+            &self,
+            _customer_id: Option<CustomerId>,
+            _project_id: Option<ProjectId>,
+            _from: Option<DateTime<Utc>>,
+            _to: Option<DateTime<Utc>>,
+            _status: Option<QuoteStatus>,
+            _query_by: QueryBy,
+            _limit: i32,
+            _cursor: Option<String>,
+        ) -> Result<QueryResponse<Vec<Quotation>, String>> {
+            let data = self
+                .quotes
+                .quotations
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|quotation| {
+                    quotation.status == QuoteStatus::PendingPayment
+                        || self.paid_quotation_ids.contains(&quotation.id)
+                })
+                .cloned()
+                .map(|mut quotation| {
+                    quotation.status = QuoteStatus::PendingPayment;
+                    quotation
+                })
+                .collect();
+            Ok(QueryResponse { data, cursor: None })
+        }
+
+        async fn update_status(
+            &self,
+            customer_id: CustomerId,
+            project_id: ProjectId,
+            quotation_id: QuoteId,
+            status: QuoteStatus,
+        ) -> Result<Quotation> {
+            self.quotes
+                .update_status(customer_id, project_id, quotation_id, status)
+                .await
+        }
+
+        async fn update_promotion(
+            &self,
+            customer_id: CustomerId,
+            quotation_id: QuoteId,
+            promotion: Option<AppliedPromotion>,
+        ) -> Result<Quotation> {
+            self.quotes
+                .update_promotion(customer_id, quotation_id, promotion)
+                .await
+        }
+
+        async fn batch_delete(&self, data: Vec<BatchDeleteQuotationObject>) -> Result<()> {
+            self.quotes.batch_delete(data).await
+        }
+
+        fn transaction_update(
+            &self,
+            customer_id: CustomerId,
+            project_id: ProjectId,
+            quote_id: QuoteId,
+            old_status: QuoteStatus,
+            new_status: QuoteStatus,
+        ) -> FakeWrite {
+            self.quotes.transaction_update(
+                customer_id,
+                project_id,
+                quote_id,
+                old_status,
+                new_status,
+            )
+        }
+    }
+
+    fn expired_part(quotation: &Quotation) -> Part {
+        let mut part = quoted_part(quotation, 1, 10_000);
+        part.part_quotes.as_mut().unwrap()[0].valid_until =
+            Utc::now().checked_sub_days(Days::new(1)).unwrap();
+        part
+    }
+
+    #[tokio::test]
+    async fn it_should_expire_the_quotations_that_are_still_pending_payment() {
+        let expired = quotation(QuoteStatus::PendingPayment);
+        let valid = quotation(QuoteStatus::PendingPayment);
+        let paid_meanwhile = quotation(QuoteStatus::Payed);
+        let parts = Arc::new(InMemoryParts::new(vec![
+            expired_part(&expired),
+            quoted_part(&valid, 1, 10_000),
+            expired_part(&paid_meanwhile),
+        ]));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![
+            paid_meanwhile.clone(),
+            expired.clone(),
+            valid.clone(),
+        ]));
+        let transaction = InMemoryTransaction::new(parts.clone(), quotes.clone());
+        let emailer = Arc::new(RecordingEmailer::default());
+        let usecase = ExpireQuotations::new(
+            Arc::new(StaleQuotes {
+                quotes: quotes.clone(),
+                paid_quotation_ids: vec![paid_meanwhile.id.clone()],
+            }),
+            parts,
+            Arc::new(Mutex::new(transaction)),
+            Arc::new(FakeIdentityManager),
+            emailer.clone(),
+        );
+
+        let response = usecase.execute(()).await.unwrap();
+
+        assert_eq!(response.quotations.len(), 1);
+        assert_eq!(response.quotations[0].id, expired.id);
+        assert_eq!(quotes.quotation(&expired.id).status, QuoteStatus::Expired);
+        assert_eq!(
+            quotes.quotation(&valid.id).status,
+            QuoteStatus::PendingPayment
+        );
+        assert_eq!(
+            quotes.quotation(&paid_meanwhile.id).status,
+            QuoteStatus::Payed
+        );
+        assert_eq!(emailer.emails.lock().unwrap().len(), 1);
+    }
+}
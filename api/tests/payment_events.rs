@@ -0,0 +1,216 @@
+mod checkout_session {
+    use api::payments::models::inputs::{
+        CheckoutSessionWebhookRequest, CompleteCheckoutSessionWebhookRequest,
+    };
+    use api::promotions::models::discount::DiscountLine;
+    use api::shared::money::Money;
+    use api::shared::shipping::{ShippingCharge, ShippingMethod};
+    use api::shared::tax::TaxJurisdiction;
+    use iso_currency::Currency;
+    use stripe::{
+        CheckoutSession, Expandable, PaymentPagesCheckoutSessionShippingCost,
+        PaymentPagesCheckoutSessionShippingOption, PaymentPagesCheckoutSessionTotalDetails,
+    };
+
+    fn session(payment_status: &str) -> CheckoutSession {
+        serde_json::from_str(&format!(
+            r#"{{
+                "id": "cs_1",
+                "object": "checkout.session",
+                "automatic_tax": {{ "enabled": false }},
+                "created": 1700000000,
+                "custom_fields": [],
+                "custom_text": {{}},
+                "expires_at": 1700086400,
+                "livemode": false,
+                "mode": "payment",
+                "payment_intent": "pi_1",
+                "payment_method_types": ["oxxo"],
+                "payment_status": "{payment_status}",
+                "shipping_options": [],
+                "status": "complete",
+                "metadata": {{
+                    "customer_id": "customer_id",
+                    "project_id": "project_id",
+                    "quotation_id": "quotation_id"
+                }},
+                "shipping_details": {{
+                    "name": "Jane Doe",
+                    "address": {{ "city": "Monterrey", "country": "MX" }}
+                }}
+            }}"#
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_not_consider_delayed_payments_as_paid() {
+        let unpaid = CompleteCheckoutSessionWebhookRequest::try_from(session("unpaid")).unwrap();
+        let paid = CompleteCheckoutSessionWebhookRequest::try_from(session("paid")).unwrap();
+
+        assert!(!unpaid.is_paid);
+        assert!(paid.is_paid);
+        assert_eq!(paid.quotation_id, "quotation_id");
+        assert_eq!(paid.shipping_recipient_name, "Jane Doe");
+        assert_eq!(paid.payment_intent_id.as_deref(), Some("pi_1"));
+        // Sessions created before taxes were recorded in the metadata.
+        assert_eq!(paid.tax_jurisdiction, None);
+    }
+
+    #[test]
+    fn it_should_read_the_tax_jurisdiction_charged() {
+        let mut session = session("paid");
+        let metadata = session.metadata.as_mut().unwrap();
+        metadata.insert(String::from("tax_country"), String::from("MX"));
+        metadata.insert(String::from("tax_state"), String::from("BC"));
+
+        let request = CompleteCheckoutSessionWebhookRequest::try_from(session).unwrap();
+
+        assert_eq!(
+            request.tax_jurisdiction,
+            Some(TaxJurisdiction {
+                country: String::from("MX"),
+                state: Some(String::from("BC")),
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_discount_charged() {
+        let mut session = session("paid");
+        assert_eq!(
+            CompleteCheckoutSessionWebhookRequest::try_from(session.clone())
+                .unwrap()
+                .discount,
+            None
+        );
+
+        session
+            .metadata
+            .as_mut()
+            .unwrap()
+            .insert(String::from("promotion_code"), String::from("REPEAT10"));
+        session.currency = Some(stripe::Currency::USD);
+        session.total_details = Some(PaymentPagesCheckoutSessionTotalDetails {
+            amount_discount: 1_500,
+            amount_shipping: None,
+            amount_tax: 2_160,
+            breakdown: None,
+        });
+
+        let request = CompleteCheckoutSessionWebhookRequest::try_from(session).unwrap();
+
+        assert_eq!(
+            request.discount,
+            Some(DiscountLine {
+                code: String::from("REPEAT10"),
+                amount: Money::new(1_500, Currency::USD),
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_read_the_shipping_method_charged() {
+        let mut session = session("paid");
+        assert_eq!(
+            CompleteCheckoutSessionWebhookRequest::try_from(session.clone())
+                .unwrap()
+                .shipping,
+            None
+        );
+
+        // The shipping rates are created with the session, in the order of the methods.
+        session.metadata.as_mut().unwrap().insert(
+            String::from("shipping_methods"),
+            String::from("standard,express"),
+        );
+        session.shipping_options = ["shr_standard", "shr_express"]
+            .into_iter()
+            .map(
+                |shipping_rate_id| PaymentPagesCheckoutSessionShippingOption {
+                    shipping_amount: 0,
+                    shipping_rate: Expandable::Id(shipping_rate_id.parse().unwrap()),
+                },
+            )
+            .collect();
+        session.currency = Some(stripe::Currency::MXN);
+        session.shipping_cost = Some(PaymentPagesCheckoutSessionShippingCost {
+            amount_subtotal: 45_000,
+            amount_tax: 0,
+            amount_total: 45_000,
+            shipping_rate: Some(Expandable::Id("shr_express".parse().unwrap())),
+            taxes: None,
+        });
+
+        let request = CompleteCheckoutSessionWebhookRequest::try_from(session.clone()).unwrap();
+
+        assert_eq!(
+            request.shipping,
+            Some(ShippingCharge {
+                method: ShippingMethod::Express,
+                cost: Money::new(45_000, Currency::MXN),
+            })
+        );
+
+        // Shipping rates that weren't offered for the session.
+        session.shipping_cost.as_mut().unwrap().shipping_rate =
+            Some(Expandable::Id("shr_other".parse().unwrap()));
+        assert!(CompleteCheckoutSessionWebhookRequest::try_from(session).is_err());
+    }
+
+    #[test]
+    fn it_should_only_require_the_metadata_for_failed_or_expired_sessions() {
+        let mut session = session("unpaid");
+        session.shipping_details = None;
+
+        assert!(CompleteCheckoutSessionWebhookRequest::try_from(session.clone()).is_err());
+
+        let request = CheckoutSessionWebhookRequest::try_from(session).unwrap();
+        assert_eq!(request.customer_id, "customer_id");
+        assert_eq!(request.project_id, "project_id");
+        assert_eq!(request.quotation_id, "quotation_id");
+    }
+}
+
+mod charge {
+    use api::payments::models::inputs::ChargeRefundedWebhookRequest;
+    use iso_currency::Currency;
+    use stripe::Charge;
+
+    fn charge(amount_refunded: i64) -> Charge {
+        serde_json::from_str(&format!(
+            r#"{{
+                "id": "ch_1",
+                "object": "charge",
+                "amount": 10000,
+                "amount_captured": 10000,
+                "amount_refunded": {amount_refunded},
+                "billing_details": {{}},
+                "captured": true,
+                "created": 1700000000,
+                "currency": "mxn",
+                "disputed": false,
+                "livemode": false,
+                "metadata": {{}},
+                "paid": true,
+                "payment_intent": "pi_1",
+                "refunded": {},
+                "status": "succeeded"
+            }}"#,
+            amount_refunded == 10000
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_distinguish_partial_from_full_refunds() {
+        let partial = ChargeRefundedWebhookRequest::try_from(charge(2500)).unwrap();
+        let full = ChargeRefundedWebhookRequest::try_from(charge(10000)).unwrap();
+
+        assert_eq!(partial.payment_intent_id, "pi_1");
+        assert_eq!(partial.amount_refunded.amount, 2500);
+        assert_eq!(partial.amount_refunded.currency, Currency::MXN);
+        assert!(!partial.is_fully_refunded);
+        assert!(full.is_fully_refunded);
+    }
+}
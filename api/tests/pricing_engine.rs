@@ -0,0 +1,136 @@
+mod instant_quotes {
+    use api::config::ConfigExchangeRates;
+    use api::config::{ConfigPricing, ConfigPricingLeadTime, ConfigPricingMaterial};
+    use api::parts::models::part::{Part, PartProcess};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes, Tolerance};
+    use api::parts::models::part_geometry::{BoundingBox, PartGeometry};
+    use api::services::exchange_rates_fixed::FixedExchangeRates;
+    use api::services::pricing_engine::PricingEngine;
+    use api::services::pricing_engine_rate_table::RateTablePricingEngine;
+    use api::shared::file::File;
+    use iso_currency::Currency;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn engine() -> RateTablePricingEngine {
+        RateTablePricingEngine::new(
+            ConfigPricing {
+                setup_cost: 50_000,
+                removal_cost_per_cm3: 100,
+                surface_cost_per_cm2: 10,
+                max_dimension_mm: 500.0,
+                max_instant_subtotal: 5_000_000,
+                materials: HashMap::from([(
+                    String::from("Aluminum 6061-T6"),
+                    ConfigPricingMaterial {
+                        stock_cost_per_cm3: 20,
+                    },
+                )]),
+                lead_times: vec![
+                    ConfigPricingLeadTime {
+                        workdays: 10,
+                        multiplier: 1.0,
+                    },
+                    ConfigPricingLeadTime {
+                        workdays: 5,
+                        multiplier: 1.5,
+                    },
+                ],
+            },
+            Arc::new(FixedExchangeRates::new(ConfigExchangeRates {
+                base: Currency::MXN,
+                rates: HashMap::from([(Currency::USD, 0.05)]),
+            })),
+        )
+    }
+
+    fn part() -> Part {
+        let mut part = Part::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("quotation_id"),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes::default()),
+            File::new(String::from("part.stl"), String::from("key")),
+        );
+        part.quantity = 10;
+        part.geometry = Some(PartGeometry {
+            bounding_box: BoundingBox {
+                x_mm: 100.0,
+                y_mm: 50.0,
+                z_mm: 20.0,
+            },
+            volume_mm3: 60_000.0,
+            surface_area_mm2: 20_000.0,
+            triangle_count: 12,
+        });
+        part
+    }
+
+    #[test]
+    fn it_should_quote_every_lead_time() {
+        let part = part();
+
+        let part_quotes_by_part = engine()
+            .instant_quotes(std::slice::from_ref(&part), Currency::MXN)
+            .unwrap();
+        let part_quotes = &part_quotes_by_part[&part.id];
+
+        // 100cm3 stock * 20 + 40cm3 removed * 100 + 200cm2 * 10 + 50,000 / 10 parts
+        assert_eq!(part_quotes.len(), 2);
+        assert_eq!(part_quotes[0].unit_price.amount, 13_000);
+        assert_eq!(part_quotes[0].sub_total.amount, 130_000);
+        assert_eq!(part_quotes[0].workdays_to_complete, 10);
+        assert_eq!(part_quotes[1].unit_price.amount, 19_500);
+        assert_eq!(part_quotes[1].workdays_to_complete, 5);
+    }
+
+    #[test]
+    fn it_should_not_quote_parts_without_geometry() {
+        let mut part = part();
+        part.geometry = None;
+
+        assert!(engine().instant_quotes(&[part], Currency::MXN).is_none());
+    }
+
+    #[test]
+    fn it_should_not_quote_custom_tolerances() {
+        let mut part = part();
+        part.attributes = PartAttributes::CNC(CNCAttributes {
+            tolerance: Tolerance::Other,
+            ..Default::default()
+        });
+
+        assert!(engine().instant_quotes(&[part], Currency::MXN).is_none());
+    }
+
+    #[test]
+    fn it_should_not_quote_parts_with_notes() {
+        let mut part = part();
+        part.additional_notes = String::from("Break every edge, no burrs on the bore");
+
+        assert!(engine().instant_quotes(&[part], Currency::MXN).is_none());
+    }
+
+    #[test]
+    fn it_should_not_quote_quantities_that_overflow_the_subtotal() {
+        let mut part = part();
+        part.quantity = u64::MAX;
+
+        assert!(engine().instant_quotes(&[part], Currency::MXN).is_none());
+    }
+
+    #[test]
+    fn it_should_not_quote_quotations_whose_subtotal_overflows() {
+        // Every lead time of each part can be priced, but the two parts together overflow.
+        let parts: Vec<Part> = (0..2)
+            .map(|_| {
+                let mut part = part();
+                part.quantity = (i64::MAX / 12_001) as u64;
+                part
+            })
+            .collect();
+
+        assert!(engine().instant_quotes(&parts, Currency::MXN).is_none());
+    }
+}
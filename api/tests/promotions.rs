@@ -0,0 +1,263 @@
+mod promotion_code {
+    use api::promotions::models::discount::Discount;
+    use api::promotions::models::promotion_code::PromotionCode;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use chrono::{Duration, Utc};
+    use iso_currency::Currency;
+
+    fn promotion_code(code: &str) -> PromotionCode {
+        PromotionCode::new(
+            String::from(code),
+            Discount::Percentage { rate: 0.1 },
+            None,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_should_normalize_and_validate_codes() {
+        let promotion_code = promotion_code(" repeat-10 ");
+
+        assert_eq!(promotion_code.code, "REPEAT-10");
+        assert!(promotion_code.validate().is_ok());
+        for code in ["", "AB", "TEN PERCENT", "DESCUENTO!"] {
+            assert!(matches!(
+                self::promotion_code(code).validate(),
+                Err(Error::InvalidPromotion(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_reject_invalid_discounts() {
+        for discount in [
+            Discount::Percentage { rate: 0.0 },
+            Discount::Percentage { rate: 1.5 },
+            Discount::Fixed {
+                amount: Money::new(-100, Currency::MXN),
+            },
+        ] {
+            let mut promotion_code = promotion_code("REPEAT10");
+            promotion_code.discount = discount;
+
+            assert!(matches!(
+                promotion_code.validate(),
+                Err(Error::InvalidPromotion(_))
+            ));
+        }
+
+        let mut promotion_code = promotion_code("REPEAT10");
+        promotion_code.discount = Discount::Fixed {
+            amount: Money::new(10_000, Currency::EUR),
+        };
+        assert!(matches!(
+            promotion_code.validate(),
+            Err(Error::UnsupportedCurrency(Currency::EUR))
+        ));
+    }
+
+    #[test]
+    fn it_should_only_be_redeemable_by_its_customer_until_it_expires_or_runs_out() {
+        let now = Utc::now();
+        let customer_id = String::from("customer_id");
+
+        let mut promotion_code = promotion_code("REPEAT10");
+        assert!(promotion_code.ensure_redeemable(&customer_id, now).is_ok());
+
+        promotion_code.customer_id = Some(String::from("other_customer_id"));
+        assert!(matches!(
+            promotion_code.ensure_redeemable(&customer_id, now),
+            Err(Error::ItemNotFoundError)
+        ));
+
+        promotion_code.customer_id = Some(customer_id.clone());
+        promotion_code.expires_at = Some(now - Duration::minutes(1));
+        assert!(matches!(
+            promotion_code.ensure_redeemable(&customer_id, now),
+            Err(Error::PromotionCodeExpired(_))
+        ));
+
+        promotion_code.expires_at = Some(now + Duration::days(1));
+        promotion_code.max_uses = Some(3);
+        promotion_code.uses = 3;
+        assert!(matches!(
+            promotion_code.ensure_redeemable(&customer_id, now),
+            Err(Error::PromotionCodeUsedUp(_))
+        ));
+    }
+}
+
+mod discount {
+    use api::promotions::models::discount::{discounted_line_amounts, Discount};
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+
+    #[test]
+    fn it_should_never_take_off_more_than_the_subtotal() {
+        let subtotal = Money::new(9_999, Currency::MXN);
+
+        assert_eq!(
+            Discount::Percentage { rate: 0.15 }
+                .amount_off(&subtotal)
+                .unwrap(),
+            Money::new(1_500, Currency::MXN)
+        );
+        assert_eq!(
+            Discount::Fixed {
+                amount: Money::new(20_000, Currency::MXN)
+            }
+            .amount_off(&subtotal)
+            .unwrap(),
+            subtotal
+        );
+        assert!(matches!(
+            Discount::Fixed {
+                amount: Money::new(1_000, Currency::USD)
+            }
+            .amount_off(&subtotal),
+            Err(Error::CurrencyMismatch(Currency::MXN, Currency::USD))
+        ));
+    }
+
+    #[test]
+    fn it_should_spread_the_discount_over_the_lines() {
+        let line_amounts = [
+            Money::new(10_000, Currency::MXN),
+            Money::new(5_000, Currency::MXN),
+            Money::new(1, Currency::MXN),
+        ];
+
+        let discounted =
+            discounted_line_amounts(&line_amounts, &Money::new(1_001, Currency::MXN)).unwrap();
+
+        assert_eq!(
+            discounted,
+            vec![
+                Money::new(9_332, Currency::MXN),
+                Money::new(4_667, Currency::MXN),
+                Money::new(1, Currency::MXN),
+            ]
+        );
+        assert_eq!(
+            Money::checked_sum(Currency::MXN, &discounted).unwrap(),
+            Money::new(14_000, Currency::MXN)
+        );
+        assert_eq!(
+            discounted_line_amounts(&line_amounts, &Money::zero(Currency::MXN)).unwrap(),
+            line_amounts.to_vec()
+        );
+    }
+}
+
+mod quotation_discount {
+    use api::config::{ConfigTaxRate, ConfigTaxes};
+    use api::parts::models::part::{Part, PartProcess, PartQuote};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::promotions::models::discount::{Discount, DiscountLine};
+    use api::promotions::models::promotion_code::AppliedPromotion;
+    use api::quotations::models::quotation::Quotation;
+    use api::quotations::models::quote_document::QuoteDocument;
+    use api::services::tax_calculator::TaxCalculator;
+    use api::services::tax_calculator_rate_table::RateTableTaxCalculator;
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use chrono::Utc;
+    use iso_currency::Currency;
+
+    fn quoted_part(quotation: &Quotation, unit_price: i64) -> Part {
+        let mut part = Part::new(
+            quotation.customer_id.clone(),
+            quotation.project_id.clone(),
+            quotation.id.clone(),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes::default()),
+            File::new(String::from("part.stl"), String::from("key")),
+        );
+        let part_quote = PartQuote::new(
+            Money::new(unit_price, Currency::MXN),
+            Money::new(unit_price, Currency::MXN),
+            10,
+            None,
+        );
+        part.selected_part_quote_id = Some(part_quote.id.clone());
+        part.part_quotes = Some(vec![part_quote]);
+        part
+    }
+
+    #[test]
+    fn it_should_take_the_discount_off_before_taxes() {
+        let mut quotation = Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets"),
+            Currency::MXN,
+        );
+        quotation.promotion = Some(AppliedPromotion {
+            code: String::from("REPEAT10"),
+            discount: Discount::Percentage { rate: 0.1 },
+        });
+        let parts = vec![
+            quoted_part(&quotation, 20_000),
+            quoted_part(&quotation, 5_000),
+        ];
+        let tax_calculator = RateTableTaxCalculator::new(ConfigTaxes {
+            default_country: String::from("MX"),
+            rates: vec![ConfigTaxRate {
+                country: String::from("MX"),
+                state: None,
+                name: String::from("IVA"),
+                rate: 0.16,
+                stripe_tax_rate_id: String::from("txr_iva"),
+            }],
+        });
+
+        let document = QuoteDocument::new(
+            &quotation,
+            &parts,
+            String::from("customer@example.com"),
+            &tax_calculator,
+            tax_calculator.jurisdiction(None).unwrap(),
+            Utc::now(),
+        )
+        .unwrap();
+
+        assert_eq!(document.subtotal, Money::new(25_000, Currency::MXN));
+        assert_eq!(
+            document.discount,
+            Some(DiscountLine {
+                code: String::from("REPEAT10"),
+                amount: Money::new(2_500, Currency::MXN),
+            })
+        );
+        assert_eq!(
+            document.tax_lines[0].amount,
+            Money::new(3_600, Currency::MXN)
+        );
+        assert_eq!(document.total, Money::new(26_100, Currency::MXN));
+    }
+}
+
+mod pricing_agreement {
+    use api::promotions::models::pricing_agreement::PricingAgreement;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+
+    #[test]
+    fn it_should_discount_prices_by_the_negotiated_rate() {
+        let pricing_agreement = PricingAgreement::new(String::from("customer_id"), 0.125);
+
+        assert!(pricing_agreement.validate().is_ok());
+        assert_eq!(
+            pricing_agreement.apply(&Money::new(1_999, Currency::USD)),
+            Money::new(1_749, Currency::USD)
+        );
+        assert!(matches!(
+            PricingAgreement::new(String::from("customer_id"), 1.0).validate(),
+            Err(Error::InvalidPromotion(_))
+        ));
+    }
+}
@@ -0,0 +1,254 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod part_quote_prices {
+    use api::parts::models::part::{PartQuote, QuantityTier};
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+
+    fn part_quote() -> PartQuote {
+        let quantity_tiers = vec![
+            QuantityTier {
+                min_quantity: 1,
+                max_quantity: Some(9),
+                unit_price: Money::new(20_000, Currency::MXN),
+            },
+            QuantityTier {
+                min_quantity: 10,
+                max_quantity: Some(49),
+                unit_price: Money::new(15_000, Currency::MXN),
+            },
+            QuantityTier {
+                min_quantity: 50,
+                max_quantity: None,
+                unit_price: Money::new(12_000, Currency::MXN),
+            },
+        ];
+
+        PartQuote::new(
+            Money::new(15_000, Currency::MXN),
+            Money::new(150_000, Currency::MXN),
+            10,
+            Some(quantity_tiers),
+        )
+    }
+
+    #[test]
+    fn it_should_price_with_the_matching_tier() {
+        let part_quote = part_quote();
+
+        assert_eq!(part_quote.unit_price_for(1).amount, 20_000);
+        assert_eq!(part_quote.unit_price_for(49).amount, 15_000);
+        assert_eq!(part_quote.unit_price_for(500).amount, 12_000);
+        assert_eq!(part_quote.sub_total_for(50).unwrap().amount, 600_000);
+    }
+
+    #[test]
+    fn it_should_not_find_a_tier_outside_of_the_priced_quantities() {
+        let mut part_quote = part_quote();
+        part_quote.quantity_tiers.as_mut().unwrap()[0].min_quantity = 5;
+
+        assert!(part_quote.quantity_tier(4).is_none());
+        assert!(part_quote.quantity_tier(5).is_some());
+    }
+
+    #[test]
+    fn it_should_use_the_quoted_price_without_tiers() {
+        let mut part_quote = part_quote();
+        part_quote.quantity_tiers = None;
+
+        assert_eq!(part_quote.unit_price_for(50).amount, 15_000);
+        assert_eq!(part_quote.sub_total_for(50).unwrap().amount, 150_000);
+    }
+
+    #[test]
+    fn it_should_reject_subtotals_that_overflow() {
+        let part_quote = part_quote();
+
+        assert!(part_quote.sub_total_for(u64::MAX).is_err());
+    }
+}
+
+mod validate_tiers {
+    use api::parts::models::part::QuantityTier;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+
+    fn tier(min_quantity: u64, max_quantity: Option<u64>) -> QuantityTier {
+        QuantityTier {
+            min_quantity,
+            max_quantity,
+            unit_price: Money::new(10_000, Currency::MXN),
+        }
+    }
+
+    #[test]
+    fn it_should_accept_contiguous_tiers_in_any_order() {
+        let quantity_tiers = vec![tier(50, None), tier(1, Some(9)), tier(10, Some(49))];
+
+        assert!(QuantityTier::validate_tiers(&quantity_tiers, Currency::MXN).is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_an_empty_list() {
+        assert!(matches!(
+            QuantityTier::validate_tiers(&[], Currency::MXN),
+            Err(Error::InvalidQuantityTiers(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_tiers_that_end_before_they_start() {
+        let quantity_tiers = vec![tier(10, Some(5))];
+
+        assert!(matches!(
+            QuantityTier::validate_tiers(&quantity_tiers, Currency::MXN),
+            Err(Error::InvalidQuantityTiers(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_overlapping_tiers() {
+        let overlapping = vec![tier(1, Some(10)), tier(10, Some(49))];
+        let after_open_ended = vec![tier(1, None), tier(10, Some(49))];
+
+        for quantity_tiers in [overlapping, after_open_ended] {
+            assert!(matches!(
+                QuantityTier::validate_tiers(&quantity_tiers, Currency::MXN),
+                Err(Error::InvalidQuantityTiers(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn it_should_reject_mixed_currencies() {
+        let mut usd_tier = tier(10, None);
+        usd_tier.unit_price = Money::new(500, Currency::USD);
+        let quantity_tiers = vec![tier(1, Some(9)), usd_tier];
+
+        assert!(matches!(
+            QuantityTier::validate_tiers(&quantity_tiers, Currency::MXN),
+            Err(Error::CurrencyMismatch(Currency::MXN, Currency::USD))
+        ));
+    }
+}
+
+mod create_part_quotes {
+    use crate::common::fakes::{
+        InMemoryParts, InMemoryPricingAgreements, InMemoryQuotationRevisions, InMemoryQuotes,
+        InMemoryTransaction,
+    };
+    use crate::common::fixtures::{part, quotation};
+    use api::config::ConfigExchangeRates;
+    use api::parts::models::inputs::{CreatePartQuotesInput, CreatePartQuotesInputData};
+    use api::parts::models::part::QuantityTier;
+    use api::parts::usecases::create_part_quotes::CreatePartQuotes;
+    use api::promotions::models::pricing_agreement::PricingAgreement;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::services::exchange_rates_fixed::FixedExchangeRates;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::UseCase;
+    use iso_currency::Currency;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    #[tokio::test]
+    async fn it_should_not_price_parts_with_invalid_tiers() {
+        let quotation = quotation(QuoteStatus::PendingReview);
+        let part = part(&quotation, "models/part.stl");
+        let parts = Arc::new(InMemoryParts::new(vec![part.clone()]));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+        let revisions = Arc::new(InMemoryQuotationRevisions::default());
+        let transaction = InMemoryTransaction::new(parts.clone(), quotes.clone())
+            .with_revisions(revisions.clone());
+        let usecase = CreatePartQuotes::new(
+            parts.clone(),
+            quotes.clone(),
+            revisions,
+            Arc::new(InMemoryPricingAgreements::default()),
+            Arc::new(Mutex::new(transaction)),
+            Arc::new(FixedExchangeRates::new(ConfigExchangeRates {
+                base: Currency::MXN,
+                rates: HashMap::new(),
+            })),
+        );
+        let tier = |min_quantity, max_quantity| QuantityTier {
+            min_quantity,
+            max_quantity,
+            unit_price: Money::new(1_000, Currency::MXN),
+        };
+
+        let result = usecase
+            .execute(CreatePartQuotesInput {
+                customer_id: quotation.customer_id.clone(),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+                data: vec![CreatePartQuotesInputData {
+                    part_id: part.id.clone(),
+                    unit_price: Money::new(1_000, Currency::MXN),
+                    sub_total: Money::new(1_000, Currency::MXN),
+                    workdays_to_complete: 10,
+                    quantity_tiers: Some(vec![tier(1, Some(20)), tier(10, None)]),
+                }],
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidQuantityTiers(_))));
+        assert!(parts.part(&part.id).part_quotes.is_none());
+        assert_eq!(
+            quotes.quotation(&quotation.id).status,
+            QuoteStatus::PendingReview
+        );
+    }
+    #[tokio::test]
+    async fn it_should_recalculate_the_subtotal_from_the_discounted_unit_price() {
+        let quotation = quotation(QuoteStatus::PendingReview);
+        let mut part = part(&quotation, "models/part.stl");
+        part.quantity = 7;
+        let parts = Arc::new(InMemoryParts::new(vec![part.clone()]));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+        let revisions = Arc::new(InMemoryQuotationRevisions::default());
+        let transaction = InMemoryTransaction::new(parts.clone(), quotes.clone())
+            .with_revisions(revisions.clone());
+        let usecase = CreatePartQuotes::new(
+            parts.clone(),
+            quotes.clone(),
+            revisions,
+            Arc::new(InMemoryPricingAgreements {
+                pricing_agreements: std::sync::Mutex::new(vec![PricingAgreement::new(
+                    quotation.customer_id.clone(),
+                    0.125,
+                )]),
+            }),
+            Arc::new(Mutex::new(transaction)),
+            Arc::new(FixedExchangeRates::new(ConfigExchangeRates {
+                base: Currency::MXN,
+                rates: HashMap::new(),
+            })),
+        );
+
+        usecase
+            .execute(CreatePartQuotesInput {
+                customer_id: quotation.customer_id.clone(),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+                data: vec![CreatePartQuotesInputData {
+                    part_id: part.id.clone(),
+                    unit_price: Money::new(1_999, Currency::MXN),
+                    sub_total: Money::new(13_993, Currency::MXN),
+                    workdays_to_complete: 10,
+                    quantity_tiers: None,
+                }],
+            })
+            .await
+            .unwrap();
+
+        // Discounting the entered subtotal would round to 122.44 MXN instead.
+        let part_quote = parts.part(&part.id).part_quotes.unwrap().remove(0);
+        assert_eq!(part_quote.unit_price, Money::new(1_749, Currency::MXN));
+        assert_eq!(part_quote.sub_total, Money::new(12_243, Currency::MXN));
+    }
+}
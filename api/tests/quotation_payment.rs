@@ -0,0 +1,305 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod create_orders_and_confirm_quotation_payment {
+    use crate::common::fakes::{
+        FakeWrite, InMemoryBillingProfiles, InMemoryInvoices, InMemoryOrders, InMemoryParts,
+        InMemoryProjects, InMemoryPromotionCodes, InMemoryQuotes, InMemoryTransaction,
+        InMemoryWebhookEvents, InMemoryWorkOrders, RecordingEmailer,
+    };
+    use crate::common::fixtures::{project, quotation, quoted_part, tax_calculator, webhook_event};
+    use api::orders::models::order::Address;
+    use api::payments::models::inputs::{CompleteCheckoutSessionWebhookRequest, WebhookEventInput};
+    use api::payments::models::webhook_event::WebhookEventStatus;
+    use api::payments::usecases::create_orders_and_confirm_quotation_payment::CreateOrdersAndConfirmQuotationPayment;
+    use api::payments::usecases::process_webhook_event::ProcessWebhookEvent;
+    use api::promotions::models::discount::{Discount, DiscountLine};
+    use api::promotions::models::promotion_code::PromotionCode;
+    use api::quotations::models::quotation::{Quotation, QuoteStatus, MAX_PARTS_PER_QUOTATION};
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::{Result, UseCase};
+    use iso_currency::Currency;
+    use std::sync::{Arc, Mutex};
+
+    struct Setup {
+        quotation: Quotation,
+        quotes: Arc<InMemoryQuotes>,
+        orders: Arc<InMemoryOrders>,
+        webhook_events: Arc<InMemoryWebhookEvents>,
+        promotion_codes: Arc<InMemoryPromotionCodes>,
+        work_orders: Arc<InMemoryWorkOrders>,
+        usecase: ProcessWebhookEvent<
+            InMemoryWebhookEvents,
+            CreateOrdersAndConfirmQuotationPayment<
+                InMemoryProjects,
+                InMemoryQuotes,
+                InMemoryOrders,
+                InMemoryParts,
+                InMemoryWebhookEvents,
+                InMemoryInvoices,
+                InMemoryBillingProfiles,
+                InMemoryPromotionCodes,
+                InMemoryWorkOrders,
+                InMemoryTransaction,
+                FakeWrite,
+            >,
+        >,
+    }
+
+    /// A quotation pending payment with `part_count` parts at 100.00 MXN, and a 10% promotion
+    /// code.
+    fn setup(part_count: usize) -> Setup {
+        let quotation = quotation(QuoteStatus::PendingPayment);
+        let parts: Vec<_> = (0..part_count)
+            .map(|_| quoted_part(&quotation, 1, 10_000))
+            .collect();
+        let promotion_code = PromotionCode::new(
+            String::from("REPEAT-10"),
+            Discount::Percentage { rate: 0.1 },
+            None,
+            None,
+            None,
+        );
+        let parts = Arc::new(InMemoryParts::new(parts));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+        let orders = Arc::new(InMemoryOrders::default());
+        let projects = Arc::new(InMemoryProjects::new(vec![project(false)]));
+        let webhook_events = Arc::new(InMemoryWebhookEvents::default());
+        let invoices = Arc::new(InMemoryInvoices::default());
+        let promotion_codes = Arc::new(InMemoryPromotionCodes {
+            promotion_codes: Mutex::new(vec![promotion_code]),
+        });
+        let work_orders = Arc::new(InMemoryWorkOrders::default());
+        let transaction = InMemoryTransaction::new(parts.clone(), quotes.clone())
+            .with_orders(orders.clone())
+            .with_projects(projects.clone())
+            .with_webhook_events(webhook_events.clone())
+            .with_invoices(invoices.clone())
+            .with_promotion_codes(promotion_codes.clone());
+
+        Setup {
+            usecase: ProcessWebhookEvent::new(
+                webhook_events.clone(),
+                CreateOrdersAndConfirmQuotationPayment::new(
+                    projects,
+                    quotes.clone(),
+                    orders.clone(),
+                    parts,
+                    webhook_events.clone(),
+                    invoices,
+                    Arc::new(InMemoryBillingProfiles::default()),
+                    promotion_codes.clone(),
+                    work_orders.clone(),
+                    Arc::new(tokio::sync::Mutex::new(transaction)),
+                    Arc::new(RecordingEmailer::default()),
+                    tax_calculator(),
+                ),
+            ),
+            quotation,
+            quotes,
+            orders,
+            webhook_events,
+            promotion_codes,
+            work_orders,
+        }
+    }
+
+    async fn pay(setup: &Setup, event_id: &str, payment_intent_id: &str) -> Result<()> {
+        setup
+            .usecase
+            .execute(WebhookEventInput {
+                webhook_event: webhook_event(event_id, "checkout.session.completed"),
+                data: CompleteCheckoutSessionWebhookRequest {
+                    customer_id: setup.quotation.customer_id.clone(),
+                    project_id: setup.quotation.project_id.clone(),
+                    quotation_id: setup.quotation.id.clone(),
+                    shipping_recipient_name: String::from("Recipient"),
+                    shipping_address: Address::default(),
+                    is_paid: true,
+                    payment_intent_id: Some(String::from(payment_intent_id)),
+                    tax_jurisdiction: None,
+                    discount: Some(DiscountLine {
+                        code: String::from("REPEAT-10"),
+                        amount: Money::new(1_000, Currency::MXN),
+                    }),
+                    shipping: None,
+                },
+            })
+            .await
+    }
+
+    fn event_status(setup: &Setup, event_id: &str) -> WebhookEventStatus {
+        setup
+            .webhook_events
+            .events
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|event| event.id == event_id)
+            .unwrap()
+            .status
+            .clone()
+    }
+
+    #[tokio::test]
+    async fn it_should_pay_for_quotations_with_as_many_parts_as_allowed() {
+        let setup = setup(MAX_PARTS_PER_QUOTATION);
+
+        pay(&setup, "evt_1", "pi_test").await.unwrap();
+
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Payed
+        );
+        assert_eq!(
+            setup.orders.orders.lock().unwrap().len(),
+            MAX_PARTS_PER_QUOTATION
+        );
+        assert_eq!(
+            setup.work_orders.work_orders.lock().unwrap().len(),
+            MAX_PARTS_PER_QUOTATION
+        );
+        assert_eq!(
+            setup.promotion_codes.promotion_codes.lock().unwrap()[0].uses,
+            1
+        );
+        assert_eq!(event_status(&setup, "evt_1"), WebhookEventStatus::Processed);
+    }
+
+    #[tokio::test]
+    async fn it_should_create_the_missing_work_orders_when_the_event_is_replayed() {
+        let setup = setup(2);
+        *setup.work_orders.fails_creates.lock().unwrap() = true;
+
+        let result = pay(&setup, "evt_1", "pi_test").await;
+
+        assert!(result.is_err());
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::Payed
+        );
+        assert!(setup.work_orders.work_orders.lock().unwrap().is_empty());
+        assert_eq!(event_status(&setup, "evt_1"), WebhookEventStatus::Failed);
+
+        *setup.work_orders.fails_creates.lock().unwrap() = false;
+        pay(&setup, "evt_1", "pi_test").await.unwrap();
+
+        assert_eq!(setup.orders.orders.lock().unwrap().len(), 2);
+        assert_eq!(setup.work_orders.work_orders.lock().unwrap().len(), 2);
+        assert_eq!(
+            setup.promotion_codes.promotion_codes.lock().unwrap()[0].uses,
+            1
+        );
+        assert_eq!(event_status(&setup, "evt_1"), WebhookEventStatus::Processed);
+    }
+
+    #[tokio::test]
+    async fn it_should_not_complete_the_payment_of_quotations_paid_with_another_payment() {
+        let setup = setup(1);
+        pay(&setup, "evt_1", "pi_test").await.unwrap();
+
+        let result = pay(&setup, "evt_2", "pi_other").await;
+
+        assert!(matches!(result, Err(Error::QuoteIsInPayedStatus)));
+        assert_eq!(setup.orders.orders.lock().unwrap().len(), 1);
+        assert_eq!(event_status(&setup, "evt_2"), WebhookEventStatus::Failed);
+    }
+}
+
+mod send_for_review {
+    use crate::common::fakes::{
+        identity, FixedPricingEngine, InMemoryParts, InMemoryPricingAgreements,
+        InMemoryQuotationRevisions, InMemoryQuotes, InMemoryTransaction, RecordingEmailer,
+    };
+    use crate::common::fixtures::{part, quotation};
+    use api::auth::models::session::Role;
+    use api::parts::models::part::Part;
+    use api::promotions::models::pricing_agreement::PricingAgreement;
+    use api::quotations::models::inputs::SendForReviewInput;
+    use api::quotations::models::quotation::{Quotation, QuoteStatus, MAX_PARTS_PER_QUOTATION};
+    use api::quotations::usecases::send_for_review::SendForReview;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::{Result, UseCase};
+    use iso_currency::Currency;
+    use std::sync::{Arc, Mutex};
+
+    /// Sends the quotation for review, pricing every part instantly at 25.00 per unit.
+    async fn send_for_review(
+        quotation: &Quotation,
+        parts: Arc<InMemoryParts>,
+        quotes: Arc<InMemoryQuotes>,
+        pricing_agreements: Vec<PricingAgreement>,
+    ) -> Result<Quotation> {
+        let revisions = Arc::new(InMemoryQuotationRevisions::default());
+        let usecase = SendForReview::new(
+            quotes.clone(),
+            parts.clone(),
+            revisions.clone(),
+            Arc::new(InMemoryPricingAgreements {
+                pricing_agreements: Mutex::new(pricing_agreements),
+            }),
+            Arc::new(tokio::sync::Mutex::new(
+                InMemoryTransaction::new(parts, quotes).with_revisions(revisions),
+            )),
+            Arc::new(RecordingEmailer::default()),
+            Arc::new(FixedPricingEngine {
+                unit_price: Some(2_500),
+            }),
+        );
+
+        usecase
+            .execute(SendForReviewInput {
+                identity: identity(&quotation.customer_id, Role::Customer),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_should_discount_instant_quotes_by_the_pricing_agreement() {
+        let quotation = quotation(QuoteStatus::Created);
+        let mut part = part(&quotation, "models/part.stl");
+        part.quantity = 3;
+        let parts = Arc::new(InMemoryParts::new(vec![part.clone()]));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+
+        send_for_review(
+            &quotation,
+            parts.clone(),
+            quotes,
+            vec![PricingAgreement::new(quotation.customer_id.clone(), 0.1)],
+        )
+        .await
+        .unwrap();
+
+        let part_quote = parts.part(&part.id).part_quotes.unwrap()[0].clone();
+        assert_eq!(part_quote.unit_price, Money::new(2_250, Currency::MXN));
+        assert_eq!(part_quote.sub_total, Money::new(6_750, Currency::MXN));
+    }
+
+    #[tokio::test]
+    async fn it_should_not_send_quotations_with_too_many_parts_to_pay_for() {
+        let quotation = quotation(QuoteStatus::Created);
+        let parts: Vec<Part> = (0..=MAX_PARTS_PER_QUOTATION)
+            .map(|_| part(&quotation, "models/part.stl"))
+            .collect();
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+
+        let result = send_for_review(
+            &quotation,
+            Arc::new(InMemoryParts::new(parts)),
+            quotes.clone(),
+            vec![],
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(Error::TooManyParts(_, MAX_PARTS_PER_QUOTATION))
+        ));
+        assert_eq!(quotes.quotation(&quotation.id).status, QuoteStatus::Created);
+    }
+}
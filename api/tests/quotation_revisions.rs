@@ -0,0 +1,303 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod diff {
+    use api::parts::models::part::{Part, PartProcess, PartQuote};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::quotations::models::quotation_revision::{
+        PartRevision, PartRevisionChange, QuotationRevision,
+    };
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use iso_currency::Currency;
+
+    fn part() -> Part {
+        Part::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("quotation_id"),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes::default()),
+            File::new(String::from("part.stl"), String::from("key")),
+        )
+    }
+
+    fn part_revision(part: &Part, unit_price: i64) -> PartRevision {
+        let part_quote = PartQuote::new(
+            Money::new(unit_price, Currency::MXN),
+            Money::new(unit_price * part.quantity as i64, Currency::MXN),
+            10,
+            None,
+        );
+        PartRevision::new(part, Some(part_quote.id.clone()), vec![part_quote])
+    }
+
+    fn revision(parts: Vec<PartRevision>) -> QuotationRevision {
+        QuotationRevision::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("quotation_id"),
+            parts,
+            None,
+        )
+    }
+
+    #[test]
+    fn it_should_classify_part_changes() {
+        let unchanged = part();
+        let mut repriced = part();
+        let removed = part();
+        let added = part();
+
+        let from = revision(vec![
+            part_revision(&unchanged, 10_000),
+            part_revision(&repriced, 10_000),
+            part_revision(&removed, 10_000),
+        ]);
+        repriced.quantity = 50;
+        let to = revision(vec![
+            part_revision(&unchanged, 10_000),
+            part_revision(&repriced, 8_000),
+            part_revision(&added, 10_000),
+        ]);
+
+        let diff = from.diff(&to);
+        let change = |part: &Part| {
+            diff.parts
+                .iter()
+                .find(|part_diff| part_diff.part_id == part.id)
+                .unwrap()
+                .change
+                .clone()
+        };
+
+        assert_eq!(diff.parts.len(), 4);
+        assert_eq!(change(&unchanged), PartRevisionChange::Unchanged);
+        assert_eq!(change(&repriced), PartRevisionChange::Modified);
+        assert_eq!(change(&removed), PartRevisionChange::Removed);
+        assert_eq!(change(&added), PartRevisionChange::Added);
+    }
+
+    #[test]
+    fn it_should_report_quantity_and_price_changes() {
+        let mut part = part();
+        let from = revision(vec![part_revision(&part, 10_000)]);
+        part.quantity = 50;
+        let to = revision(vec![part_revision(&part, 8_000)]);
+
+        let diff = from.diff(&to);
+
+        assert_eq!(diff.from_revision_id, from.id);
+        assert_eq!(diff.to_revision_id, to.id);
+        assert_eq!(diff.parts[0].from_quantity, Some(1));
+        assert_eq!(diff.parts[0].to_quantity, Some(50));
+        assert_eq!(
+            diff.parts[0].from_unit_price.as_ref().unwrap().amount,
+            10_000
+        );
+        assert_eq!(diff.parts[0].to_unit_price.as_ref().unwrap().amount, 8_000);
+    }
+
+    #[test]
+    fn it_should_only_match_parts_with_the_same_specification() {
+        let mut part = part();
+        let part_revision = part_revision(&part, 10_000);
+
+        assert!(part_revision.matches(&part));
+
+        part.quantity = 2;
+        assert!(!part_revision.matches(&part));
+    }
+}
+
+mod pricing_rounds {
+    use crate::common::fakes::{
+        identity, FixedPricingEngine, InMemoryParts, InMemoryPricingAgreements,
+        InMemoryQuotationRevisions, InMemoryQuotes, InMemoryTransaction, RecordingEmailer,
+    };
+    use crate::common::fixtures::{part, quotation, quoted_part};
+    use api::auth::models::session::Role;
+    use api::config::ConfigExchangeRates;
+    use api::parts::models::inputs::{CreatePartQuotesInput, CreatePartQuotesInputData};
+    use api::parts::models::part::Part;
+    use api::parts::usecases::create_part_quotes::CreatePartQuotes;
+    use api::quotations::models::inputs::{AdminRestoreQuotationRevisionInput, SendForReviewInput};
+    use api::quotations::models::quotation::{Quotation, QuoteStatus};
+    use api::quotations::usecases::admin_restore_quotation_revision::AdminRestoreQuotationRevision;
+    use api::quotations::usecases::send_for_review::SendForReview;
+    use api::services::exchange_rates_fixed::FixedExchangeRates;
+    use api::shared::money::Money;
+    use api::shared::{Result, UseCase};
+    use iso_currency::Currency;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    struct Repositories {
+        parts: Arc<InMemoryParts>,
+        quotes: Arc<InMemoryQuotes>,
+        revisions: Arc<InMemoryQuotationRevisions>,
+        transaction: Arc<Mutex<InMemoryTransaction>>,
+    }
+
+    fn repositories(quotation: Quotation, parts: Vec<Part>) -> Repositories {
+        let parts = Arc::new(InMemoryParts::new(parts));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation]));
+        let revisions = Arc::new(InMemoryQuotationRevisions::default());
+        let transaction = Arc::new(Mutex::new(
+            InMemoryTransaction::new(parts.clone(), quotes.clone())
+                .with_revisions(revisions.clone()),
+        ));
+
+        Repositories {
+            parts,
+            quotes,
+            revisions,
+            transaction,
+        }
+    }
+
+    async fn create_part_quotes(
+        repositories: &Repositories,
+        quotation: &Quotation,
+        part: &Part,
+    ) -> Result<()> {
+        let usecase = CreatePartQuotes::new(
+            repositories.parts.clone(),
+            repositories.quotes.clone(),
+            repositories.revisions.clone(),
+            Arc::new(InMemoryPricingAgreements::default()),
+            repositories.transaction.clone(),
+            Arc::new(FixedExchangeRates::new(ConfigExchangeRates {
+                base: Currency::MXN,
+                rates: HashMap::new(),
+            })),
+        );
+        usecase
+            .execute(CreatePartQuotesInput {
+                customer_id: quotation.customer_id.clone(),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+                data: vec![CreatePartQuotesInputData {
+                    part_id: part.id.clone(),
+                    unit_price: Money::new(1_000, Currency::MXN),
+                    sub_total: Money::new(1_000, Currency::MXN),
+                    workdays_to_complete: 10,
+                    quantity_tiers: None,
+                }],
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_should_write_the_revision_with_the_part_quotes() {
+        let quotation = quotation(QuoteStatus::PendingReview);
+        let part = part(&quotation, "models/part.stl");
+        let repositories = repositories(quotation.clone(), vec![part.clone()]);
+
+        create_part_quotes(&repositories, &quotation, &part)
+            .await
+            .unwrap();
+
+        let priced_part = repositories.parts.part(&part.id);
+        let revisions = repositories.revisions.revisions.lock().unwrap().clone();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].parts.len(), 1);
+        assert_eq!(
+            revisions[0].parts[0].selected_part_quote_id,
+            priced_part.selected_part_quote_id
+        );
+        assert_eq!(
+            Some(revisions[0].parts[0].part_quotes.clone()),
+            priced_part.part_quotes
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_write_the_revision_when_the_pricing_fails() {
+        let quotation = quotation(QuoteStatus::Created);
+        let part = part(&quotation, "models/part.stl");
+        let repositories = repositories(quotation.clone(), vec![part.clone()]);
+        let result = create_part_quotes(&repositories, &quotation, &part).await;
+
+        assert!(result.is_err());
+        assert!(repositories.revisions.revisions.lock().unwrap().is_empty());
+        assert!(repositories.parts.part(&part.id).part_quotes.is_none());
+    }
+
+    #[tokio::test]
+    async fn it_should_write_a_revision_for_instant_quotes() {
+        let quotation = quotation(QuoteStatus::Created);
+        let part = part(&quotation, "models/part.stl");
+        let repositories = repositories(quotation.clone(), vec![part.clone()]);
+        let usecase = SendForReview::new(
+            repositories.quotes.clone(),
+            repositories.parts.clone(),
+            repositories.revisions.clone(),
+            Arc::new(InMemoryPricingAgreements::default()),
+            repositories.transaction.clone(),
+            Arc::new(RecordingEmailer::default()),
+            Arc::new(FixedPricingEngine {
+                unit_price: Some(2_500),
+            }),
+        );
+
+        let quotation = usecase
+            .execute(SendForReviewInput {
+                identity: identity(&quotation.customer_id, Role::Customer),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(quotation.status, QuoteStatus::PendingPayment);
+        let priced_part = repositories.parts.part(&part.id);
+        let revisions = repositories.revisions.revisions.lock().unwrap().clone();
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].quotation_id, quotation.id);
+        assert_eq!(
+            revisions[0].parts[0].selected_part_quote_id,
+            priced_part.selected_part_quote_id
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_write_the_restored_revision_with_the_part_quotes() {
+        let quotation = quotation(QuoteStatus::PendingReview);
+        let part = quoted_part(&quotation, 1, 1_000);
+        let mut unpriced_part = part.clone();
+        unpriced_part.selected_part_quote_id = None;
+        unpriced_part.part_quotes = None;
+        let repositories = repositories(quotation.clone(), vec![unpriced_part]);
+        create_part_quotes(&repositories, &quotation, &part)
+            .await
+            .unwrap();
+        let first_revision = repositories.revisions.revisions.lock().unwrap()[0].clone();
+        let usecase = AdminRestoreQuotationRevision::new(
+            repositories.quotes.clone(),
+            repositories.parts.clone(),
+            repositories.revisions.clone(),
+            repositories.transaction.clone(),
+        );
+
+        usecase
+            .execute(AdminRestoreQuotationRevisionInput {
+                customer_id: quotation.customer_id.clone(),
+                project_id: quotation.project_id.clone(),
+                quotation_id: quotation.id.clone(),
+                revision_id: first_revision.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        let priced_part = repositories.parts.part(&part.id);
+        let revisions = repositories.revisions.revisions.lock().unwrap().clone();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!(revisions[1].restored_from, Some(first_revision.id));
+        assert_eq!(
+            revisions[1].parts[0].selected_part_quote_id,
+            priced_part.selected_part_quote_id
+        );
+    }
+}
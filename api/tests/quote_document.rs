@@ -0,0 +1,203 @@
+mod quote_document {
+    use api::config::{ConfigTaxRate, ConfigTaxes};
+    use api::parts::models::part::{Part, PartProcess, PartQuote};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::quotations::models::quotation::Quotation;
+    use api::quotations::models::quote_document::QuoteDocument;
+    use api::services::tax_calculator::TaxCalculator;
+    use api::services::tax_calculator_rate_table::RateTableTaxCalculator;
+    use api::shared::error::Error;
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use chrono::{Days, Utc};
+    use iso_currency::Currency;
+
+    fn quotation() -> Quotation {
+        Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets"),
+            Currency::MXN,
+        )
+    }
+
+    fn quoted_part(quotation: &Quotation, unit_price: i64, workdays: u64) -> Part {
+        let mut part = Part::new(
+            quotation.customer_id.clone(),
+            quotation.project_id.clone(),
+            quotation.id.clone(),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes::default()),
+            File::new(String::from("part.stl"), String::from("key")),
+        );
+        part.quantity = 2;
+        let part_quote = PartQuote::new(
+            Money::new(unit_price, Currency::MXN),
+            Money::new(unit_price * 2, Currency::MXN),
+            workdays,
+            None,
+        );
+        part.selected_part_quote_id = Some(part_quote.id.clone());
+        part.part_quotes = Some(vec![part_quote]);
+        part
+    }
+
+    fn document(quotation: &Quotation, parts: &[Part]) -> Result<QuoteDocument, Error> {
+        let tax_calculator = RateTableTaxCalculator::new(ConfigTaxes {
+            default_country: String::from("MX"),
+            rates: vec![ConfigTaxRate {
+                country: String::from("MX"),
+                state: None,
+                name: String::from("IVA"),
+                rate: 0.16,
+                stripe_tax_rate_id: String::from("txr_iva"),
+            }],
+        });
+
+        QuoteDocument::new(
+            quotation,
+            parts,
+            String::from("customer@example.com"),
+            &tax_calculator,
+            tax_calculator.jurisdiction(None).unwrap(),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn it_should_add_taxes_on_top_of_the_subtotal() {
+        let quotation = quotation();
+        let parts = vec![
+            quoted_part(&quotation, 10_000, 5),
+            quoted_part(&quotation, 2_500, 10),
+        ];
+
+        let document = document(&quotation, &parts).unwrap();
+
+        assert_eq!(document.subtotal, Money::new(25_000, Currency::MXN));
+        assert_eq!(
+            document.tax_lines[0].amount,
+            Money::new(4_000, Currency::MXN)
+        );
+        assert_eq!(document.total, Money::new(29_000, Currency::MXN));
+        assert_eq!(document.workdays_to_complete(), 10);
+    }
+
+    #[test]
+    fn it_should_be_valid_until_the_earliest_part_quote_expires() {
+        let quotation = quotation();
+        let mut parts = vec![
+            quoted_part(&quotation, 10_000, 5),
+            quoted_part(&quotation, 10_000, 5),
+        ];
+        let earliest = Utc::now().checked_add_days(Days::new(2)).unwrap();
+        parts[1].part_quotes.as_mut().unwrap()[0].valid_until = earliest;
+
+        let document = document(&quotation, &parts).unwrap();
+
+        assert_eq!(document.valid_until, earliest);
+    }
+
+    #[test]
+    fn it_should_reject_parts_without_a_selected_part_quote() {
+        let quotation = quotation();
+        let mut part = quoted_part(&quotation, 10_000, 5);
+        part.selected_part_quote_id = None;
+
+        assert!(matches!(
+            document(&quotation, &[part.clone()]),
+            Err(Error::NoSelectedQuoteAvailableForPart(part_id)) if part_id == part.id
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_quotations_without_parts() {
+        assert!(matches!(
+            document(&quotation(), &[]),
+            Err(Error::NoPdfQuoteAvailable)
+        ));
+    }
+}
+
+mod pdf_quote_renderer {
+    use api::config::{ConfigQuotesDocument, ConfigTaxRate, ConfigTaxes};
+    use api::parts::models::part::{Part, PartProcess, PartQuote};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::quotations::models::quotation::Quotation;
+    use api::services::quote_renderer::QuoteRenderer;
+    use api::services::quote_renderer_pdf::PdfQuoteRenderer;
+    use api::services::tax_calculator_rate_table::RateTableTaxCalculator;
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use api::shared::tax::TaxJurisdiction;
+    use iso_currency::Currency;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn it_should_render_a_pdf_document() {
+        let tax_calculator = RateTableTaxCalculator::new(ConfigTaxes {
+            default_country: String::from("MX"),
+            rates: vec![ConfigTaxRate {
+                country: String::from("MX"),
+                state: None,
+                name: String::from("IVA"),
+                rate: 0.16,
+                stripe_tax_rate_id: String::from("txr_iva"),
+            }],
+        });
+        let renderer = PdfQuoteRenderer::new(
+            ConfigQuotesDocument {
+                company_name: String::from("Torvek"),
+                company_address: vec![String::from("Monterrey, N.L.")],
+            },
+            Arc::new(tax_calculator),
+        );
+        let quotation = Quotation::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("Brackets (rev. A)"),
+            Currency::MXN,
+        );
+        let parts = (0..40)
+            .map(|_| {
+                let mut part = Part::new(
+                    quotation.customer_id.clone(),
+                    quotation.project_id.clone(),
+                    quotation.id.clone(),
+                    PartProcess::CNC,
+                    PartAttributes::CNC(CNCAttributes::default()),
+                    File::new(String::from("bracket.stl"), String::from("key")),
+                );
+                let part_quote = PartQuote::new(
+                    Money::new(10_000, Currency::MXN),
+                    Money::new(10_000, Currency::MXN),
+                    5,
+                    None,
+                );
+                part.selected_part_quote_id = Some(part_quote.id.clone());
+                part.part_quotes = Some(vec![part_quote]);
+                part
+            })
+            .collect::<Vec<Part>>();
+
+        let pdf = renderer
+            .render_quote(
+                &quotation,
+                &parts,
+                "customer@example.com",
+                TaxJurisdiction {
+                    country: String::from("MX"),
+                    state: None,
+                },
+            )
+            .await
+            .unwrap();
+        let pdf = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with("%PDF-1.4"));
+        assert!(pdf.ends_with("%%EOF\n"));
+        assert!(pdf.contains("Brackets \\(rev. A\\)"));
+        assert!(pdf.contains("/Count 3 >>"));
+        assert!(pdf.contains("IVA \\(16%\\)"));
+    }
+}
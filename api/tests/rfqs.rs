@@ -0,0 +1,304 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod rfqs {
+    use crate::common::fixtures::{self, quotation};
+    use api::parts::models::part::Part;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::suppliers::models::rfq::{Rfq, RfqBid, RfqStatus};
+    use api::suppliers::models::rfq_bid_comparison::RfqBidComparison;
+    use iso_currency::Currency;
+
+    fn part() -> Part {
+        fixtures::part(&quotation(QuoteStatus::Created), "models/part.step")
+    }
+
+    fn rfq(supplier_id: &str, parts: &[Part]) -> Rfq {
+        Rfq::new(
+            &quotation(QuoteStatus::Created),
+            String::from(supplier_id),
+            parts,
+        )
+    }
+
+    fn bid(part: &Part, sub_total: i64, workdays_to_complete: u64) -> RfqBid {
+        RfqBid {
+            part_id: part.id.clone(),
+            unit_price: Money::new(sub_total, Currency::MXN),
+            sub_total: Money::new(sub_total, Currency::MXN),
+            workdays_to_complete,
+        }
+    }
+
+    #[test]
+    fn it_should_only_give_access_with_the_rfq_token() {
+        let rfq = rfq("sup_1", &[part()]);
+
+        assert!(rfq.verify_access_token(&rfq.access_token).is_ok());
+        assert!(matches!(
+            rfq.verify_access_token("guessed"),
+            Err(Error::Forbidden)
+        ));
+    }
+
+    #[test]
+    fn it_should_reject_bids_on_parts_outside_the_rfq_or_in_another_currency() {
+        let part = part();
+        let rfq = rfq("sup_1", std::slice::from_ref(&part));
+        let mut in_usd = bid(&part, 1_000, 5);
+        in_usd.sub_total = Money::new(1_000, Currency::USD);
+
+        assert!(rfq.validate_bids(&[bid(&part, 1_000, 5)]).is_ok());
+        assert!(matches!(
+            rfq.validate_bids(&[bid(&self::part(), 1_000, 5)]),
+            Err(Error::InvalidRfqBid(_))
+        ));
+        assert!(matches!(
+            rfq.validate_bids(&[bid(&part, 1_000, 5), bid(&part, 900, 7)]),
+            Err(Error::InvalidRfqBid(_))
+        ));
+        assert!(matches!(
+            rfq.validate_bids(&[in_usd]),
+            Err(Error::CurrencyMismatch(Currency::MXN, Currency::USD))
+        ));
+    }
+
+    #[test]
+    fn it_should_not_accept_bids_once_the_rfq_was_closed() {
+        let part = part();
+        let mut rfq = rfq("sup_1", std::slice::from_ref(&part));
+        rfq.status = RfqStatus::Closed;
+
+        assert!(matches!(
+            rfq.validate_bids(&[bid(&part, 1_000, 5)]),
+            Err(Error::RfqClosed(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_mark_up_the_awarded_bid_by_the_margin() {
+        let part = part();
+        let priced = bid(&part, 10_001, 5).with_margin(0.35, 3).unwrap();
+
+        // The subtotal is the rounded unit price times the quantity, not 30_003 marked up.
+        assert_eq!(priced.unit_price, Money::new(13_501, Currency::MXN));
+        assert_eq!(priced.sub_total, Money::new(40_503, Currency::MXN));
+        assert_eq!(priced.workdays_to_complete, 5);
+    }
+
+    #[test]
+    fn it_should_compare_bids_per_part_cheapest_first() {
+        let (bracket, shaft) = (part(), part());
+        let parts = [bracket.clone(), shaft.clone()];
+        let mut expensive = rfq("sup_expensive", &parts);
+        expensive.bids = vec![bid(&bracket, 2_000, 3), bid(&shaft, 5_000, 3)];
+        let mut cheap = rfq("sup_cheap", &parts);
+        cheap.bids = vec![bid(&bracket, 1_500, 10)];
+        let pending = rfq("sup_pending", &parts);
+
+        let comparison = RfqBidComparison::new(&[expensive, cheap, pending]);
+
+        assert_eq!(
+            comparison.pending_suppliers,
+            vec![String::from("sup_pending")]
+        );
+        assert_eq!(comparison.parts.len(), 2);
+        assert_eq!(comparison.parts[0].part_id, bracket.id);
+        let suppliers: Vec<_> = comparison.parts[0]
+            .bids
+            .iter()
+            .map(|bid| bid.supplier_id.as_str())
+            .collect();
+        assert_eq!(suppliers, vec!["sup_cheap", "sup_expensive"]);
+        assert_eq!(comparison.parts[1].bids.len(), 1);
+    }
+}
+
+mod admin_award_rfq_bids {
+    use crate::common::fakes::{
+        InMemoryParts, InMemoryPricingAgreements, InMemoryQuotationRevisions, InMemoryQuotes,
+        InMemoryRfqs, InMemoryTransaction,
+    };
+    use crate::common::fixtures::{exchange_rates, part, quotation};
+    use api::parts::models::part::Part;
+    use api::parts::usecases::create_part_quotes::CreatePartQuotes;
+    use api::quotations::models::quotation::{Quotation, QuoteStatus};
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::UseCase;
+    use api::suppliers::models::inputs::{AdminAwardRfqBidsInput, RfqAward};
+    use api::suppliers::models::rfq::{Rfq, RfqBid, RfqStatus};
+    use api::suppliers::usecases::admin_award_rfq_bids::AdminAwardRfqBids;
+    use iso_currency::Currency;
+    use std::sync::Arc;
+    use tokio::sync::Mutex;
+
+    struct Setup {
+        quotation: Quotation,
+        bracket: Part,
+        shaft: Part,
+        parts: Arc<InMemoryParts>,
+        quotes: Arc<InMemoryQuotes>,
+        rfqs: Arc<InMemoryRfqs>,
+        usecase: AdminAwardRfqBids<InMemoryRfqs>,
+    }
+
+    fn bid(part: &Part, unit_price: i64) -> RfqBid {
+        RfqBid {
+            part_id: part.id.clone(),
+            unit_price: Money::new(unit_price, Currency::MXN),
+            sub_total: Money::new(unit_price * part.quantity as i64, Currency::MXN),
+            workdays_to_complete: 5,
+        }
+    }
+
+    fn rfq(quotation: &Quotation, supplier_id: &str, parts: &[Part], bids: Vec<RfqBid>) -> Rfq {
+        let mut rfq = Rfq::new(quotation, String::from(supplier_id), parts);
+        if !bids.is_empty() {
+            rfq.status = RfqStatus::Bid;
+        }
+        rfq.bids = bids;
+        rfq
+    }
+
+    /// Two suppliers bid on a bracket and a shaft, a third one didn't answer.
+    fn setup() -> Setup {
+        let quotation = quotation(QuoteStatus::PendingReview);
+        let mut bracket = part(&quotation, "models/bracket.step");
+        bracket.quantity = 3;
+        let shaft = part(&quotation, "models/shaft.step");
+        let parts = [bracket.clone(), shaft.clone()];
+        let rfqs = Arc::new(InMemoryRfqs::new(vec![
+            rfq(
+                &quotation,
+                "sup_expensive",
+                &parts,
+                vec![bid(&bracket, 12_000), bid(&shaft, 5_000)],
+            ),
+            rfq(&quotation, "sup_cheap", &parts, vec![bid(&bracket, 9_000)]),
+            rfq(&quotation, "sup_pending", &parts, Vec::new()),
+        ]));
+
+        let parts = Arc::new(InMemoryParts::new(parts.to_vec()));
+        let quotes = Arc::new(InMemoryQuotes::new(vec![quotation.clone()]));
+        let revisions = Arc::new(InMemoryQuotationRevisions::default());
+        let transaction = Arc::new(Mutex::new(
+            InMemoryTransaction::new(parts.clone(), quotes.clone())
+                .with_revisions(revisions.clone()),
+        ));
+        let create_part_quotes = CreatePartQuotes::new(
+            parts.clone(),
+            quotes.clone(),
+            revisions,
+            Arc::new(InMemoryPricingAgreements::default()),
+            transaction,
+            exchange_rates(),
+        );
+        let usecase = AdminAwardRfqBids::new(rfqs.clone(), Arc::new(create_part_quotes), 0.35);
+
+        Setup {
+            quotation,
+            bracket,
+            shaft,
+            parts,
+            quotes,
+            rfqs,
+            usecase,
+        }
+    }
+
+    fn rfq_id(setup: &Setup, supplier_id: &str) -> String {
+        setup
+            .rfqs
+            .rfqs
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|rfq| rfq.supplier_id == supplier_id)
+            .map(|rfq| rfq.id.clone())
+            .unwrap()
+    }
+
+    fn award(setup: &Setup, part: &Part, supplier_id: &str) -> RfqAward {
+        RfqAward {
+            part_id: part.id.clone(),
+            rfq_id: rfq_id(setup, supplier_id),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_price_the_quotation_with_the_awarded_bids_plus_the_margin() {
+        let setup = setup();
+
+        setup
+            .usecase
+            .execute(AdminAwardRfqBidsInput {
+                customer_id: setup.quotation.customer_id.clone(),
+                quotation_id: setup.quotation.id.clone(),
+                awards: vec![
+                    award(&setup, &setup.bracket, "sup_cheap"),
+                    award(&setup, &setup.shaft, "sup_expensive"),
+                ],
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::PendingPayment
+        );
+        let bracket = setup.parts.part(&setup.bracket.id);
+        let part_quotes = bracket.part_quotes.unwrap();
+        assert_eq!(part_quotes.len(), 1);
+        assert_eq!(
+            bracket.selected_part_quote_id,
+            Some(part_quotes[0].id.clone())
+        );
+        assert_eq!(part_quotes[0].unit_price, Money::new(12_150, Currency::MXN));
+        assert_eq!(part_quotes[0].sub_total, Money::new(36_450, Currency::MXN));
+        let shaft_quotes = setup.parts.part(&setup.shaft.id).part_quotes.unwrap();
+        assert_eq!(shaft_quotes[0].unit_price, Money::new(6_750, Currency::MXN));
+
+        let statuses: Vec<_> = ["sup_expensive", "sup_cheap", "sup_pending"]
+            .into_iter()
+            .map(|supplier_id| setup.rfqs.rfq(&rfq_id(&setup, supplier_id)).status)
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![RfqStatus::Awarded, RfqStatus::Awarded, RfqStatus::Closed]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_price_anything_when_a_supplier_did_not_bid_on_the_part() {
+        let setup = setup();
+
+        let result = setup
+            .usecase
+            .execute(AdminAwardRfqBidsInput {
+                customer_id: setup.quotation.customer_id.clone(),
+                quotation_id: setup.quotation.id.clone(),
+                awards: vec![
+                    award(&setup, &setup.bracket, "sup_expensive"),
+                    award(&setup, &setup.shaft, "sup_cheap"),
+                ],
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::InvalidRfqBid(_))));
+        assert_eq!(
+            setup.quotes.quotation(&setup.quotation.id).status,
+            QuoteStatus::PendingReview
+        );
+        assert!(setup.parts.part(&setup.bracket.id).part_quotes.is_none());
+        assert!(setup
+            .rfqs
+            .rfqs
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|rfq| rfq.accepts_bids()));
+    }
+}
@@ -0,0 +1,200 @@
+mod shipping_options {
+    use api::config::{ConfigExchangeRates, ConfigShipping, ConfigShippingRate};
+    use api::parts::models::part::{Part, PartProcess};
+    use api::parts::models::part_attributes::{CNCAttributes, PartAttributes};
+    use api::parts::models::part_geometry::{BoundingBox, PartGeometry};
+    use api::services::exchange_rates_fixed::FixedExchangeRates;
+    use api::services::shipping_calculator::ShippingCalculator;
+    use api::services::shipping_calculator_rate_table::RateTableShippingCalculator;
+    use api::shared::error::Error;
+    use api::shared::file::File;
+    use api::shared::money::Money;
+    use api::shared::shipping::ShippingMethod;
+    use iso_currency::Currency;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    fn rate(
+        method: ShippingMethod,
+        base_cost: i64,
+        cost_per_kg: i64,
+        workdays: (u64, u64),
+    ) -> ConfigShippingRate {
+        ConfigShippingRate {
+            country: String::from("MX"),
+            method,
+            base_cost,
+            cost_per_kg,
+            min_workdays: workdays.0,
+            max_workdays: workdays.1,
+        }
+    }
+
+    fn calculator() -> RateTableShippingCalculator {
+        RateTableShippingCalculator::new(
+            ConfigShipping {
+                material_densities: HashMap::from([(String::from("Aluminum 6061-T6"), 2.7)]),
+                default_density: 8.0,
+                default_part_weight_kg: 1.5,
+                rates: vec![
+                    rate(ShippingMethod::Express, 30_000, 5_000, (1, 2)),
+                    rate(ShippingMethod::Standard, 15_000, 2_000, (3, 5)),
+                    rate(ShippingMethod::Pickup, 0, 0, (0, 0)),
+                ],
+            },
+            Arc::new(FixedExchangeRates::new(ConfigExchangeRates {
+                base: Currency::MXN,
+                rates: HashMap::from([(Currency::USD, 0.05)]),
+            })),
+        )
+    }
+
+    fn part(material: &str, volume_mm3: Option<f64>, quantity: u64) -> Part {
+        let mut part = Part::new(
+            String::from("customer_id"),
+            String::from("project_id"),
+            String::from("quotation_id"),
+            PartProcess::CNC,
+            PartAttributes::CNC(CNCAttributes {
+                material: String::from(material),
+                ..Default::default()
+            }),
+            File::new(String::from("part.stl"), String::from("key")),
+        );
+        part.quantity = quantity;
+        part.geometry = volume_mm3.map(|volume_mm3| PartGeometry {
+            bounding_box: BoundingBox {
+                x_mm: 100.0,
+                y_mm: 50.0,
+                z_mm: 20.0,
+            },
+            volume_mm3,
+            surface_area_mm2: 20_000.0,
+            triangle_count: 12,
+        });
+        part
+    }
+
+    #[test]
+    fn it_should_estimate_the_weight_from_density_and_volume() {
+        let calculator = calculator();
+
+        // 100 cm³ of aluminum weigh 270 g.
+        let aluminum = part("Aluminum 6061-T6", Some(100_000.0), 10);
+        assert!(
+            (calculator.estimated_weight_kg(std::slice::from_ref(&aluminum)) - 2.7).abs() < 1e-9
+        );
+
+        // Materials without a density weigh like steel, parts without geometry a fixed weight.
+        let unknown_material = part("Unobtainium", Some(100_000.0), 1);
+        let without_geometry = part("Aluminum 6061-T6", None, 2);
+        assert!(
+            (calculator.estimated_weight_kg(&[aluminum, unknown_material, without_geometry]) - 6.5)
+                .abs()
+                < 1e-9
+        );
+    }
+
+    #[test]
+    fn it_should_price_every_method_by_started_kilogram_cheapest_first() {
+        let parts = vec![part("Aluminum 6061-T6", Some(100_000.0), 10)];
+
+        let shipping_options = calculator()
+            .shipping_options("mx", Currency::MXN, &parts)
+            .unwrap();
+
+        assert_eq!(
+            shipping_options
+                .iter()
+                .map(|shipping_option| (
+                    shipping_option.method.clone(),
+                    shipping_option.cost.amount
+                ))
+                .collect::<Vec<(ShippingMethod, i64)>>(),
+            vec![
+                (ShippingMethod::Pickup, 0),
+                (ShippingMethod::Standard, 21_000),
+                (ShippingMethod::Express, 45_000),
+            ]
+        );
+        assert_eq!(shipping_options[2].min_workdays, 1);
+        assert_eq!(shipping_options[2].max_workdays, 2);
+    }
+
+    #[test]
+    fn it_should_price_in_the_currency_of_the_quotation() {
+        let parts = vec![part("Aluminum 6061-T6", Some(100_000.0), 10)];
+
+        let shipping_options = calculator()
+            .shipping_options("MX", Currency::USD, &parts)
+            .unwrap();
+
+        assert_eq!(shipping_options[1].cost, Money::new(1_050, Currency::USD));
+    }
+
+    #[test]
+    fn it_should_reject_countries_without_rates() {
+        assert!(matches!(
+            calculator().shipping_options("US", Currency::USD, &[]),
+            Err(Error::UnsupportedShippingCountry(country)) if country == "US"
+        ));
+    }
+}
+
+mod checkout_shipping_options {
+    use api::services::stripe::checkout_shipping_options;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::shipping::{ShippingMethod, ShippingOption};
+    use iso_currency::Currency;
+
+    fn shipping_option(method: ShippingMethod, cost: Money) -> ShippingOption {
+        ShippingOption {
+            method,
+            cost,
+            min_workdays: 0,
+            max_workdays: 2,
+        }
+    }
+
+    #[test]
+    fn it_should_create_the_shipping_rates_with_the_session() {
+        let checkout_shipping_options = checkout_shipping_options(&[
+            shipping_option(ShippingMethod::Standard, Money::new(15_000, Currency::MXN)),
+            shipping_option(ShippingMethod::Pickup, Money::zero(Currency::MXN)),
+        ])
+        .unwrap();
+
+        let standard = checkout_shipping_options[0]
+            .shipping_rate_data
+            .as_ref()
+            .unwrap();
+        assert!(checkout_shipping_options[0].shipping_rate.is_none());
+        assert_eq!(standard.fixed_amount.as_ref().unwrap().amount, 15_000);
+        assert_eq!(
+            standard.metadata.as_ref().unwrap()["shipping_method"],
+            ShippingMethod::Standard.to_string()
+        );
+        // Carriers take at least a business day.
+        let delivery_estimate = standard.delivery_estimate.as_ref().unwrap();
+        assert_eq!(delivery_estimate.minimum.as_ref().unwrap().value, 1);
+        assert_eq!(delivery_estimate.maximum.as_ref().unwrap().value, 2);
+
+        let pickup = checkout_shipping_options[1]
+            .shipping_rate_data
+            .as_ref()
+            .unwrap();
+        assert!(pickup.delivery_estimate.is_none());
+    }
+
+    #[test]
+    fn it_should_reject_unsupported_currencies() {
+        assert!(matches!(
+            checkout_shipping_options(&[shipping_option(
+                ShippingMethod::Standard,
+                Money::new(1_000, Currency::EUR)
+            )]),
+            Err(Error::UnsupportedCurrency(Currency::EUR))
+        ));
+    }
+}
@@ -0,0 +1,309 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod suppliers {
+    use crate::common::fixtures;
+    use api::auth::models::session::{MetadataPublic, Role};
+    use api::orders::models::order::{Order, OrderStatus};
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::suppliers::models::order_margins::OrderMargins;
+    use api::suppliers::models::supplier::Supplier;
+    use api::suppliers::models::supplier_order::SupplierOrder;
+    use iso_currency::Currency;
+
+    fn order_with_payout(price: i64, payout: Option<i64>, status: OrderStatus) -> Order {
+        let mut order = fixtures::order(status);
+        order.price = Some(Money::new(price, Currency::MXN));
+        order.payout = payout.map(|payout| Money::new(payout, Currency::MXN));
+        order
+    }
+
+    #[test]
+    fn it_should_normalize_the_supplier_contact() {
+        let supplier = Supplier::new(
+            String::from("  Maquinados del Norte "),
+            String::from(" Ventas@Maquinados.MX "),
+            String::from("mx"),
+        );
+
+        assert!(supplier.id.starts_with("sup_"));
+        assert_eq!(supplier.name, "Maquinados del Norte");
+        assert_eq!(supplier.email, "ventas@maquinados.mx");
+        assert_eq!(supplier.country, "MX");
+        assert!(supplier.validate().is_ok());
+    }
+
+    #[test]
+    fn it_should_reject_suppliers_without_a_valid_email_or_country() {
+        let without_email = Supplier::new(
+            String::from("Maquinados del Norte"),
+            String::from("ventas"),
+            String::from("MX"),
+        );
+        let without_country = Supplier::new(
+            String::from("Maquinados del Norte"),
+            String::from("ventas@maquinados.mx"),
+            String::from("Mexico"),
+        );
+
+        assert!(matches!(
+            without_email.validate(),
+            Err(Error::InvalidSupplier(_))
+        ));
+        assert!(matches!(
+            without_country.validate(),
+            Err(Error::InvalidSupplier(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_compute_the_margin_once_the_payout_is_set() {
+        assert_eq!(
+            order_with_payout(10_000, None, OrderStatus::Open)
+                .margin()
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            order_with_payout(10_000, Some(6_500), OrderStatus::Open)
+                .margin()
+                .unwrap(),
+            Some(Money::new(3_500, Currency::MXN))
+        );
+    }
+
+    #[test]
+    fn it_should_only_total_the_margin_of_active_orders() {
+        let orders = vec![
+            order_with_payout(10_000, Some(6_500), OrderStatus::Ready),
+            order_with_payout(4_000, Some(3_000), OrderStatus::Shipped),
+            order_with_payout(8_000, Some(5_000), OrderStatus::Cancelled),
+            order_with_payout(2_000, None, OrderStatus::Open),
+        ];
+
+        let margins = OrderMargins::new(&orders).unwrap();
+
+        assert_eq!(margins.orders.len(), 4);
+        assert_eq!(margins.price, Some(Money::new(14_000, Currency::MXN)));
+        assert_eq!(margins.payout, Some(Money::new(9_500, Currency::MXN)));
+        assert_eq!(margins.margin, Some(Money::new(4_500, Currency::MXN)));
+        assert_eq!(
+            margins.orders[2].margin,
+            Some(Money::new(3_000, Currency::MXN))
+        );
+    }
+
+    #[test]
+    fn it_should_not_total_quotations_without_payouts() {
+        let margins =
+            OrderMargins::new(&[order_with_payout(2_000, None, OrderStatus::Open)]).unwrap();
+
+        assert_eq!(margins.price, None);
+        assert_eq!(margins.margin, None);
+    }
+
+    #[test]
+    fn it_should_only_accept_the_invitation_token_once() {
+        let mut supplier = Supplier::new(
+            String::from("Maquinados del Norte"),
+            String::from("ventas@maquinados.mx"),
+            String::from("MX"),
+        );
+        assert!(matches!(
+            supplier.verify_invitation_token("token"),
+            Err(Error::Forbidden)
+        ));
+
+        supplier.invitation_token = Some(String::from("token"));
+        assert!(supplier.verify_invitation_token("token").is_ok());
+        assert!(matches!(
+            supplier.verify_invitation_token("other_token"),
+            Err(Error::Forbidden)
+        ));
+
+        supplier.identity_id = Some(String::from("identity_id"));
+        assert!(matches!(
+            supplier.verify_invitation_token("token"),
+            Err(Error::Forbidden)
+        ));
+    }
+
+    #[test]
+    fn it_should_hide_the_customer_and_price_from_supplier_orders() {
+        let order = order_with_payout(10_000, Some(6_500), OrderStatus::InProgress);
+        let supplier_order = SupplierOrder::from(order.clone());
+        let json = serde_json::to_value(&supplier_order).unwrap();
+
+        assert_eq!(supplier_order.id, order.id);
+        assert_eq!(
+            supplier_order.payout,
+            Some(Money::new(6_500, Currency::MXN))
+        );
+        assert!(json.get("customer_id").is_none());
+        assert!(json.get("price").is_none());
+        assert!(json.get("shipping").is_none());
+    }
+
+    #[test]
+    fn it_should_read_identities_created_before_suppliers_signed_in() {
+        let metadata: MetadataPublic =
+            serde_json::from_str(r#"{"stripe_customer_id":"cus_1","role":"Customer"}"#).unwrap();
+        assert_eq!(metadata.role, Role::Customer);
+        assert_eq!(metadata.supplier_id, None);
+
+        let metadata: MetadataPublic = serde_json::from_str(
+            r#"{"stripe_customer_id":null,"role":"Supplier","supplier_id":"sup_1"}"#,
+        )
+        .unwrap();
+        assert_eq!(metadata.role, Role::Supplier);
+        assert_eq!(metadata.supplier_id, Some(String::from("sup_1")));
+    }
+}
+
+mod supplier_orders {
+    use crate::common::fakes::{
+        InMemoryObjectStorage, InMemoryOrders, InMemoryParts, InMemorySuppliers,
+    };
+    use crate::common::fixtures::{order, part, quotation};
+    use api::orders::models::order::{Order, OrderStatus};
+    use api::parts::models::part::Part;
+    use api::quotations::models::quotation::QuoteStatus;
+    use api::shared::error::Error;
+    use api::shared::UseCase;
+    use api::suppliers::models::inputs::{
+        AdminAssignOrderSupplierInput, GetSupplierOrderInput, QuerySupplierOrdersInput,
+    };
+    use api::suppliers::models::supplier::Supplier;
+    use api::suppliers::usecases::admin_assign_order_supplier::AdminAssignOrderSupplier;
+    use api::suppliers::usecases::get_supplier_order::GetSupplierOrder;
+    use api::suppliers::usecases::query_supplier_orders::QuerySupplierOrders;
+    use std::sync::Arc;
+
+    struct Setup {
+        supplier: Supplier,
+        order: Order,
+        part: Part,
+        orders: Arc<InMemoryOrders>,
+        parts: Arc<InMemoryParts>,
+    }
+
+    /// Order assigned to a supplier by an admin, next to another order still unassigned.
+    async fn setup() -> Setup {
+        let supplier = Supplier::new(
+            String::from("Maquinados del Norte"),
+            String::from("ventas@maquinados.mx"),
+            String::from("MX"),
+        );
+        let part = part(&quotation(QuoteStatus::Payed), "models/part.step");
+        let mut order = order(OrderStatus::Open);
+        order.part_id = part.id.clone();
+        let orders = Arc::new(InMemoryOrders::new(vec![
+            order.clone(),
+            self::order(OrderStatus::Open),
+        ]));
+
+        AdminAssignOrderSupplier::new(
+            orders.clone(),
+            Arc::new(InMemorySuppliers::new(vec![supplier.clone()])),
+        )
+        .execute(AdminAssignOrderSupplierInput {
+            customer_id: order.customer_id.clone(),
+            order_id: order.id.clone(),
+            supplier_id: supplier.id.clone(),
+        })
+        .await
+        .unwrap();
+
+        Setup {
+            supplier,
+            order,
+            part: part.clone(),
+            orders,
+            parts: Arc::new(InMemoryParts::new(vec![part])),
+        }
+    }
+
+    fn get_supplier_order(setup: &Setup) -> GetSupplierOrder<InMemoryOrders, InMemoryParts> {
+        GetSupplierOrder::new(
+            setup.orders.clone(),
+            setup.parts.clone(),
+            Arc::new(InMemoryObjectStorage::default()),
+        )
+    }
+
+    #[tokio::test]
+    async fn it_should_give_suppliers_the_orders_assigned_to_them() {
+        let setup = setup().await;
+
+        let response = get_supplier_order(&setup)
+            .execute(GetSupplierOrderInput {
+                supplier_id: setup.supplier.id.clone(),
+                order_id: setup.order.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(response.order.id, setup.order.id);
+        assert_eq!(response.part.part_id, setup.part.id);
+        assert_eq!(
+            response.part.model_file_url,
+            "https://storage.test/get/models/part.step"
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_give_suppliers_orders_of_other_suppliers() {
+        let setup = setup().await;
+
+        let result = get_supplier_order(&setup)
+            .execute(GetSupplierOrderInput {
+                supplier_id: String::from("sup_other"),
+                order_id: setup.order.id.clone(),
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::ItemNotFoundError)));
+    }
+
+    #[tokio::test]
+    async fn it_should_only_list_the_orders_of_the_supplier() {
+        let setup = setup().await;
+        let usecase = QuerySupplierOrders::new(setup.orders.clone());
+        let query = |supplier_id: &str| QuerySupplierOrdersInput {
+            supplier_id: String::from(supplier_id),
+            cursor: None,
+            limit: 10,
+        };
+
+        let response = usecase.execute(query(&setup.supplier.id)).await.unwrap();
+        let order_ids: Vec<_> = response.orders.iter().map(|order| &order.id).collect();
+        assert_eq!(order_ids, vec![&setup.order.id]);
+
+        let response = usecase.execute(query("sup_other")).await.unwrap();
+        assert!(response.orders.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_not_assign_orders_to_unknown_suppliers() {
+        let setup = setup().await;
+        let usecase = AdminAssignOrderSupplier::new(
+            setup.orders.clone(),
+            Arc::new(InMemorySuppliers::default()),
+        );
+
+        let result = usecase
+            .execute(AdminAssignOrderSupplierInput {
+                customer_id: setup.order.customer_id.clone(),
+                order_id: setup.order.id.clone(),
+                supplier_id: String::from("sup_other"),
+            })
+            .await;
+
+        assert!(matches!(result, Err(Error::ItemNotFoundError)));
+        assert_eq!(
+            setup.orders.order(&setup.order.id).supplier_id,
+            Some(setup.supplier.id)
+        );
+    }
+}
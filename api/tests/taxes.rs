@@ -0,0 +1,136 @@
+mod rate_table_tax_calculator {
+    use api::config::{ConfigTaxRate, ConfigTaxes};
+    use api::orders::models::order::Address;
+    use api::services::tax_calculator::TaxCalculator;
+    use api::services::tax_calculator_rate_table::RateTableTaxCalculator;
+    use api::shared::error::Error;
+    use api::shared::money::Money;
+    use api::shared::tax::{total_with_taxes, TaxJurisdiction};
+    use iso_currency::Currency;
+
+    fn tax_rate(country: &str, state: Option<&str>, name: &str, rate: f64) -> ConfigTaxRate {
+        ConfigTaxRate {
+            country: String::from(country),
+            state: state.map(String::from),
+            name: String::from(name),
+            rate,
+            stripe_tax_rate_id: format!("txr_{country}_{}_{name}", state.unwrap_or("all")),
+        }
+    }
+
+    fn tax_calculator() -> RateTableTaxCalculator {
+        RateTableTaxCalculator::new(ConfigTaxes {
+            default_country: String::from("MX"),
+            rates: vec![
+                tax_rate("MX", None, "IVA", 0.16),
+                tax_rate("MX", Some("BC"), "IVA", 0.08),
+                tax_rate("US", None, "Sales tax", 0.0),
+                tax_rate("US", Some("TX"), "Sales tax", 0.0625),
+            ],
+        })
+    }
+
+    fn address(country: &str, state: &str) -> Address {
+        Address {
+            country: Some(String::from(country)),
+            state: Some(String::from(state)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_should_tax_quotations_without_an_address_in_the_default_country() {
+        let tax_calculator = tax_calculator();
+        let jurisdiction = tax_calculator.jurisdiction(None).unwrap();
+
+        assert_eq!(
+            jurisdiction,
+            TaxJurisdiction {
+                country: String::from("MX"),
+                state: None,
+            }
+        );
+        assert_eq!(
+            tax_calculator.stripe_tax_rate_ids(&jurisdiction).unwrap(),
+            vec![String::from("txr_MX_all_IVA")]
+        );
+    }
+
+    #[test]
+    fn it_should_replace_the_country_rate_with_the_state_one() {
+        let tax_calculator = tax_calculator();
+        let jurisdiction = tax_calculator
+            .jurisdiction(Some(&address(" mx", "bc ")))
+            .unwrap();
+
+        let tax_lines = tax_calculator
+            .tax_lines(
+                &jurisdiction,
+                Currency::MXN,
+                &[Money::new(10_000, Currency::MXN)],
+            )
+            .unwrap();
+
+        assert_eq!(jurisdiction.state.as_deref(), Some("BC"));
+        assert_eq!(tax_lines.len(), 1);
+        assert_eq!(tax_lines[0].rate, 0.08);
+        assert_eq!(tax_lines[0].amount, Money::new(800, Currency::MXN));
+        assert_eq!(
+            tax_calculator.stripe_tax_rate_ids(&jurisdiction).unwrap(),
+            vec![String::from("txr_MX_BC_IVA")]
+        );
+    }
+
+    #[test]
+    fn it_should_tax_states_without_rates_like_the_rest_of_the_country() {
+        let tax_calculator = tax_calculator();
+        let jurisdiction = tax_calculator
+            .jurisdiction(Some(&address("MX", "NLE")))
+            .unwrap();
+
+        assert_eq!(jurisdiction.state, None);
+        assert_eq!(
+            tax_calculator
+                .tax_lines(
+                    &jurisdiction,
+                    Currency::MXN,
+                    &[Money::new(10_000, Currency::MXN)]
+                )
+                .unwrap()[0]
+                .rate,
+            0.16
+        );
+    }
+
+    #[test]
+    fn it_should_round_taxes_per_line_like_stripe() {
+        let tax_calculator = tax_calculator();
+        let jurisdiction = tax_calculator
+            .jurisdiction(Some(&address("US", "TX")))
+            .unwrap();
+        let line_amounts = [
+            Money::new(1_010, Currency::USD),
+            Money::new(1_010, Currency::USD),
+        ];
+
+        let tax_lines = tax_calculator
+            .tax_lines(&jurisdiction, Currency::USD, &line_amounts)
+            .unwrap();
+        let subtotal = Money::checked_sum(Currency::USD, &line_amounts).unwrap();
+
+        // 63.125 cents rounds to 63 per line, instead of 126.25 rounding to 126 on the subtotal.
+        assert_eq!(tax_lines[0].amount, Money::new(126, Currency::USD));
+        assert_eq!(
+            total_with_taxes(&subtotal, &tax_lines).unwrap(),
+            Money::new(2_146, Currency::USD)
+        );
+    }
+
+    #[test]
+    fn it_should_reject_countries_without_rates() {
+        assert!(matches!(
+            tax_calculator().jurisdiction(Some(&address("CA", "ON"))),
+            Err(Error::UnsupportedShippingCountry(country)) if country == "CA"
+        ));
+    }
+}
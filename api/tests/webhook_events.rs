@@ -0,0 +1,61 @@
+mod webhook_event {
+    use api::payments::models::webhook_event::{WebhookEvent, WebhookEventStatus};
+    use stripe::{Event, EventType};
+
+    fn event() -> Event {
+        serde_json::from_str(
+            r#"{
+                "id": "evt_1",
+                "object": "event",
+                "type": "checkout.session.completed",
+                "created": 1700000000,
+                "livemode": false,
+                "pending_webhooks": 1,
+                "data": {
+                    "object": {
+                        "id": "cs_1",
+                        "object": "checkout.session",
+                        "automatic_tax": { "enabled": false },
+                        "created": 1700000000,
+                        "custom_fields": [],
+                        "custom_text": {},
+                        "expires_at": 1700086400,
+                        "livemode": false,
+                        "mode": "payment",
+                        "payment_method_types": ["card"],
+                        "payment_status": "paid",
+                        "shipping_options": [],
+                        "status": "complete",
+                        "metadata": {}
+                    }
+                }
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn it_should_keep_the_event_to_replay_it() {
+        let webhook_event = WebhookEvent::new(&event());
+
+        assert_eq!(webhook_event.id, "evt_1");
+        assert_eq!(webhook_event.event_type, "checkout.session.completed");
+        assert_eq!(webhook_event.status, WebhookEventStatus::Processed);
+
+        let replayed = webhook_event.event().unwrap();
+        assert_eq!(replayed.id.to_string(), "evt_1");
+        assert_eq!(replayed.type_, EventType::CheckoutSessionCompleted);
+    }
+
+    #[test]
+    fn it_should_record_the_failure_until_processed() {
+        let webhook_event = WebhookEvent::new(&event()).failed(String::from("boom"));
+
+        assert_eq!(webhook_event.status, WebhookEventStatus::Failed);
+        assert_eq!(webhook_event.error.as_deref(), Some("boom"));
+
+        let webhook_event = webhook_event.processed();
+        assert_eq!(webhook_event.status, WebhookEventStatus::Processed);
+        assert_eq!(webhook_event.error, None);
+    }
+}
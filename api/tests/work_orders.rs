@@ -0,0 +1,339 @@
+#[path = "common/mod.rs"]
+mod common;
+
+mod work_orders {
+    use crate::common::fixtures;
+    use api::orders::models::order::OrderStatus;
+    use api::orders::models::work_order::{OperationStatus, TimeLog, WorkOrder};
+    use api::parts::models::part_attributes::{
+        CNCAttributes, InspectionLevel, PartAttributes, SheetMetalAttributes,
+    };
+    use api::shared::error::Error;
+    use chrono::Utc;
+
+    fn operation_names(work_order: &WorkOrder) -> Vec<&str> {
+        work_order
+            .operations
+            .iter()
+            .map(|operation| operation.name.as_str())
+            .collect()
+    }
+
+    fn time_log(minutes: u64) -> TimeLog {
+        TimeLog {
+            operator_id: String::from("operator_id"),
+            minutes,
+            note: None,
+            logged_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn it_should_route_cnc_parts_through_their_finish_and_inspection() {
+        let attributes = PartAttributes::CNC(CNCAttributes {
+            surface_finish: Some(String::from("Anodized Type II")),
+            color: Some(String::from("Black")),
+            inspection_level: InspectionLevel::FirstArticle,
+            ..CNCAttributes::default()
+        });
+
+        let order = fixtures::order(OrderStatus::Open);
+        let work_order = WorkOrder::new(&order, 10, &attributes);
+
+        assert!(work_order.id.starts_with("wo_"));
+        assert_eq!(work_order.order_id, order.id);
+        assert_eq!(work_order.quantity, 10);
+        assert_eq!(
+            operation_names(&work_order),
+            vec![
+                "Saw",
+                "Mill Op 1",
+                "Mill Op 2",
+                "Deburr",
+                "Anodized Type II (Black)",
+                "First Article Inspection",
+            ]
+        );
+        assert_eq!(
+            work_order
+                .operations
+                .iter()
+                .map(|operation| operation.sequence)
+                .collect::<Vec<u32>>(),
+            vec![10, 20, 30, 40, 50, 60]
+        );
+    }
+
+    #[test]
+    fn it_should_only_bend_sheet_metal_parts_with_bends() {
+        let flat = PartAttributes::SheetMetal(SheetMetalAttributes::default());
+        let bent = PartAttributes::SheetMetal(SheetMetalAttributes {
+            bend_count: 2,
+            ..SheetMetalAttributes::default()
+        });
+
+        assert_eq!(
+            operation_names(&WorkOrder::new(
+                &fixtures::order(OrderStatus::Open),
+                1,
+                &flat
+            )),
+            vec!["Laser Cut", "Deburr", "Inspect"]
+        );
+        assert_eq!(
+            operation_names(&WorkOrder::new(
+                &fixtures::order(OrderStatus::Open),
+                1,
+                &bent
+            )),
+            vec!["Laser Cut", "Deburr", "Bend", "Inspect"]
+        );
+    }
+
+    #[test]
+    fn it_should_do_operations_in_the_order_of_the_routing() {
+        let mut work_order = WorkOrder::new(
+            &fixtures::order(OrderStatus::Open),
+            1,
+            &PartAttributes::default(),
+        );
+        let operator_id = String::from("operator_id");
+
+        assert!(matches!(
+            work_order.start_operation(20, operator_id.clone(), Utc::now()),
+            Err(Error::InvalidWorkOrderOperation(_))
+        ));
+        assert!(matches!(
+            work_order.complete_operation(10, operator_id.clone(), Utc::now()),
+            Err(Error::InvalidWorkOrderOperation(_))
+        ));
+
+        work_order
+            .start_operation(10, operator_id.clone(), Utc::now())
+            .unwrap();
+        assert!(matches!(
+            work_order.start_operation(10, operator_id.clone(), Utc::now()),
+            Err(Error::InvalidWorkOrderOperation(_))
+        ));
+        work_order
+            .complete_operation(10, operator_id.clone(), Utc::now())
+            .unwrap();
+        work_order
+            .start_operation(20, operator_id.clone(), Utc::now())
+            .unwrap();
+
+        assert_eq!(work_order.operations[0].status, OperationStatus::Completed);
+        assert_eq!(work_order.operations[0].completed_by, Some(operator_id));
+        assert_eq!(work_order.operations[1].status, OperationStatus::InProgress);
+        assert!(matches!(
+            work_order.start_operation(15, String::from("operator_id"), Utc::now()),
+            Err(Error::InvalidWorkOrderOperation(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_move_the_order_along_with_the_operations() {
+        let mut work_order = WorkOrder::new(
+            &fixtures::order(OrderStatus::Open),
+            1,
+            &PartAttributes::default(),
+        );
+        let operator_id = String::from("operator_id");
+        assert!(work_order.reached_order_statuses().is_empty());
+
+        work_order
+            .start_operation(10, operator_id.clone(), Utc::now())
+            .unwrap();
+        assert_eq!(
+            work_order.reached_order_statuses(),
+            vec![OrderStatus::InProgress]
+        );
+
+        let sequences: Vec<u32> = work_order
+            .operations
+            .iter()
+            .map(|operation| operation.sequence)
+            .collect();
+        for (index, sequence) in sequences.into_iter().enumerate() {
+            if index > 0 {
+                work_order
+                    .start_operation(sequence, operator_id.clone(), Utc::now())
+                    .unwrap();
+            }
+            work_order
+                .complete_operation(sequence, operator_id.clone(), Utc::now())
+                .unwrap();
+        }
+
+        assert!(work_order.is_completed());
+        assert_eq!(
+            work_order.reached_order_statuses(),
+            vec![OrderStatus::InProgress, OrderStatus::Ready]
+        );
+    }
+
+    #[test]
+    fn it_should_only_log_time_on_started_operations() {
+        let mut work_order = WorkOrder::new(
+            &fixtures::order(OrderStatus::Open),
+            1,
+            &PartAttributes::default(),
+        );
+
+        assert!(matches!(
+            work_order.log_time(10, time_log(30)),
+            Err(Error::InvalidWorkOrderOperation(_))
+        ));
+
+        work_order
+            .start_operation(10, String::from("operator_id"), Utc::now())
+            .unwrap();
+        assert!(matches!(
+            work_order.log_time(10, time_log(0)),
+            Err(Error::InvalidTimeLog(_))
+        ));
+        work_order.log_time(10, time_log(30)).unwrap();
+        work_order.log_time(10, time_log(15)).unwrap();
+
+        assert_eq!(work_order.operations[0].time_logs.len(), 2);
+    }
+}
+
+mod admin_update_work_order_operation {
+    use crate::common::fakes::{
+        identity, FakeIdentityManager, InMemoryOrders, InMemoryWorkOrders, RecordingEmailer,
+    };
+    use crate::common::fixtures::order;
+    use api::auth::models::session::Role;
+    use api::orders::models::inputs::{AdminUpdateWorkOrderOperationInput, OperationUpdate};
+    use api::orders::models::order::{Order, OrderStatus};
+    use api::orders::models::work_order::{OperationStatus, WorkOrder};
+    use api::orders::usecases::admin_update_order_status::AdminUpdateOrderStatus;
+    use api::orders::usecases::admin_update_work_order_operation::AdminUpdateWorkOrderOperation;
+    use api::parts::models::part_attributes::PartAttributes;
+    use api::shared::error::Error;
+    use api::shared::{Result, UseCase};
+    use std::sync::Arc;
+
+    struct Setup {
+        orders: Arc<InMemoryOrders>,
+        work_orders: Arc<InMemoryWorkOrders>,
+        usecase: AdminUpdateWorkOrderOperation<InMemoryOrders, InMemoryWorkOrders>,
+    }
+
+    fn setup(order: &Order) -> Setup {
+        let orders = Arc::new(InMemoryOrders::new(vec![order.clone()]));
+        let work_orders = Arc::new(InMemoryWorkOrders::new(vec![WorkOrder::new(
+            order,
+            1,
+            &PartAttributes::default(),
+        )]));
+        let usecase = AdminUpdateWorkOrderOperation::new(
+            orders.clone(),
+            work_orders.clone(),
+            Arc::new(AdminUpdateOrderStatus::new(
+                orders.clone(),
+                Arc::new(FakeIdentityManager),
+                Arc::new(RecordingEmailer::default()),
+            )),
+        );
+
+        Setup {
+            orders,
+            work_orders,
+            usecase,
+        }
+    }
+
+    async fn update(
+        setup: &Setup,
+        order: &Order,
+        sequence: u32,
+        update: OperationUpdate,
+    ) -> Result<WorkOrder> {
+        setup
+            .usecase
+            .execute(AdminUpdateWorkOrderOperationInput {
+                identity: identity("operator_id", Role::Admin),
+                customer_id: order.customer_id.clone(),
+                order_id: order.id.clone(),
+                sequence,
+                update,
+            })
+            .await
+    }
+
+    #[tokio::test]
+    async fn it_should_move_the_order_along_with_the_operations() {
+        let order = order(OrderStatus::Open);
+        let setup = setup(&order);
+        let sequences: Vec<u32> = setup.work_orders.work_orders.lock().unwrap()[0]
+            .operations
+            .iter()
+            .map(|operation| operation.sequence)
+            .collect();
+
+        let work_order = update(&setup, &order, sequences[0], OperationUpdate::Start)
+            .await
+            .unwrap();
+        assert_eq!(work_order.operations[0].status, OperationStatus::InProgress);
+        assert_eq!(
+            setup.orders.order(&order.id).status,
+            OrderStatus::InProgress
+        );
+
+        for (index, sequence) in sequences.iter().enumerate() {
+            if index > 0 {
+                update(&setup, &order, *sequence, OperationUpdate::Start)
+                    .await
+                    .unwrap();
+            }
+            update(&setup, &order, *sequence, OperationUpdate::Complete)
+                .await
+                .unwrap();
+        }
+
+        let order = setup.orders.order(&order.id);
+        assert_eq!(order.status, OrderStatus::Ready);
+        let statuses: Vec<_> = order
+            .status_history
+            .iter()
+            .map(|transition| (transition.from.clone(), transition.to.clone()))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (OrderStatus::Open, OrderStatus::InProgress),
+                (OrderStatus::InProgress, OrderStatus::Ready),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_move_orders_back_when_operations_start() {
+        let order = order(OrderStatus::Ready);
+        let setup = setup(&order);
+
+        update(&setup, &order, 10, OperationUpdate::Start)
+            .await
+            .unwrap();
+
+        let order = setup.orders.order(&order.id);
+        assert_eq!(order.status, OrderStatus::Ready);
+        assert!(order.status_history.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_should_not_update_operations_of_orders_that_left_the_shop() {
+        let order = order(OrderStatus::Shipped);
+        let setup = setup(&order);
+
+        let result = update(&setup, &order, 10, OperationUpdate::Start).await;
+
+        assert!(matches!(result, Err(Error::InvalidWorkOrderOperation(_))));
+        assert_eq!(
+            setup.work_orders.work_orders.lock().unwrap()[0].operations[0].status,
+            OperationStatus::Pending
+        );
+    }
+}
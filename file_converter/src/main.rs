@@ -0,0 +1,53 @@
+use api::app::load_lambda_config;
+use api::app_state::AppStateParts;
+use api::parts::models::inputs::ConvertPartModelInput;
+use api::parts::models::part::Part;
+use api::parts::models::part_file_key::PartFileKey;
+use api::parts::usecases::convert_part_model::ConvertPartModel;
+use api::shared::UseCase;
+use aws_lambda_events::event::s3::S3Event;
+use lambda_runtime::{run, service_fn, Error, LambdaEvent};
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt::init();
+
+    let config = load_lambda_config().await;
+    let parts = AppStateParts::from(&config).await;
+    let usecase = ConvertPartModel::new(parts.dynamodb_parts, parts.s3, parts.model_converter);
+
+    run(service_fn(|event: LambdaEvent<S3Event>| {
+        handle_event(&usecase, event)
+    }))
+    .await
+}
+
+/// Converts every uploaded model in the event. Failures are recorded on the part by the use
+/// case, so they are only logged here to avoid S3 retrying the whole batch.
+async fn handle_event(
+    usecase: &impl UseCase<ConvertPartModelInput, Part>,
+    event: LambdaEvent<S3Event>,
+) -> Result<(), Error> {
+    for record in event.payload.records {
+        let Some(event_key) = record.s3.object.key else {
+            continue;
+        };
+        let key = match PartFileKey::from_event_key(&event_key) {
+            Ok(part_file_key) => part_file_key.to_string(),
+            Err(err) => {
+                tracing::error!("failed to convert `{event_key}`: {err:?}");
+                continue;
+            }
+        };
+
+        match usecase
+            .execute(ConvertPartModelInput { key: key.clone() })
+            .await
+        {
+            Ok(_) => tracing::info!("converted `{key}`"),
+            Err(err) => tracing::error!("failed to convert `{key}`: {err:?}"),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use api::app::load_lambda_config;
+use api::app_state::AppState;
+use api::quotations::models::responses::ExpireQuotationsResponse;
+use api::quotations::usecases::expire_quotations::ExpireQuotations;
+use api::shared::UseCase;
+use aws_lambda_events::event::eventbridge::EventBridgeEvent;
+use lambda_runtime::{run, service_fn, Error, LambdaEvent};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[tokio::main]
+async fn main() -> Result<(), Error> {
+    tracing_subscriber::fmt::init();
+
+    let config = load_lambda_config().await;
+    let app_state = AppState::from(&config).await;
+    let usecase = ExpireQuotations::new(
+        app_state.quotes.dynamodb_quotes,
+        app_state.parts.dynamodb_parts,
+        Arc::new(Mutex::new(app_state.payments.transaction)),
+        app_state.auth.ory_kratos,
+        app_state.services.emailer.ses,
+    );
+
+    run(service_fn(|event: LambdaEvent<EventBridgeEvent>| {
+        handle_event(&usecase, event)
+    }))
+    .await
+}
+
+/// Runs on a schedule, the event itself carries nothing the job needs.
+async fn handle_event(
+    usecase: &impl UseCase<(), ExpireQuotationsResponse>,
+    _: LambdaEvent<EventBridgeEvent>,
+) -> Result<(), Error> {
+    let response = usecase.execute(()).await.map_err(|err| {
+        tracing::error!("failed to expire quotations: {err:?}");
+        err
+    })?;
+
+    for quotation in response.quotations {
+        tracing::info!("expired quotation `{}`", quotation.id);
+    }
+
+    Ok(())
+}